@@ -0,0 +1,84 @@
+use std::{borrow::Cow, fmt::Write as _, path::PathBuf, sync::Arc};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rolldown::{Bundler, BundlerOptions, InputItem, ModuleType};
+use rolldown_plugin::{
+  __inner::SharedPluginable, HookFilter, HookTransformArgs, HookTransformReturn, HookUsage, Plugin,
+  SharedTransformPluginContext,
+};
+
+/// Only handles css modules, which the fixture doesn't have, so every call of its hook is a miss.
+#[derive(Debug)]
+struct CssOnly {
+  use_filter: bool,
+}
+
+impl Plugin for CssOnly {
+  fn name(&self) -> Cow<'static, str> {
+    "css-only".into()
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if !matches!(args.module_type, ModuleType::Css) {
+      return Ok(None);
+    }
+    unreachable!("the fixture has no css modules")
+  }
+
+  fn transform_filter(&self) -> Option<HookFilter> {
+    self
+      .use_filter
+      .then(|| HookFilter { module_type: Some(vec![ModuleType::Css]), ..Default::default() })
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Transform
+  }
+}
+
+/// Writes many small modules, so the time spent per transform call adds up.
+fn many_modules_fixture() -> PathBuf {
+  let dir = std::env::temp_dir().join("rolldown-bench-hook-filter");
+  std::fs::create_dir_all(&dir).unwrap();
+  let mut main = String::new();
+  for i in 0..2000 {
+    std::fs::write(dir.join(format!("mod{i}.js")), format!("export const value{i} = {i};\n"))
+      .unwrap();
+    writeln!(main, "export {{ value{i} }} from './mod{i}.js';").unwrap();
+  }
+  std::fs::write(dir.join("main.js"), main).unwrap();
+  dir
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+  let mut group = c.benchmark_group("hook_filter");
+  let cwd = many_modules_fixture();
+  let options = BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(cwd),
+    ..Default::default()
+  };
+
+  for (name, use_filter) in [("check_in_hook", false), ("filtered", true)] {
+    let options = options.clone();
+    group.bench_function(format!("transform_miss@{name}"), move |b| {
+      b.to_async(tokio::runtime::Runtime::new().unwrap()).iter(|| async {
+        let plugins =
+          (0..20).map(|_| Arc::new(CssOnly { use_filter }) as SharedPluginable).collect::<Vec<_>>();
+        let mut bundler = Bundler::with_plugins(options.clone(), plugins);
+        bundler.generate().await.expect("should not fail to bundle");
+      });
+    });
+  }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+use std::{fmt::Write as _, path::PathBuf, sync::Arc};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin_isolated_declaration::IsolatedDeclarationPlugin;
+
+/// Writes a large TS module whose function and class bodies don't contribute to the declaration,
+/// so the time is dominated by walking the AST rather than by emitting types.
+fn large_ts_fixture() -> PathBuf {
+  let dir = std::env::temp_dir().join("rolldown-bench-isolated-declaration");
+  std::fs::create_dir_all(&dir).unwrap();
+  std::fs::write(dir.join("types.ts"), "export interface Meta { weight: number }\n").unwrap();
+
+  let mut source = String::from("import type { Meta } from './types';\n\n");
+  for i in 0..5000 {
+    writeln!(
+      source,
+      "export interface Item{i} {{ id: number; tags: string[]; meta: Meta }}
+export function compute{i}(input: Item{i}, factor: number): number {{
+  let total = 0;
+  for (const tag of input.tags) {{
+    total += tag.length * factor + {i};
+  }}
+  return input.id > {i} ? total : Math.max(total, input.meta.weight);
+}}
+export class Store{i} {{
+  private items: Item{i}[] = [];
+  add(item: Item{i}): void {{
+    this.items.push(item);
+  }}
+}}"
+    )
+    .unwrap();
+  }
+  std::fs::write(dir.join("main.ts"), source).unwrap();
+  dir
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+  let mut group = c.benchmark_group("isolated_declaration");
+  let cwd = large_ts_fixture();
+  let options = BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.ts".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(cwd),
+    ..Default::default()
+  };
+
+  group.bench_function("bundle@large_ts", move |b| {
+    b.to_async(tokio::runtime::Runtime::new().unwrap()).iter(|| async {
+      let mut bundler = Bundler::with_plugins(
+        options.clone(),
+        vec![Arc::new(IsolatedDeclarationPlugin::new(false, false))],
+      );
+      bundler.generate().await.expect("should not fail to bundle");
+    });
+  });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,84 @@
+use std::{borrow::Cow, fmt::Write as _, path::PathBuf, sync::Arc};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, HookUsage, Plugin,
+  SharedTransformPluginContext,
+};
+
+/// Stands in for a costly transform, e.g. compiling another language, by rewriting each module
+/// many times.
+#[derive(Debug)]
+struct SlowTransform;
+
+impl Plugin for SlowTransform {
+  fn name(&self) -> Cow<'static, str> {
+    "slow-transform".into()
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let mut code = args.code.clone();
+    for _ in 0..100 {
+      code = code.replace("value", "VALUE").replace("VALUE", "value");
+    }
+    Ok(Some(HookTransformOutput { code: Some(code), ..Default::default() }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Transform
+  }
+}
+
+/// Writes many small modules, so the time spent per transform call adds up.
+fn many_modules_fixture() -> PathBuf {
+  let dir = std::env::temp_dir().join("rolldown-bench-persistent-cache");
+  std::fs::create_dir_all(&dir).unwrap();
+  let mut main = String::new();
+  for i in 0..2000 {
+    std::fs::write(dir.join(format!("mod{i}.js")), format!("export const value{i} = {i};\n"))
+      .unwrap();
+    writeln!(main, "export {{ value{i} }} from './mod{i}.js';").unwrap();
+  }
+  std::fs::write(dir.join("main.js"), main).unwrap();
+  dir
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+  let mut group = c.benchmark_group("persistent_cache");
+  let cwd = many_modules_fixture();
+  let cache_dir = cwd.join(".cache");
+  let options = BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(cwd),
+    cache_dir: Some(cache_dir.clone()),
+    ..Default::default()
+  };
+
+  // The cache is cleared before each cold build, and filled by the first warm one.
+  for (name, is_warm) in [("cold", false), ("warm", true)] {
+    let options = options.clone();
+    let cache_dir = cache_dir.clone();
+    let _ = std::fs::remove_dir_all(&cache_dir);
+    group.bench_function(format!("slow_transform@{name}"), move |b| {
+      b.to_async(tokio::runtime::Runtime::new().unwrap()).iter(|| async {
+        if !is_warm {
+          let _ = std::fs::remove_dir_all(&cache_dir);
+        }
+        let mut bundler = Bundler::with_plugins(options.clone(), vec![Arc::new(SlowTransform)]);
+        bundler.generate().await.expect("should not fail to bundle");
+      });
+    });
+  }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
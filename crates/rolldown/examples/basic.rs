@@ -10,7 +10,11 @@ async fn main() {
     input: Some(vec![
       "./entry.js".to_string().into(),
       InputItem { import: "./other-entry.js".to_string(), ..Default::default() },
-      InputItem { name: Some("third-entry".to_string()), import: "./third-entry.js".to_string() },
+      InputItem {
+        name: Some("third-entry".to_string()),
+        import: "./third-entry.js".to_string(),
+        ..Default::default()
+      },
     ]),
     cwd: Some(workspace::crate_dir("rolldown").join("./examples/basic").normalize()),
     sourcemap: Some(SourceMapType::File),
@@ -12,6 +12,7 @@ async fn main() {
     input: Some(vec![InputItem {
       name: Some("rome-ts".to_string()),
       import: root.join("tmp/bench/rome/src/entry.ts").to_str().unwrap().to_string(),
+      ..Default::default()
     }]),
     cwd: Some(project_root.join("examples")),
     sourcemap: Some(SourceMapType::File),
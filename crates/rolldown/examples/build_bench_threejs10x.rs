@@ -12,6 +12,7 @@ async fn main() {
     input: Some(vec![InputItem {
       name: Some("threejs10x".to_string()),
       import: root.join("tmp/bench/three10x/entry.js").to_str().unwrap().to_string(),
+      ..Default::default()
     }]),
     cwd: Some(project_root.join("examples")),
     sourcemap: Some(SourceMapType::File),
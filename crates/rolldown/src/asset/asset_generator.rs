@@ -24,8 +24,7 @@ impl Generator for AssetGenerator {
       let asset_view = asset_module.asset_view.unpack_ref();
       let preliminary_filename =
         ctx.chunk.asset_preliminary_filenames.get(&asset_module.idx).unpack();
-      let file_path =
-        ctx.options.cwd.as_path().join(&ctx.options.out_dir).join(preliminary_filename.as_str());
+      let file_path = ctx.options.resolved_out_dir().join(preliminary_filename.as_str());
       let file_dir = file_path.parent().expect("chunk file name should have a parent");
       instantiated_chunks.push(InstantiatedChunk {
         origin_chunk: ctx.chunk_idx,
@@ -0,0 +1,73 @@
+use oxc::ast::ast::{Expression, ObjectPropertyKind, WithClause};
+use rolldown_common::{ImportAttributes, ImportRecordIdx, ModuleType};
+
+use super::AstScanner;
+
+impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
+  /// Handle `with { ... }` of `import`, `export ... from` and `export * from` declarations.
+  pub fn set_import_attributes_from_with_clause(
+    &mut self,
+    record_id: ImportRecordIdx,
+    with_clause: Option<&WithClause<'_>>,
+  ) {
+    let Some(with_clause) = with_clause else {
+      return;
+    };
+    let attributes = with_clause
+      .with_entries
+      .iter()
+      .map(|attr| (attr.key.as_atom().as_str().into(), attr.value.value.as_str().into()))
+      .collect();
+    self.set_import_attributes(record_id, attributes);
+  }
+
+  /// Handle the exact `import('path', { with: { ... } })` pattern, where every attribute value is
+  /// a string literal.
+  pub fn set_import_attributes_from_import_options(
+    &mut self,
+    record_id: ImportRecordIdx,
+    options: Option<&Expression<'_>>,
+  ) {
+    let Some(Expression::ObjectExpression(options)) = options else {
+      return;
+    };
+    let with = options.properties.iter().find_map(|prop| match prop {
+      ObjectPropertyKind::ObjectProperty(prop) if prop.key.is_specific_static_name("with") => {
+        Some(&prop.value)
+      }
+      _ => None,
+    });
+    let Some(Expression::ObjectExpression(with)) = with else {
+      return;
+    };
+    let attributes = with
+      .properties
+      .iter()
+      .map(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(prop) if !prop.computed => {
+          let key = prop.key.static_name()?;
+          let Expression::StringLiteral(value) = &prop.value else {
+            return None;
+          };
+          Some((key.as_ref().into(), value.value.as_str().into()))
+        }
+        _ => None,
+      })
+      .collect::<Option<ImportAttributes>>();
+    if let Some(attributes) = attributes {
+      self.set_import_attributes(record_id, attributes);
+    }
+  }
+
+  fn set_import_attributes(&mut self, record_id: ImportRecordIdx, attributes: ImportAttributes) {
+    let record = &mut self.result.import_records[record_id];
+    record.attributes = Some(attributes);
+    // `with { type: 'json' }` and `with { type: 'css' }` pick the loader of the importee regardless
+    // of its extension.
+    match record.attribute_type() {
+      Some("json") => record.asserted_module_type = Some(ModuleType::Json),
+      Some("css") => record.asserted_module_type = Some(ModuleType::Css),
+      _ => {}
+    }
+  }
+}
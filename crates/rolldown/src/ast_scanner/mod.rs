@@ -3,6 +3,7 @@ pub mod dynamic_import;
 mod hmr;
 pub mod impl_visit;
 mod import_assign_analyzer;
+mod import_attributes;
 mod new_url;
 pub mod side_effect_detector;
 
@@ -518,6 +519,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         ImportRecordMeta::empty()
       },
     );
+    self.set_import_attributes_from_with_clause(id, decl.with_clause.as_deref());
     if let Some(exported) = &decl.exported {
       // export * as ns from '...'
       self.add_star_re_export(exported.name().as_str(), id, decl.span);
@@ -541,6 +543,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
           ImportRecordMeta::empty()
         },
       );
+      self.set_import_attributes_from_with_clause(record_id, decl.with_clause.as_deref());
       decl.specifiers.iter().for_each(|spec| {
         self.add_re_export(
           spec.exported.name().as_str(),
@@ -633,6 +636,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         ImportRecordMeta::empty()
       },
     );
+    self.set_import_attributes_from_with_clause(rec_id, decl.with_clause.as_deref());
     self.result.imports.insert(decl.span, rec_id);
     // // `import '...'` or `import {} from '...'`
     if decl.specifiers.as_ref().is_none_or(|s| s.is_empty()) {
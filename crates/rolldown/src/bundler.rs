@@ -1,4 +1,7 @@
-use super::stages::{link_stage::LinkStage, scan_stage::NormalizedScanStageOutput};
+use super::stages::{
+  link_stage::{LinkStage, LinkStageOutput},
+  scan_stage::NormalizedScanStageOutput,
+};
 use crate::{
   BundlerOptions, SharedOptions, SharedResolver,
   bundler_builder::BundlerBuilder,
@@ -7,27 +10,39 @@ use crate::{
     generate_stage::GenerateStage,
     scan_stage::{ScanStage, ScanStageOutput},
   },
-  types::{bundle_output::BundleOutput, scan_stage_cache::ScanStageCache},
+  types::{
+    bundle_output::{BundleOutput, CancellableBundleOutput},
+    scan_stage_cache::ScanStageCache,
+  },
+  utils::{
+    check_duplicate_packages::check_duplicate_packages, emit_manifest::emit_manifest,
+    normalize_options::normalize_output_options,
+  },
 };
 use anyhow::Result;
 
 use arcstr::ArcStr;
 use rolldown_common::{
-  GetLocalDbMut, HmrOutput, Module, NormalizedBundlerOptions, ScanMode, SharedFileEmitter,
-  SymbolRefDb,
+  CancellationToken, GetLocalDbMut, HmrChange, HmrOutput, Log, LogAction, LogLevel, Module,
+  NormalModule, NormalizedBundlerOptions, OutputOptions, ScanMode, SharedFileEmitter,
+  SharedFileSystem, SymbolRefDb,
 };
 use rolldown_debug::{action, trace_action};
-use rolldown_error::{BuildDiagnostic, BuildResult};
-use rolldown_fs::{FileSystem, OsFileSystem};
+use rolldown_error::{
+  BuildDiagnostic, BuildResult, EventKindSwitcher, Severity, dedupe_diagnostics,
+  filter_out_disabled_diagnostics,
+};
+use rolldown_fs::FileSystem;
 use rolldown_plugin::{
-  __inner::SharedPluginable, HookBuildEndArgs, HookRenderErrorArgs, SharedPluginDriver,
+  __inner::SharedPluginable, HookBuildEndArgs, HookRenderErrorArgs, LinkedModuleGraph,
+  SharedPluginDriver,
 };
 use rolldown_utils::dashmap::FxDashSet;
 use std::{any::Any, sync::Arc};
 
 pub struct Bundler {
   pub closed: bool,
-  pub(crate) fs: OsFileSystem,
+  pub(crate) fs: SharedFileSystem,
   pub(crate) options: SharedOptions,
   pub(crate) resolver: SharedResolver,
   pub(crate) file_emitter: SharedFileEmitter,
@@ -58,7 +73,11 @@ impl Bundler {
     trace_action!(action::BuildStart { action: "BuildStart" });
     let scan_stage_output = self.scan(vec![]).await?;
 
-    let ret = self.bundle_write(scan_stage_output).await;
+    let mut ret = self.bundle_write(scan_stage_output).await;
+    if let Ok(output) = &mut ret {
+      output.warnings =
+        Self::apply_on_log(&self.options, std::mem::take(&mut output.warnings)).await?;
+    }
     trace_action!(action::BuildEnd { action: "BuildEnd" });
     ret
   }
@@ -68,14 +87,131 @@ impl Bundler {
     trace_action!(action::BuildStart { action: "BuildStart" });
     let scan_stage_output = self.scan(vec![]).await?;
 
-    let ret = self.bundle_up(scan_stage_output, /* is_write */ false).await.map(|mut output| {
-      output.warnings.append(&mut self.warnings);
-      output
-    });
+    let options = Arc::clone(&self.options);
+    let mut ret =
+      self.bundle_up(scan_stage_output, &options, /* is_write */ false).await.map(|mut output| {
+        output.warnings.append(&mut self.warnings);
+        output
+      });
+    if let Ok(output) = &mut ret {
+      output.warnings =
+        Self::apply_on_log(&self.options, std::mem::take(&mut output.warnings)).await?;
+    }
+    trace_action!(action::BuildEnd { action: "BuildEnd" });
+    ret
+  }
+
+  /// Same as [Bundler::generate], but stops at the next phase boundary once `token` is cancelled,
+  /// returning [CancellableBundleOutput::Cancelled] instead of a partial output. Plugins could
+  /// observe it through `PluginContext::is_cancelled`.
+  #[tracing::instrument(level = "debug", skip_all, parent = &self.session_span)]
+  pub async fn generate_with_cancellation(
+    &mut self,
+    token: CancellationToken,
+  ) -> BuildResult<CancellableBundleOutput> {
+    self.plugin_driver.set_cancellation_token(Some(token));
+    let ret = self.generate_cancellable().await;
+    self.plugin_driver.set_cancellation_token(None);
+    ret
+  }
+
+  async fn generate_cancellable(&mut self) -> BuildResult<CancellableBundleOutput> {
+    trace_action!(action::BuildStart { action: "BuildStart" });
+    let ret = self.bundle_up_until_cancelled().await;
+    if matches!(ret, Ok(CancellableBundleOutput::Cancelled)) {
+      // A cancelled build has no output to report its warnings with. Dropping them keeps them from
+      // showing up in the next build.
+      self.warnings.clear();
+      self.plugin_driver.take_warnings();
+    }
     trace_action!(action::BuildEnd { action: "BuildEnd" });
     ret
   }
 
+  async fn bundle_up_until_cancelled(&mut self) -> BuildResult<CancellableBundleOutput> {
+    let scan_stage_output = self.scan(vec![]).await?;
+    if self.plugin_driver.is_cancelled() {
+      return Ok(CancellableBundleOutput::Cancelled);
+    }
+
+    let options = Arc::clone(&self.options);
+    let Some(mut output) =
+      self.bundle_up_cancellable(scan_stage_output, &options, /* is_write */ false).await?
+    else {
+      return Ok(CancellableBundleOutput::Cancelled);
+    };
+    output.warnings.append(&mut self.warnings);
+    output.warnings =
+      Self::apply_on_log(&self.options, std::mem::take(&mut output.warnings)).await?;
+    Ok(CancellableBundleOutput::Completed(output))
+  }
+
+  /// Generates one [BundleOutput] for each of `outputs`. The scan stage and the build hooks only run
+  /// once, while the link and generate stage run for every output in order.
+  #[tracing::instrument(level = "debug", skip_all, parent = &self.session_span)]
+  pub async fn generate_multi(
+    &mut self,
+    outputs: Vec<OutputOptions>,
+  ) -> BuildResult<Vec<BundleOutput>> {
+    self.bundle_multi(outputs, /* is_write */ false).await
+  }
+
+  /// Same as [Bundler::generate_multi], but writes each output to its own directory.
+  #[tracing::instrument(level = "debug", skip_all, parent = &self.session_span)]
+  pub async fn write_multi(
+    &mut self,
+    outputs: Vec<OutputOptions>,
+  ) -> BuildResult<Vec<BundleOutput>> {
+    self.bundle_multi(outputs, /* is_write */ true).await
+  }
+
+  async fn bundle_multi(
+    &mut self,
+    outputs: Vec<OutputOptions>,
+    is_write: bool,
+  ) -> BuildResult<Vec<BundleOutput>> {
+    trace_action!(action::BuildStart { action: "BuildStart" });
+    let mut scan_stage_output = self.scan(vec![]).await?;
+    // Warnings of the scan stage are reported only once, in the first output.
+    let mut scan_warnings = std::mem::take(&mut scan_stage_output.warnings);
+    // Files emitted by build hooks are shared by all outputs.
+    let build_phase_files = self.file_emitter.snapshot_build_phase_files();
+
+    let outputs_len = outputs.len();
+    let mut scan_stage_output = Some(scan_stage_output);
+    let mut bundle_outputs = Vec::with_capacity(outputs_len);
+    for (index, mut output_options) in outputs.into_iter().enumerate() {
+      self.plugin_driver.output_options(&mut output_options);
+      let options: SharedOptions =
+        Arc::new(normalize_output_options(&self.options, output_options));
+      // Only the last output could take the scan stage output, the others render from a copy.
+      let mut scan_stage_output = if index + 1 == outputs_len {
+        scan_stage_output.take().expect("should have scan stage output for the last output")
+      } else {
+        scan_stage_output
+          .as_ref()
+          .expect("should have scan stage output")
+          .clone_with_another_arena()
+      };
+      scan_stage_output.warnings = std::mem::take(&mut scan_warnings);
+      if index > 0 {
+        self.file_emitter.restore_build_phase_files(&build_phase_files);
+      }
+
+      let mut output = if is_write {
+        self.bundle_write_with_options(scan_stage_output, &options).await?
+      } else {
+        let mut output = self.bundle_up(scan_stage_output, &options, is_write).await?;
+        output.warnings.append(&mut self.warnings);
+        output
+      };
+      output.warnings = Self::apply_on_log(&options, std::mem::take(&mut output.warnings)).await?;
+      bundle_outputs.push(output);
+    }
+    trace_action!(action::BuildEnd { action: "BuildEnd" });
+    Ok(bundle_outputs)
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn close(&mut self) -> Result<()> {
     if self.closed {
@@ -95,14 +231,33 @@ impl Bundler {
   }
 
   #[tracing::instrument(target = "devtool", level = "debug", skip_all)]
-  pub async fn scan(&mut self, changed_ids: Vec<ArcStr>) -> BuildResult<NormalizedScanStageOutput> {
+  pub async fn scan(
+    &mut self,
+    mut changed_ids: Vec<ArcStr>,
+  ) -> BuildResult<NormalizedScanStageOutput> {
     trace_action!(action::BuildStart { action: "BuildStart" });
+    self.plugin_driver.reset_linked_module_graph();
+    // Modules that registered a changed file via `add_watch_file_dependency` in `transform` need to be
+    // transformed again.
+    for dependent in changed_ids
+      .iter()
+      .flat_map(|id| self.plugin_driver.get_watch_file_dependents(id))
+      .collect::<Vec<_>>()
+    {
+      if !changed_ids.contains(&dependent) {
+        changed_ids.push(dependent);
+      }
+    }
     let mode =
       if !self.options.experimental.is_incremental_build_enabled() || changed_ids.is_empty() {
         ScanMode::Full
       } else {
         ScanMode::Partial(changed_ids)
       };
+    self.plugin_driver.clear_watch_file_dependents(match &mode {
+      ScanMode::Full => None,
+      ScanMode::Partial(changed_ids) => Some(changed_ids),
+    });
     let is_full_scan_mode = mode.is_full();
 
     // Make sure the cache is reset if incremental build is not enabled.
@@ -114,7 +269,7 @@ impl Bundler {
     let scan_stage_output = match ScanStage::new(
       Arc::clone(&self.options),
       Arc::clone(&self.plugin_driver),
-      self.fs,
+      self.fs.clone(),
       Arc::clone(&self.resolver),
       self.session_span.clone(),
     )
@@ -127,6 +282,8 @@ impl Bundler {
           .plugin_driver
           .build_end(Some(&HookBuildEndArgs { errors: &errs, cwd: &self.options.cwd }))
           .await?;
+        // A failed build is closed right away, so a later `close()` must not call `closeBundle` again.
+        self.closed = true;
         self.plugin_driver.close_bundle().await?;
         return Err(errs);
       }
@@ -135,10 +292,22 @@ impl Bundler {
     // Manually drop it to avoid holding the mut reference.
     drop(scan_stage_cache_guard);
 
-    let scan_stage_output =
+    let mut scan_stage_output =
       self.normalize_scan_stage_output_and_update_cache(scan_stage_output, is_full_scan_mode);
+    if self.options.debug_plugin_order {
+      scan_stage_output
+        .warnings
+        .push(BuildDiagnostic::plugin_order(self.plugin_driver.describe_hook_order()));
+    }
+    if self.options.checks.contains(EventKindSwitcher::DuplicatePackage) {
+      check_duplicate_packages(
+        &scan_stage_output.module_table,
+        &self.plugin_driver,
+        &mut scan_stage_output.warnings,
+      );
+    }
 
-    Self::trace_action_module_graph_ready(&scan_stage_output);
+    Self::trace_action_module_graph_ready(&scan_stage_output, &self.plugin_driver);
     self.plugin_driver.build_end(None).await?;
     trace_action!(action::BuildEnd { action: "BuildEnd" });
     Ok(scan_stage_output)
@@ -167,9 +336,18 @@ impl Bundler {
     &mut self,
     scan_stage_output: NormalizedScanStageOutput,
   ) -> BuildResult<BundleOutput> {
-    let mut output = self.bundle_up(scan_stage_output, /* is_write */ true).await?;
+    let options = Arc::clone(&self.options);
+    self.bundle_write_with_options(scan_stage_output, &options).await
+  }
+
+  async fn bundle_write_with_options(
+    &mut self,
+    scan_stage_output: NormalizedScanStageOutput,
+    options: &SharedOptions,
+  ) -> BuildResult<BundleOutput> {
+    let mut output = self.bundle_up(scan_stage_output, options, /* is_write */ true).await?;
 
-    let dist_dir = self.options.cwd.join(&self.options.out_dir);
+    let dist_dir = options.resolved_out_dir();
 
     self.fs.create_dir_all(&dist_dir).map_err(|err| {
       anyhow::anyhow!("Could not create directory for output chunks: {:?}", dist_dir).context(err)
@@ -188,22 +366,33 @@ impl Bundler {
         .map_err(|err| anyhow::anyhow!("Failed to write file in {:?}", dest).context(err))?;
     }
 
-    self
-      .plugin_driver
-      .write_bundle(&mut output.assets, &self.options, &mut output.warnings)
-      .await?;
+    self.plugin_driver.write_bundle(&mut output.assets, options, &mut output.warnings).await?;
 
     output.warnings.append(&mut self.warnings);
+    output.warnings.extend(self.plugin_driver.take_warnings());
 
     Ok(output)
   }
 
-  #[allow(clippy::missing_transmute_annotations, clippy::needless_pass_by_ref_mut)]
   async fn bundle_up(
     &mut self,
     scan_stage_output: NormalizedScanStageOutput,
+    options: &SharedOptions,
     is_write: bool,
   ) -> BuildResult<BundleOutput> {
+    let output = self.bundle_up_cancellable(scan_stage_output, options, is_write).await?;
+    Ok(output.expect("should only be cancelled by `generate_with_cancellation`"))
+  }
+
+  /// Returns `None` if the build got cancelled between the link and generate stage, or before the
+  /// output is handed to `generateBundle`.
+  #[allow(clippy::missing_transmute_annotations, clippy::needless_pass_by_ref_mut)]
+  async fn bundle_up_cancellable(
+    &mut self,
+    scan_stage_output: NormalizedScanStageOutput,
+    options: &SharedOptions,
+    is_write: bool,
+  ) -> BuildResult<Option<BundleOutput>> {
     if self.closed {
       return Err(
         anyhow::anyhow!(
@@ -213,41 +402,51 @@ impl Bundler {
       );
     }
 
-    let mut link_stage_output = LinkStage::new(scan_stage_output, &self.options).link();
+    let mut link_stage_output = LinkStage::new(scan_stage_output, options).link();
+    if self.plugin_driver.is_cancelled() {
+      return Ok(None);
+    }
+    self.plugin_driver.set_linked_module_graph(linked_module_graph(&link_stage_output));
 
     let bundle_output =
-      GenerateStage::new(&mut link_stage_output, &self.options, &self.plugin_driver)
-        .generate()
-        .await; // Notice we don't use `?` to break the control flow here.
+      GenerateStage::new(&mut link_stage_output, options, &self.plugin_driver).generate().await; // Notice we don't use `?` to break the control flow here.
 
     if let Err(errors) = &bundle_output {
-      self
-        .plugin_driver
-        .render_error(&HookRenderErrorArgs { errors, cwd: &self.options.cwd })
-        .await?;
+      self.plugin_driver.render_error(&HookRenderErrorArgs { errors, cwd: &options.cwd }).await?;
     }
 
     let mut output = bundle_output?;
+    if self.plugin_driver.is_cancelled() {
+      return Ok(None);
+    }
 
     // Add additional files from build plugins.
     self.file_emitter.add_additional_files(&mut output.assets, &mut output.warnings);
+    emit_manifest(options, &mut output.assets, &mut output.warnings)?;
 
     self
       .plugin_driver
-      .generate_bundle(&mut output.assets, is_write, &self.options, &mut output.warnings)
+      .generate_bundle(&mut output.assets, is_write, options, &mut output.warnings)
       .await?;
+    // Warnings plugins reported from the build hooks up to `generateBundle`.
+    output.warnings.extend(self.plugin_driver.take_warnings());
+
+    let mut watch_files =
+      self.plugin_driver.watch_files.iter().map(|file| file.clone()).collect::<Vec<_>>();
+    watch_files.sort_unstable();
+    output.watch_files = watch_files;
 
-    if let Some(invalidate_js_side_cache) = &self.options.invalidate_js_side_cache {
+    if let Some(invalidate_js_side_cache) = &options.invalidate_js_side_cache {
       invalidate_js_side_cache.call().await?;
     }
 
     self.merge_immutable_fields_for_cache(link_stage_output.symbol_db);
 
-    if self.options.is_hmr_enabled() {
+    if options.is_hmr_enabled() {
       self.hmr_manager = Some(HmrManager::new(HmrManagerInput {
         module_db: link_stage_output.module_table,
-        fs: self.fs,
-        options: Arc::clone(&self.options),
+        fs: self.fs.clone(),
+        options: Arc::clone(options),
         resolver: Arc::clone(&self.resolver),
         plugin_driver: Arc::clone(&self.plugin_driver),
         index_ecma_ast: link_stage_output.ast_table,
@@ -256,7 +455,72 @@ impl Bundler {
         session_span: self.session_span.clone(),
       }));
     }
-    Ok(output)
+    Ok(Some(output))
+  }
+
+  /// Routes warnings through `dedupe_diagnostics`, `severity_overrides`, `log_level` and `on_log`, so a single filter covers both core and plugin warnings.
+  /// Warnings promoted by `severity_overrides` or escalated by `on_log` are collected and returned as errors.
+  /// `write` and `generate` already apply it, callers running a stage on its own, e.g.
+  /// [`Bundler::scan`], apply it themselves.
+  pub async fn apply_on_log(
+    options: &NormalizedBundlerOptions,
+    warnings: Vec<BuildDiagnostic>,
+  ) -> BuildResult<Vec<BuildDiagnostic>> {
+    let warnings = if options.dedupe_diagnostics {
+      dedupe_diagnostics(warnings, &options.diagnostic_options())
+    } else {
+      warnings
+    };
+    let warnings = Self::apply_severity_overrides(options, warnings)?;
+    let log_level = options.log_level.unwrap_or_default();
+    let warnings = filter_out_disabled_diagnostics(warnings, &options.checks)
+      .filter(|warning| Self::warning_log_level(warning) <= log_level);
+    let Some(on_log) = options.on_log.as_ref() else {
+      return Ok(warnings.collect());
+    };
+
+    let mut passed = vec![];
+    let mut escalated = vec![];
+    for warning in warnings {
+      let log = Log {
+        id: warning.id(),
+        exporter: warning.exporter(),
+        code: warning.kind().to_string(),
+        message: options.log_message(&warning),
+      };
+      match on_log.call(Self::warning_log_level(&warning), log).await? {
+        LogAction::Pass => passed.push(warning),
+        LogAction::Skip => {}
+        LogAction::Error => escalated.push(warning.with_severity_error()),
+      }
+    }
+
+    if escalated.is_empty() { Ok(passed) } else { Err(escalated.into()) }
+  }
+
+  fn warning_log_level(warning: &BuildDiagnostic) -> LogLevel {
+    if warning.severity() == Severity::Info { LogLevel::Info } else { LogLevel::Warn }
+  }
+
+  fn apply_severity_overrides(
+    options: &NormalizedBundlerOptions,
+    warnings: Vec<BuildDiagnostic>,
+  ) -> BuildResult<Vec<BuildDiagnostic>> {
+    if options.severity_overrides.is_empty() {
+      return Ok(warnings);
+    }
+
+    let mut kept = vec![];
+    let mut promoted = vec![];
+    for warning in warnings {
+      match options.severity_overrides.get(&warning.kind().to_string()) {
+        Some(Severity::Error) => promoted.push(warning.with_severity_error()),
+        Some(severity) => kept.push(warning.with_severity(*severity)),
+        None => kept.push(warning),
+      }
+    }
+
+    if promoted.is_empty() { Ok(kept) } else { Err(promoted.into()) }
   }
 
   #[inline]
@@ -268,8 +532,12 @@ impl Bundler {
     &self.plugin_driver.watch_files
   }
 
-  pub async fn generate_hmr_patch(&mut self, changed_files: Vec<String>) -> BuildResult<HmrOutput> {
-    self.hmr_manager.as_mut().expect("HMR manager is not initialized").hmr(changed_files).await
+  pub async fn generate_hmr_patch(&mut self, changes: Vec<HmrChange>) -> BuildResult<HmrOutput> {
+    let mut output =
+      self.hmr_manager.as_mut().expect("HMR manager is not initialized").hmr(changes).await?;
+    output.warnings =
+      Self::apply_on_log(&self.options, std::mem::take(&mut output.warnings)).await?;
+    Ok(output)
   }
 
   pub async fn hmr_invalidate(
@@ -277,12 +545,33 @@ impl Bundler {
     file: String,
     first_invalidated_by: Option<String>,
   ) -> BuildResult<HmrOutput> {
-    self
+    let mut output = self
       .hmr_manager
       .as_mut()
       .expect("HMR manager is not initialized")
       .hmr_invalidate(file, first_invalidated_by)
-      .await
+      .await?;
+    output.warnings =
+      Self::apply_on_log(&self.options, std::mem::take(&mut output.warnings)).await?;
+    Ok(output)
+  }
+
+  /// Generates a patch for a module that didn't change on disk, as if it was edited. Its `load`
+  /// hooks run again, so virtual modules pick up their latest content.
+  pub async fn invalidate(
+    &mut self,
+    module_id: &str,
+    first_invalidated_by: Option<String>,
+  ) -> BuildResult<HmrOutput> {
+    let mut output = self
+      .hmr_manager
+      .as_mut()
+      .expect("HMR manager is not initialized")
+      .invalidate(module_id, first_invalidated_by)
+      .await?;
+    output.warnings =
+      Self::apply_on_log(&self.options, std::mem::take(&mut output.warnings)).await?;
+    Ok(output)
   }
 
   fn merge_immutable_fields_for_cache(&mut self, symbol_db: SymbolRefDb) {
@@ -300,8 +589,21 @@ impl Bundler {
     }
   }
 
-  fn trace_action_module_graph_ready(scan_stage_output: &NormalizedScanStageOutput) {
+  fn trace_action_module_graph_ready(
+    scan_stage_output: &NormalizedScanStageOutput,
+    plugin_driver: &SharedPluginDriver,
+  ) {
     if tracing::enabled!(tracing::Level::TRACE) {
+      let meta_of = |id: &str| {
+        let mut meta = plugin_driver
+          .get_module_meta(id)
+          .iter()
+          .map(|entry| (entry.key().clone(), entry.value().clone()))
+          .collect::<Vec<_>>();
+        // Keeps the dump stable, the meta map has no order.
+        meta.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        meta.into_iter().collect()
+      };
       let modules = scan_stage_output
         .module_table
         .modules
@@ -322,12 +624,14 @@ impl Bundler {
                 .collect(),
             ),
             importers: Some(module.importers.iter().map(|i| i.to_string()).collect()),
+            meta: meta_of(&module.id),
           },
           Module::External(module) => action::Module {
             id: module.id.to_string(),
             is_external: true,
             imports: None,
             importers: None,
+            meta: meta_of(&module.id),
           },
         })
         .collect();
@@ -364,3 +668,17 @@ fn _test_bundler() {
   let generate_fut = bundler.generate();
   assert_send(generate_fut);
 }
+
+fn linked_module_graph(link_stage_output: &LinkStageOutput) -> LinkedModuleGraph {
+  // The runtime module is an implementation detail, so it's hidden from plugins like in `getModuleInfo`.
+  let runtime_idx = link_stage_output.runtime.id();
+  let modules = link_stage_output.module_table.modules.iter().filter(|m| m.idx() != runtime_idx);
+  LinkedModuleGraph {
+    module_ids: modules.clone().map(|module| module.id_clone().clone()).collect(),
+    included_module_ids: modules
+      .filter(|module| module.as_normal().is_some_and(NormalModule::is_included))
+      .map(|module| module.id_clone().clone())
+      .collect(),
+    module_chunk_filenames: None,
+  }
+}
@@ -1,8 +1,7 @@
 use std::sync::Arc;
 
-use rolldown_common::{FileEmitter, NormalizedBundlerOptions};
+use rolldown_common::{FileEmitter, NormalizedBundlerOptions, SharedFileSystem};
 use rolldown_error::BuildDiagnostic;
-use rolldown_fs::OsFileSystem;
 use rolldown_plugin::{__inner::SharedPluginable, PluginDriver};
 use rolldown_resolver::{ResolveError, Resolver};
 
@@ -23,6 +22,11 @@ pub struct BundlerBuilder {
 
 impl BundlerBuilder {
   pub fn build(mut self) -> Bundler {
+    // Before anything reads the options, so the changes of plugins apply to all of them.
+    for plugin in &self.plugins {
+      plugin.call_options(&mut self.options);
+    }
+
     let mut session_id: Arc<str> = std::time::SystemTime::now()
       .duration_since(std::time::UNIX_EPOCH)
       .expect("Time went backwards")
@@ -41,11 +45,13 @@ impl BundlerBuilder {
 
     let maybe_guard = rolldown_tracing::try_init_tracing();
 
+    let fs =
+      self.options.file_system.take().map_or_else(SharedFileSystem::default, SharedFileSystem::new);
     let NormalizeOptionsReturn { mut options, resolve_options, mut warnings } =
       normalize_options(self.options);
     let tsconfig_filename = resolve_options.tsconfig_filename.clone();
     let resolver: SharedResolver =
-      Resolver::new(resolve_options, options.platform, options.cwd.clone(), OsFileSystem).into();
+      Resolver::new(resolve_options, options.platform, options.cwd.clone(), fs.clone()).into();
 
     // TODO: error handling
     Self::merge_transform_config_from_ts_config(
@@ -66,7 +72,7 @@ impl BundlerBuilder {
       file_emitter,
       resolver,
       options,
-      fs: OsFileSystem,
+      fs,
       warnings,
       _log_guard: maybe_guard,
       cache: ScanStageCache::default(),
@@ -58,7 +58,7 @@ impl Generator for CssGenerator {
 
     // Here file path is generated by chunk file name template, it maybe including path segments.
     // So here need to read it's parent directory as file_dir.
-    let file_path = ctx.options.cwd.as_path().join(&ctx.options.out_dir).join(
+    let file_path = ctx.options.resolved_out_dir().join(
       ctx
         .chunk
         .css_preliminary_filename
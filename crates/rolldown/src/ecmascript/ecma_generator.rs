@@ -84,7 +84,14 @@ impl Generator for EcmaGenerator {
         .collect::<Vec<_>>();
       rendered_modules.insert(
         module_id.clone(),
-        RenderedModule::new(sources.clone(), rendered_exports, *exec_order),
+        RenderedModule::new(
+          sources.clone(),
+          rendered_exports,
+          *exec_order,
+          ctx.link_output.module_table[*module_idx]
+            .as_normal()
+            .map_or(0, |m| u32::try_from(m.source.len()).unwrap_or(u32::MAX)),
+        ),
       );
     });
 
@@ -119,9 +126,10 @@ impl Generator for EcmaGenerator {
       .unwrap_or_default();
 
     let banner = {
-      let injection = match ctx.options.banner.as_ref() {
-        Some(hook) => hook.call(Arc::clone(&rendered_chunk)).await?,
-        None => None,
+      let injection = match (&ctx.chunk.banner, ctx.options.banner.as_ref()) {
+        (Some(banner), _) => Some(banner.to_string()),
+        (None, Some(hook)) => hook.call(Arc::clone(&rendered_chunk)).await?,
+        (None, None) => None,
       };
       ctx
         .plugin_driver
@@ -206,7 +214,7 @@ impl Generator for EcmaGenerator {
 
     // Here file path is generated by chunk file name template, it maybe including path segments.
     // So here need to read it's parent directory as file_dir.
-    let file_path = ctx.options.cwd.as_path().join(&ctx.options.out_dir).join(
+    let file_path = ctx.options.resolved_out_dir().join(
       ctx
         .chunk
         .preliminary_filename
@@ -226,6 +234,9 @@ impl Generator for EcmaGenerator {
           debug_id: 0,
           imports: vec![],
           dynamic_imports: vec![],
+          imported_bindings: FxHashMap::default(),
+          imported_css: vec![],
+          unminified_length: None,
         }),
         augment_chunk_hash: None,
         file_dir: file_dir.to_path_buf(),
@@ -1,12 +1,13 @@
 use oxc_index::IndexVec;
 use rolldown_common::{
-  EcmaRelated, EcmaView, EcmaViewMeta, ImportRecordIdx, ModuleId, ModuleType, RawImportRecord,
-  ResolvedId, SharedNormalizedBundlerOptions,
+  EcmaRelated, EcmaView, EcmaViewMeta, ImportKind, ImportRecordIdx, ModuleId, ModuleType,
+  RawImportRecord, ResolvedId, SharedNormalizedBundlerOptions,
   side_effects::{DeterminedSideEffects, HookSideEffects},
 };
 use rolldown_error::BuildResult;
 use rolldown_std_utils::PathExt;
 use rolldown_utils::{ecmascript::legitimize_identifier_name, indexmap::FxIndexSet};
+use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
 use crate::{
@@ -19,6 +20,8 @@ pub struct CreateEcmaViewReturn {
   pub ecma_view: EcmaView,
   pub ecma_related: EcmaRelated,
   pub raw_import_records: IndexVec<ImportRecordIdx, RawImportRecord>,
+  /// `import()` expressions resolved by `resolve_dynamic_import` hooks, keyed by their import record.
+  pub resolved_dynamic_imports: FxHashMap<ImportRecordIdx, ResolvedId>,
 }
 
 #[allow(clippy::too_many_lines)]
@@ -27,8 +30,13 @@ pub async fn create_ecma_view(
   args: CreateModuleViewArgs,
 ) -> BuildResult<CreateEcmaViewReturn> {
   let CreateModuleViewArgs { source, sourcemap_chain, hook_side_effects } = args;
-  let ParseToEcmaAstResult { ast, scoping, has_lazy_export, warning } =
-    parse_to_ecma_ast(ctx, source).await?;
+  let ParseToEcmaAstResult {
+    ast,
+    scoping,
+    has_lazy_export,
+    warning,
+    resolved_dynamic_imports: mut resolved_dynamic_import_specifiers,
+  } = parse_to_ecma_ast(ctx, source).await?;
 
   ctx.warnings.extend(warning);
 
@@ -128,8 +136,22 @@ pub async fn create_ecma_view(
     dummy_record_set,
   };
 
+  // Matched by span rather than by specifier, so a static `import()` of the same string as a resolved
+  // expression keeps its own resolution.
+  let resolved_dynamic_imports = if resolved_dynamic_import_specifiers.is_empty() {
+    FxHashMap::default()
+  } else {
+    raw_import_records
+      .iter_enumerated()
+      .filter(|(_, record)| matches!(record.kind, ImportKind::DynamicImport))
+      .filter_map(|(idx, record)| {
+        Some((idx, resolved_dynamic_import_specifiers.remove(&record.state.span)?))
+      })
+      .collect()
+  };
+
   let ecma_related = EcmaRelated { ast, symbols, dynamic_import_rec_exports_usage };
-  Ok(CreateEcmaViewReturn { ecma_view, ecma_related, raw_import_records })
+  Ok(CreateEcmaViewReturn { ecma_view, ecma_related, raw_import_records, resolved_dynamic_imports })
 }
 
 /// The side effects priority is:
@@ -51,9 +51,11 @@ pub fn render_cjs<'code>(
       let export_mode = determine_export_mode(warnings, ctx, entry_module, &export_names)?;
       // Only `named` export can we render the namespace markers.
       if matches!(&export_mode, OutputExports::Named) && entry_module.exports_kind.is_esm() {
-        if let Some(marker) =
-          render_namespace_markers(ctx.options.es_module, has_default_export, false)
-        {
+        if let Some(marker) = render_namespace_markers(
+          ctx.options.es_module,
+          has_default_export,
+          ctx.options.generated_code.symbols,
+        ) {
           source_joiner.append_source(marker);
         }
       }
@@ -105,7 +107,8 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
     if items.is_empty() {
       s.push_str(&require_path_str);
     } else {
-      s.push_str("const ");
+      s.push_str(ctx.options.generated_code.const_keyword());
+      s.push(' ');
       s.push_str(&ctx.chunk.require_binding_names_for_other_chunks[exporter_id]);
       s.push_str(" = ");
       s.push_str(&require_path_str);
@@ -129,7 +132,8 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
       );
 
       let external_module_symbol_name = &ctx.chunk.canonical_names[&importee.namespace_ref];
-      s.push_str("const ");
+      s.push_str(ctx.options.generated_code.const_keyword());
+      s.push(' ');
       s.push_str(external_module_symbol_name);
       s.push_str(" = ");
       s.push_str(&to_esm_fn_name);
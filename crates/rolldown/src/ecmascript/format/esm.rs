@@ -1,6 +1,8 @@
 use arcstr::ArcStr;
 use itertools::Itertools;
-use rolldown_common::{AddonRenderContext, ExportsKind, Specifier};
+use rolldown_common::{
+  AddonRenderContext, ExportsKind, ExternalModule, Specifier, render_import_attributes,
+};
 use rolldown_sourcemap::SourceJoiner;
 use rolldown_utils::{concat_string, ecmascript::to_module_import_export_name};
 use rustc_hash::FxHashSet;
@@ -53,11 +55,17 @@ pub fn render_esm<'code>(
         .star_export_module_ids()
         .filter_map(|importee| {
           let importee = &ctx.link_output.module_table[importee];
-          importee.as_external().map(|m| m.get_import_path(ctx.chunk))
+          importee.as_external().map(|m| (m.get_import_path(ctx.chunk), render_with_clause(m)))
         })
         .dedup()
-        .for_each(|ext_name| {
-          source_joiner.append_source(concat_string!("export * from \"", ext_name, "\"\n"));
+        .for_each(|(ext_name, with_clause)| {
+          source_joiner.append_source(concat_string!(
+            "export * from \"",
+            ext_name,
+            "\"",
+            with_clause,
+            "\n"
+          ));
         });
     }
   }
@@ -124,6 +132,7 @@ fn render_esm_chunk_imports(ctx: &GenerateContext<'_>) -> Option<String> {
       specifiers,
       &default_alias,
       &ctx.chunk.import_path_for(importee_chunk),
+      "",
     ));
   });
   let mut rendered_external_import_namespace_modules = FxHashSet::default();
@@ -153,7 +162,9 @@ fn render_esm_chunk_imports(ctx: &GenerateContext<'_>) -> Option<String> {
             s.push_str(alias);
             s.push_str(" from \"");
             s.push_str(&importee.get_import_path(ctx.chunk));
-            s.push_str("\";\n");
+            s.push('"');
+            s.push_str(&render_with_clause(importee));
+            s.push_str(";\n");
             None
           }
           Specifier::Literal(imported) => {
@@ -184,6 +195,7 @@ fn render_esm_chunk_imports(ctx: &GenerateContext<'_>) -> Option<String> {
         specifiers,
         &default_alias,
         &importee.get_import_path(ctx.chunk),
+        &render_with_clause(importee),
       ));
     }
   });
@@ -194,6 +206,7 @@ fn create_import_declaration(
   mut specifiers: Vec<String>,
   default_alias: &[ArcStr],
   path: &str,
+  with_clause: &str,
 ) -> String {
   let mut ret = String::new();
   let first_default_alias = match &default_alias {
@@ -214,17 +227,30 @@ fn create_import_declaration(
     ret.push_str(&specifiers.join(", "));
     ret.push_str(" } from \"");
     ret.push_str(path);
-    ret.push_str("\";\n");
+    ret.push('"');
+    ret.push_str(with_clause);
+    ret.push_str(";\n");
   } else if let Some(first_default_alias) = first_default_alias {
     ret.push_str("import ");
     ret.push_str(first_default_alias);
     ret.push_str(" from \"");
     ret.push_str(path);
-    ret.push_str("\";\n");
+    ret.push('"');
+    ret.push_str(with_clause);
+    ret.push_str(";\n");
   } else {
     ret.push_str("import \"");
     ret.push_str(path);
-    ret.push_str("\";\n");
+    ret.push('"');
+    ret.push_str(with_clause);
+    ret.push_str(";\n");
   }
   ret
 }
+
+/// ` with { type: "json" }` if the external module is imported with import attributes.
+fn render_with_clause(importee: &ExternalModule) -> String {
+  importee.import_attributes.as_ref().map_or_else(String::new, |attributes| {
+    concat_string!(" with ", render_import_attributes(attributes))
+  })
+}
@@ -130,8 +130,11 @@ pub async fn render_iife<'code>(
   }
 
   if named_exports && entry_module.exports_kind.is_esm() {
-    if let Some(marker) = render_namespace_markers(ctx.options.es_module, has_default_export, false)
-    {
+    if let Some(marker) = render_namespace_markers(
+      ctx.options.es_module,
+      has_default_export,
+      ctx.options.generated_code.symbols,
+    ) {
       source_joiner.append_source(marker);
     }
   }
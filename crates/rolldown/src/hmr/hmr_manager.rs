@@ -1,19 +1,22 @@
 use std::{
+  collections::BTreeSet,
   ops::{Deref, DerefMut},
+  path::Path,
   sync::Arc,
 };
 
 use arcstr::ArcStr;
 use oxc::ast_visit::VisitMut;
 use rolldown_common::{
-  EcmaModuleAstUsage, HmrBoundary, HmrBoundaryOutput, HmrOutput, Module, ModuleIdx, ModuleTable,
+  EcmaModuleAstUsage, FullReloadReason, HmrBoundary, HmrBoundaryOutput, HmrChange, HmrExportChange,
+  HmrOutput, HmrPatch, Module, ModuleIdx, ModuleTable, SharedFileSystem,
 };
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler, PrintOptions};
 use rolldown_ecmascript_utils::AstSnippet;
-use rolldown_error::BuildResult;
-use rolldown_fs::OsFileSystem;
-use rolldown_plugin::SharedPluginDriver;
-use rolldown_sourcemap::{SourceJoiner, SourceMapSource};
+use rolldown_error::{BuildDiagnostic, BuildResult};
+use rolldown_fs::FileSystem;
+use rolldown_plugin::{HookHmrAcceptArgs, HookHmrAcceptOutput, HookUsage, SharedPluginDriver};
+use rolldown_sourcemap::{SourceJoiner, SourceMap, SourceMapSource};
 use rolldown_utils::indexmap::FxIndexSet;
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -26,7 +29,7 @@ use crate::{
 pub struct HmrManagerInput {
   pub module_db: ModuleTable,
   pub options: SharedOptions,
-  pub fs: OsFileSystem,
+  pub fs: SharedFileSystem,
   pub resolver: SharedResolver,
   pub plugin_driver: SharedPluginDriver,
   pub index_ecma_ast: IndexEcmaAst,
@@ -81,12 +84,14 @@ impl HmrManager {
       .expect("Not found hmr invalidate module")
       .idx();
     let module = self.module_db.modules[module_idx].as_normal().unwrap();
+    let all_invalidated_by = vec![module.stable_id.to_string()];
 
     // only self accept modules can be invalidated
     if !module.ast_usage.contains(EcmaModuleAstUsage::HmrSelfAccept) {
       return Ok(HmrOutput {
         is_self_accepting: false,
         first_invalidated_by,
+        all_invalidated_by,
         ..Default::default()
       });
     }
@@ -95,28 +100,103 @@ impl HmrManager {
     if module.importers_idx.is_empty() {
       return Ok(HmrOutput {
         is_self_accepting: true,
+        full_reload_reason: Some(FullReloadReason::NoAcceptingBoundary {
+          module: module.stable_id.to_string(),
+        }),
         first_invalidated_by,
+        all_invalidated_by,
         full_reload: true,
         ..Default::default()
       });
     }
 
-    let mut ret =
-      self.generate_hmr_patch(module.importers_idx.clone(), first_invalidated_by).await?;
+    let mut ret = self
+      .generate_hmr_patch(module.importers_idx.clone(), first_invalidated_by, FxHashMap::default())
+      .await?;
     ret.is_self_accepting = true;
+    ret.all_invalidated_by = all_invalidated_by;
+    Ok(ret)
+  }
+
+  /// Rebuilds a module that didn't change on disk, e.g. a virtual module whose data changed, as if
+  /// it was edited.
+  pub async fn invalidate(
+    &mut self,
+    module_id: &str,
+    first_invalidated_by: Option<String>,
+  ) -> BuildResult<HmrOutput> {
+    let module_id = ArcStr::from(module_id);
+    let Some(module_idx) = self
+      .cache
+      .module_id_to_idx
+      .get(&module_id)
+      .map(|v| v.idx())
+      .or_else(|| self.module_idx_by_abs_path.get(&module_id).copied())
+    else {
+      return Err(BuildDiagnostic::hmr_unknown_module(module_id.to_string()).into());
+    };
+    let stable_id = self.module_db.modules[module_idx].stable_id().to_string();
+
+    let mut ret = self
+      .generate_hmr_patch(
+        FxIndexSet::from_iter([module_idx]),
+        first_invalidated_by.clone(),
+        FxHashMap::default(),
+      )
+      .await?;
+    ret.first_invalidated_by = first_invalidated_by.or_else(|| Some(stable_id.clone()));
+    ret.all_invalidated_by = vec![stable_id];
     Ok(ret)
   }
 
   #[expect(clippy::dbg_macro)] // FIXME: Remove dbg! macro once the feature is stable
-  pub async fn hmr(&mut self, changed_file_paths: Vec<String>) -> BuildResult<HmrOutput> {
+  pub async fn hmr(&mut self, changes: Vec<HmrChange>) -> BuildResult<HmrOutput> {
     let mut changed_modules = FxIndexSet::default();
-    for changed_file_path in changed_file_paths {
-      let changed_file_path = ArcStr::from(changed_file_path);
+    let mut deleted_modules = FxIndexSet::default();
+    let mut patched_sources = FxHashMap::default();
+    for change in changes {
+      let changed_file_path = match change {
+        HmrChange::Path(path) => ArcStr::from(path),
+        HmrChange::Patched { id, code } => {
+          let id = ArcStr::from(id);
+          let module_idx = self
+            .module_idx_by_abs_path
+            .get(&id)
+            .copied()
+            .or_else(|| self.cache.module_id_to_idx.get(&id).map(|v| v.idx()));
+          let Some(module_idx) = module_idx else {
+            tracing::debug!(target: "hmr", "No corresponding module found for patched module: {id:?}");
+            continue;
+          };
+          let Some(module) = self.module_db.modules[module_idx].as_normal() else {
+            tracing::debug!(target: "hmr", "Skipping patched external module: {id:?}");
+            continue;
+          };
+          // Modules that aren't backed by a file are loaded by a plugin, so they're only
+          // invalidated to let its `load` hook run again.
+          let resource_id = module.id.resource_id();
+          if !module.is_virtual() && self.fs.exists(Path::new(resource_id.as_str())) {
+            patched_sources.insert(resource_id.clone(), ArcStr::from(code));
+          }
+          changed_modules.insert(module_idx);
+          continue;
+        }
+      };
+      // Modules that registered the changed file via `add_watch_file_dependency` in `transform` are stale as well.
+      let dependents = self.plugin_driver.get_watch_file_dependents(&changed_file_path);
+      changed_modules.extend(
+        dependents.iter().filter_map(|id| self.cache.module_id_to_idx.get(id)).map(|v| v.idx()),
+      );
       match self.module_idx_by_abs_path.get(&changed_file_path) {
+        Some(module_idx) if !self.fs.exists(Path::new(changed_file_path.as_str())) => {
+          deleted_modules.insert(*module_idx);
+        }
         Some(module_idx) => {
           changed_modules.insert(*module_idx);
         }
-        _ => {
+        // The file isn't a module, but only a watch file of the dependents handled above.
+        None if !dependents.is_empty() => {}
+        None => {
           dbg!("No corresponding module found for changed file path: {:?}", changed_file_path);
         }
       }
@@ -129,7 +209,36 @@ impl HmrManager {
         .collect::<Vec<_>>(),
     );
 
-    self.generate_hmr_patch(changed_modules, None).await
+    let all_invalidated_by = changed_modules
+      .iter()
+      .chain(&deleted_modules)
+      .map(|module_idx| self.module_db.modules[*module_idx].stable_id().to_string())
+      .collect();
+    // A deleted module can't be fetched again, the update starts from the importers that have to
+    // drop their imports of it instead.
+    let mut deleted_module_by_importer = FxHashMap::default();
+    for module_idx in &deleted_modules {
+      if let Module::Normal(module) = &self.module_db.modules[*module_idx] {
+        changed_modules.extend(module.importers_idx.iter().copied());
+        for importer_idx in &module.importers_idx {
+          deleted_module_by_importer.insert(
+            self.module_db.modules[*importer_idx].stable_id().to_string(),
+            module.stable_id.to_string(),
+          );
+        }
+      }
+    }
+    changed_modules.retain(|module_idx| !deleted_modules.contains(module_idx));
+    let mut ret = self.generate_hmr_patch(changed_modules, None, patched_sources).await?;
+    // No boundary accepting the update of an importer of a deleted module means the page reloads
+    // because of the deletion.
+    if let Some(FullReloadReason::NoAcceptingBoundary { module }) = &ret.full_reload_reason {
+      if let Some(deleted_module) = deleted_module_by_importer.remove(module) {
+        ret.full_reload_reason = Some(FullReloadReason::ModuleDeleted { module: deleted_module });
+      }
+    }
+    ret.all_invalidated_by = all_invalidated_by;
+    Ok(ret)
   }
 
   #[expect(clippy::too_many_lines)]
@@ -137,7 +246,17 @@ impl HmrManager {
     &mut self,
     mut changed_modules: FxIndexSet<ModuleIdx>,
     first_invalidated_by: Option<String>,
+    patched_sources: FxHashMap<ArcStr, ArcStr>,
   ) -> BuildResult<HmrOutput> {
+    // Style modules are swapped in place by the runtime, so they never propagate to a JS boundary.
+    let style_modules = changed_modules
+      .iter()
+      .copied()
+      .filter(|module_idx| self.is_style_module(*module_idx))
+      .collect::<FxIndexSet<_>>();
+    changed_modules.retain(|module_idx| !style_modules.contains(module_idx));
+    let is_style_only_update = !style_modules.is_empty() && changed_modules.is_empty();
+
     let mut affected_modules = FxIndexSet::default();
     let mut hmr_boundaries = FxIndexSet::default();
     let mut need_to_full_reload = false;
@@ -146,10 +265,12 @@ impl HmrManager {
       if need_to_full_reload {
         break;
       }
+      let accept_overrides = self.hmr_accept_overrides(changed_module_idx).await?;
       let mut visited_modules = FxHashSet::default();
       let mut boundaries = FxIndexSet::default();
       let is_reach_to_hmr_root_boundary = self.propagate_update(
         changed_module_idx,
+        &accept_overrides,
         &mut visited_modules,
         &mut boundaries,
         &mut affected_modules,
@@ -157,6 +278,9 @@ impl HmrManager {
 
       if is_reach_to_hmr_root_boundary {
         need_to_full_reload = true;
+        full_reload_reason = Some(FullReloadReason::NoAcceptingBoundary {
+          module: self.module_db.modules[changed_module_idx].stable_id().to_string(),
+        });
         continue;
       }
 
@@ -167,7 +291,7 @@ impl HmrManager {
           self.module_db.modules[boundary.accepted_via].stable_id() == first_invalidated_by
         }) {
           need_to_full_reload = true;
-          full_reload_reason = Some("circular import invalidate".to_string());
+          full_reload_reason = Some(FullReloadReason::CircularAcceptChain);
           continue;
         }
       }
@@ -184,6 +308,13 @@ impl HmrManager {
       });
     }
 
+    // Modules re-executed by the patch, from the changed modules up to the boundaries. Modules
+    // fetched for the first time below were never evaluated, so they have nothing to dispose.
+    let modules_to_dispose = affected_modules
+      .iter()
+      .map(|module_idx| self.module_db.modules[*module_idx].stable_id().to_string())
+      .collect::<Vec<_>>();
+
     tracing::debug!(
       target: "hmr",
       "computed out `affected_modules` {:?}",
@@ -197,6 +328,19 @@ impl HmrManager {
     // For those modules that are not edited, we should be able to reuse their AST. But currently we don't have a good way to do that
     // due to architecture limitation.
     modules_to_invalidate.extend(affected_modules.clone());
+    modules_to_invalidate.extend(style_modules.iter().copied());
+
+    // Importers of a module accepting its own updates keep the bindings they imported, so exports
+    // added or removed by the edit need to be checked once the module is fetched again.
+    let exports_before_update = modules_to_invalidate
+      .iter()
+      .filter_map(|module_idx| {
+        let module = self.module_db.modules[*module_idx].as_normal()?;
+        (module.ast_usage.contains(EcmaModuleAstUsage::HmrSelfAccept)
+          && !module.importers_idx.is_empty())
+        .then(|| (*module_idx, Self::export_names(&self.module_db.modules[*module_idx])))
+      })
+      .collect::<Vec<_>>();
 
     let module_infos_to_be_updated = modules_to_invalidate
       .iter()
@@ -209,14 +353,24 @@ impl HmrManager {
       })
       .collect::<Vec<_>>();
 
+    let export_change =
+      self.options.experimental.hmr.as_ref().and_then(|hmr| hmr.export_change).unwrap_or_default();
+    // A reload because of changed exports leaves the module graph as it was, so the next update is
+    // still compared with the exports the page runs. The module loader updates the cache in place,
+    // so it's restored then.
+    let cache_before_update = (matches!(export_change, HmrExportChange::FullReload)
+      && !exports_before_update.is_empty())
+    .then(|| (self.cache.module_id_to_idx.clone(), self.cache.importers.clone()));
+
     let build_span = self.session_span.clone();
     let mut module_loader = ModuleLoader::new(
-      self.fs,
+      self.fs.clone(),
       Arc::clone(&self.options),
       Arc::clone(&self.resolver),
       Arc::clone(&self.plugin_driver),
       &mut self.cache,
       false,
+      patched_sources,
       build_span,
     )?;
 
@@ -228,6 +382,46 @@ impl HmrManager {
     // implementation, so we need to manually drop it.
     drop(module_loader);
 
+    let mut warnings = vec![];
+    for (module_idx, exports_before) in exports_before_update {
+      let module = module_loader_output.module_table.get(module_idx);
+      let exports_after = Self::export_names(module);
+      let added = exports_after.difference(&exports_before).cloned().collect::<Vec<_>>();
+      let removed = exports_before.difference(&exports_after).cloned().collect::<Vec<_>>();
+      if added.is_empty() && removed.is_empty() {
+        continue;
+      }
+      match export_change {
+        HmrExportChange::FullReload => {
+          if let Some((module_id_to_idx, importers)) = cache_before_update {
+            self.cache.module_id_to_idx = module_id_to_idx;
+            self.cache.importers = importers;
+          }
+          return Ok(HmrOutput {
+            full_reload: true,
+            full_reload_reason: Some(FullReloadReason::ExportsChanged {
+              module: module.stable_id().to_string(),
+              added,
+              removed,
+            }),
+            first_invalidated_by,
+            ..Default::default()
+          });
+        }
+        HmrExportChange::Warn => {
+          warnings.push(
+            BuildDiagnostic::hmr_export_change(
+              module.id().to_string(),
+              module.stable_id().to_string(),
+              added,
+              removed,
+            )
+            .with_severity_warning(),
+          );
+        }
+      }
+    }
+
     tracing::debug!(
       target: "hmr",
       "New added modules` {:?}",
@@ -254,7 +448,11 @@ impl HmrManager {
     // TODO(hyf0): This is a temporary merging solution. We need to find a better way to handle this.
     for (idx, module) in updated_modules {
       if idx.index() >= self.module_db.modules.len() {
-        // This module is newly added, we need to insert it into the module db.
+        // This module is newly added, we need to insert it into the module db. Registering its path
+        // lets later edits of it update it like any other module.
+        if let Module::Normal(module) = &module {
+          self.module_idx_by_abs_path.insert(module.id.resource_id().clone(), idx);
+        }
         let generated_id = self.module_db.modules.push(module);
         assert_eq!(generated_id, idx, "Module index mismatch");
       } else {
@@ -295,7 +493,7 @@ impl HmrManager {
       })
       .collect::<FxHashMap<_, _>>();
 
-    let mut source_joiner = SourceJoiner::default();
+    let mut definitions = vec![];
 
     for affected_module_idx in affected_modules {
       let affected_module = &self.input.module_db.modules[affected_module_idx];
@@ -304,10 +502,12 @@ impl HmrManager {
       };
 
       let enable_sourcemap = self.options.sourcemap.is_some() && !affected_module.is_virtual();
+      let compact = self.options.compact;
       let ecma_ast_idx = affected_module.ecma_ast_idx.unwrap();
       let modules = &self.input.module_db.modules;
       let ast = &mut self.input.index_ecma_ast[ecma_ast_idx].0;
 
+      let mut dependencies = vec![];
       ast.program.with_mut(|fields| {
         let scoping = EcmaAst::make_semantic(fields.program, /*with_cfg*/ false).into_scoping();
         let mut finalizer = HmrAstFinalizer {
@@ -324,6 +524,11 @@ impl HmrManager {
         };
 
         finalizer.visit_program(fields.program);
+        dependencies = finalizer
+          .dependencies
+          .iter()
+          .filter_map(|dep| module_idx_to_init_fn_name.get(dep).cloned())
+          .collect();
       });
 
       let codegen = EcmaCompiler::print_with(
@@ -332,21 +537,62 @@ impl HmrManager {
           sourcemap: enable_sourcemap,
           filename: affected_module.id.to_string(),
           print_legal_comments: false, // ignore hmr chunk comments
+          compact,
         },
       );
-      if let Some(map) = codegen.map {
-        source_joiner.append_source(SourceMapSource::new(codegen.code, map));
-      } else {
-        source_joiner.append_source(codegen.code);
-      }
+      definitions.push(PatchDefinition {
+        init_fn_name: Some(module_idx_to_init_fn_name[&affected_module_idx].clone()),
+        dependencies,
+        code: codegen.code,
+        map: codegen.map,
+      });
     }
 
-    hmr_boundaries.iter().for_each(|boundary| {
-      let init_fn_name = &module_idx_to_init_fn_name[&boundary.boundary];
-      source_joiner.append_source(format!("{init_fn_name}()"));
-    });
+    for style_module_idx in &style_modules {
+      let Module::Normal(module) = &self.module_db.modules[*style_module_idx] else {
+        unreachable!("Style modules are always normal modules");
+      };
+      let css_view = module.css_view.as_ref().expect("Style modules should have a css view");
+      let mut magic_string = string_wizard::MagicString::new(&css_view.source);
+      for mutation in &css_view.mutations {
+        mutation.apply(&mut magic_string);
+      }
+      definitions.push(PatchDefinition {
+        init_fn_name: None,
+        dependencies: vec![],
+        code: format!(
+          "__rolldown_runtime__.updateStyle({}, {});",
+          serde_json::to_string(module.stable_id.as_str()).expect("Should serialize a string"),
+          serde_json::to_string(&magic_string.to_string()).expect("Should serialize a string"),
+        ),
+        map: None,
+      });
+    }
 
-    source_joiner.append_source(format!(
+    let boundary_init_fn_names = hmr_boundaries
+      .iter()
+      .map(|boundary| module_idx_to_init_fn_name[&boundary.boundary].clone())
+      .collect::<Vec<_>>();
+    let mut update_code = vec![];
+    if !modules_to_dispose.is_empty() {
+      update_code.push(format!(
+        "__rolldown_runtime__.disposeModules({});",
+        serde_json::to_string(&modules_to_dispose).expect("Should serialize a list of strings")
+      ));
+    }
+    update_code.extend(hmr_boundaries.iter().zip(&boundary_init_fn_names).map(
+      |(boundary, init_fn_name)| {
+        if boundary.unevaluated_ok {
+          format!(
+            "if (__rolldown_runtime__.isModuleEvaluated('{}')) {init_fn_name}()",
+            self.module_db.modules[boundary.accepted_via].stable_id()
+          )
+        } else {
+          format!("{init_fn_name}()")
+        }
+      },
+    ));
+    update_code.push(format!(
       "__rolldown_runtime__.applyUpdates([{}]);",
       hmr_boundaries
         .iter()
@@ -358,14 +604,142 @@ impl HmrManager {
         .join(",")
     ));
 
-    let (mut code, mut map) = source_joiner.join();
+    let timestamp =
+      std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis();
+    let patch_size = definitions.iter().map(|definition| definition.code.len()).sum::<usize>()
+      + update_code.iter().map(String::len).sum::<usize>();
+    let max_patch_size = self.options.experimental.hmr.as_ref().and_then(|hmr| hmr.max_patch_size);
+
+    let patches = match max_patch_size {
+      Some(max_patch_size) if patch_size > max_patch_size => {
+        self
+          .render_split_patches(
+            timestamp,
+            definitions,
+            &boundary_init_fn_names,
+            update_code,
+            max_patch_size,
+          )
+          .await?
+      }
+      _ => {
+        let sources = definitions
+          .into_iter()
+          .map(|definition| (definition.code, definition.map))
+          .chain(update_code.into_iter().map(|code| (code, None)))
+          .collect();
+        vec![self.render_patch(format!("{timestamp}.js"), sources).await?]
+      }
+    };
 
-    let filename = format!(
-      "{}.js",
-      std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis()
-    );
+    Ok(HmrOutput {
+      patches,
+      first_invalidated_by,
+      hmr_boundaries: hmr_boundaries
+        .into_iter()
+        .chain(style_modules.into_iter().map(|module_idx| HmrBoundary {
+          boundary: module_idx,
+          accepted_via: module_idx,
+          unevaluated_ok: false,
+        }))
+        .map(|boundary| HmrBoundaryOutput {
+          boundary: self.module_db.modules[boundary.boundary].stable_id().into(),
+          accepted_via: self.module_db.modules[boundary.accepted_via].stable_id().into(),
+          unevaluated_ok: boundary.unevaluated_ok,
+        })
+        .collect(),
+      is_self_accepting: is_style_only_update,
+      modules_to_dispose,
+      warnings,
+      ..Default::default()
+    })
+  }
+
+  /// Splits the module definitions of a patch into parts of about `max_patch_size` bytes, followed
+  /// by a manifest running the update once every part is loaded. Parts are separate scripts, so
+  /// they register their initializers to the runtime, and call the ones of other parts through it.
+  async fn render_split_patches(
+    &self,
+    timestamp: u128,
+    definitions: Vec<PatchDefinition>,
+    boundary_init_fn_names: &[String],
+    update_code: Vec<String>,
+    max_patch_size: usize,
+  ) -> BuildResult<Vec<HmrPatch>> {
+    let mut parts: Vec<Vec<PatchDefinition>> = vec![];
+    let mut part_size = 0;
+    for definition in definitions {
+      match parts.last_mut() {
+        Some(part) if part_size + definition.code.len() <= max_patch_size => {
+          part_size += definition.code.len();
+          part.push(definition);
+        }
+        _ => {
+          part_size = definition.code.len();
+          parts.push(vec![definition]);
+        }
+      }
+    }
 
-    let file_dir = self.options.cwd.as_path().join(&self.options.out_dir);
+    let mut patches = Vec::with_capacity(parts.len() + 1);
+    for (index, part) in parts.into_iter().enumerate() {
+      let defined = part
+        .iter()
+        .filter_map(|definition| definition.init_fn_name.clone())
+        .collect::<FxIndexSet<_>>();
+      let called_from_other_parts = part
+        .iter()
+        .flat_map(|definition| &definition.dependencies)
+        .filter(|init_fn_name| !defined.contains(*init_fn_name))
+        .collect::<FxIndexSet<_>>();
+
+      let mut sources = called_from_other_parts
+        .into_iter()
+        .map(|init_fn_name| (Self::patch_initializer_stub(init_fn_name), None))
+        .collect::<Vec<_>>();
+      sources.extend(part.into_iter().map(|definition| (definition.code, definition.map)));
+      if !defined.is_empty() {
+        sources.push((
+          format!(
+            "__rolldown_runtime__.registerPatchInitializers({{ {} }});",
+            defined.iter().map(String::as_str).collect::<Vec<_>>().join(", ")
+          ),
+          None,
+        ));
+      }
+      patches.push(self.render_patch(format!("{timestamp}-{index}.js"), sources).await?);
+    }
+
+    let sources = boundary_init_fn_names
+      .iter()
+      .map(|init_fn_name| Self::patch_initializer_stub(init_fn_name))
+      .chain(update_code)
+      .map(|code| (code, None))
+      .collect();
+    patches.push(self.render_patch(format!("{timestamp}.js"), sources).await?);
+    Ok(patches)
+  }
+
+  fn patch_initializer_stub(init_fn_name: &str) -> String {
+    format!("var {init_fn_name} = () => __rolldown_runtime__.patchInitializers.{init_fn_name}();")
+  }
+
+  async fn render_patch(
+    &self,
+    filename: String,
+    sources: Vec<(String, Option<SourceMap>)>,
+  ) -> BuildResult<HmrPatch> {
+    let mut source_joiner = SourceJoiner::default();
+    for (code, map) in sources {
+      if let Some(map) = map {
+        source_joiner.append_source(SourceMapSource::new(code, map));
+      } else {
+        source_joiner.append_source(code);
+      }
+    }
+    let (mut code, mut map) = source_joiner.join();
+
+    let file_dir = self.options.resolved_out_dir();
 
     let sourcemap_asset = if let Some(map) = map.as_mut() {
       process_code_and_sourcemap(
@@ -382,26 +756,65 @@ impl HmrManager {
       None
     };
 
-    Ok(HmrOutput {
-      code,
+    Ok(HmrPatch {
       filename,
-      sourcemap_filename: sourcemap_asset.as_ref().map(|asset| asset.filename.to_string()),
-      sourcemap: sourcemap_asset.map(|asset| asset.source.try_into_string()).transpose()?,
-      first_invalidated_by,
-      hmr_boundaries: hmr_boundaries
-        .into_iter()
-        .map(|boundary| HmrBoundaryOutput {
-          boundary: self.module_db.modules[boundary.boundary].stable_id().into(),
-          accepted_via: self.module_db.modules[boundary.accepted_via].stable_id().into(),
-        })
-        .collect(),
-      ..Default::default()
+      code,
+      map: map.filter(|_| sourcemap_asset.is_some()),
+      sourcemap_filename: sourcemap_asset.map(|asset| asset.filename.to_string()),
+    })
+  }
+
+  fn export_names(module: &Module) -> BTreeSet<String> {
+    module.as_normal().map_or_else(BTreeSet::default, |module| {
+      module.named_exports.keys().map(ToString::to_string).collect()
     })
   }
 
+  fn is_style_module(&self, module_idx: ModuleIdx) -> bool {
+    self.module_db.modules[module_idx].as_normal().is_some_and(|module| module.css_view.is_some())
+  }
+
+  /// Asks the `hmr_accept` hook about each module an update of `changed_module_idx` could propagate
+  /// through, keeping the modules whose decision is overridden.
+  async fn hmr_accept_overrides(
+    &self,
+    changed_module_idx: ModuleIdx,
+  ) -> BuildResult<FxHashMap<ModuleIdx, HookHmrAcceptOutput>> {
+    let mut overrides = FxHashMap::default();
+    if !self.plugin_driver.has_hook_usage(HookUsage::HmrAccept) {
+      return Ok(overrides);
+    }
+    let changed_module_id = self.module_db.modules[changed_module_idx].id();
+    let mut visited_modules = FxHashSet::default();
+    let mut stack = vec![changed_module_idx];
+    while let Some(module_idx) = stack.pop() {
+      if !visited_modules.insert(module_idx) {
+        continue;
+      }
+      let Module::Normal(module) = &self.module_db.modules[module_idx] else { continue };
+      let args = HookHmrAcceptArgs {
+        changed_module_id,
+        module_id: &module.id,
+        is_self_accepting: module.ast_usage.contains(EcmaModuleAstUsage::HmrSelfAccept),
+      };
+      if let Some(output) = self.plugin_driver.hmr_accept(&args).await? {
+        overrides.insert(module_idx, output);
+      }
+      stack.extend(module.importers_idx.iter().copied());
+      stack.extend(
+        module
+          .dynamic_importers
+          .iter()
+          .filter_map(|importer| self.module_idx_by_abs_path.get(importer.resource_id()).copied()),
+      );
+    }
+    Ok(overrides)
+  }
+
   fn propagate_update(
     &self,
     module_idx: ModuleIdx,
+    accept_overrides: &FxHashMap<ModuleIdx, HookHmrAcceptOutput>,
     visited_modules: &mut FxHashSet<ModuleIdx>,
     hmr_boundaries: &mut FxIndexSet<HmrBoundary>,
     affected_modules: &mut FxIndexSet<ModuleIdx>,
@@ -416,28 +829,63 @@ impl HmrManager {
 
     affected_modules.insert(module_idx);
 
-    if module.ast_usage.contains(EcmaModuleAstUsage::HmrSelfAccept) {
-      hmr_boundaries.insert(HmrBoundary { boundary: module_idx, accepted_via: module_idx });
+    let is_self_accepting = match accept_overrides.get(&module_idx) {
+      Some(HookHmrAcceptOutput::SelfAccept) => true,
+      Some(HookHmrAcceptOutput::Invalidate) => false,
+      None => module.ast_usage.contains(EcmaModuleAstUsage::HmrSelfAccept),
+    };
+    if is_self_accepting {
+      hmr_boundaries.insert(HmrBoundary {
+        boundary: module_idx,
+        accepted_via: module_idx,
+        unevaluated_ok: false,
+      });
       return false;
     }
 
+    // Modules only loaded through `import()` might not be evaluated yet, so updates reaching them
+    // this way are skipped at runtime until they are.
+    let dynamic_importers_idx = module
+      .dynamic_importers
+      .iter()
+      .filter_map(|importer| self.module_idx_by_abs_path.get(importer.resource_id()).copied())
+      .filter(|importer_idx| !module.importers_idx.contains(importer_idx))
+      .collect::<Vec<_>>();
+
     // If the module is not imported by one module, it should be a hmr root boundary.
-    if module.importers_idx.is_empty() {
+    if module.importers_idx.is_empty() && dynamic_importers_idx.is_empty() {
       return true;
     }
 
-    for importer_idx in &module.importers_idx {
-      let Module::Normal(importer) = &self.module_db.modules[*importer_idx] else {
+    let importers = module
+      .importers_idx
+      .iter()
+      .map(|importer_idx| (*importer_idx, false))
+      .chain(dynamic_importers_idx.into_iter().map(|importer_idx| (importer_idx, true)));
+    for (importer_idx, is_dynamic_importer) in importers {
+      let Module::Normal(importer) = &self.module_db.modules[importer_idx] else {
         continue;
       };
 
-      if importer.hmr_info.deps.contains(&module.id) {
-        affected_modules.insert(*importer_idx);
-        hmr_boundaries.insert(HmrBoundary { boundary: *importer_idx, accepted_via: module_idx });
+      if importer.hmr_info.deps.contains(&module.id)
+        && accept_overrides.get(&importer_idx) != Some(&HookHmrAcceptOutput::Invalidate)
+      {
+        affected_modules.insert(importer_idx);
+        hmr_boundaries.insert(HmrBoundary {
+          boundary: importer_idx,
+          accepted_via: module_idx,
+          unevaluated_ok: is_dynamic_importer,
+        });
         continue;
       }
 
-      if self.propagate_update(*importer_idx, visited_modules, hmr_boundaries, affected_modules) {
+      if self.propagate_update(
+        importer_idx,
+        accept_overrides,
+        visited_modules,
+        hmr_boundaries,
+        affected_modules,
+      ) {
         return true;
       }
     }
@@ -445,3 +893,13 @@ impl HmrManager {
     false
   }
 }
+
+/// Code of a module, or of a style update, in a patch.
+struct PatchDefinition {
+  /// The initializer the code defines, absent for style updates.
+  init_fn_name: Option<String>,
+  /// Initializers of other modules of the patch called by the code.
+  dependencies: Vec<String>,
+  code: String,
+  map: Option<SourceMap>,
+}
@@ -16,16 +16,15 @@ mod watch;
 mod watcher;
 use std::sync::Arc;
 
-use rolldown_fs::OsFileSystem;
 use rolldown_resolver::Resolver;
 
-pub(crate) type SharedResolver = Arc<Resolver<OsFileSystem>>;
+pub(crate) type SharedResolver = Arc<Resolver>;
 pub(crate) type SharedOptions = SharedNormalizedBundlerOptions;
 
 pub use crate::{
   bundler::Bundler,
   bundler_builder::BundlerBuilder,
-  types::bundle_output::BundleOutput,
+  types::bundle_output::{BundleOutput, CancellableBundleOutput},
   watch::event::{BundleEvent, WatcherEvent},
   watcher::Watcher,
 };
@@ -8,7 +8,7 @@ use oxc::{
     },
   },
   semantic::{ReferenceId, SymbolId},
-  span::{Atom, GetSpan, GetSpanMut, SPAN},
+  span::{Atom, GetSpan, GetSpanMut, SPAN, Span},
 };
 use rolldown_common::{
   AstScopes, ExportsKind, ImportRecordIdx, ImportRecordMeta, MemberExprRefResolution, Module,
@@ -430,7 +430,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
           } else {
             ast::PropertyKey::StringLiteral(self.snippet.alloc_string_literal(prop_name, SPAN))
           },
-          value: self.snippet.only_return_arrow_expr(returned),
+          value: self.snippet.only_return_fn_expr(returned),
           ..ast::ObjectProperty::dummy(self.alloc)
         }
         .into_in(self.alloc),
@@ -471,54 +471,9 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       match property_name {
         // Try to polyfill `import.meta.url`
         "url" => {
-          let new_expr = if is_node_cjs {
-            // Replace it with `require('url').pathToFileURL(__filename).href`
-
-            // require('url')
-            let require_call = self.snippet.builder.alloc_call_expression(
-              SPAN,
-              self.snippet.builder.expression_identifier(SPAN, "require"),
-              oxc::ast::NONE,
-              self.snippet.builder.vec1(ast::Argument::StringLiteral(
-                self.snippet.builder.alloc_string_literal(SPAN, "url", None),
-              )),
-              false,
-            );
-
-            // require('url').pathToFileURL
-            let require_path_to_file_url = self.snippet.builder.alloc_static_member_expression(
-              SPAN,
-              ast::Expression::CallExpression(require_call),
-              self.snippet.builder.identifier_name(SPAN, "pathToFileURL"),
-              false,
-            );
-
-            // require('url').pathToFileURL(__filename)
-            let require_path_to_file_url_call = self.snippet.builder.alloc_call_expression(
-              SPAN,
-              ast::Expression::StaticMemberExpression(require_path_to_file_url),
-              oxc::ast::NONE,
-              self.snippet.builder.vec1(ast::Argument::Identifier(
-                self.snippet.builder.alloc_identifier_reference(SPAN, "__filename"),
-              )),
-              false,
-            );
-
-            // require('url').pathToFileURL(__filename).href
-            let require_path_to_file_url_href =
-              self.snippet.builder.alloc_static_member_expression(
-                original_expr_span,
-                ast::Expression::CallExpression(require_path_to_file_url_call),
-                self.snippet.builder.identifier_name(SPAN, "href"),
-                false,
-              );
-            Some(ast::Expression::StaticMemberExpression(require_path_to_file_url_href))
-          } else {
-            // If we don't support polyfill `import.meta.url` in this platform and format, we just keep it as it is
-            // so users may handle it in their own way.
-            None
-          };
-          return new_expr;
+          // If we don't support polyfill `import.meta.url` in this platform and format, we just keep it as it is
+          // so users may handle it in their own way.
+          return is_node_cjs.then(|| self.import_meta_url_polyfill(original_expr_span));
         }
         "dirname" | "filename" => {
           let name = self.snippet.atom(&format!("__{property_name}"));
@@ -533,54 +488,104 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     None
   }
 
+  /// `require('url').pathToFileURL(__filename).href`, the `import.meta.url` of a cjs chunk on node.
+  fn import_meta_url_polyfill(&self, span: Span) -> Expression<'ast> {
+    // require('url')
+    let require_call = self.snippet.builder.alloc_call_expression(
+      SPAN,
+      self.snippet.builder.expression_identifier(SPAN, "require"),
+      oxc::ast::NONE,
+      self.snippet.builder.vec1(ast::Argument::StringLiteral(
+        self.snippet.builder.alloc_string_literal(SPAN, "url", None),
+      )),
+      false,
+    );
+
+    // require('url').pathToFileURL
+    let require_path_to_file_url = self.snippet.builder.alloc_static_member_expression(
+      SPAN,
+      ast::Expression::CallExpression(require_call),
+      self.snippet.builder.identifier_name(SPAN, "pathToFileURL"),
+      false,
+    );
+
+    // require('url').pathToFileURL(__filename)
+    let require_path_to_file_url_call = self.snippet.builder.alloc_call_expression(
+      SPAN,
+      ast::Expression::StaticMemberExpression(require_path_to_file_url),
+      oxc::ast::NONE,
+      self.snippet.builder.vec1(ast::Argument::Identifier(
+        self.snippet.builder.alloc_identifier_reference(SPAN, "__filename"),
+      )),
+      false,
+    );
+
+    // require('url').pathToFileURL(__filename).href
+    let require_path_to_file_url_href = self.snippet.builder.alloc_static_member_expression(
+      span,
+      ast::Expression::CallExpression(require_path_to_file_url_call),
+      self.snippet.builder.identifier_name(SPAN, "href"),
+      false,
+    );
+    ast::Expression::StaticMemberExpression(require_path_to_file_url_href)
+  }
+
+  /// Rewrites `import.meta.ROLLDOWN_FILE_URL_<referenceId>`, or its rollup counterpart
+  /// `import.meta.ROLLUP_FILE_URL_<referenceId>`, to the url of the emitted file.
   fn rewrite_rollup_file_url(&self, property_name: &str) -> Option<Expression<'ast>> {
-    // rewrite `import.meta.ROLLUP_FILE_URL_<referenceId>`
-    if let Some(reference_id) = property_name.strip_prefix("ROLLUP_FILE_URL_") {
-      // compute relative path from chunk to asset
-      let Ok(asset_file_name) = self.ctx.file_emitter.get_file_name(reference_id) else {
-        return None;
-      };
-      let absolute_asset_file_name = asset_file_name
-        .absolutize_with(self.ctx.options.cwd.as_path().join(&self.ctx.options.out_dir));
-      let relative_asset_path = &self.ctx.chunk_graph.chunk_table[self.ctx.chunk_id]
-        .relative_path_for(&absolute_asset_file_name);
-
-      // new URL({relative_asset_path}, import.meta.url).href
-      // TODO: needs import.meta.url polyfill for non esm
-      let new_expr = ast::Expression::StaticMemberExpression(
+    let reference_id = property_name
+      .strip_prefix("ROLLDOWN_FILE_URL_")
+      .or_else(|| property_name.strip_prefix("ROLLUP_FILE_URL_"))?;
+    // compute relative path from chunk to asset
+    let Ok(asset_file_name) = self.ctx.file_emitter.get_file_name(reference_id) else {
+      return None;
+    };
+    let absolute_asset_file_name =
+      asset_file_name.absolutize_with(self.ctx.options.resolved_out_dir());
+    let relative_asset_path = &self.ctx.chunk_graph.chunk_table[self.ctx.chunk_id]
+      .relative_path_for(&absolute_asset_file_name);
+    let relative_asset_path =
+      ast::Expression::StringLiteral(self.snippet.builder.alloc_string_literal(
+        SPAN,
+        self.snippet.builder.atom(relative_asset_path),
+        None,
+      ));
+
+    let import_meta_url = match (self.ctx.options.platform, &self.ctx.options.format) {
+      (_, OutputFormat::Esm) => ast::Expression::StaticMemberExpression(
         self.snippet.builder.alloc_static_member_expression(
           SPAN,
-          self.snippet.builder.expression_new(
+          self.snippet.builder.expression_meta_property(
             SPAN,
-            self.snippet.builder.expression_identifier(SPAN, "URL"),
-            NONE,
-            self.snippet.builder.vec_from_array([
-              ast::Argument::StringLiteral(self.snippet.builder.alloc_string_literal(
-                SPAN,
-                self.snippet.builder.atom(relative_asset_path),
-                None,
-              )),
-              ast::Argument::StaticMemberExpression(
-                self.snippet.builder.alloc_static_member_expression(
-                  SPAN,
-                  self.snippet.builder.expression_meta_property(
-                    SPAN,
-                    self.snippet.builder.identifier_name(SPAN, "import"),
-                    self.snippet.builder.identifier_name(SPAN, "meta"),
-                  ),
-                  self.snippet.builder.identifier_name(SPAN, "url"),
-                  false,
-                ),
-              ),
-            ]),
+            self.snippet.builder.identifier_name(SPAN, "import"),
+            self.snippet.builder.identifier_name(SPAN, "meta"),
           ),
-          self.snippet.builder.identifier_name(SPAN, "href"),
+          self.snippet.builder.identifier_name(SPAN, "url"),
           false,
         ),
-      );
-      return Some(new_expr);
-    }
-    None
+      ),
+      (Platform::Node, OutputFormat::Cjs) => self.import_meta_url_polyfill(SPAN),
+      // Without a url of the chunk, the path relative to it is the best we could offer.
+      _ => return Some(relative_asset_path),
+    };
+
+    // new URL({relative_asset_path}, import.meta.url).href
+    Some(ast::Expression::StaticMemberExpression(
+      self.snippet.builder.alloc_static_member_expression(
+        SPAN,
+        self.snippet.builder.expression_new(
+          SPAN,
+          self.snippet.builder.expression_identifier(SPAN, "URL"),
+          NONE,
+          self.snippet.builder.vec_from_array([
+            ast::Argument::from(relative_asset_path),
+            ast::Argument::from(import_meta_url),
+          ]),
+        ),
+        self.snippet.builder.identifier_name(SPAN, "href"),
+        false,
+      ),
+    ))
   }
 
   pub fn handle_new_url_with_string_literal_and_import_meta_url(
@@ -1184,7 +1189,11 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
               // `export function foo() {}` => `function foo() {}`
               // `export class Foo {}` => `class Foo {}`
 
-              *decl.span_mut() = named_decl_span;
+              // Spans past the end of the source are made up to keep them unique, like the ones of
+              // `export const a = 1, b = 2` split into declarations, and have no code to map to.
+              if named_decl_span.end as usize <= self.ctx.module.ecma_view.source.len() {
+                *decl.span_mut() = named_decl_span;
+              }
               top_stmt = ast::Statement::from(decl.take_in(self.alloc));
             } else {
               // `export { foo }`
@@ -1236,12 +1245,13 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
   fn try_rewrite_import_expression(&self, node: &mut ast::Expression<'ast>) -> bool {
     if let ast::Expression::ImportExpression(expr) = node {
-      if expr.options.is_none() {
-        // Make sure the import expression is in correct form. If it's not, we should leave it as it is.
-        if let Some(str) = expr.source.as_static_module_request() {
+      // Make sure the import expression is in correct form. If it's not, we should leave it as it is.
+      if let Some(str) = expr.source.as_static_module_request() {
+        let rec_id = self.ctx.module.imports[&expr.span];
+        let rec = &self.ctx.module.import_records[rec_id];
+        // Only options that are statically known import attributes could be handled.
+        if expr.options.is_none() || rec.attributes.is_some() {
           let mut needs_to_esm_helper = false;
-          let rec_id = self.ctx.module.imports[&expr.span];
-          let rec = &self.ctx.module.import_records[rec_id];
           let importee_id = rec.resolved_module;
           let importer_chunk = &self.ctx.chunk_graph.chunk_table[self.ctx.chunk_id];
           match &self.ctx.modules[importee_id] {
@@ -1254,6 +1264,8 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
               expr.source = Expression::StringLiteral(
                 self.snippet.alloc_string_literal(&import_path, expr.source.span()),
               );
+              // The importee is bundled into a js chunk, so the import attributes don't apply anymore.
+              expr.options = None;
               needs_to_esm_helper = importee.exports_kind.is_commonjs();
             }
             Module::External(importee) => {
@@ -52,17 +52,23 @@ impl ExternalModuleTask {
       normalize_side_effects(&self.ctx.options, resolved_id, None, None, resolved_id.side_effects)
         .await?;
     let id = ModuleId::new(&resolved_id.id);
+    let meta = self.ctx.plugin_driver.get_module_meta(&id);
     self.ctx.plugin_driver.set_module_info(
       &id.clone(),
       Arc::new(ModuleInfo {
         code: None,
+        stable_id: id.stabilize(&self.ctx.options.cwd),
         id,
+        module_type: None,
         is_entry: false,
+        has_side_effects: external_module_side_effects.has_side_effects(),
+        import_records: vec![],
         importers: FxIndexSet::default(),
         dynamic_importers: FxIndexSet::default(),
         imported_ids: FxIndexSet::default(),
         dynamically_imported_ids: FxIndexSet::default(),
         exports: vec![],
+        meta,
       }),
     );
 
@@ -1,5 +1,6 @@
 pub mod module_loader;
 pub mod module_task;
+pub mod persistent_cache;
 mod runtime_module_task;
 pub mod task_context;
 pub use module_loader::ModuleLoader;
@@ -1,5 +1,6 @@
 use super::external_module_task::ExternalModuleTask;
 use super::module_task::{ModuleTask, ModuleTaskOwner};
+use super::persistent_cache::PersistentCache;
 use super::runtime_module_task::RuntimeModuleTask;
 use super::task_context::TaskContextMeta;
 use crate::ecmascript::ecma_module_view_factory::normalize_side_effects;
@@ -16,13 +17,14 @@ use rolldown_common::dynamic_import_usage::DynamicImportExportsUsage;
 use rolldown_common::side_effects::{DeterminedSideEffects, HookSideEffects};
 use rolldown_common::{
   EcmaRelated, EntryPoint, EntryPointKind, ExternalModule, ExternalModuleTaskResult,
-  HybridIndexVec, ImportKind, ImportRecordIdx, ImportRecordMeta, ImporterRecord, Module, ModuleId,
-  ModuleIdx, ModuleLoaderMsg, ModuleType, NormalModuleTaskResult, PreserveEntrySignatures,
-  RUNTIME_MODULE_KEY, ResolvedId, RuntimeModuleBrief, RuntimeModuleTaskResult, StmtInfoIdx,
-  SymbolRef, SymbolRefDb, SymbolRefDbForModule,
+  FetchModuleMsg, HybridIndexVec, ImportKind, ImportRecordIdx, ImportRecordMeta, ImporterRecord,
+  Module, ModuleId, ModuleIdx, ModuleLoaderMsg, ModuleType, NormalModuleTaskResult,
+  PreserveEntrySignatures, RUNTIME_MODULE_KEY, ResolvedId, RuntimeModuleBrief,
+  RuntimeModuleTaskResult, SharedFileSystem, StmtInfoIdx, SymbolRef, SymbolRefDb,
+  SymbolRefDbForModule,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult};
-use rolldown_fs::OsFileSystem;
+
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_utils::indexmap::FxIndexSet;
 use rolldown_utils::rayon::{IntoParallelIterator, ParallelIterator};
@@ -128,13 +130,15 @@ impl Drop for ModuleLoader<'_> {
 }
 
 impl<'a> ModuleLoader<'a> {
+  #[expect(clippy::too_many_arguments)]
   pub fn new(
-    fs: OsFileSystem,
+    fs: SharedFileSystem,
     options: SharedOptions,
     resolver: SharedResolver,
     plugin_driver: SharedPluginDriver,
     cache: &'a mut ScanStageCache,
     is_full_scan: bool,
+    patched_sources: FxHashMap<ArcStr, ArcStr>,
     build_span: tracing::Span,
   ) -> BuildResult<Self> {
     // 1024 should be enough for most cases
@@ -152,14 +156,21 @@ impl<'a> ModuleLoader<'a> {
             .collect::<Vec<BuildDiagnostic>>()
         })?
       },
+      patched_sources,
     };
 
+    let persistent_cache = options
+      .cache_dir
+      .clone()
+      .map(|cache_dir| PersistentCache::new(cache_dir, fs.clone(), &options, &plugin_driver));
+
     let shared_context = Arc::new(TaskContext {
       options: Arc::clone(&options),
       tx: tx.clone(),
       resolver,
       fs,
       plugin_driver,
+      persistent_cache,
       meta,
     });
 
@@ -274,7 +285,7 @@ impl<'a> ModuleLoader<'a> {
     let mut entry_points: FxIndexSet<EntryPoint> = FxIndexSet::default();
     let user_defined_entries: Arc<Vec<(Option<ArcStr>, ResolvedId)>> =
       Arc::new(user_defined_entries);
-    for (defined_name, resolved_id) in user_defined_entries.iter() {
+    for (index, (defined_name, resolved_id)) in user_defined_entries.iter().enumerate() {
       let id = self.try_spawn_new_task(
         resolved_id.clone(),
         None,
@@ -284,11 +295,14 @@ impl<'a> ModuleLoader<'a> {
       );
       user_defined_entry_ids.insert(id);
 
+      // User defined entries are resolved from `input` in order.
+      let input_item = self.options.input.get(index);
       entry_points.insert(EntryPoint {
         name: defined_name.clone(),
         id,
         kind: EntryPointKind::UserDefined,
-        file_name: None,
+        file_name: input_item.and_then(|item| item.file_name.as_deref()).map(Into::into),
+        banner: input_item.and_then(|item| item.banner.as_deref()).map(Into::into),
         related_stmt_infos: vec![],
       });
     }
@@ -492,12 +506,13 @@ impl<'a> ModuleLoader<'a> {
           runtime_brief = Some(runtime);
           self.remaining -= 1;
         }
-        ModuleLoaderMsg::FetchModule(resolve_id) => {
+        ModuleLoaderMsg::FetchModule(msg) => {
+          let FetchModuleMsg { resolved_id, asserted_module_type } = *msg;
           self.try_spawn_new_task(
-            *resolve_id,
+            resolved_id,
             None,
             false,
-            None,
+            asserted_module_type,
             Arc::clone(&user_defined_entries),
           );
         }
@@ -532,6 +547,7 @@ impl<'a> ModuleLoader<'a> {
             id: module_idx,
             kind: EntryPointKind::UserDefined,
             file_name: data.file_name.clone(),
+            banner: None,
             related_stmt_infos: vec![],
           };
 
@@ -653,10 +669,16 @@ impl<'a> ModuleLoader<'a> {
       let Some(module) = module.as_normal() else {
         return;
       };
-      self
-        .shared_context
-        .plugin_driver
-        .set_module_info(&module.id, Arc::new(module.to_module_info(None)));
+      let plugin_driver = &self.shared_context.plugin_driver;
+      // Import records with resolved ids are only collected while the module is parsed, so keep them as they were.
+      let import_records = plugin_driver
+        .modules
+        .get(module.id.resource_id())
+        .map(|info| info.import_records.clone())
+        .unwrap_or_default();
+      let meta = plugin_driver.get_module_meta(&module.id);
+      plugin_driver
+        .set_module_info(&module.id, Arc::new(module.to_module_info(None, import_records, meta)));
     });
     // if `inline_dynamic_imports` is set to be true, here we should not put dynamic imports to entries
     if !self.options.inline_dynamic_imports {
@@ -669,6 +691,7 @@ impl<'a> ModuleLoader<'a> {
           id,
           kind: EntryPointKind::DynamicImport,
           file_name: None,
+          banner: None,
           related_stmt_infos,
         }
       }));
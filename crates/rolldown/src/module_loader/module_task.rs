@@ -8,18 +8,25 @@ use std::sync::Arc;
 use sugar_path::SugarPath;
 
 use rolldown_common::{
-  ImportKind, ModuleId, ModuleIdx, ModuleInfo, ModuleLoaderMsg, ModuleType, NormalModule,
-  NormalModuleTaskResult, ResolvedId, StrOrBytes,
+  ImportKind, ModuleId, ModuleIdx, ModuleInfo, ModuleInfoImportRecord, ModuleLoaderMsg, ModuleType,
+  NormalModule, NormalModuleTaskResult, ResolvedId, StrOrBytes,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult, UnloadableDependencyContext};
+use rolldown_fs::FileSystem;
+use rolldown_plugin::PluginDriver;
 
-use super::{resolve_utils::resolve_dependencies, task_context::TaskContext};
+use super::{
+  persistent_cache::PersistentCache, resolve_utils::resolve_dependencies, task_context::TaskContext,
+};
 use crate::{
   asset::create_asset_view,
   css::create_css_view,
   ecmascript::ecma_module_view_factory::{CreateEcmaViewReturn, create_ecma_view},
   types::module_factory::{CreateModuleContext, CreateModuleViewArgs},
-  utils::{load_source::load_source, transform_source::transform_source},
+  utils::{
+    input_sourcemap::read_input_sourcemap, load_source::load_source,
+    transform_source::transform_source,
+  },
 };
 
 pub struct ModuleTaskOwner {
@@ -68,7 +75,8 @@ impl ModuleTask {
 
   #[tracing::instrument(name="NormalModuleTask::run", parent = &self.build_span, level = "trace", skip_all, fields(module_id = ?self.resolved_id.id))]
   pub async fn run(mut self) {
-    if let Err(errs) = self.run_inner().await {
+    let module_id = self.resolved_id.id.clone();
+    if let Err(errs) = PluginDriver::with_processing_module(module_id, self.run_inner()).await {
       self
         .ctx
         .plugin_driver
@@ -90,18 +98,26 @@ impl ModuleTask {
 
     // Add watch files for watcher recover if build errors occurred.
     self.ctx.plugin_driver.watch_files.insert(self.resolved_id.id.clone());
+    self.ctx.plugin_driver.mark_context_load_module_loading(&id);
+
+    let stable_id = id.stabilize(&self.ctx.options.cwd);
 
     self.ctx.plugin_driver.set_module_info(
       &id,
       Arc::new(ModuleInfo {
         code: None,
         id: id.clone(),
+        stable_id: stable_id.clone(),
+        module_type: None,
         is_entry: self.is_user_defined_entry,
+        has_side_effects: true,
+        import_records: vec![],
         importers: FxIndexSet::default(),
         dynamic_importers: FxIndexSet::default(),
         imported_ids: FxIndexSet::default(),
         dynamically_imported_ids: FxIndexSet::default(),
         exports: vec![],
+        meta: self.ctx.plugin_driver.get_module_meta(&id),
       }),
     );
 
@@ -109,9 +125,8 @@ impl ModuleTask {
     let mut hook_side_effects = self.resolved_id.side_effects.take();
 
     let (mut source, module_type) =
-      self.load_source_without_cache(&mut sourcemap_chain, &mut hook_side_effects).await?;
+      self.load_source(&mut sourcemap_chain, &mut hook_side_effects).await?;
 
-    let stable_id = id.stabilize(&self.ctx.options.cwd);
     let mut raw_import_records = IndexVec::default();
 
     let (asset_view, css_view) = match module_type {
@@ -154,10 +169,13 @@ impl ModuleTask {
       mut ecma_view,
       ecma_related,
       raw_import_records: ecma_raw_import_records,
+      mut resolved_dynamic_imports,
     } = ret;
 
     if css_view.is_none() {
       raw_import_records = ecma_raw_import_records;
+    } else {
+      resolved_dynamic_imports.clear();
     }
 
     let resolved_deps = resolve_dependencies(
@@ -166,6 +184,7 @@ impl ModuleTask {
       &self.ctx.resolver,
       &self.ctx.plugin_driver,
       &raw_import_records,
+      &resolved_dynamic_imports,
       ecma_view.source.clone(),
       &mut warnings,
       &module_type,
@@ -208,7 +227,18 @@ impl ModuleTask {
       originative_resolved_id: self.resolved_id.clone(),
     };
 
-    let module_info = Arc::new(module.to_module_info(Some(&raw_import_records)));
+    let import_records = raw_import_records
+      .iter()
+      .zip(&resolved_deps)
+      .map(|(record, info)| ModuleInfoImportRecord {
+        specifier: record.module_request.clone(),
+        resolved_id: ModuleId::new(ArcStr::clone(&info.id)),
+        kind: record.kind,
+      })
+      .collect();
+    let meta = self.ctx.plugin_driver.get_module_meta(&module.id);
+    let module_info =
+      Arc::new(module.to_module_info(Some(&raw_import_records), import_records, meta));
     self.ctx.plugin_driver.set_module_info(&module.id, Arc::clone(&module_info));
     self.ctx.plugin_driver.module_parsed(Arc::clone(&module_info), &module).await?;
     self.ctx.plugin_driver.mark_context_load_modules_loaded(&module.id, true).await?;
@@ -227,6 +257,56 @@ impl ModuleTask {
     Ok(())
   }
 
+  /// Reuses the results of an earlier build from `cache_dir` if it's set, see [`PersistentCache`].
+  async fn load_source(
+    &self,
+    sourcemap_chain: &mut Vec<rolldown_sourcemap::SourceMap>,
+    hook_side_effects: &mut Option<rolldown_common::side_effects::HookSideEffects>,
+  ) -> BuildResult<(StrOrBytes, ModuleType)> {
+    let Some(cache) = &self.ctx.persistent_cache else {
+      return self.load_source_without_cache(sourcemap_chain, hook_side_effects).await;
+    };
+    let Some(key) = self.persistent_cache_key(cache, *hook_side_effects) else {
+      return self.load_source_without_cache(sourcemap_chain, hook_side_effects).await;
+    };
+    if let Some(cached) = cache.read(key, &self.resolved_id.id) {
+      sourcemap_chain.extend(cached.sourcemap_chain);
+      *hook_side_effects = cached.side_effects;
+      return Ok((StrOrBytes::Str(cached.source), cached.module_type));
+    }
+    let (source, module_type) =
+      self.load_source_without_cache(sourcemap_chain, hook_side_effects).await?;
+    if let StrOrBytes::Str(code) = &source {
+      cache.write(
+        key,
+        &self.resolved_id.id,
+        code,
+        &module_type,
+        sourcemap_chain,
+        *hook_side_effects,
+      );
+    }
+    Ok((source, module_type))
+  }
+
+  /// `None` if the module can't be cached. Only modules backed by a file are, as the content of
+  /// the others is only known once they're loaded.
+  fn persistent_cache_key(
+    &self,
+    cache: &PersistentCache,
+    hook_side_effects: Option<rolldown_common::side_effects::HookSideEffects>,
+  ) -> Option<u128> {
+    let id = &self.resolved_id.id;
+    if self.resolved_id.ignored
+      || self.ctx.meta.patched_sources.contains_key(id)
+      || !self.ctx.plugin_driver.should_cache_module(id)
+    {
+      return None;
+    }
+    let content = self.ctx.fs.read(id.as_path()).ok()?;
+    Some(cache.key(id, &content, self.asserted_module_type.as_ref(), hook_side_effects))
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   async fn load_source_without_cache(
     &self,
@@ -241,6 +321,7 @@ impl ModuleTask {
       hook_side_effects,
       &self.ctx.options,
       self.asserted_module_type.as_ref(),
+      self.ctx.meta.patched_sources.get(&self.resolved_id.id),
     )
     .await;
     let (source, mut module_type) = result.map_err(|err| {
@@ -259,6 +340,17 @@ impl ModuleTask {
     }
     let source = match source {
       StrOrBytes::Str(source) => {
+        // A map left by a compiler which ran before the bundler starts the chain, unless a load
+        // hook gave one.
+        if sourcemap_chain.is_empty()
+          && self.ctx.options.sourcemap.is_some()
+          && matches!(
+            module_type,
+            ModuleType::Js | ModuleType::Jsx | ModuleType::Ts | ModuleType::Tsx
+          )
+        {
+          sourcemap_chain.extend(read_input_sourcemap(&source, &self.resolved_id.id, &self.ctx.fs));
+        }
         // Run plugin transform.
         let source = transform_source(
           &self.ctx.plugin_driver,
@@ -0,0 +1,166 @@
+use std::path::PathBuf;
+
+use rolldown_common::{
+  ModuleType, NormalizedBundlerOptions, SharedFileSystem, side_effects::HookSideEffects,
+};
+use rolldown_fs::FileSystem;
+use rolldown_plugin::PluginDriver;
+use rolldown_sourcemap::SourceMap;
+use serde_json::{Value, json};
+use xxhash_rust::xxh3::Xxh3;
+
+/// Bumped when the layout of the entries changes, so the entries written before are missed instead
+/// of misread.
+const FORMAT_VERSION: u64 = 1;
+
+/// Stores the `load` and `transform` results of modules in `cache_dir`, keyed by the content of their
+/// file, so later builds skip the plugins for the files that didn't change. Entries go through the
+/// `file_system` of the build, like the modules they're made of.
+///
+/// Only what the plugins produce is cached. The import records and the side effects detected in the
+/// code are rebuilt by parsing the cached source, which is cheap compared to running the plugins.
+///
+/// Entries that can't be read or parsed are treated as missing, and failing to write one only costs
+/// the next build a cache miss, so a broken cache never fails a build.
+pub struct PersistentCache {
+  dir: PathBuf,
+  fs: SharedFileSystem,
+  /// Hash of everything other than the content of a module its cached results depend on.
+  fingerprint: u128,
+}
+
+pub struct CachedModule {
+  pub source: String,
+  pub module_type: ModuleType,
+  pub sourcemap_chain: Vec<SourceMap>,
+  pub side_effects: Option<HookSideEffects>,
+}
+
+impl PersistentCache {
+  pub fn new(
+    dir: PathBuf,
+    fs: SharedFileSystem,
+    options: &NormalizedBundlerOptions,
+    plugin_driver: &PluginDriver,
+  ) -> Self {
+    let mut hasher = Xxh3::new();
+    hasher.update(&FORMAT_VERSION.to_le_bytes());
+    update_with_str(&mut hasher, env!("CARGO_PKG_VERSION"));
+    for name in plugin_driver.plugin_names() {
+      update_with_str(&mut hasher, &name);
+    }
+    let mut module_types = options.module_types.iter().collect::<Vec<_>>();
+    module_types.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    for (extension, module_type) in module_types {
+      update_with_str(&mut hasher, extension);
+      update_with_str(&mut hasher, &module_type.to_string());
+    }
+    update_with_str(&mut hasher, &options.cwd.to_string_lossy());
+    update_with_str(&mut hasher, &format!("{:?}", options.platform));
+    hasher.update(&[u8::from(options.is_sourcemap_enabled())]);
+    Self { dir, fs, fingerprint: hasher.digest128() }
+  }
+
+  pub fn key(
+    &self,
+    id: &str,
+    content: &[u8],
+    asserted_module_type: Option<&ModuleType>,
+    side_effects: Option<HookSideEffects>,
+  ) -> u128 {
+    let mut hasher = Xxh3::new();
+    hasher.update(&self.fingerprint.to_le_bytes());
+    update_with_str(&mut hasher, id);
+    update_with_str(
+      &mut hasher,
+      &asserted_module_type.map(ToString::to_string).unwrap_or_default(),
+    );
+    update_with_str(&mut hasher, side_effects.map_or("", side_effects_to_str));
+    hasher.update(content);
+    hasher.digest128()
+  }
+
+  pub fn read(&self, key: u128, id: &str) -> Option<CachedModule> {
+    let content = self.fs.read(&self.entry_path(key)).ok()?;
+    let entry = serde_json::from_slice::<Value>(&content).ok()?;
+    if entry["version"].as_u64()? != FORMAT_VERSION || entry["id"].as_str()? != id {
+      return None;
+    }
+    let sourcemap_chain = entry["sourcemapChain"]
+      .as_array()?
+      .iter()
+      .map(|map| SourceMap::from_json_string(map.as_str()?).ok())
+      .collect::<Option<Vec<_>>>()?;
+    Some(CachedModule {
+      source: entry["source"].as_str()?.to_string(),
+      module_type: ModuleType::from_known_str(entry["moduleType"].as_str()?).ok()?,
+      sourcemap_chain,
+      side_effects: match &entry["sideEffects"] {
+        Value::Null => None,
+        value => Some(side_effects_from_str(value.as_str()?)?),
+      },
+    })
+  }
+
+  pub fn write(
+    &self,
+    key: u128,
+    id: &str,
+    source: &str,
+    module_type: &ModuleType,
+    sourcemap_chain: &[SourceMap],
+    side_effects: Option<HookSideEffects>,
+  ) {
+    // Custom module types fail the build later on, and can't be read back anyway.
+    if matches!(module_type, ModuleType::Custom(_)) {
+      return;
+    }
+    let entry = json!({
+      "version": FORMAT_VERSION,
+      "id": id,
+      "source": source,
+      "moduleType": module_type.to_string(),
+      "sourcemapChain": sourcemap_chain.iter().map(SourceMap::to_json_string).collect::<Vec<_>>(),
+      "sideEffects": side_effects.map(side_effects_to_str),
+    });
+    // Written to a temporary file first, so builds running at the same time never read a partial
+    // entry.
+    let path = self.entry_path(key);
+    let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+    let written = self
+      .fs
+      .create_dir_all(&self.dir)
+      .and_then(|()| self.fs.write(&temp_path, entry.to_string().as_bytes()))
+      .and_then(|()| self.fs.rename(&temp_path, &path));
+    if written.is_err() {
+      let _ = self.fs.remove_file(&temp_path);
+    }
+  }
+
+  fn entry_path(&self, key: u128) -> PathBuf {
+    self.dir.join(format!("{key:032x}.json"))
+  }
+}
+
+/// Hashes the length first, so consecutive strings can't be confused with each other.
+fn update_with_str(hasher: &mut Xxh3, value: &str) {
+  hasher.update(&value.len().to_le_bytes());
+  hasher.update(value.as_bytes());
+}
+
+fn side_effects_to_str(side_effects: HookSideEffects) -> &'static str {
+  match side_effects {
+    HookSideEffects::True => "true",
+    HookSideEffects::False => "false",
+    HookSideEffects::NoTreeshake => "no-treeshake",
+  }
+}
+
+fn side_effects_from_str(value: &str) -> Option<HookSideEffects> {
+  match value {
+    "true" => Some(HookSideEffects::True),
+    "false" => Some(HookSideEffects::False),
+    "no-treeshake" => Some(HookSideEffects::NoTreeshake),
+    _ => None,
+  }
+}
@@ -7,6 +7,7 @@ use rolldown_utils::{
   concat_string,
   ecmascript::{self},
 };
+use rustc_hash::FxHashMap;
 use std::sync::Arc;
 
 use rolldown_common::{
@@ -45,6 +46,7 @@ pub async fn resolve_id(
     None,
     Arc::default(),
     false,
+    &[],
     bundle_options,
   )
   .await
@@ -57,6 +59,7 @@ pub async fn resolve_dependencies(
   resolver: &SharedResolver,
   plugin_driver: &SharedPluginDriver,
   dependencies: &IndexVec<ImportRecordIdx, RawImportRecord>,
+  resolved_dynamic_imports: &FxHashMap<ImportRecordIdx, ResolvedId>,
   source: ArcStr,
   warnings: &mut Vec<BuildDiagnostic>,
   module_type: &ModuleType,
@@ -69,7 +72,12 @@ pub async fn resolve_dependencies(
     let plugin_driver = Arc::clone(plugin_driver);
     let importer = &self_resolved_id.id;
     let kind = item.kind;
+    // `import()` expressions that have been resolved by `resolve_dynamic_import` hooks before scanning.
+    let resolved_dynamic_import = resolved_dynamic_imports.get(&idx).cloned();
     async move {
+      if let Some(resolved_id) = resolved_dynamic_import {
+        return Ok((specifier, idx, Ok(resolved_id)));
+      }
       // TODO: We should early return when `async closure is stable`
       resolve_id(&bundle_options, &resolver, &plugin_driver, importer, &specifier, kind)
         .await
@@ -1,8 +1,14 @@
+use std::path::Path;
 use std::sync::Arc;
 
 use arcstr::ArcStr;
-use oxc::ast_visit::VisitMut;
+use oxc::ast::AstBuilder;
+use oxc::ast::ast::{Expression, ObjectProperty, PropertyKey};
+use oxc::ast_visit::{VisitMut, walk_mut};
 use oxc::span::SourceType;
+use oxc::transformer::{
+  ArrowFunctionsOptions, ES2015Options, EnvOptions, TransformOptions, Transformer,
+};
 use oxc_index::IndexVec;
 use rolldown_common::{
   EcmaView, EcmaViewMeta, ExportsKind, ModuleDefFormat, ModuleIdx, ModuleType, NormalModule,
@@ -13,7 +19,7 @@ use rolldown_common::{
   RuntimeModuleTaskResult,
 };
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
-use rolldown_error::BuildResult;
+use rolldown_error::{BuildDiagnostic, BuildResult, InvalidOptionType};
 use rolldown_utils::indexmap::FxIndexSet;
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -82,7 +88,13 @@ impl RuntimeModuleTask {
       }
       runtime_source.push_str(&get_runtime_js());
       runtime_source.push_str(include_str!("../runtime/runtime-extra-dev-common.js"));
-      if let Some(implement) = hmr_options.implement.as_deref() {
+      if let Some(implement) = &hmr_options.implement {
+        let format = self.ctx.options.format;
+        let implement = implement.for_format(format).ok_or_else(|| {
+          BuildDiagnostic::invalid_option(InvalidOptionType::MissingHmrImplementForFormat(
+            format.to_string(),
+          ))
+        })?;
         runtime_source.push_str(implement);
       } else {
         let content = include_str!("../runtime/runtime-extra-dev-default.js");
@@ -141,6 +153,7 @@ impl RuntimeModuleTask {
         &self.ctx.resolver,
         &self.ctx.plugin_driver,
         &raw_import_records,
+        &FxHashMap::default(),
         source.clone(),
         &mut vec![],
         &module_type,
@@ -224,6 +237,29 @@ impl RuntimeModuleTask {
 
     let mut ast = EcmaCompiler::parse(filename, source, source_type)?;
 
+    if !self.ctx.options.generated_code.arrow_functions {
+      // Runtime helpers are written with arrow functions, lower them to function expressions.
+      let scoping = ast.make_scoping();
+      let transform_options = TransformOptions {
+        env: EnvOptions {
+          es2015: ES2015Options { arrow_function: Some(ArrowFunctionsOptions::default()) },
+          ..Default::default()
+        },
+        ..Default::default()
+      };
+      ast.program.with_mut(|fields| {
+        Transformer::new(fields.allocator, Path::new(filename), &transform_options)
+          .build_with_scoping(scoping, fields.program);
+      });
+    }
+
+    if !self.ctx.options.generated_code.object_shorthand {
+      ast.program.with_mut(|fields| {
+        ObjectShorthandExpander { ast: AstBuilder::new(fields.allocator) }
+          .visit_program(fields.program);
+      });
+    }
+
     ast.program.with_mut(|fields| {
       let mut pre_processor = PreProcessor::new(fields.allocator, false);
       pre_processor.visit_program(fields.program);
@@ -246,3 +282,25 @@ impl RuntimeModuleTask {
     Ok((ast, scan_result))
   }
 }
+
+/// Rewrites `{ a }` and `{ a: a }` in runtime helpers to `{ "a": a }`. The codegen prints any
+/// property whose key matches its identifier value as a shorthand, so quoting the key is the only
+/// way to keep the output free of es2015 object shorthands.
+struct ObjectShorthandExpander<'a> {
+  ast: AstBuilder<'a>,
+}
+
+impl<'a> VisitMut<'a> for ObjectShorthandExpander<'a> {
+  fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+    if let (PropertyKey::StaticIdentifier(key), Expression::Identifier(value)) =
+      (&prop.key, &prop.value)
+    {
+      if key.name == value.name {
+        prop.key =
+          PropertyKey::StringLiteral(self.ast.alloc_string_literal(key.span, key.name, None));
+        prop.shorthand = false;
+      }
+    }
+    walk_mut::walk_object_property(self, prop);
+  }
+}
@@ -1,8 +1,10 @@
+use arcstr::ArcStr;
 use oxc::transformer_plugins::ReplaceGlobalDefinesConfig;
-use rolldown_common::ModuleLoaderMsg;
-use rolldown_fs::OsFileSystem;
+use rolldown_common::{ModuleLoaderMsg, SharedFileSystem};
 use rolldown_plugin::SharedPluginDriver;
+use rustc_hash::FxHashMap;
 
+use super::persistent_cache::PersistentCache;
 use crate::{SharedOptions, SharedResolver};
 
 /// Used to store common data shared between all tasks.
@@ -10,11 +12,15 @@ pub struct TaskContext {
   pub options: SharedOptions,
   pub tx: tokio::sync::mpsc::Sender<ModuleLoaderMsg>,
   pub resolver: SharedResolver,
-  pub fs: OsFileSystem,
+  pub fs: SharedFileSystem,
   pub plugin_driver: SharedPluginDriver,
+  /// Set when `cache_dir` is.
+  pub persistent_cache: Option<PersistentCache>,
   pub meta: TaskContextMeta,
 }
 
 pub struct TaskContextMeta {
   pub replace_global_define_config: Option<ReplaceGlobalDefinesConfig>,
+  /// Sources provided in memory for HMR, used instead of reading the module from disk.
+  pub patched_sources: FxHashMap<ArcStr, ArcStr>,
 }
@@ -1,7 +1,34 @@
 // @ts-check
 
+/**
+ * Base class of the HMR runtime. Generated chunks and patches only talk to the instance assigned to
+ * `globalThis.__rolldown_runtime__`, through these methods:
+ *
+ * - `createModuleHotContext(moduleId)` returns the `import.meta.hot` of a module. User code calls
+ *   `accept`, `dispose` and `invalidate` on it.
+ * - `registerModule(moduleId, module)` and `loadExports(moduleId)` track the exports of each module.
+ *   Patches check `isModuleEvaluated(moduleId)` before updating a boundary that only imports the
+ *   module with `import()`, so modules that were never loaded aren't evaluated by the update.
+ * - `updateStyle(moduleId, css)` swaps the content of an edited style module. Style modules accept
+ *   their own updates, so the runtime provides a default implementation.
+ * - `registerPatchInitializers(initializers)` is called by the parts of a patch split with
+ *   `experimental.hmr.maxPatchSize`, so the other parts can call the initializers they define.
+ * - `disposeModules(moduleIds)` starts every patch, with the modules it runs again. It calls their
+ *   `dispose` handlers, registered with `addDisposeHandler`, passing the `hot.data` of the module.
+ *   Hot contexts read it with `getHotData(moduleId)`, so the next evaluation sees the same object.
+ * - `applyUpdates(boundaries)` ends every patch, with the ids of the modules accepting the update.
+ *
+ * A custom runtime, passed as `experimental.hmr.implement`, is appended after this class. It has to
+ * extend it, implement `createModuleHotContext` and `applyUpdates`, and assign an instance to
+ * `globalThis.__rolldown_runtime__`.
+ */
 // oxlint-disable-next-line no-unused-vars
 class DevRuntime {
+  /**
+   * Bumped whenever the calls above change in a way existing runtimes need to handle, so a custom
+   * runtime could check it is compatible.
+   */
+  static API_VERSION = 1
   /**
    * @type {Record<string, { exports: any }>}
    */
@@ -27,6 +54,99 @@ class DevRuntime {
     console.debug('Registering module', id, module);
     this.modules[id] = module
   }
+  /**
+   * @param {string} id
+   */
+  isModuleEvaluated(id) {
+    return id in this.modules
+  }
+  /**
+   * @type {Map<string, HTMLStyleElement>}
+   */
+  styleElements = new Map()
+  /**
+   * Injects the latest `css` of a style module after the stylesheets of the initial build, so it
+   * takes precedence over the stale rules. Outside of browsers, there is nothing to update.
+   *
+   * @param {string} id
+   * @param {string} css
+   */
+  updateStyle(id, css) {
+    if (typeof document === 'undefined') {
+      return
+    }
+    let style = this.styleElements.get(id)
+    if (!style) {
+      style = document.createElement('style')
+      style.setAttribute('data-rolldown-module-id', id)
+      document.head.appendChild(style)
+      this.styleElements.set(id, style)
+    }
+    style.textContent = css
+  }
+  /**
+   * @type {Record<string, () => any>}
+   * @internal
+   */
+  patchInitializers = {}
+  /**
+   * Parts of a split patch are separate scripts, so the module initializers they define are shared
+   * through the runtime.
+   *
+   * @param {Record<string, () => any>} initializers
+   */
+  registerPatchInitializers(initializers) {
+    Object.assign(this.patchInitializers, initializers)
+  }
+  /**
+   * @type {Record<string, Record<string, any>>}
+   * @internal
+   */
+  hotData = {}
+  /**
+   * @type {Map<string, ((data: Record<string, any>) => void)[]>}
+   * @internal
+   */
+  disposeHandlers = new Map()
+  /**
+   * The `hot.data` of a module, kept across its evaluations.
+   *
+   * @param {string} id
+   */
+  getHotData(id) {
+    return (this.hotData[id] ??= {})
+  }
+  /**
+   * @param {string} id
+   * @param {(data: Record<string, any>) => void} handler
+   */
+  addDisposeHandler(id, handler) {
+    const handlers = this.disposeHandlers.get(id)
+    if (handlers) {
+      handlers.push(handler)
+    } else {
+      this.disposeHandlers.set(id, [handler])
+    }
+  }
+  /**
+   * Modules are listed from the edited ones up to the boundaries, so importers are disposed after
+   * the modules they import. Handlers only run once, the next evaluation registers its own ones.
+   *
+   * @param {string[]} ids
+   */
+  disposeModules(ids) {
+    for (const id of ids) {
+      const handlers = this.disposeHandlers.get(id)
+      if (!handlers) {
+        continue
+      }
+      this.disposeHandlers.delete(id)
+      const data = this.getHotData(id)
+      for (const handler of handlers) {
+        handler(data)
+      }
+    }
+  }
   /**
    * @param {string} id
    */
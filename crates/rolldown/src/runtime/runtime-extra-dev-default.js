@@ -15,6 +15,17 @@ class ModuleHotContext {
     this.devRuntime = devRuntime;
   }
 
+  get data() {
+    return this.devRuntime.getHotData(this.moduleId);
+  }
+
+  /**
+   * @param {(data: Record<string, any>) => void} cb
+   */
+  dispose(cb) {
+    this.devRuntime.addDisposeHandler(this.moduleId, cb);
+  }
+
   /**
    * @overload
    * @param {(mod: Record<string, any>) => void} cb
@@ -75,8 +75,7 @@ impl GenerateStage<'_> {
     let mut index_module_groups: IndexVec<ModuleGroupIdx, ModuleGroup> = IndexVec::new();
     let mut name_to_module_group: FxHashMap<ArcStr, ModuleGroupIdx> = FxHashMap::default();
 
-    for normal_module in self.link_output.module_table.modules.iter().filter_map(Module::as_normal)
-    {
+    for normal_module in self.normal_modules_in_assignment_order() {
       if !normal_module.meta.is_included() {
         continue;
       }
@@ -102,6 +102,7 @@ impl GenerateStage<'_> {
           },
           self.options,
         );
+        chunk.banner = matched_entry.and_then(|item| item.banner.clone());
         let chunk_idx = chunk_graph.add_chunk(chunk);
         if let Some(entry) = matched_entry {
           if let Some(reference_ids) = self.link_output.entry_point_to_reference_ids.get(entry) {
@@ -374,6 +375,7 @@ impl GenerateStage<'_> {
         },
         self.options,
       );
+      chunk.banner.clone_from(&entry_point.banner);
       let chunk_idx = chunk_graph.add_chunk(chunk);
       if let Some(reference_ids) = self.link_output.entry_point_to_reference_ids.get(entry_point) {
         chunk_graph.chunk_idx_to_reference_ids.insert(chunk_idx, reference_ids.clone());
@@ -423,8 +425,7 @@ impl GenerateStage<'_> {
         || !self.link_output.overrode_preserve_entry_signature_map.is_empty();
     // 1. Assign modules to corresponding chunks
     // 2. Create shared chunks to store modules that belong to multiple chunks.
-    for normal_module in self.link_output.module_table.modules.iter().filter_map(Module::as_normal)
-    {
+    for normal_module in self.normal_modules_in_assignment_order() {
       if !normal_module.meta.is_included() {
         continue;
       }
@@ -395,7 +395,7 @@ impl GenerateStage<'_> {
               let imports_from_other_chunks = &mut index_imports_from_other_chunks[chunk_id];
               imports_from_other_chunks.entry(importee_chunk_idx).or_default();
             });
-        } else {
+        } else if self.options.hoist_transitive_imports {
           chunk_graph
             .chunk_table
             .iter_enumerated()
@@ -419,6 +419,31 @@ impl GenerateStage<'_> {
         }
       }
 
+      // Without hoisting, every chunk imports the chunks of its direct dependencies by itself, so the
+      // transitive ones are still evaluated for their side effects.
+      if !self.options.preserve_modules
+        && !self.options.hoist_transitive_imports
+        && !self.options.experimental.is_strict_execution_order_enabled()
+      {
+        chunk
+          .modules
+          .iter()
+          .filter_map(|module_idx| self.link_output.module_table[*module_idx].as_normal())
+          .flat_map(|module| module.import_records.iter())
+          .filter(|rec| rec.kind != ImportKind::DynamicImport)
+          .filter_map(|rec| chunk_graph.module_to_chunk[rec.resolved_module])
+          .filter(|importee_chunk_id| {
+            *importee_chunk_id != chunk_id
+              && chunk_graph.chunk_table[*importee_chunk_id]
+                .has_side_effect(self.link_output.runtime.id())
+          })
+          .for_each(|importee_chunk_id| {
+            index_cross_chunk_imports[chunk_id].insert(importee_chunk_id);
+            let imports_from_other_chunks = &mut index_imports_from_other_chunks[chunk_id];
+            imports_from_other_chunks.entry(importee_chunk_id).or_default();
+          });
+      }
+
       // Make sure the runtime module is imported at hmr.
       if self.options.is_hmr_enabled() {
         if let Some(importee_chunk_id) = chunk_graph.module_to_chunk[self.link_output.runtime.id()]
@@ -441,6 +466,7 @@ impl GenerateStage<'_> {
     // Generate cross-chunk exports. These must be computed before cross-chunk
     // imports because of export alias renaming, which must consider all export
     // aliases simultaneously to avoid collisions.
+    let is_deterministic = self.options.experimental.is_deterministic_enabled();
     for (chunk_id, chunk) in chunk_graph.chunk_table.iter_mut_enumerated() {
       let mut name_count = FxHashMap::with_capacity(index_chunk_exported_symbols[chunk_id].len());
       for (chunk_export, predefined_names) in index_chunk_exported_symbols[chunk_id]
@@ -448,7 +474,12 @@ impl GenerateStage<'_> {
         .sorted_by_cached_key(|(symbol_ref, _predefined_names)| {
           // same deconflict order in deconflict_chunk_symbols.rs
           // https://github.com/rolldown/rolldown/blob/504ea76c00563eb7db7a49c2b6e04b2fbe61bdc1/crates/rolldown/src/utils/chunk/deconflict_chunk_symbols.rs?plain=1#L86-L102
-          Reverse::<u32>(self.link_output.module_table[symbol_ref.owner].exec_order())
+          (
+            Reverse::<u32>(self.link_output.module_table[symbol_ref.owner].exec_order()),
+            // Symbols of the same module are otherwise visited in the order of a hash map keyed by
+            // module index.
+            is_deterministic.then_some(symbol_ref.symbol),
+          )
         })
       {
         let original_name: rolldown_rstr::Rstr = match predefined_names.as_slice() {
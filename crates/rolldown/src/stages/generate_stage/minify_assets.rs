@@ -16,8 +16,8 @@ impl GenerateStage<'_> {
         if test_d_ts_pattern(&asset.filename) {
           return Ok(());
         }
-        match asset.meta {
-          rolldown_common::InstantiationKind::Ecma(_) => {
+        match &mut asset.meta {
+          rolldown_common::InstantiationKind::Ecma(ecma_meta) => {
             // TODO: Do we need to ensure `asset.filename` to be absolute path?
             let (minified_content, new_map) = EcmaCompiler::minify(
               asset.content.try_as_inner_str()?,
@@ -32,6 +32,7 @@ impl GenerateStage<'_> {
               },
               matches!(self.options.legal_comments, LegalComments::Inline),
             );
+            ecma_meta.unminified_length = Some(asset.content.try_as_inner_str()?.len());
             asset.content = minified_content.into();
             match (&asset.map, &new_map) {
               (Some(origin_map), Some(new_map)) => {
@@ -1,17 +1,17 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use arcstr::ArcStr;
 use futures::future::try_join_all;
 use oxc::semantic::{ScopeId, SymbolId};
 use oxc_index::IndexVec;
 use render_chunk_to_assets::set_emitted_chunk_preliminary_filenames;
-use rolldown_error::BuildResult;
+use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_std_utils::OptionExt;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use rolldown_common::{
-  ChunkIdx, ChunkKind, CssAssetNameReplacer, ImportMetaRolldownAssetReplacer, Module, ModuleIdx,
-  PreliminaryFilename, RollupPreRenderedAsset,
+  Chunk, ChunkIdx, ChunkKind, CssAssetNameReplacer, ImportMetaRolldownAssetReplacer, Module,
+  ModuleIdx, NormalModule, PreliminaryFilename, RollupPreRenderedAsset,
 };
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_std_utils::{PathBufExt, PathExt, representative_file_name_for_preserve_modules};
@@ -60,10 +60,29 @@ impl<'a> GenerateStage<'a> {
     Self { link_output, options, plugin_driver }
   }
 
+  /// Normal modules in the order chunks are assigned from. Module indexes follow the order modules
+  /// were discovered in, which changes with the timing of the parallel scan, so they are sorted by
+  /// stable id with `experimental.deterministic`.
+  fn normal_modules_in_assignment_order(&self) -> Vec<&NormalModule> {
+    let mut modules = self
+      .link_output
+      .module_table
+      .modules
+      .iter()
+      .filter_map(Module::as_normal)
+      .collect::<Vec<_>>();
+    if self.options.experimental.is_deterministic_enabled() {
+      modules.sort_unstable_by(|a, b| a.stable_id.cmp(&b.stable_id));
+    }
+    modules
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn generate(&mut self) -> BuildResult<BundleOutput> {
     self.plugin_driver.render_start(self.options).await?;
 
+    self.apply_output_paths().await?;
+
     let mut chunk_graph = self.generate_chunks().await?;
     if chunk_graph.chunk_table.len() > 1 {
       validate_options_for_multi_chunk_output(self.options)?;
@@ -71,8 +90,10 @@ impl<'a> GenerateStage<'a> {
 
     self.compute_cross_chunk_links(&mut chunk_graph);
 
+    let mut warnings = vec![];
     let index_chunk_id_to_name =
-      self.generate_chunk_name_and_preliminary_filenames(&mut chunk_graph).await?;
+      self.generate_chunk_name_and_preliminary_filenames(&mut chunk_graph, &mut warnings).await?;
+    self.link_output.warnings.extend(warnings);
     self.patch_asset_modules(&chunk_graph);
     set_emitted_chunk_preliminary_filenames(&self.plugin_driver.file_emitter, &chunk_graph);
 
@@ -154,6 +175,7 @@ impl<'a> GenerateStage<'a> {
   async fn generate_chunk_name_and_preliminary_filenames(
     &self,
     chunk_graph: &mut ChunkGraph,
+    warnings: &mut Vec<BuildDiagnostic>,
   ) -> BuildResult<FxHashMap<ChunkIdx, ArcStr>> {
     let modules = &self.link_output.module_table.modules;
 
@@ -223,6 +245,23 @@ impl<'a> GenerateStage<'a> {
 
     let used_name_counts = FxDashMap::default();
 
+    // Chunks with a specified filename keep it, so the filenames rendered from templates are
+    // deconflicted against them instead.
+    if self.options.file.is_none() {
+      let mut fixed_filenames = FxHashSet::default();
+      for chunk_id in &chunk_graph.sorted_chunk_idx_vec {
+        let Some(file_name) = &chunk_graph.chunk_table[*chunk_id].file_name else {
+          continue;
+        };
+        used_name_counts.entry(file_name.clone()).or_insert(2);
+        // Follow rollup using lowercase filename to check conflicts
+        if !fixed_filenames.insert(file_name.to_lowercase()) {
+          warnings
+            .push(BuildDiagnostic::filename_conflict(file_name.clone()).with_severity_warning());
+        }
+      }
+    }
+
     for chunk_id in &chunk_graph.sorted_chunk_idx_vec {
       let chunk = &mut chunk_graph.chunk_table[*chunk_id];
       if chunk.preliminary_filename.is_some() {
@@ -236,6 +275,20 @@ impl<'a> GenerateStage<'a> {
       let pre_rendered_chunk =
         generate_pre_rendered_chunk(chunk, &pre_generated_chunk_name.0, self.link_output);
 
+      if self.is_input_file_name(chunk)
+        && chunk.filename_template(self.options, &pre_rendered_chunk).await?.has_hash_pattern()
+      {
+        warnings.push(
+          BuildDiagnostic::configuration_field_conflict(
+            "input",
+            "fileName",
+            "output.entryFileNames",
+            "[hash]",
+          )
+          .with_severity_warning(),
+        );
+      }
+
       let preliminary_filename = chunk
         .generate_preliminary_filename(
           self.options,
@@ -297,9 +350,7 @@ impl<'a> GenerateStage<'a> {
 
           chunk.asset_absolute_preliminary_filenames.insert(
             module.idx,
-            preliminary
-              .absolutize_with(self.options.cwd.join(&self.options.out_dir))
-              .expect_into_string(),
+            preliminary.absolutize_with(self.options.resolved_out_dir()).expect_into_string(),
           );
           chunk.asset_preliminary_filenames.insert(module.idx, preliminary);
         }
@@ -308,13 +359,11 @@ impl<'a> GenerateStage<'a> {
       chunk.pre_rendered_chunk = Some(pre_rendered_chunk);
 
       chunk.absolute_preliminary_filename = Some(
-        preliminary_filename
-          .absolutize_with(self.options.cwd.join(&self.options.out_dir))
-          .expect_into_string(),
+        preliminary_filename.absolutize_with(self.options.resolved_out_dir()).expect_into_string(),
       );
       chunk.css_absolute_preliminary_filename = Some(
         css_preliminary_filename
-          .absolutize_with(self.options.cwd.join(&self.options.out_dir))
+          .absolutize_with(self.options.resolved_out_dir())
           .expect_into_string(),
       );
       chunk.preliminary_filename = Some(preliminary_filename);
@@ -323,6 +372,14 @@ impl<'a> GenerateStage<'a> {
     Ok(index_chunk_id_to_name)
   }
 
+  /// Whether the filename of the chunk is specified by `InputItem#file_name`, which bypasses
+  /// `entry_filenames`, rather than by an emitted chunk.
+  fn is_input_file_name(&self, chunk: &Chunk) -> bool {
+    chunk.file_name.as_ref().is_some_and(|file_name| {
+      self.options.input.iter().any(|item| item.file_name.as_deref() == Some(file_name.as_str()))
+    })
+  }
+
   pub fn patch_asset_modules(&mut self, chunk_graph: &ChunkGraph) {
     chunk_graph.chunk_table.iter().for_each(|chunk| {
       let mut module_idx_to_filenames = FxHashMap::default();
@@ -355,4 +412,29 @@ impl<'a> GenerateStage<'a> {
       });
     });
   }
+  /// Rewrites the rendered specifiers of external modules according to `output.paths`. Relative
+  /// externals are looked up by their id relative to `cwd`.
+  async fn apply_output_paths(&mut self) -> BuildResult<()> {
+    if self.options.paths.is_empty() {
+      return Ok(());
+    }
+    for module in self.link_output.module_table.iter_mut() {
+      let Some(external) = module.as_external_mut() else {
+        continue;
+      };
+      let id = if external.need_renormalize_render_path {
+        Path::new(external.id.as_str()).relative(&self.options.cwd).to_slash_lossy().into_owned()
+      } else {
+        external.id.to_string()
+      };
+      external.mapped_path = self
+        .options
+        .paths
+        .call(&id)
+        .await?
+        .filter(|mapped_path| *mapped_path != id)
+        .map(ArcStr::from);
+    }
+    Ok(())
+  }
 }
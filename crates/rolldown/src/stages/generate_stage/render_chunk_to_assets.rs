@@ -1,18 +1,21 @@
 use std::ops::Deref;
 
+use arcstr::ArcStr;
 use futures::future::try_join_all;
 use oxc_index::{IndexVec, index_vec};
 use rolldown_common::{
-  Asset, EmittedChunkInfo, InstantiationKind, ModuleRenderArgs, ModuleRenderOutput, Output,
-  OutputAsset, OutputChunk, SharedFileEmitter, SourceMapType, SymbolRef,
+  Asset, EmittedChunkInfo, InstantiationKind, ModuleRenderArgs, ModuleRenderOutput, NormalModule,
+  Output, OutputAsset, OutputChunk, SharedFileEmitter, SourceMapType, SymbolRef,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_rstr::Rstr;
+use rolldown_sourcemap::SourceMap;
 use rolldown_utils::{
   concat_string,
   indexmap::{FxIndexMap, FxIndexSet},
   rayon::{IntoParallelRefIterator, ParallelIterator},
 };
+use rustc_hash::FxHashMap;
 
 use crate::{
   BundleOutput,
@@ -52,13 +55,14 @@ impl GenerateStage<'_> {
       self.link_output,
       instantiated_chunks,
       &index_chunk_to_assets,
-      self.options.hash_characters,
+      self.options,
     );
 
     self.minify_assets(&mut assets)?;
 
     // Set emitted chunk info for file emitter, it should be set before call generate_bundle hook
     set_emitted_chunk_filenames(&self.plugin_driver.file_emitter, &assets, chunk_graph);
+    self.set_module_chunk_filenames(&assets, chunk_graph);
 
     let mut output = Vec::with_capacity(assets.len());
     let mut output_assets = vec![];
@@ -76,6 +80,13 @@ impl GenerateStage<'_> {
         InstantiationKind::Ecma(ecma_meta) => {
           let mut code = code.try_into_string()?;
           let rendered_chunk = ecma_meta.rendered_chunk;
+          // Measured before the sourcemap comment is added.
+          let modules = match ecma_meta.unminified_length {
+            Some(unminified_length) => {
+              rendered_chunk.modules.with_minified_lengths(unminified_length, code.len())
+            }
+            None => rendered_chunk.modules.clone(),
+          };
           if let Some(map) = map.as_mut() {
             if let Some(sourcemap_asset) = process_code_and_sourcemap(
               self.options,
@@ -98,6 +109,7 @@ impl GenerateStage<'_> {
             } else {
               Some(concat_string!(filename, ".map"))
             };
+          let debug_id = map.as_ref().and_then(SourceMap::get_debug_id).map(ToString::to_string);
           output.push(Output::Chunk(Box::new(OutputChunk {
             name: rendered_chunk.name.clone(),
             filename: filename.clone(),
@@ -105,13 +117,17 @@ impl GenerateStage<'_> {
             is_entry: rendered_chunk.is_entry,
             is_dynamic_entry: rendered_chunk.is_dynamic_entry,
             facade_module_id: rendered_chunk.facade_module_id.clone(),
-            modules: rendered_chunk.modules.clone(),
+            modules,
             exports: rendered_chunk.exports.clone(),
             module_ids: rendered_chunk.module_ids.clone(),
             imports: ecma_meta.imports,
             dynamic_imports: ecma_meta.dynamic_imports,
+            imported_bindings: ecma_meta.imported_bindings,
+            imported_css: ecma_meta.imported_css,
+            interop: rendered_chunk.interop.clone(),
             map,
             sourcemap_filename,
+            debug_id,
             preliminary_filename: preliminary_filename.to_string(),
           })));
         }
@@ -171,7 +187,29 @@ impl GenerateStage<'_> {
       return Err(errors.into());
     }
 
-    Ok(BundleOutput { assets: output, warnings })
+    Ok(BundleOutput { assets: output, warnings, watch_files: vec![] })
+  }
+
+  /// Lets plugins look up the chunk of a module from `generateBundle` on, once its filename is final.
+  fn set_module_chunk_filenames(&self, assets: &IndexAssets, chunk_graph: &ChunkGraph) {
+    let chunk_filenames = assets
+      .iter()
+      .filter(|asset| matches!(asset.meta, InstantiationKind::Ecma(_)))
+      .map(|asset| (asset.origin_chunk, &asset.filename))
+      .collect::<FxHashMap<_, _>>();
+    let filenames = chunk_graph
+      .module_to_chunk
+      .iter_enumerated()
+      .filter_map(|(module_idx, chunk_idx)| {
+        let module = &self.link_output.module_table[module_idx];
+        if !module.as_normal().is_some_and(NormalModule::is_included) {
+          return None;
+        }
+        let filename = chunk_filenames.get(&(*chunk_idx)?)?;
+        Some((module.id_clone().clone(), ArcStr::clone(filename)))
+      })
+      .collect();
+    self.plugin_driver.set_module_chunk_filenames(filenames);
   }
 
   async fn instantiate_chunks(
@@ -887,14 +887,32 @@ impl BindImportsAndExportsContext<'_> {
         match &tracker.imported {
           Specifier::Star => unreachable!("star should always exist, no need to shim"),
           Specifier::Literal(imported) => {
-            let shimmed_symbol_ref = self.metas[tracker.importee]
-              .shimmed_missing_exports
-              .entry(imported.clone())
-              .or_insert_with(|| {
-                self.symbol_db.create_facade_root_symbol_ref(tracker.importee, imported.as_str())
+            let shimmed_missing_exports = &mut self.metas[tracker.importee].shimmed_missing_exports;
+            if let Some(shimmed_symbol_ref) = shimmed_missing_exports.get(imported) {
+              return MatchImportKind::Normal(MatchImportKindNormal {
+                symbol: *shimmed_symbol_ref,
+                reexports: vec![],
               });
+            }
+            let shimmed_symbol_ref =
+              self.symbol_db.create_facade_root_symbol_ref(tracker.importee, imported.as_str());
+            shimmed_missing_exports.insert(imported.clone(), shimmed_symbol_ref);
+            // Importing from an empty module is shimmed silently.
+            if !matches!(importee.module_type, ModuleType::Empty) {
+              let importer = &self.index_modules[tracker.importer];
+              self.warnings.push(
+                BuildDiagnostic::shimmed_export(
+                  importer.id().to_string(),
+                  importer.stable_id().to_string(),
+                  importee.id.to_string(),
+                  importee.stable_id.to_string(),
+                  imported.to_string(),
+                )
+                .with_severity_warning(),
+              );
+            }
             return MatchImportKind::Normal(MatchImportKindNormal {
-              symbol: *shimmed_symbol_ref,
+              symbol: shimmed_symbol_ref,
               reexports: vec![],
             });
           }
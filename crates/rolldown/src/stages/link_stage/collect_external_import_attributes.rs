@@ -0,0 +1,44 @@
+use rolldown_common::{ImportKind, Module, OutputFormat, render_import_attributes};
+use rolldown_error::BuildDiagnostic;
+use rustc_hash::FxHashMap;
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Static imports of external modules are kept in esm output, so they need to keep their
+  /// `with { ... }` clause as well. Other formats turn them into `require` calls, which only
+  /// understand `with { type: 'json' }`.
+  pub(super) fn collect_external_import_attributes(&mut self) {
+    let mut attributes_by_external = FxHashMap::default();
+    for module in self.module_table.modules.iter().filter_map(Module::as_normal) {
+      for rec in &module.import_records {
+        let Some(attributes) = &rec.attributes else {
+          continue;
+        };
+        if !matches!(rec.kind, ImportKind::Import)
+          || !self.module_table[rec.resolved_module].is_external()
+        {
+          continue;
+        }
+        let is_json_only = attributes.len() == 1 && rec.attribute_type() == Some("json");
+        if !matches!(self.options.format, OutputFormat::Esm) && !is_json_only {
+          self.warnings.push(
+            BuildDiagnostic::unsupported_import_attributes(
+              module.id.to_string(),
+              rec.module_request.to_string(),
+              render_import_attributes(attributes),
+              self.options.format.to_string(),
+            )
+            .with_severity_warning(),
+          );
+        }
+        attributes_by_external.entry(rec.resolved_module).or_insert_with(|| attributes.clone());
+      }
+    }
+    for (idx, attributes) in attributes_by_external {
+      if let Some(external) = self.module_table[idx].as_external_mut() {
+        external.import_attributes = Some(attributes);
+      }
+    }
+  }
+}
@@ -19,6 +19,7 @@ use crate::{
 use super::scan_stage::NormalizedScanStageOutput;
 
 mod bind_imports_and_exports;
+mod collect_external_import_attributes;
 mod compute_tla;
 mod create_exports_for_ecma_modules;
 mod determine_module_exports_kind;
@@ -131,6 +132,7 @@ impl<'a> LinkStage<'a> {
     self.reference_needed_symbols();
     self.include_statements();
     self.patch_module_dependencies();
+    self.collect_external_import_attributes();
 
     tracing::trace!("meta {:#?}", self.metas.iter_enumerated().collect::<Vec<_>>());
 
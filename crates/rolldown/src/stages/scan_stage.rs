@@ -4,11 +4,11 @@ use arcstr::ArcStr;
 use futures::future::join_all;
 use rolldown_common::{
   EntryPoint, HybridIndexVec, Module, ModuleIdx, ModuleTable, PreserveEntrySignatures, ResolvedId,
-  RuntimeModuleBrief, ScanMode, SymbolRef, SymbolRefDb,
+  RuntimeModuleBrief, ScanMode, SharedFileSystem, SymbolRef, SymbolRefDb,
   dynamic_import_usage::DynamicImportExportsUsage,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult};
-use rolldown_fs::OsFileSystem;
+
 use rolldown_plugin::SharedPluginDriver;
 use rustc_hash::FxHashMap;
 
@@ -23,7 +23,7 @@ use crate::{
 pub struct ScanStage {
   options: SharedOptions,
   plugin_driver: SharedPluginDriver,
-  fs: OsFileSystem,
+  fs: SharedFileSystem,
   resolver: SharedResolver,
   build_span: tracing::Span,
 }
@@ -64,6 +64,27 @@ impl NormalizedScanStageOutput {
       entry_point_to_reference_ids: self.entry_point_to_reference_ids.clone(),
     }
   }
+
+  /// Make a full copy that could go through the link and generate stage on its own. Used to render
+  /// multiple outputs from a single scan.
+  pub fn clone_with_another_arena(&self) -> Self {
+    Self {
+      module_table: self.module_table.clone(),
+      index_ecma_ast: self
+        .index_ecma_ast
+        .iter()
+        .map(|(ast, module_idx)| (ast.clone_with_another_arena(), *module_idx))
+        .collect(),
+      entry_points: self.entry_points.clone(),
+      symbol_ref_db: self.symbol_ref_db.clone_with_another_arena(),
+      runtime: self.runtime.clone(),
+      warnings: vec![],
+      dynamic_import_exports_usage_map: self.dynamic_import_exports_usage_map.clone(),
+      safely_merge_cjs_ns_map: self.safely_merge_cjs_ns_map.clone(),
+      overrode_preserve_entry_signature_map: self.overrode_preserve_entry_signature_map.clone(),
+      entry_point_to_reference_ids: self.entry_point_to_reference_ids.clone(),
+    }
+  }
 }
 
 impl From<ScanStageOutput> for NormalizedScanStageOutput {
@@ -104,7 +125,7 @@ impl ScanStage {
   pub fn new(
     options: SharedOptions,
     plugin_driver: SharedPluginDriver,
-    fs: OsFileSystem,
+    fs: SharedFileSystem,
     resolver: SharedResolver,
     build_span: tracing::Span,
   ) -> Self {
@@ -118,12 +139,13 @@ impl ScanStage {
     cache: &mut ScanStageCache,
   ) -> BuildResult<ScanStageOutput> {
     let mut module_loader = ModuleLoader::new(
-      self.fs,
+      self.fs.clone(),
       Arc::clone(&self.options),
       Arc::clone(&self.resolver),
       Arc::clone(&self.plugin_driver),
       cache,
       mode.is_full(),
+      FxHashMap::default(),
       self.build_span.clone(),
     )?;
 
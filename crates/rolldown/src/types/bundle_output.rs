@@ -1,3 +1,4 @@
+use arcstr::ArcStr;
 use rolldown_common::Output;
 use rolldown_error::BuildDiagnostic;
 
@@ -5,4 +6,14 @@ use rolldown_error::BuildDiagnostic;
 pub struct BundleOutput {
   pub warnings: Vec<BuildDiagnostic>,
   pub assets: Vec<Output>,
+  /// Files the build depends on, including the loaded modules and the files added by
+  /// `this.addWatchFile`. Sorted, so it could be compared across builds.
+  pub watch_files: Vec<ArcStr>,
+}
+
+/// Result of [`crate::Bundler::generate_with_cancellation`].
+pub enum CancellableBundleOutput {
+  Completed(BundleOutput),
+  /// The build was cancelled before it finished, so there's no output.
+  Cancelled,
 }
@@ -0,0 +1,103 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use arcstr::ArcStr;
+use rolldown_common::{ModuleTable, NormalModule};
+use rolldown_error::BuildDiagnostic;
+use rolldown_plugin::PluginDriver;
+use rustc_hash::FxHashMap;
+
+/// Warns about packages that are bundled in more than one version, e.g. when two dependencies
+/// depend on different versions of the same package.
+pub fn check_duplicate_packages(
+  module_table: &ModuleTable,
+  plugin_driver: &PluginDriver,
+  warnings: &mut Vec<BuildDiagnostic>,
+) {
+  let mut packages: BTreeMap<String, BTreeMap<String, Vec<&NormalModule>>> = BTreeMap::new();
+  for module in module_table.modules.iter().filter_map(|module| module.as_normal()) {
+    if let Some((name, version)) = package_of(module) {
+      packages.entry(name).or_default().entry(version).or_default().push(module);
+    }
+  }
+
+  for (name, versions) in packages {
+    if versions.len() < 2 {
+      continue;
+    }
+    let sizes = versions
+      .values()
+      .map(|modules| modules.iter().map(|module| module.source.len()).sum::<usize>())
+      .collect::<Vec<_>>();
+    let duplicated_bytes = sizes.iter().sum::<usize>() - sizes.iter().max().unwrap_or(&0);
+    let versions = versions
+      .into_iter()
+      .map(|(version, modules)| (version, importer_chain(plugin_driver, modules)))
+      .collect();
+    warnings.push(
+      BuildDiagnostic::duplicate_package(name, versions, duplicated_bytes).with_severity_warning(),
+    );
+  }
+}
+
+/// The name and version of the package in `node_modules` that the module belongs to. The version
+/// is parsed from the path in pnpm's layout, e.g. `node_modules/.pnpm/react@18.2.0/node_modules/react`,
+/// and read from the `package.json` of the package otherwise.
+fn package_of(module: &NormalModule) -> Option<(String, String)> {
+  let id = module.id.resource_id().replace('\\', "/");
+  let (dir, rest) = id.rsplit_once("/node_modules/")?;
+  let mut segments = rest.split('/');
+  let first = segments.next()?;
+  let name = if first.starts_with('@') {
+    format!("{first}/{}", segments.next()?)
+  } else {
+    first.to_string()
+  };
+  let version = dir
+    .rsplit_once("/.pnpm/")
+    .and_then(|(_, store_dir)| {
+      // Scoped packages are stored as `@scope+name@version`, followed by their peers, if any.
+      let store_dir = store_dir.split('/').next()?;
+      let version = store_dir.strip_prefix(&name.replace('/', "+"))?.strip_prefix('@')?;
+      version.split(['_', '(']).next().map(ToString::to_string)
+    })
+    .or_else(|| {
+      let package_json = module.originative_resolved_id.package_json.as_ref()?;
+      package_json.version.as_ref().map(ToString::to_string)
+    })?;
+  Some((name, version))
+}
+
+/// Ids of the modules on a shortest import path from an entry to one of `modules`, found through
+/// the module infos that plugins query with `get_module_info`.
+fn importer_chain(plugin_driver: &PluginDriver, mut modules: Vec<&NormalModule>) -> Vec<String> {
+  modules.sort_unstable_by(|a, b| a.stable_id.cmp(&b.stable_id));
+  // The module each visited module was reached from, which is one step closer to `modules`.
+  let mut importee_of = FxHashMap::<ArcStr, Option<ArcStr>>::default();
+  let mut queue = VecDeque::new();
+  for module in modules {
+    importee_of.insert(module.id.resource_id().clone(), None);
+    queue.push_back(module.id.resource_id().clone());
+  }
+  while let Some(id) = queue.pop_front() {
+    let Some(info) = plugin_driver.modules.get(&id).map(|info| info.clone()) else { continue };
+    if info.is_entry {
+      return std::iter::successors(Some(id), |id| importee_of[id].clone())
+        .map(|id| id.to_string())
+        .collect();
+    }
+    let mut importers = info
+      .importers
+      .iter()
+      .chain(&info.dynamic_importers)
+      .map(|importer| importer.resource_id().clone())
+      .collect::<Vec<_>>();
+    importers.sort_unstable();
+    for importer in importers {
+      if !importee_of.contains_key(&importer) {
+        importee_of.insert(importer.clone(), Some(id.clone()));
+        queue.push_back(importer);
+      }
+    }
+  }
+  vec![]
+}
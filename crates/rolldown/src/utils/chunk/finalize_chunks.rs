@@ -3,7 +3,7 @@ use std::{hash::Hash, mem};
 use arcstr::ArcStr;
 use itertools::Itertools;
 use oxc_index::{IndexVec, index_vec};
-use rolldown_common::{AssetIdx, HashCharacters, InstantiationKind, StrOrBytes};
+use rolldown_common::{AssetIdx, InstantiationKind, StrOrBytes};
 #[cfg(not(target_family = "wasm"))]
 use rolldown_utils::rayon::IndexedParallelIterator;
 use rolldown_utils::{
@@ -20,6 +20,7 @@ use rustc_hash::FxHashMap;
 use xxhash_rust::xxh3::Xxh3;
 
 use crate::{
+  SharedOptions,
   chunk_graph::ChunkGraph,
   stages::link_stage::LinkStageOutput,
   type_alias::{IndexAssets, IndexChunkToAssets, IndexInstantiatedChunks},
@@ -32,7 +33,7 @@ pub fn finalize_assets(
   link_output: &LinkStageOutput,
   preliminary_assets: IndexInstantiatedChunks,
   index_chunk_to_assets: &IndexChunkToAssets,
-  hash_characters: HashCharacters,
+  options: &SharedOptions,
 ) -> IndexAssets {
   let finder = hash_placeholder_left_finder();
 
@@ -65,7 +66,7 @@ pub fn finalize_assets(
   let index_transitive_dependencies: IndexVec<AssetIdx, FxIndexSet<AssetIdx>> =
     collect_transitive_dependencies(&index_direct_dependencies);
 
-  let hash_base = hash_characters.base();
+  let hash_base = options.hash_characters.base();
   let index_standalone_content_hashes: IndexVec<AssetIdx, String> = preliminary_assets
     .par_iter()
     .map(|chunk| {
@@ -94,8 +95,13 @@ pub fn finalize_assets(
       // hash itself's preliminary filename to prevent different chunks that have the same content from having the same hash
       preliminary_assets[asset_idx].preliminary_filename.hash(&mut hasher);
 
-      let dependencies = &index_transitive_dependencies[asset_idx];
-      dependencies.iter().copied().for_each(|dep_id| {
+      let mut dependencies = index_transitive_dependencies[asset_idx].iter().copied().collect_vec();
+      if options.experimental.is_deterministic_enabled() {
+        // The dependencies are collected in the order their placeholders show up in the code.
+        dependencies
+          .sort_by_cached_key(|dep_id| preliminary_assets[*dep_id].preliminary_filename.as_str());
+      }
+      dependencies.into_iter().for_each(|dep_id| {
         index_standalone_content_hashes[dep_id].hash(&mut hasher);
       });
 
@@ -159,6 +165,17 @@ pub fn finalize_assets(
   let index_asset_to_filename: IndexVec<AssetIdx, ArcStr> =
     assets.iter().map(|asset| asset.filename.clone()).collect::<Vec<_>>().into();
 
+  let filename_by_preliminary_filename = assets
+    .iter()
+    .map(|asset| (asset.preliminary_filename.as_str().to_string(), asset.filename.clone()))
+    .collect::<FxHashMap<_, _>>();
+
+  let css_filename_by_asset = assets
+    .iter_enumerated()
+    .filter(|(_, asset)| matches!(asset.meta, InstantiationKind::Css(_)))
+    .map(|(asset_idx, asset)| (asset_idx, asset.filename.clone()))
+    .collect::<FxHashMap<_, _>>();
+
   assets.par_iter_mut().for_each(|asset| {
     if let InstantiationKind::Ecma(ecma_meta) = &mut asset.meta {
       let chunk = &chunk_graph.chunk_table[asset.origin_chunk];
@@ -181,6 +198,25 @@ pub fn finalize_assets(
         .flat_map(|importee_idx| &index_chunk_to_assets[*importee_idx])
         .map(|importee_asset_idx| index_asset_to_filename[*importee_asset_idx].clone())
         .collect();
+
+      // The stylesheets imported by the modules of the chunk are concatenated into its css asset.
+      ecma_meta.imported_css = index_chunk_to_assets[asset.origin_chunk]
+        .iter()
+        .filter_map(|asset_idx| css_filename_by_asset.get(asset_idx).cloned())
+        .collect();
+
+      ecma_meta.imported_bindings = ecma_meta
+        .rendered_chunk
+        .imported_bindings
+        .iter()
+        .map(|(importee, bindings)| {
+          let importee = filename_by_preliminary_filename
+            .get(importee.as_str())
+            .cloned()
+            .unwrap_or_else(|| importee.clone());
+          (importee, bindings.clone())
+        })
+        .collect();
     }
   });
 
@@ -1,12 +1,20 @@
 use self::render_chunk_exports::get_chunk_export_names;
 use arcstr::ArcStr;
+use itertools::Itertools;
+use oxc_index::IndexVec;
 use rolldown_common::{
-  Chunk, ChunkKind, ChunkMeta, ModuleId, ModuleIdx, PreserveEntrySignatures, RenderedModule,
-  RollupPreRenderedChunk, RollupRenderedChunk, SharedNormalizedBundlerOptions,
+  Chunk, ChunkIdx, ChunkInterop, ChunkKind, ChunkMeta, ExportsKind, ImportKind, ImportRecordMeta,
+  InteropHelper, Module, ModuleId, ModuleIdx, NormalizedBundlerOptions, OutputFormat,
+  PreserveEntrySignatures, RenderedModule, RollupPreRenderedChunk, RollupRenderedChunk,
+  SharedNormalizedBundlerOptions, Specifier, SymbolRef, WrapKind,
 };
+use rolldown_rstr::Rstr;
+use rolldown_utils::indexmap::FxIndexMap;
 use rustc_hash::FxHashMap;
 
-use crate::{stages::link_stage::LinkStageOutput, types::generator::GenerateContext};
+use crate::{
+  chunk_graph::ChunkGraph, stages::link_stage::LinkStageOutput, types::generator::GenerateContext,
+};
 
 pub mod deconflict_chunk_symbols;
 pub mod determine_export_mode;
@@ -38,7 +46,14 @@ pub fn generate_rendered_chunk(
   chunk: &GenerateContext<'_>,
   render_modules: FxHashMap<ModuleId, RenderedModule>,
 ) -> RollupRenderedChunk {
-  let GenerateContext { chunk_graph, chunk, link_output, .. } = chunk;
+  let GenerateContext {
+    chunk_graph,
+    chunk,
+    link_output,
+    render_export_items_index_vec,
+    options,
+    ..
+  } = chunk;
   let pre_rendered_chunk =
     chunk.pre_rendered_chunk.as_ref().expect("Should have pre-rendered chunk");
   RollupRenderedChunk {
@@ -82,7 +97,128 @@ pub fn generate_rendered_chunk(
           .clone()
       })
       .collect(),
+    imported_bindings: generate_imported_bindings(
+      chunk,
+      chunk_graph,
+      link_output,
+      render_export_items_index_vec,
+    ),
+    interop: generate_interop(chunk, link_output, options),
+  }
+}
+
+/// Mirrors the helpers referenced for import records in `reference_needed_symbols`, for the
+/// statements kept by tree shaking.
+fn generate_interop(
+  chunk: &Chunk,
+  link_output: &LinkStageOutput,
+  options: &NormalizedBundlerOptions,
+) -> Vec<ChunkInterop> {
+  let mut interop = vec![];
+  for importer in chunk.modules.iter().filter_map(|idx| link_output.module_table[*idx].as_normal())
+  {
+    let records = importer
+      .stmt_infos
+      .iter()
+      .filter(|stmt_info| stmt_info.is_included)
+      .flat_map(|stmt_info| &stmt_info.import_records)
+      .map(|rec_idx| &importer.import_records[*rec_idx]);
+    for rec in records {
+      let importee = &link_output.module_table[rec.resolved_module];
+      let is_reexport_all = rec.meta.contains(ImportRecordMeta::IS_EXPORT_STAR);
+      let helpers: &[InteropHelper] = match (importee, rec.kind) {
+        (Module::External(_), ImportKind::Import)
+          if !is_reexport_all
+            && !rec.meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT)
+            && matches!(
+              options.format,
+              OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd
+            ) =>
+        {
+          &[InteropHelper::ToEsm]
+        }
+        (Module::Normal(importee), kind) => {
+          let meta = &link_output.metas[importee.idx];
+          match (kind, meta.wrap_kind) {
+            (ImportKind::Import, WrapKind::Cjs) if is_reexport_all => {
+              &[InteropHelper::ToEsm, InteropHelper::ReExport]
+            }
+            (ImportKind::Import, WrapKind::Cjs) => &[InteropHelper::ToEsm],
+            (ImportKind::Import, _) if is_reexport_all && meta.has_dynamic_exports => {
+              &[InteropHelper::ReExport]
+            }
+            (ImportKind::Require, WrapKind::Esm)
+              if !rec.meta.contains(ImportRecordMeta::IS_REQUIRE_UNUSED) =>
+            {
+              &[InteropHelper::ToCommonJs]
+            }
+            (ImportKind::DynamicImport, WrapKind::Cjs) if options.inline_dynamic_imports => {
+              &[InteropHelper::ToEsm]
+            }
+            (ImportKind::DynamicImport, _)
+              if !options.inline_dynamic_imports
+                && matches!(importee.exports_kind, ExportsKind::CommonJs) =>
+            {
+              &[InteropHelper::ToDynamicImportEsm]
+            }
+            _ => &[],
+          }
+        }
+        _ => &[],
+      };
+      interop.extend(helpers.iter().map(|helper| ChunkInterop {
+        helper: *helper,
+        importer: importer.id.clone(),
+        importee: importee.id().into(),
+      }));
+    }
   }
+  interop.sort_unstable();
+  interop.dedup();
+  interop
+}
+
+fn generate_imported_bindings(
+  chunk: &Chunk,
+  chunk_graph: &ChunkGraph,
+  link_output: &LinkStageOutput,
+  render_export_items_index_vec: &IndexVec<ChunkIdx, FxIndexMap<SymbolRef, Vec<Rstr>>>,
+) -> FxHashMap<ArcStr, Vec<Rstr>> {
+  let from_other_chunks = chunk.imports_from_other_chunks.iter().map(|(exporter_id, items)| {
+    let importee_chunk = &chunk_graph.chunk_table[*exporter_id];
+    let bindings = items
+      .iter()
+      .map(|item| {
+        render_export_items_index_vec[*exporter_id]
+          .get(&item.import_ref)
+          .expect("should have export item index")[0]
+          .clone()
+      })
+      .sorted_unstable()
+      .dedup()
+      .collect();
+    let filename =
+      importee_chunk.preliminary_filename.as_deref().expect("should have preliminary_filename");
+    (filename.clone(), bindings)
+  });
+  let from_external_modules =
+    chunk.imports_from_external_modules.iter().map(|(importee_id, named_imports)| {
+      let bindings = named_imports
+        .iter()
+        .filter(|item| {
+          let canonical_ref = link_output.symbol_db.canonical_ref_for(item.imported_as);
+          link_output.used_symbol_refs.contains(&canonical_ref)
+        })
+        .map(|item| match &item.imported {
+          Specifier::Star => Rstr::new("*"),
+          Specifier::Literal(imported) => imported.clone(),
+        })
+        .sorted_unstable()
+        .dedup()
+        .collect();
+      (link_output.module_table[*importee_id].id().into(), bindings)
+    });
+  from_other_chunks.chain(from_external_modules).collect()
 }
 
 pub fn normalize_preserve_entry_signature(
@@ -11,13 +11,11 @@ pub fn determine_es_module(es_module_flag: EsModuleFlag, has_default_export: boo
 }
 
 /// Render namespace markers for the module.
-/// It contains the `__esModule` and `Symbol.toStringTag` properties.
-/// Since rolldown doesn't support `generatedCode.symbol` yet,
-/// it's not possible to use `Symbol.toStringTag` in the output.
+/// It contains the `__esModule` and `Symbol.toStringTag` properties, the latter is controlled by
+/// `generatedCode.symbols`.
 pub fn render_namespace_markers(
   es_module_flag: EsModuleFlag,
   has_default_export: bool,
-  // TODO namespace_to_string_tag
   namespace_to_string_tag: bool,
 ) -> Option<&'static str> {
   let es_module = determine_es_module(es_module_flag, has_default_export);
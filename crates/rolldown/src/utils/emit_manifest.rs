@@ -0,0 +1,110 @@
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  path::Path,
+};
+
+use arcstr::ArcStr;
+use rolldown_common::{NormalizedBundlerOptions, Output, OutputAsset, OutputChunk};
+use rolldown_error::BuildDiagnostic;
+use rustc_hash::FxHashMap;
+use serde_json::{Value, json};
+
+/// Adds the `manifest` asset to the output. It's called after the files emitted by plugins are
+/// added, so the assets they emitted for a module, e.g. its declaration, are listed too.
+pub fn emit_manifest(
+  options: &NormalizedBundlerOptions,
+  assets: &mut Vec<Output>,
+  warnings: &mut Vec<BuildDiagnostic>,
+) -> anyhow::Result<()> {
+  let Some(filename) = &options.manifest else {
+    return Ok(());
+  };
+  // Follow rollup using lowercase filename to check conflicts
+  if assets.iter().any(|output| output.filename().to_lowercase() == filename.to_lowercase()) {
+    warnings
+      .push(BuildDiagnostic::filename_conflict(filename.as_str().into()).with_severity_warning());
+  }
+  let source = serde_json::to_string_pretty(&render_manifest(assets, &options.cwd))?;
+  assets.push(Output::Asset(Box::new(OutputAsset {
+    names: vec![],
+    original_file_names: vec![],
+    filename: filename.as_str().into(),
+    source: source.into(),
+  })));
+  Ok(())
+}
+
+/// Keyed by the stabilized id of the facade module of each entry and dynamic entry chunk. Keys and
+/// lists are sorted, so the manifest doesn't depend on the order the chunks are rendered in.
+fn render_manifest(assets: &[Output], cwd: &Path) -> BTreeMap<String, Value> {
+  let mut chunks_by_filename = FxHashMap::default();
+  let mut assets_by_module_id: FxHashMap<&str, Vec<&str>> = FxHashMap::default();
+  for output in assets {
+    match output {
+      Output::Chunk(chunk) => {
+        chunks_by_filename.insert(chunk.filename.as_str(), chunk.as_ref());
+      }
+      Output::Asset(asset) => {
+        for original_file_name in &asset.original_file_names {
+          assets_by_module_id
+            .entry(original_file_name.as_str())
+            .or_default()
+            .push(asset.filename.as_str());
+        }
+      }
+    }
+  }
+
+  chunks_by_filename
+    .values()
+    .filter(|chunk| chunk.is_entry || chunk.is_dynamic_entry)
+    .filter_map(|chunk| chunk.facade_module_id.as_ref().map(|id| (id, *chunk)))
+    .map(|(id, chunk)| {
+      // The chunks loaded along with the entry one. External modules are in `imports` too, they
+      // are left out as they aren't part of the output.
+      let mut loaded_chunks: Vec<&OutputChunk> = vec![chunk];
+      let mut imports = BTreeSet::new();
+      let mut index = 0;
+      while index < loaded_chunks.len() {
+        let importer = loaded_chunks[index];
+        index += 1;
+        for import in &importer.imports {
+          if let Some(importee) = chunks_by_filename.get(import.as_str()) {
+            if importee.filename != chunk.filename && imports.insert(import.as_str()) {
+              loaded_chunks.push(importee);
+            }
+          }
+        }
+      }
+
+      let mut dynamic_imports = BTreeSet::new();
+      let mut entry_assets = BTreeSet::new();
+      for loaded_chunk in loaded_chunks {
+        dynamic_imports.extend(
+          loaded_chunk
+            .dynamic_imports
+            .iter()
+            .map(ArcStr::as_str)
+            .filter(|filename| chunks_by_filename.contains_key(filename)),
+        );
+        entry_assets.extend(
+          loaded_chunk
+            .module_ids
+            .iter()
+            .filter_map(|module_id| assets_by_module_id.get(module_id.as_ref()))
+            .flatten()
+            .copied(),
+        );
+      }
+
+      let entry = json!({
+        "assets": entry_assets,
+        "dynamicImports": dynamic_imports,
+        "file": chunk.filename.as_str(),
+        "imports": imports,
+        "isEntry": chunk.is_entry,
+      });
+      (id.stabilize(cwd), entry)
+    })
+    .collect()
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use rolldown_fs::FileSystem;
+use rolldown_sourcemap::SourceMap;
+use rolldown_utils::{base64::from_standard_base64, dataurl::parse_data_url};
+use sugar_path::SugarPath;
+
+/// The sourcemap an input file points at with a `//# sourceMappingURL=` comment, such as the one
+/// left by a compiler which ran before the bundler. Its `sources` are made absolute, like the ones
+/// of the other maps in the chain of a module.
+///
+/// Maps which are missing or malformed are ignored, as the file can still be bundled without them.
+pub fn read_input_sourcemap(source: &str, id: &str, fs: &dyn FileSystem) -> Option<SourceMap> {
+  let url = source_mapping_url(source)?;
+  let module_dir = Path::new(id).parent()?;
+  let (json, map_dir) = if let Some(data_url) = parse_data_url(url) {
+    let json = if data_url.is_base64 {
+      String::from_utf8(from_standard_base64(data_url.data)?).ok()?
+    } else {
+      urlencoding::decode(data_url.data).ok()?.into_owned()
+    };
+    (json, module_dir.to_path_buf())
+  } else {
+    let map_path = module_dir.join(urlencoding::decode(url).ok()?.as_ref()).normalize();
+    let map_dir = map_path.parent()?.to_path_buf();
+    (fs.read_to_string(&map_path).ok()?, map_dir)
+  };
+
+  let mut map = SourceMap::from_json_string(&json).ok()?;
+  let source_dir = map_dir.join(map.get_source_root().unwrap_or_default());
+  let sources = map
+    .get_sources()
+    .map(|source| {
+      // Sources with a scheme, such as `webpack://`, are kept as they are.
+      if source.contains("://") {
+        source.to_string()
+      } else {
+        source_dir.join(source).normalize().to_string_lossy().into_owned()
+      }
+    })
+    .collect::<Vec<_>>();
+  map.set_sources(sources.iter().map(String::as_str).collect());
+  Some(map)
+}
+
+/// The URL of the last `sourceMappingURL` comment in the comments which end `source`.
+fn source_mapping_url(source: &str) -> Option<&str> {
+  for line in source.lines().rev().map(str::trim) {
+    if line.is_empty() {
+      continue;
+    }
+    let comment = line.strip_prefix("//")?;
+    if let Some(url) = comment
+      .strip_prefix("# sourceMappingURL=")
+      .or_else(|| comment.strip_prefix("@ sourceMappingURL="))
+    {
+      return Some(url.trim()).filter(|url| !url.is_empty());
+    }
+  }
+  None
+}
+
+#[test]
+fn test_source_mapping_url() {
+  assert_eq!(source_mapping_url("a();\n//# sourceMappingURL=a.js.map\n"), Some("a.js.map"));
+  assert_eq!(source_mapping_url("a();\n//@ sourceMappingURL=a.js.map"), Some("a.js.map"));
+  assert_eq!(
+    source_mapping_url("a();\n//# sourceMappingURL=a.js.map\n// @license MIT\n\n"),
+    Some("a.js.map")
+  );
+  // Only the comments at the end of the file count.
+  assert_eq!(source_mapping_url("//# sourceMappingURL=a.js.map\na();\n"), None);
+  assert_eq!(source_mapping_url("a();"), None);
+}
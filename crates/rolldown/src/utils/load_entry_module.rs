@@ -24,6 +24,7 @@ pub async fn load_entry_module(
     None,
     Arc::default(),
     true,
+    &[],
   )
   .await?;
 
@@ -1,5 +1,7 @@
 use std::{borrow::Cow, path::Path};
 
+use arcstr::ArcStr;
+
 use rolldown_common::{
   ModuleType, NormalizedBundlerOptions, ResolvedId, StrOrBytes, side_effects::HookSideEffects,
 };
@@ -8,6 +10,7 @@ use rolldown_sourcemap::SourceMap;
 use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
+#[expect(clippy::too_many_arguments)]
 pub async fn load_source(
   plugin_driver: &PluginDriver,
   resolved_id: &ResolvedId,
@@ -16,6 +19,7 @@ pub async fn load_source(
   side_effects: &mut Option<HookSideEffects>,
   options: &NormalizedBundlerOptions,
   asserted_module_type: Option<&ModuleType>,
+  patched_source: Option<&ArcStr>,
 ) -> anyhow::Result<(StrOrBytes, ModuleType)> {
   let (maybe_source, maybe_module_type) =
     match plugin_driver.load(&HookLoadArgs { id: &resolved_id.id }).await? {
@@ -31,7 +35,8 @@ pub async fn load_source(
         if resolved_id.ignored {
           (Some(String::new()), Some(ModuleType::Empty))
         } else {
-          (None, None)
+          // Sources patched in memory stand in for the content on disk.
+          (patched_source.map(ToString::to_string), None)
         }
       }
     };
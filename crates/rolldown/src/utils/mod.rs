@@ -1,7 +1,10 @@
 pub mod apply_inner_plugins;
 pub mod augment_chunk_hash;
+pub mod check_duplicate_packages;
 pub mod chunk;
 pub mod ecma_visitors;
+pub mod emit_manifest;
+pub mod input_sourcemap;
 pub mod load_entry_module;
 pub mod load_source;
 pub mod normalize_options;
@@ -11,6 +14,7 @@ pub mod process_code_and_sourcemap;
 pub mod renamer;
 pub mod render_chunks;
 pub mod render_ecma_module;
+pub mod resolve_dynamic_import_expressions;
 pub mod resolve_id;
 pub mod transform_source;
 pub mod tweak_ast_for_scanning;
@@ -32,11 +36,14 @@ pub fn finalize_normal_module(
 ) {
   ast.program.with_mut(|fields| {
     let (oxc_program, alloc) = (fields.program, fields.allocator);
+    let snippet = AstSnippet::new(alloc)
+      .with_arrow_functions(ctx.options.generated_code.arrow_functions)
+      .with_object_shorthand(ctx.options.generated_code.object_shorthand);
     let mut finalizer = ScopeHoistingFinalizer {
       alloc,
       ctx,
       scope: ast_scope,
-      snippet: AstSnippet::new(alloc),
+      snippet,
       comments: oxc_program.comments.take_in(alloc),
       namespace_alias_symbol_id_to_resolved_module: FxHashMap::default(),
       interested_namespace_alias_ref_id: FxHashSet::default(),
@@ -3,11 +3,25 @@ use std::{borrow::Cow, path::Path};
 use oxc::transformer_plugins::InjectGlobalVariablesConfig;
 use rolldown_common::{
   AttachDebugInfo, GlobalsOutputOption, InjectImport, LegalComments, MinifyOptions, ModuleType,
-  NormalizedBundlerOptions, OutputFormat, Platform, PreserveEntrySignatures,
+  NormalizedBundlerOptions, OutputFormat, OutputOptions, Platform, PreserveEntrySignatures,
 };
-use rolldown_error::{BuildDiagnostic, InvalidOptionType};
+use rolldown_error::{BuildDiagnostic, EventKind, InvalidOptionType};
 use rustc_hash::{FxHashMap, FxHashSet};
 
+// If the `file` is provided, use the parent directory of the file as the `out_dir`.
+// Otherwise, use the `dir` if provided, or default to `dist`.
+fn resolve_out_dir(dir: Option<&str>, file: Option<&str>) -> String {
+  file.map_or_else(
+    || dir.unwrap_or("dist").to_string(),
+    |file| {
+      Path::new(file)
+        .parent()
+        .map(|parent| parent.to_string_lossy().to_string())
+        .unwrap_or_default()
+    },
+  )
+}
+
 pub struct NormalizeOptionsReturn {
   pub options: NormalizedBundlerOptions,
   pub resolve_options: rolldown_resolver::ResolveOptions,
@@ -38,6 +52,18 @@ fn verify_raw_options(raw_options: &crate::BundlerOptions) -> Vec<BuildDiagnosti
     _ => {}
   }
 
+  if let Some(severity_overrides) = &raw_options.severity_overrides {
+    let mut unknown_kinds = severity_overrides
+      .keys()
+      .filter(|kind| !EventKind::ALL.iter().any(|known| known.to_string() == **kind))
+      .collect::<Vec<_>>();
+    unknown_kinds.sort();
+    warnings.extend(unknown_kinds.into_iter().map(|kind| {
+      BuildDiagnostic::invalid_option(InvalidOptionType::UnknownSeverityOverrideKind(kind.clone()))
+        .with_severity_warning()
+    }));
+  }
+
   warnings
 }
 
@@ -81,6 +107,13 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
         raw_define
           .entry("import.meta.filename".to_string())
           .or_insert_with(|| "import.meta.filename".to_string());
+        // `import.meta.hot` is rewritten to the hot context of the module in hmr mode.
+        if raw_options.experimental.as_ref().is_some_and(|experimental| experimental.hmr.is_some())
+        {
+          raw_define
+            .entry("import.meta.hot".to_string())
+            .or_insert_with(|| "import.meta.hot".to_string());
+        }
       }
     }
   }
@@ -92,8 +125,8 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
 
   // https://github.com/evanw/esbuild/blob/ea453bf687c8e5cf3c5f11aae372c5ca33be0c98/pkg/api/api_impl.go#L1403-L1405
   // https://github.com/evanw/esbuild/commit/5abe0715f9be662b182989d2f38a44c7c8b28a2d
-  if raw_resolve.condition_names.is_none() && matches!(platform, Platform::Browser | Platform::Node)
-  {
+  // `neutral` gets the same default, so its conditions are limited to `default`, `module` and `import`/`require`.
+  if raw_resolve.condition_names.is_none() {
     raw_resolve.condition_names = Some(vec!["module".to_string()]);
   }
 
@@ -180,17 +213,7 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     _ => raw_options.inline_dynamic_imports.unwrap_or(false),
   };
 
-  // If the `file` is provided, use the parent directory of the file as the `out_dir`.
-  // Otherwise, use the `dir` if provided, or default to `dist`.
-  let out_dir = raw_options.file.as_ref().map_or_else(
-    || raw_options.dir.clone().unwrap_or_else(|| "dist".to_string()),
-    |file| {
-      Path::new(file.as_str())
-        .parent()
-        .map(|parent| parent.to_string_lossy().to_string())
-        .unwrap_or_default()
-    },
-  );
+  let out_dir = resolve_out_dir(raw_options.dir.as_deref(), raw_options.file.as_deref());
   let cwd =
     raw_options.cwd.unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir"));
   let normalized = NormalizedBundlerOptions {
@@ -225,16 +248,23 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     exports: raw_options.exports.unwrap_or(crate::OutputExports::Auto),
     hash_characters: raw_options.hash_characters.unwrap_or(crate::HashCharacters::Base64),
     globals,
+    paths: raw_options.paths.unwrap_or_default(),
+    generated_code: raw_options.generated_code.map(Into::into).unwrap_or_default(),
     sourcemap: raw_options.sourcemap,
     sourcemap_ignore_list: raw_options.sourcemap_ignore_list,
     sourcemap_path_transform: raw_options.sourcemap_path_transform,
     sourcemap_debug_ids: raw_options.sourcemap_debug_ids.unwrap_or(false),
+    sourcemap_url: raw_options.sourcemap_url,
+    sourcemap_include_sources: raw_options.sourcemap_include_sources.unwrap_or(true),
+    manifest: raw_options.manifest,
     shim_missing_exports: raw_options.shim_missing_exports.unwrap_or(false),
     module_types,
+    alias: raw_options.alias.unwrap_or_default(),
     experimental,
     // https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/bundler/bundler.go#L2767
     profiler_names: raw_options.profiler_names.unwrap_or(!minify.is_enabled()),
     minify,
+    compact: raw_options.compact.unwrap_or(false),
     define,
     inject: raw_options.inject.unwrap_or_default(),
     oxc_inject_global_variables_config,
@@ -248,6 +278,7 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     drop_labels: FxHashSet::from_iter(raw_options.drop_labels.unwrap_or_default()),
     keep_names: raw_options.keep_names.unwrap_or_default(),
     polyfill_require: raw_options.polyfill_require.unwrap_or(true),
+    hoist_transitive_imports: raw_options.hoist_transitive_imports.unwrap_or(true),
     defer_sync_scan_data: raw_options.defer_sync_scan_data,
     transform_options: raw_options.transform.unwrap_or_default(),
     make_absolute_externals_relative: raw_options
@@ -256,7 +287,18 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     invalidate_js_side_cache: raw_options.invalidate_js_side_cache,
     mark_module_loaded: raw_options.mark_module_loaded,
     log_level: raw_options.log_level,
+    log_format: raw_options.log_format.unwrap_or_default(),
+    log_color: raw_options.log_color.unwrap_or_default().into(),
+    severity_overrides: raw_options
+      .severity_overrides
+      .unwrap_or_default()
+      .into_iter()
+      .map(|(kind, severity)| (kind, severity.into()))
+      .collect(),
+    dedupe_diagnostics: raw_options.dedupe_diagnostics.unwrap_or(true),
     on_log: raw_options.on_log,
+    debug_plugin_order: raw_options.debug_plugin_order.unwrap_or_default(),
+    cache_dir: raw_options.cache_dir.map(|cache_dir| cwd.join(cache_dir)),
     preserve_modules: raw_options.preserve_modules.unwrap_or_default(),
     virtual_dirname: raw_options.virtual_dirname.unwrap_or_else(|| "_virtual".to_string()),
     preserve_modules_root: raw_options.preserve_modules_root.map(|preserve_modules_root| {
@@ -273,3 +315,103 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
 
   NormalizeOptionsReturn { options: normalized, resolve_options: raw_resolve, warnings }
 }
+
+/// Creates the options of one output in a multi-output build by overriding the output related
+/// fields of the already normalized `base` options.
+///
+/// Options that are consumed while scanning, such as `platform` and `define`, always come from
+/// `base`, since the scan stage only runs once for all outputs.
+pub fn normalize_output_options(
+  base: &NormalizedBundlerOptions,
+  output: OutputOptions,
+) -> NormalizedBundlerOptions {
+  let mut options = base.clone();
+
+  if let Some(format) = output.format {
+    options.format = format;
+    if matches!(format, OutputFormat::Umd | OutputFormat::Iife) {
+      options.inline_dynamic_imports = true;
+    }
+  }
+  if output.dir.is_some() || output.file.is_some() {
+    options.out_dir = resolve_out_dir(output.dir.as_deref(), output.file.as_deref());
+    options.dir = output.dir;
+    options.file = output.file;
+  }
+  if let Some(entry_filenames) = output.entry_filenames {
+    options.entry_filenames = entry_filenames;
+  }
+  if let Some(chunk_filenames) = output.chunk_filenames {
+    options.chunk_filenames = chunk_filenames;
+  }
+  if let Some(css_entry_filenames) = output.css_entry_filenames {
+    options.css_entry_filenames = css_entry_filenames;
+  }
+  if let Some(css_chunk_filenames) = output.css_chunk_filenames {
+    options.css_chunk_filenames = css_chunk_filenames;
+  }
+  if let Some(asset_filenames) = output.asset_filenames {
+    options.asset_filenames = asset_filenames;
+  }
+  if let Some(sourcemap) = output.sourcemap {
+    options.sourcemap = Some(sourcemap);
+  }
+  if let Some(banner) = output.banner {
+    options.banner = Some(banner);
+  }
+  if let Some(footer) = output.footer {
+    options.footer = Some(footer);
+  }
+  if let Some(intro) = output.intro {
+    options.intro = Some(intro);
+  }
+  if let Some(outro) = output.outro {
+    options.outro = Some(outro);
+  }
+  if let Some(paths) = output.paths {
+    options.paths = paths;
+  }
+  if let Some(minify) = output.minify {
+    options.minify = minify.into();
+  }
+  if let Some(compact) = output.compact {
+    options.compact = compact;
+  }
+
+  options
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use super::*;
+
+  fn resolved_out_dir(cwd: &Path, dir: &str) -> PathBuf {
+    let raw_options = crate::BundlerOptions {
+      cwd: Some(cwd.to_path_buf()),
+      dir: Some(dir.to_string()),
+      ..Default::default()
+    };
+    normalize_options(raw_options).options.resolved_out_dir()
+  }
+
+  #[test]
+  fn relative_dir_is_resolved_against_cwd() {
+    let root = std::env::temp_dir();
+    let cwd = root.join("project");
+    assert_eq!(resolved_out_dir(&cwd, "dist"), cwd.join("dist"));
+    assert_eq!(resolved_out_dir(&cwd, "./dist/"), cwd.join("dist"));
+    assert_eq!(resolved_out_dir(&cwd, "../out/./esm"), root.join("out").join("esm"));
+  }
+
+  #[test]
+  fn absolute_dir_is_kept() {
+    let root = std::env::temp_dir();
+    let cwd = root.join("project");
+    let out = root.join("out");
+    let with_trailing_slash = format!("{}/", out.to_string_lossy());
+    assert_eq!(resolved_out_dir(&cwd, &with_trailing_slash), out);
+    assert_eq!(resolved_out_dir(&cwd, &out.join("..").join("out").to_string_lossy()), out);
+  }
+}
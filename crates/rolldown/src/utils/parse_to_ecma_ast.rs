@@ -1,16 +1,25 @@
 use std::path::Path;
 
 use arcstr::ArcStr;
-use oxc::{semantic::Scoping, span::SourceType as OxcSourceType};
-use rolldown_common::{ModuleType, NormalizedBundlerOptions, RUNTIME_MODULE_KEY, StrOrBytes};
+use oxc::{
+  semantic::Scoping,
+  span::{SourceType as OxcSourceType, Span},
+};
+use rolldown_common::{
+  ModuleType, NormalizedBundlerOptions, RUNTIME_MODULE_KEY, ResolvedId, StrOrBytes,
+};
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_loader_utils::{binary_to_esm, text_to_string_literal};
 use rolldown_plugin::HookTransformAstArgs;
 use rolldown_utils::mime::guess_mime;
+use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
-use super::pre_process_ecma_ast::PreProcessEcmaAst;
+use super::{
+  pre_process_ecma_ast::PreProcessEcmaAst,
+  resolve_dynamic_import_expressions::resolve_dynamic_import_expressions,
+};
 
 use crate::types::{module_factory::CreateModuleContext, oxc_parse_type::OxcParseType};
 
@@ -29,6 +38,8 @@ pub struct ParseToEcmaAstResult {
   pub scoping: Scoping,
   pub has_lazy_export: bool,
   pub warning: Vec<BuildDiagnostic>,
+  /// `import()` expressions resolved by `resolve_dynamic_import` hooks, keyed by the span of their specifier.
+  pub resolved_dynamic_imports: FxHashMap<Span, ResolvedId>,
 }
 
 pub async fn parse_to_ecma_ast(
@@ -81,14 +92,19 @@ pub async fn parse_to_ecma_ast(
     })
     .await?;
 
-  PreProcessEcmaAst::default().build(
+  let resolved_dynamic_imports =
+    resolve_dynamic_import_expressions(&mut ecma_ast, &resolved_id.id, plugin_driver).await?;
+
+  let mut ret = PreProcessEcmaAst::default().build(
     ecma_ast,
     stable_id,
     &parsed_type,
     replace_global_define_config.as_ref(),
     options,
     has_lazy_export,
-  )
+  )?;
+  ret.resolved_dynamic_imports = resolved_dynamic_imports;
+  Ok(ret)
 }
 
 fn pre_process_source(
@@ -13,6 +13,7 @@ use oxc::transformer_plugins::{
 use rolldown_common::NormalizedBundlerOptions;
 use rolldown_ecmascript::{EcmaAst, WithMutFields};
 use rolldown_error::{BuildDiagnostic, BuildResult, Severity};
+use rustc_hash::FxHashMap;
 
 use crate::types::oxc_parse_type::OxcParseType;
 
@@ -60,7 +61,9 @@ impl PreProcessEcmaAst {
       let WithMutFields { allocator, program, .. } = fields;
       // Use built-in define plugin.
       if let Some(replace_global_define_config) = replace_global_define_config {
-        let ret = ReplaceGlobalDefines::new(allocator, replace_global_define_config.clone())
+        let replace_global_define_config = keep_file_url_placeholders(&source, bundle_options)
+          .unwrap_or_else(|| replace_global_define_config.clone());
+        let ret = ReplaceGlobalDefines::new(allocator, replace_global_define_config)
           .build(scoping, program);
         self.ast_changed = true;
         ret.scoping
@@ -148,6 +151,51 @@ impl PreProcessEcmaAst {
         .into_scoping()
     });
 
-    Ok(ParseToEcmaAstResult { ast, scoping, has_lazy_export, warning })
+    Ok(ParseToEcmaAstResult {
+      ast,
+      scoping,
+      has_lazy_export,
+      warning,
+      resolved_dynamic_imports: FxHashMap::default(),
+    })
   }
 }
+
+/// `import.meta.*` is defined as `undefined` for non-esm formats, which would also swallow the
+/// `import.meta.ROLLDOWN_FILE_URL_<referenceId>` placeholders before the finalizer could resolve
+/// them. Defining the placeholders found in `source` as themselves takes precedence over it.
+fn keep_file_url_placeholders(
+  source: &str,
+  bundle_options: &NormalizedBundlerOptions,
+) -> Option<ReplaceGlobalDefinesConfig> {
+  if !bundle_options.define.iter().any(|(target, _)| target == "import.meta.*") {
+    return None;
+  }
+  let placeholders = ["import.meta.ROLLDOWN_FILE_URL_", "import.meta.ROLLUP_FILE_URL_"]
+    .into_iter()
+    .flat_map(|prefix| {
+      source.match_indices(prefix).map(move |(start, _)| {
+        let rest = &source[start + prefix.len()..];
+        let len = rest
+          .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+          .unwrap_or(rest.len());
+        &source[start..start + prefix.len() + len]
+      })
+    })
+    .unique()
+    .collect_vec();
+  if placeholders.is_empty() {
+    return None;
+  }
+  let define = bundle_options
+    .define
+    .iter()
+    .cloned()
+    .chain(
+      placeholders
+        .into_iter()
+        .map(|placeholder| (placeholder.to_string(), placeholder.to_string())),
+    )
+    .collect_vec();
+  ReplaceGlobalDefinesConfig::new(&define).ok()
+}
@@ -55,6 +55,11 @@ pub async fn process_code_and_sourcemap(
     map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
   }
 
+  if !options.sourcemap_include_sources {
+    // The paths in `sources` are relative to the map, so tools can still load the sources.
+    map.set_source_contents(map.get_sources().map(|_| None).collect());
+  }
+
   if options.sourcemap_debug_ids && options.sourcemap.is_some() {
     let debug_id_str = uuid_v4_string_from_u128(debug_id);
     map.set_debug_id(&debug_id_str);
@@ -73,47 +78,42 @@ pub async fn process_code_and_sourcemap(
   let sources = map.get_sources().map(|x| x.to_slash_lossy().to_string()).collect::<Vec<_>>();
   map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
 
-  if let Some(sourcemap) = &options.sourcemap {
-    match sourcemap {
-      SourceMapType::File | SourceMapType::Hidden => {
-        let source = map.to_json_string();
-        if matches!(sourcemap, SourceMapType::File) {
-          process_sourcemap_related_reference(
-            code,
-            |source| {
-              source.push_str("# sourceMappingURL=");
-              source.push_str(
-                &Path::new(&map_filename)
-                  .file_name()
-                  .expect("should have filename")
-                  .to_string_lossy(),
-              );
-            },
-            source_map_link_comment_kind,
-          );
-        }
-        return Ok(Some(OutputAsset {
-          filename: map_filename.as_str().into(),
-          source: source.into(),
-          original_file_names: vec![],
-          names: vec![],
-        }));
-      }
-      SourceMapType::Inline => {
-        let data_url = map.to_data_url();
-        process_sourcemap_related_reference(
-          code,
-          |source| {
-            source.push_str("# sourceMappingURL=");
-            source.push_str(&data_url);
-          },
-          source_map_link_comment_kind,
-        );
-      }
-    }
+  let Some(sourcemap) = &options.sourcemap else { return Ok(None) };
+
+  // Whether the map is emitted as an asset and whether the chunk links to it are decided apart,
+  // `Hidden` emits the map without linking to it.
+  let map_asset =
+    matches!(sourcemap, SourceMapType::File | SourceMapType::Hidden).then(|| OutputAsset {
+      filename: map_filename.as_str().into(),
+      source: map.to_json_string().into(),
+      original_file_names: vec![],
+      names: vec![],
+    });
+
+  let url = match sourcemap {
+    SourceMapType::File => Some(match &options.sourcemap_url {
+      Some(sourcemap_url) => sourcemap_url.call(&map_filename.to_slash_lossy()).await?,
+      None => Path::new(&map_filename)
+        .file_name()
+        .expect("should have filename")
+        .to_string_lossy()
+        .into_owned(),
+    }),
+    SourceMapType::Inline => Some(map.to_data_url()),
+    SourceMapType::Hidden => None,
+  };
+  if let Some(url) = url {
+    process_sourcemap_related_reference(
+      code,
+      |source| {
+        source.push_str("# sourceMappingURL=");
+        source.push_str(&url);
+      },
+      source_map_link_comment_kind,
+    );
   }
 
-  Ok(None)
+  Ok(map_asset)
 }
 
 fn process_sourcemap_related_reference(
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use arcstr::ArcStr;
+use oxc::{
+  ast::{AstBuilder, ast},
+  ast_visit::{Visit, VisitMut, walk, walk_mut},
+  span::{GetSpan, Span},
+};
+use rolldown_common::{ModuleDefFormat, ResolvedId};
+use rolldown_ecmascript::EcmaAst;
+use rolldown_ecmascript_utils::ExpressionExt;
+use rolldown_plugin::{
+  DynamicImportSpecifier, HookResolveDynamicImportArgs, HookUsage, PluginDriver,
+};
+use rustc_hash::FxHashMap;
+
+/// Give `resolve_dynamic_import` hooks a chance to resolve `import()` expressions whose specifier can't be
+/// determined statically. Resolved expressions are rewritten to `import('<resolved id>')`, so they are scanned
+/// like any other dynamic import. The returned map is keyed by the span of the rewritten specifier, which is
+/// also the span of the import record created for it.
+pub async fn resolve_dynamic_import_expressions(
+  ast: &mut EcmaAst,
+  importer: &str,
+  plugin_driver: &PluginDriver,
+) -> anyhow::Result<FxHashMap<Span, ResolvedId>> {
+  let mut resolved_ids = FxHashMap::default();
+  if !plugin_driver.has_hook_usage(HookUsage::ResolveDynamicImport) {
+    return Ok(resolved_ids);
+  }
+
+  let mut collector = NonStaticSpecifierCollector::default();
+  collector.visit_program(ast.program());
+  if collector.specifier_spans.is_empty() {
+    return Ok(resolved_ids);
+  }
+
+  let source = ArcStr::clone(ast.source());
+  let mut replacements = FxHashMap::default();
+  for span in collector.specifier_spans {
+    let Some(output) = plugin_driver
+      .resolve_dynamic_import(
+        &HookResolveDynamicImportArgs {
+          importer: Some(importer),
+          specifier: DynamicImportSpecifier::Expression(span.source_text(&source)),
+          custom: Arc::default(),
+        },
+        None,
+      )
+      .await?
+    else {
+      // Leave the expression untouched, it's handled as before.
+      continue;
+    };
+    replacements.insert(span, ArcStr::clone(&output.id));
+    resolved_ids.insert(
+      span,
+      ResolvedId {
+        module_def_format: ModuleDefFormat::from_path(output.id.as_str()),
+        id: output.id,
+        external: output.external.unwrap_or_default(),
+        normalize_external_id: output.normalize_external_id,
+        side_effects: output.side_effects,
+        ..Default::default()
+      },
+    );
+  }
+
+  if !replacements.is_empty() {
+    ast.program.with_mut(|fields| {
+      let mut rewriter =
+        SpecifierRewriter { builder: AstBuilder::new(fields.allocator), replacements };
+      rewriter.visit_program(fields.program);
+    });
+  }
+
+  Ok(resolved_ids)
+}
+
+#[derive(Default)]
+struct NonStaticSpecifierCollector {
+  specifier_spans: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for NonStaticSpecifierCollector {
+  fn visit_import_expression(&mut self, it: &ast::ImportExpression<'a>) {
+    if it.source.as_static_module_request().is_none() && !it.source.span().is_unspanned() {
+      self.specifier_spans.push(it.source.span());
+    }
+    walk::walk_import_expression(self, it);
+  }
+}
+
+struct SpecifierRewriter<'a> {
+  builder: AstBuilder<'a>,
+  replacements: FxHashMap<Span, ArcStr>,
+}
+
+impl<'a> VisitMut<'a> for SpecifierRewriter<'a> {
+  fn visit_import_expression(&mut self, it: &mut ast::ImportExpression<'a>) {
+    let span = it.source.span();
+    if let Some(resolved_id) = self.replacements.remove(&span) {
+      it.source =
+        self.builder.expression_string_literal(span, self.builder.atom(resolved_id.as_str()), None);
+      return;
+    }
+    walk_mut::walk_import_expression(self, it);
+  }
+}
@@ -15,7 +15,10 @@ use arcstr::ArcStr;
 use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use rolldown_common::{OutputsDiagnostics, WatcherChangeKind};
 use rolldown_error::{BuildDiagnostic, BuildResult, ResultExt};
-use rolldown_utils::{dashmap::FxDashSet, pattern_filter};
+use rolldown_utils::{
+  dashmap::FxDashSet,
+  pattern_filter::{self, FilterResult},
+};
 use tokio::sync::Mutex;
 
 pub struct WatcherTask {
@@ -67,8 +70,8 @@ impl WatcherTask {
           if bundler.options.watch.skip_write {
             Ok(())
           } else {
-            // avoid watching scan stage files twice
-            watched_files.clear();
+            // `watch_files` skips the files watched after the scan stage, so the set is kept intact
+            // for the `watch_files` of the bundle output.
             let output = bundler.bundle_write(scan_stage_output).await;
             self.watch_files(&watched_files, &bundler.options).await?;
             match output {
@@ -123,15 +126,7 @@ impl WatcherTask {
         continue;
       }
       let path = Path::new(file.as_str());
-      if path.exists()
-        && pattern_filter::filter(
-          options.watch.exclude.as_deref(),
-          options.watch.include.as_deref(),
-          file.as_str(),
-          options.cwd.to_string_lossy().as_ref(),
-        )
-        .inner()
-      {
+      if path.exists() && should_watch(file.as_str(), options) {
         self.watch_files.insert(file.clone());
         // we should skip the file that is already watched, here here some reasons:
         // - The watching files has a ms level overhead.
@@ -191,3 +186,28 @@ impl WatcherTask {
     });
   }
 }
+
+/// Files under `node_modules` and the output directory are excluded unless `watch.include` matches
+/// them, on top of `watch.exclude`, so installing packages or writing the bundle doesn't trigger a
+/// rebuild.
+fn should_watch(file: &str, options: &SharedOptions) -> bool {
+  match pattern_filter::filter(
+    options.watch.exclude.as_deref(),
+    options.watch.include.as_deref(),
+    file,
+    options.cwd.to_string_lossy().as_ref(),
+  ) {
+    FilterResult::Match(included) => included,
+    FilterResult::NoneMatch(included) => included && !is_excluded_by_default(file, options),
+  }
+}
+
+fn is_excluded_by_default(file: &str, options: &SharedOptions) -> bool {
+  let path = Path::new(file);
+  if path.components().any(|component| component.as_os_str() == "node_modules") {
+    return true;
+  }
+  // With `output.file` in the cwd, the output directory is the cwd itself, which holds the sources.
+  let out_dir = options.resolved_out_dir();
+  !options.cwd.starts_with(&out_dir) && path.starts_with(&out_dir)
+}
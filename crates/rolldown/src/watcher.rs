@@ -1,12 +1,15 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use rolldown_common::NotifyOption;
+use rolldown_common::{NotifyOption, WatcherChangeKind};
 use tokio::sync::Mutex;
 
 use crate::{
   Bundler,
-  watch::watcher::{WatcherImpl, wait_for_change},
+  watch::{
+    event::WatcherChangeData,
+    watcher::{WatcherImpl, emit_change_event, wait_for_change},
+  },
 };
 
 pub struct Watcher(Arc<WatcherImpl>);
@@ -26,6 +29,13 @@ impl Watcher {
     self.0.start().await;
   }
 
+  /// Reports a change observed outside of the watcher, e.g. by a dev server with its own file
+  /// watcher, as if `notify` reported it.
+  pub fn invalidate(&self, path: &str, kind: WatcherChangeKind) {
+    emit_change_event(&self.0, path, kind);
+    self.0.invalidate(Some(WatcherChangeData { path: path.into(), kind }));
+  }
+
   pub async fn close(&self) -> Result<()> {
     self.0.close().await
   }
@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { bar, foo } from './shared.js'
+
+assert.strictEqual(foo, 'foo')
+assert.strictEqual(bar, 'bar')
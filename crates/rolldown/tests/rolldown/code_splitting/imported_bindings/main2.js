@@ -0,0 +1,3 @@
+import { foo, unused } from './shared.js'
+
+export const value = foo + unused
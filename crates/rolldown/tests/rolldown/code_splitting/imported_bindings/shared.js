@@ -0,0 +1,3 @@
+export const foo = 'foo'
+export const bar = 'bar'
+export const unused = 'unused'
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { formatGreeting } from './shared.js'
+
+assert.strictEqual(formatGreeting('Hi', 'there'), 'Hi, there!')
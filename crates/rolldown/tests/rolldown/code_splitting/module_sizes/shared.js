@@ -0,0 +1,11 @@
+export function formatGreeting(greeting, name) {
+  const trimmedName = name.trim()
+  return `${greeting}, ${trimmedName}!`
+}
+
+// Tree shaken, so the shared module renders smaller than its source.
+export function formatFarewell(farewell, name) {
+  const trimmedName = name.trim()
+  const capitalizedName = trimmedName[0].toUpperCase() + trimmedName.slice(1)
+  return `${farewell}, ${capitalizedName}. See you soon!`
+}
@@ -41,7 +41,11 @@ async fn test() {
   IntegrationTest::new(TestMeta { expect_error: true, ..Default::default() })
     .run_with_plugins(
       BundlerOptions {
-        input: Some(vec![InputItem { name: Some("ext".to_string()), import: "ext".to_string() }]),
+        input: Some(vec![InputItem {
+          name: Some("ext".to_string()),
+          import: "ext".to_string(),
+          ..Default::default()
+        }]),
         cwd: Some(cwd),
         ..Default::default()
       },
@@ -1,2 +1,3 @@
 mod entry_cannot_be_external;
+mod parse_error;
 mod unresolved_import;
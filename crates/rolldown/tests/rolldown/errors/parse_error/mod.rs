@@ -0,0 +1,90 @@
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_error::{ColorMode, DiagnosticOptions, Severity, StructuredLabel};
+use rolldown_testing::abs_file_dir;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn structured_diagnostic_matches_rendered_position() {
+  let cwd = abs_file_dir!().join("normal");
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(cwd.clone()),
+    ..Default::default()
+  });
+
+  let Err(errors) = bundler.generate().await else {
+    panic!("`cons a = 1;` should fail to parse");
+  };
+  let opts = DiagnosticOptions::new(cwd, ColorMode::Never);
+  let structured = errors[0].to_structured(&opts);
+
+  assert_eq!(structured.code, "PARSE_ERROR");
+  assert_eq!(structured.severity, Severity::Error);
+  assert_eq!(
+    structured.message,
+    "Expected a semicolon or an implicit semicolon after a statement, but found none"
+  );
+  assert_eq!(structured.help.as_deref(), Some("Try insert a semicolon here"));
+  assert_eq!(
+    structured.primary_label(),
+    Some(&StructuredLabel {
+      file: "main.js".into(),
+      start: 4,
+      end: 4,
+      start_line: 1,
+      start_column: 5,
+      end_line: 1,
+      end_column: 5,
+      message: None,
+    })
+  );
+
+  let rendered = errors[0].to_diagnostic_with(&opts).to_string();
+  assert!(rendered.contains("[ main.js:1:5 ]"), "{rendered}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn json_diagnostic_contains_labeled_spans() {
+  let cwd = abs_file_dir!().join("normal");
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(cwd.clone()),
+    ..Default::default()
+  });
+
+  let Err(errors) = bundler.generate().await else {
+    panic!("`cons a = 1;` should fail to parse");
+  };
+
+  assert_eq!(
+    errors[0].to_json(&DiagnosticOptions::new(cwd, ColorMode::Never)),
+    serde_json::json!({
+      "kind": "PARSE_ERROR",
+      "severity": "error",
+      "message": "Expected a semicolon or an implicit semicolon after a statement, but found none",
+      "help": "Try insert a semicolon here",
+      "id": null,
+      "plugin": null,
+      "hook": null,
+      "spans": [{
+        "file": "main.js",
+        "start": 4,
+        "end": 4,
+        "startLine": 1,
+        "startColumn": 5,
+        "endLine": 1,
+        "endColumn": 5,
+        "message": null,
+      }],
+      "occurrences": 1,
+      "duplicates": [],
+    })
+  );
+}
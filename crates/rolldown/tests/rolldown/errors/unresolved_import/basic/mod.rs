@@ -45,6 +45,7 @@ async fn should_failed_to_resolve_the_module_with_diagnostic() {
         input: Some(vec![InputItem {
           name: Some("entry".to_string()),
           import: "./entry.js".to_string(),
+          ..Default::default()
         }]),
         cwd: Some(cwd),
         ..Default::default()
@@ -48,6 +48,7 @@ async fn test() {
         input: Some(vec![InputItem {
           name: Some("entry".to_string()),
           import: "entry.js".to_string(),
+          ..Default::default()
         }]),
         cwd: Some(cwd),
         asset_filenames: Some(AssetFilenamesOutputOption::String(
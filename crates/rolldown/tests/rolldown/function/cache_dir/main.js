@@ -0,0 +1,4 @@
+import { value } from './dep.js';
+import { flag } from './opt-out.js';
+
+console.log(value, flag);
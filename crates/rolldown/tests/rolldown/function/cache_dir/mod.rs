@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, HookUsage, Plugin,
+  SharedTransformPluginContext,
+};
+use rolldown_testing::{abs_file_dir, memory_file_system::InMemoryFileSystem, utils::TempDir};
+use rustc_hash::FxHashMap;
+
+/// Counts its `transform` calls and marks the transformed code, so a cached result is recognizable.
+/// Opts `opt-out.js` out of the cache, as if it depended on the environment.
+#[derive(Debug, Default)]
+struct CountTransforms {
+  calls: AtomicUsize,
+}
+
+impl Plugin for CountTransforms {
+  fn name(&self) -> Cow<'static, str> {
+    "CountTransforms".into()
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    self.calls.fetch_add(1, Ordering::Relaxed);
+    Ok(Some(HookTransformOutput {
+      code: Some(format!("{}\nconsole.log('transformed');\n", args.code)),
+      ..Default::default()
+    }))
+  }
+
+  fn should_cache_module(&self, id: &str) -> bool {
+    !id.ends_with("opt-out.js")
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Transform
+  }
+}
+
+fn options(cwd: PathBuf, cache_dir: &Path) -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(cwd),
+    cache_dir: Some(cache_dir.to_path_buf()),
+    ..Default::default()
+  }
+}
+
+/// Returns the code of the output and the number of `transform` calls.
+async fn build(options: BundlerOptions) -> (String, usize) {
+  let plugin = Arc::new(CountTransforms::default());
+  let output = Bundler::with_plugins(options, vec![Arc::clone(&plugin) as _])
+    .generate()
+    .await
+    .expect("build should succeed");
+  let code = String::from_utf8(output.assets[0].content_as_bytes().to_vec()).unwrap();
+  (code, plugin.calls.load(Ordering::Relaxed))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn second_build_skips_transforms() {
+  let cache_dir = TempDir::new("cache-dir-warm");
+  let (cold, cold_calls) = build(options(abs_file_dir!(), cache_dir.path())).await;
+  assert_eq!(cold_calls, 3);
+  assert_eq!(cold.matches("console.log(\"transformed\")").count(), 3);
+
+  let (warm, warm_calls) = build(options(abs_file_dir!(), cache_dir.path())).await;
+  assert_eq!(warm_calls, 1, "only the module opted out of the cache should be transformed");
+  assert_eq!(warm, cold);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn corrupted_entries_fall_back_to_transforming() {
+  let cache_dir = TempDir::new("cache-dir-corrupted");
+  let (cold, _) = build(options(abs_file_dir!(), cache_dir.path())).await;
+  for entry in std::fs::read_dir(cache_dir.path()).unwrap() {
+    std::fs::write(entry.unwrap().path(), "{\"version\":").unwrap();
+  }
+
+  let (rebuilt, calls) = build(options(abs_file_dir!(), cache_dir.path())).await;
+  assert_eq!(calls, 3);
+  assert_eq!(rebuilt, cold);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn entries_go_through_the_file_system() {
+  let fs = Arc::new(InMemoryFileSystem::new(FxHashMap::from_iter([
+    ("/project/main.js", "import './dep.js';\nconsole.log('main');\n"),
+    ("/project/dep.js", "console.log('dep');\n"),
+  ])));
+  let cache_dir = Path::new("/project/.cache");
+  let in_memory_options = || BundlerOptions {
+    file_system: Some(Arc::clone(&fs) as _),
+    ..options(PathBuf::from("/project"), cache_dir)
+  };
+
+  let (cold, cold_calls) = build(in_memory_options()).await;
+  assert_eq!(cold_calls, 2);
+  assert_eq!(fs.files_in(cache_dir).len(), 2);
+  assert!(!cache_dir.exists());
+
+  let (warm, warm_calls) = build(in_memory_options()).await;
+  assert_eq!(warm_calls, 0);
+  assert_eq!(warm, cold);
+}
@@ -0,0 +1 @@
+export const flag = process.env.FLAG;
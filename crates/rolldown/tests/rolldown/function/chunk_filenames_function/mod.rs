@@ -12,6 +12,7 @@ async fn test() {
       input: Some(vec![InputItem {
         name: Some("entry".to_string()),
         import: "entry.js".to_string(),
+        ..Default::default()
       }]),
       cwd: Some(cwd),
       chunk_filenames: Some(rolldown::ChunkFilenamesOutputOption::Fn(Arc::new(|chunk| {
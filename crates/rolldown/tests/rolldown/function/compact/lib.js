@@ -0,0 +1,18 @@
+export class ShoppingCart {
+  constructor() {
+    this.items = [];
+  }
+
+  addItem(name, price, quantity = 1) {
+    this.items.push({ name, price, quantity });
+    return this;
+  }
+
+  get totalPrice() {
+    let total = 0;
+    for (const item of this.items) {
+      total += item.price * item.quantity;
+    }
+    return total;
+  }
+}
@@ -0,0 +1,5 @@
+import { ShoppingCart } from './lib.js';
+
+const cart = new ShoppingCart().addItem('apple', 3, 2).addItem('pear', 5);
+
+console.log(`total: ${cart.totalPrice}`);
@@ -0,0 +1,55 @@
+use rolldown::{Bundler, BundlerOptions, InputItem, RawMinifyOptions};
+use rolldown_common::Output;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+async fn generate_code(compact: bool, minify: bool) -> String {
+  let output = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(abs_file_dir!()),
+    compact: Some(compact),
+    minify: Some(RawMinifyOptions::Bool(minify)),
+    ..Default::default()
+  })
+  .generate()
+  .await
+  .unwrap();
+  output
+    .assets
+    .into_iter()
+    .find_map(|asset| match asset {
+      Output::Chunk(chunk) => Some(chunk.code.clone()),
+      Output::Asset(_) => None,
+    })
+    .expect("should emit the main chunk")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn compact_output_sits_between_normal_and_minified() {
+  let normal = generate_code(false, false).await;
+  let compact = generate_code(true, false).await;
+  let minified = generate_code(false, true).await;
+
+  assert!(compact.len() < normal.len(), "{compact}");
+  assert!(minified.len() < compact.len(), "{minified}");
+  // Only the whitespace goes away, the names are left for the reader.
+  for name in ["ShoppingCart", "addItem", "totalPrice", "quantity", "total"] {
+    assert!(compact.contains(name), "{compact}");
+  }
+  assert!(!minified.contains("ShoppingCart"), "{minified}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn compact_output_keeps_the_runtime_behavior() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_diff(
+      BundlerOptions { cwd: Some(cwd.clone()), ..Default::default() },
+      BundlerOptions { cwd: Some(cwd), compact: Some(true), ..Default::default() },
+    )
+    .await;
+}
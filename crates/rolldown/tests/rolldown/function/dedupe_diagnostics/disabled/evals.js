@@ -0,0 +1,5 @@
+export const one = eval('1')
+export const two = eval('2')
+export const three = eval('3')
+export const four = eval('4')
+export const five = eval('5')
@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { one, five } from './evals.js'
+import { six } from './other.js'
+
+assert.strictEqual(one + five + six, 12)
@@ -0,0 +1 @@
+export const six = eval('6')
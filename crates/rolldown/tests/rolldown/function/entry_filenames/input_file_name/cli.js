@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { greet } from './index.js'
+
+assert.strictEqual(greet('cli'), 'hello cli')
@@ -0,0 +1,3 @@
+import { value as c } from './shared-c.js'
+
+export const value = `lazy ${c}`
@@ -0,0 +1,5 @@
+import { value as a } from './shared-a.js'
+import { value as b } from './shared-b.js'
+
+console.log(a, b)
+import('./lazy.js').then(({ value }) => console.log(value))
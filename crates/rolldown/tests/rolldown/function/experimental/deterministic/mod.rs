@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+use std::path::Path;
+use std::sync::Arc;
+
+use rolldown::{Bundler, BundlerOptions, ExperimentalOptions, InputItem};
+use rolldown_common::{EmittedAsset, Output};
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformReturn, HookUsage, Plugin, SharedTransformPluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+/// Emits an asset for each module from the `transform` hook, which runs in parallel.
+#[derive(Debug)]
+struct EmitAssetPerModule;
+
+impl Plugin for EmitAssetPerModule {
+  fn name(&self) -> Cow<'static, str> {
+    "EmitAssetPerModule".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let name = Path::new(args.id).file_stem().unwrap().to_string_lossy().to_string();
+    ctx.inner.emit_file(
+      EmittedAsset {
+        name: Some(format!("{name}.txt")),
+        original_file_name: None,
+        file_name: None,
+        source: format!("asset of {name}").into(),
+      },
+      None,
+      None,
+    );
+    Ok(None)
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Transform
+  }
+}
+
+/// Builds the fixture on a runtime with `worker_threads` threads, so modules are scanned in a
+/// different order.
+fn build(worker_threads: usize) -> Vec<(String, Vec<u8>)> {
+  let options = BundlerOptions {
+    input: Some(vec![
+      InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      },
+      InputItem {
+        name: Some("other".to_string()),
+        import: "./other.js".to_string(),
+        ..Default::default()
+      },
+    ]),
+    cwd: Some(abs_file_dir!()),
+    entry_filenames: Some("[name]-[hash].js".to_string().into()),
+    chunk_filenames: Some("[name]-[hash].js".to_string().into()),
+    experimental: Some(ExperimentalOptions { deterministic: Some(true), ..Default::default() }),
+    ..Default::default()
+  };
+  let runtime = tokio::runtime::Builder::new_multi_thread()
+    .worker_threads(worker_threads)
+    .enable_all()
+    .build()
+    .unwrap();
+  let output = runtime
+    .block_on(Bundler::with_plugins(options, vec![Arc::new(EmitAssetPerModule)]).generate())
+    .expect("build should succeed");
+  output
+    .assets
+    .iter()
+    .map(|asset| match asset {
+      Output::Chunk(chunk) => (chunk.filename.to_string(), chunk.code.as_bytes().to_vec()),
+      Output::Asset(asset) => (asset.filename.to_string(), asset.source.as_bytes().to_vec()),
+    })
+    .collect()
+}
+
+#[test]
+fn outputs_are_identical_across_thread_counts() {
+  let expected = build(1);
+  let chunks = expected
+    .iter()
+    .filter(|(filename, _)| Path::new(filename).extension().is_some_and(|ext| ext == "js"))
+    .count();
+  assert!(chunks > 2, "the fixture should be split into shared chunks");
+  for worker_threads in [2, 8] {
+    assert_eq!(build(worker_threads), expected, "output differs with {worker_threads} threads");
+  }
+}
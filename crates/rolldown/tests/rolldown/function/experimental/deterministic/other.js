@@ -0,0 +1,6 @@
+import { value as a } from './shared-a.js'
+import { value as b } from './shared-b.js'
+import { value as c } from './shared-c.js'
+
+console.log(a, b, c)
+import('./lazy.js').then(({ value }) => console.log(value))
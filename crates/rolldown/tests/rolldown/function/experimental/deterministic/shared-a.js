@@ -0,0 +1,2 @@
+export const value = 'shared-a'
+export const unused = 'unused-a'
@@ -0,0 +1,2 @@
+export const value = 'shared-b'
+export const unused = 'unused-b'
@@ -0,0 +1,2 @@
+export const value = 'shared-c'
+export const unused = 'unused-c'
@@ -0,0 +1,9 @@
+import assert from 'node:assert';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+const main = require('./dist/main.cjs');
+
+// A lone default export is unwrapped instead of being exposed as `{ default }`.
+assert.strictEqual(typeof main, 'function');
+assert.strictEqual(main(), 'default');
@@ -0,0 +1,11 @@
+import assert from 'node:assert';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+const main = require('./dist/main.cjs');
+
+// Mixing default and named exports falls back to named mode, so the default export
+// ends up on `.default`.
+assert.strictEqual(main.__esModule, true);
+assert.strictEqual(main.a(), 'named');
+assert.strictEqual(main.default, 'default');
@@ -0,0 +1,4 @@
+export function a() {
+  return 'named'
+}
+export default 'default'
@@ -0,0 +1,10 @@
+import assert from 'node:assert';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+const main = require('./dist/main.cjs');
+
+// Without a default export there is no need for the `__esModule` marker.
+assert.strictEqual(main.__esModule, undefined);
+assert.deepStrictEqual(Object.keys(main), ['a']);
+assert.strictEqual(main.a(), 'named');
@@ -0,0 +1,10 @@
+import assert from 'node:assert';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+const main = require('./dist/main.cjs');
+
+// `module.exports` is the default export itself, which is the namespace of `mod.js` here.
+assert.strictEqual(main.__esModule, undefined);
+assert.deepStrictEqual(Object.keys(main), ['default']);
+assert.strictEqual(main.default(), 'default');
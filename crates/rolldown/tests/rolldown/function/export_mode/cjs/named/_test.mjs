@@ -0,0 +1,9 @@
+import assert from 'node:assert';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+const main = require('./dist/main.cjs');
+
+assert.strictEqual(main.__esModule, true);
+assert.strictEqual(main.a(), 'named');
+assert.strictEqual(main.default, 'named');
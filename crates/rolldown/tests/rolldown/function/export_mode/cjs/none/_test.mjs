@@ -0,0 +1,8 @@
+import assert from 'node:assert';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+const main = require('./dist/main.cjs');
+
+assert.deepStrictEqual(main, {});
+assert.strictEqual(globalThis.sideEffect, 'none');
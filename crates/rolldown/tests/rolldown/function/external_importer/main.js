@@ -0,0 +1,4 @@
+import { a } from './pkg-a/index.js'
+import { b } from './pkg-b/index.js'
+
+export { a, b }
@@ -0,0 +1,20 @@
+use rolldown::{BundlerOptions, IsExternal};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run(BundlerOptions {
+      cwd: Some(cwd),
+      // Only imports of `shared` from `pkg-a` are external, the one from `pkg-b` gets bundled.
+      external: Some(IsExternal::from_closure(|specifier, importer, _is_resolved| {
+        let is_external =
+          specifier == "shared" && importer.is_some_and(|importer| importer.contains("pkg-a"));
+        Box::pin(async move { Ok(is_external) })
+      })),
+      ..Default::default()
+    })
+    .await;
+}
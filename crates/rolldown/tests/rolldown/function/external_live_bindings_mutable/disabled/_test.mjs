@@ -0,0 +1,10 @@
+import assert from 'node:assert';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+const main = require('./dist/main.cjs');
+
+assert.strictEqual(main.count, 0);
+main.increment();
+// The re-export was copied once when the chunk was loaded.
+assert.strictEqual(main.count, 0);
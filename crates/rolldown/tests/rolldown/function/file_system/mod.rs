@@ -0,0 +1,55 @@
+use std::{path::PathBuf, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_testing::memory_file_system::InMemoryFileSystem;
+use rustc_hash::FxHashMap;
+
+/// A project that only exists in memory, in a directory that doesn't exist on the disk.
+fn in_memory_project() -> Arc<InMemoryFileSystem> {
+  let files = FxHashMap::from_iter([
+    ("/project/main.js", "import { answer } from './answer.js';\nconsole.log(answer);\n"),
+    ("/project/answer.js", "export const answer = 42;\n"),
+  ]);
+  Arc::new(InMemoryFileSystem::new(files))
+}
+
+fn options(fs: &Arc<InMemoryFileSystem>) -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(PathBuf::from("/project")),
+    file_system: Some(Arc::clone(fs) as _),
+    ..Default::default()
+  }
+}
+
+const EXPECTED: &str = "//#region answer.js
+const answer = 42;
+
+//#endregion
+//#region main.js
+console.log(answer);
+
+//#endregion";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bundles_in_memory_project() {
+  let fs = in_memory_project();
+  let output = Bundler::new(options(&fs)).generate().await.expect("build should succeed");
+
+  assert_eq!(output.assets.len(), 1);
+  let code = String::from_utf8(output.assets[0].content_as_bytes().to_vec()).unwrap();
+  assert_eq!(code.trim(), EXPECTED);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn writes_into_file_system() {
+  let fs = in_memory_project();
+  Bundler::new(options(&fs)).write().await.expect("build should succeed");
+
+  assert_eq!(fs.files_in("/project/dist"), [PathBuf::from("/project/dist/main.js")]);
+  assert_eq!(fs.file("/project/dist/main.js").unwrap().trim(), EXPECTED);
+}
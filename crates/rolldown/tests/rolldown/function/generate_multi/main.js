@@ -0,0 +1,5 @@
+import { greet } from './greet.js';
+
+export const message = greet('multi');
+
+export const loadLazy = () => import('./lazy.js');
@@ -0,0 +1,128 @@
+use std::borrow::Cow;
+use std::sync::{
+  Arc, Mutex,
+  atomic::{AtomicUsize, Ordering},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem, OutputFormat, OutputOptions};
+use rolldown_common::{EmittedAsset, Output};
+use rolldown_plugin::{
+  HookBuildStartArgs, HookNoopReturn, HookRenderChunkArgs, HookRenderChunkReturn, HookUsage,
+  Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug, Default)]
+struct TrackOutputs {
+  build_start_calls: AtomicUsize,
+  rendered: Mutex<Vec<(OutputFormat, String)>>,
+}
+
+impl Plugin for TrackOutputs {
+  fn name(&self) -> Cow<'static, str> {
+    "TrackOutputs".into()
+  }
+
+  async fn build_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    self.build_start_calls.fetch_add(1, Ordering::Relaxed);
+    ctx.emit_file(
+      EmittedAsset {
+        name: None,
+        original_file_name: None,
+        file_name: Some("shared.txt".into()),
+        source: "shared".to_string().into(),
+      },
+      None,
+      None,
+    );
+    Ok(())
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderChunkArgs<'_>,
+  ) -> HookRenderChunkReturn {
+    self.rendered.lock().unwrap().push((args.options.format, args.chunk.filename.to_string()));
+    Ok(None)
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::RenderChunk
+  }
+}
+
+fn bundler_options(format: Option<OutputFormat>) -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(abs_file_dir!()),
+    format,
+    ..Default::default()
+  }
+}
+
+fn chunk_codes(output: &rolldown::BundleOutput) -> Vec<(String, String)> {
+  let mut codes = output
+    .assets
+    .iter()
+    .filter_map(|asset| match asset {
+      Output::Chunk(chunk) => Some((chunk.filename.to_string(), chunk.code.clone())),
+      Output::Asset(_) => None,
+    })
+    .collect::<Vec<_>>();
+  codes.sort();
+  codes
+}
+
+fn asset_filenames(output: &rolldown::BundleOutput) -> Vec<String> {
+  output
+    .assets
+    .iter()
+    .filter_map(|asset| match asset {
+      Output::Asset(asset) => Some(asset.filename.to_string()),
+      Output::Chunk(_) => None,
+    })
+    .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn generate_multi_matches_separate_builds() {
+  let plugin = Arc::new(TrackOutputs::default());
+  let mut bundler =
+    Bundler::with_plugins(bundler_options(None), vec![Arc::<TrackOutputs>::clone(&plugin)]);
+  let outputs = bundler
+    .generate_multi(vec![
+      OutputOptions { format: Some(OutputFormat::Esm), ..Default::default() },
+      OutputOptions { format: Some(OutputFormat::Cjs), ..Default::default() },
+    ])
+    .await
+    .expect("generate_multi should succeed");
+  assert_eq!(outputs.len(), 2);
+
+  for (output, format) in outputs.iter().zip([OutputFormat::Esm, OutputFormat::Cjs]) {
+    let separate = Bundler::new(bundler_options(Some(format)))
+      .generate()
+      .await
+      .expect("separate build should succeed");
+    assert_eq!(chunk_codes(output), chunk_codes(&separate), "{format} output differs");
+    // Files emitted by build hooks are shared by all outputs.
+    assert_eq!(asset_filenames(output), vec!["shared.txt".to_string()]);
+  }
+
+  assert_eq!(plugin.build_start_calls.load(Ordering::Relaxed), 1);
+  let rendered = plugin.rendered.lock().unwrap();
+  let esm_chunks =
+    rendered.iter().filter(|(format, _)| matches!(format, OutputFormat::Esm)).count();
+  let cjs_chunks =
+    rendered.iter().filter(|(format, _)| matches!(format, OutputFormat::Cjs)).count();
+  assert_eq!(esm_chunks, chunk_codes(&outputs[0]).len());
+  assert_eq!(cjs_chunks, chunk_codes(&outputs[1]).len());
+}
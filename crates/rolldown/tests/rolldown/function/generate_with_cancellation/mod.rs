@@ -0,0 +1,92 @@
+use std::borrow::Cow;
+use std::sync::{
+  Arc,
+  atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use rolldown::{Bundler, BundlerOptions, CancellableBundleOutput, InputItem};
+use rolldown_common::CancellationToken;
+use rolldown_plugin::{
+  HookRenderChunkArgs, HookRenderChunkReturn, HookTransformArgs, HookTransformReturn, HookUsage,
+  Plugin, PluginContext, PluginDiagnostic, SharedTransformPluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+/// Cancels the build while transforming, like a newer change arriving in watch mode. It warns only
+/// in the build it cancels.
+#[derive(Debug, Default)]
+struct CancelOnTransform {
+  token: CancellationToken,
+  observed_cancellation: AtomicBool,
+  render_chunk_calls: AtomicUsize,
+}
+
+impl Plugin for CancelOnTransform {
+  fn name(&self) -> Cow<'static, str> {
+    "CancelOnTransform".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    _args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if self.token.is_cancelled() {
+      return Ok(None);
+    }
+    ctx.inner.warn(PluginDiagnostic::new("Reported by the cancelled build"));
+    self.token.cancel();
+    self.observed_cancellation.store(ctx.inner.is_cancelled(), Ordering::Relaxed);
+    Ok(None)
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderChunkArgs<'_>,
+  ) -> HookRenderChunkReturn {
+    self.render_chunk_calls.fetch_add(1, Ordering::Relaxed);
+    Ok(None)
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Transform | HookUsage::RenderChunk
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cancelled_build_has_no_output() {
+  let cwd = abs_file_dir!();
+  let plugin = Arc::new(CancelOnTransform::default());
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(cwd.clone()),
+      ..Default::default()
+    },
+    vec![Arc::<CancelOnTransform>::clone(&plugin)],
+  );
+
+  let ret = bundler
+    .generate_with_cancellation(plugin.token.clone())
+    .await
+    .expect("cancellation isn't an error");
+  assert!(matches!(ret, CancellableBundleOutput::Cancelled));
+  assert!(plugin.observed_cancellation.load(Ordering::Relaxed));
+  assert_eq!(plugin.render_chunk_calls.load(Ordering::Relaxed), 0);
+  assert!(!cwd.join("dist").exists());
+
+  // Builds without a cancellation token aren't affected by the cancelled one.
+  let output = bundler.generate().await.expect("the next build should succeed");
+  assert_eq!(output.assets.len(), 1);
+  assert!(
+    output.warnings.is_empty(),
+    "warnings of the cancelled build leaked: {:?}",
+    output.warnings
+  );
+  assert_eq!(plugin.render_chunk_calls.load(Ordering::Relaxed), 1);
+}
@@ -0,0 +1,15 @@
+import nodeFs from 'node:fs'
+import nodePath from 'node:path'
+
+const require = (await import('node:module')).createRequire(import.meta.url)
+const assert = require('node:assert')
+const main = require('./dist/main.cjs')
+
+assert.strictEqual(main.sep, nodePath.sep)
+assert.strictEqual(main.shared, 'shared')
+assert.strictEqual((await main.loadOther()).other, 'other-shared')
+
+for (const file of nodeFs.readdirSync(nodePath.join(import.meta.dirname, 'dist'))) {
+  const content = nodeFs.readFileSync(nodePath.join(import.meta.dirname, 'dist', file), 'utf-8')
+  assert(!content.includes('const '), `${file} should not contain const bindings`)
+}
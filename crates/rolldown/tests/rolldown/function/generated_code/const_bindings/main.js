@@ -0,0 +1,10 @@
+import * as path from 'node:path'
+import { shared } from './shared.js'
+
+export var sep = path.sep
+
+export function loadOther() {
+  return import('./other.js')
+}
+
+export { shared }
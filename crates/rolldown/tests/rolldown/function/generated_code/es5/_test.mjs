@@ -0,0 +1,18 @@
+import nodeFs from 'node:fs'
+import nodePath from 'node:path'
+
+const require = (await import('node:module')).createRequire(import.meta.url)
+const assert = require('node:assert')
+const main = require('./dist/main.cjs')
+
+assert.deepStrictEqual(main.legacy, { name: 'legacy' })
+assert.strictEqual(main.ns.a, 'a')
+assert.strictEqual(main.sep, nodePath.sep)
+assert.strictEqual(main.named.name, 'named')
+assert.strictEqual((await main.loadDynamic()).default, 'dynamic')
+
+for (const file of nodeFs.readdirSync(nodePath.join(import.meta.dirname, 'dist'))) {
+  const content = nodeFs.readFileSync(nodePath.join(import.meta.dirname, 'dist', file), 'utf-8')
+  assert(!content.includes('=>'), `${file} should not contain arrow functions`)
+  assert(!/\b(const|let)\s/.test(content), `${file} should not contain block scoped bindings`)
+}
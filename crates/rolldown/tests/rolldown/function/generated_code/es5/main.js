@@ -0,0 +1,13 @@
+import * as path from 'node:path'
+import legacy from './legacy.js'
+import * as ns from './ns.js'
+
+export { legacy, ns }
+
+export var sep = path.sep
+
+export var named = function () {}
+
+export function loadDynamic() {
+  return import('./dynamic.js')
+}
@@ -0,0 +1,2 @@
+export var a = 'a'
+export var b = 'b'
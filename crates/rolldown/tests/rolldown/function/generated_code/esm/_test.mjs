@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import * as main from './dist/main.js'
+
+assert.deepStrictEqual(main.legacy, { name: 'legacy' })
+assert.strictEqual(main.ns.a, 'a')
+assert.strictEqual(main.lazy, 'lazy')
+assert.strictEqual((await main.loadDynamic()).default, 'dynamic')
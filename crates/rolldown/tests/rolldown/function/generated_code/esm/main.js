@@ -0,0 +1,10 @@
+import legacy from './legacy.js'
+import * as ns from './ns.js'
+
+export { legacy, ns }
+
+export const lazy = require('./lazy.js').lazy
+
+export function loadDynamic() {
+  return import('./dynamic.js')
+}
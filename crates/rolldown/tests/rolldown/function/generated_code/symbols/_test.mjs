@@ -0,0 +1,16 @@
+import assert from 'node:assert'
+import nodeFs from 'node:fs'
+import nodePath from 'node:path'
+import nodeVm from 'node:vm'
+
+// Run the chunk as a script, so the same test covers the cjs, iife and umd outputs.
+const code = nodeFs.readFileSync(nodePath.join(import.meta.dirname, 'dist/main.js'), 'utf-8')
+const module = { exports: {} }
+const context = { module, exports: module.exports }
+nodeVm.runInNewContext(code, context)
+const main = context.lib ?? module.exports
+
+assert.strictEqual(main.default, 'symbols')
+assert.strictEqual(main.value, true)
+assert.strictEqual(main.__esModule, true)
+assert.strictEqual(Object.prototype.toString.call(main), '[object Module]')
@@ -0,0 +1,2 @@
+export default 'symbols'
+export const value = true
@@ -0,0 +1,32 @@
+// @ts-check
+
+/// <reference path="../../../../src/runtime/runtime-extra-dev-common.js" />
+
+class TestCjsDevRuntime extends DevRuntime {
+  /**
+   * @override
+   * @param {string} _moduleId
+   */
+  createModuleHotContext(_moduleId) {
+    return { accept() {} };
+  }
+  /**
+   * @override
+   * @param {string[]} _boundaries
+   */
+  applyUpdates(_boundaries) {
+    // do nothing
+  }
+}
+
+(/** @type {any} */ (globalThis)).__rolldown_runtime__ ??= new TestCjsDevRuntime();
+
+/** @type {string[]} */
+const testPatches = /** @type {any} */ (globalThis).__testPatches;
+if (testPatches) {
+  setTimeout(() => {
+    for (const patchChunk of testPatches) {
+      require(patchChunk);
+    }
+  }, 0);
+}
@@ -0,0 +1,3 @@
+export const value = 'edited'
+
+import.meta.hot.accept(() => {})
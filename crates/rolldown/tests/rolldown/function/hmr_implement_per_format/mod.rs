@@ -0,0 +1,43 @@
+use rolldown::{
+  BundlerOptions, ExperimentalOptions, HmrImplement, HmrImplementPerFormat, HmrOptions,
+  OutputFormat, Platform, TreeshakeOptions,
+};
+use rolldown_testing::{
+  abs_file_dir,
+  integration_test::{IntegrationTest, NamedBundlerOptions},
+  test_config::TestMeta,
+};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cjs_output_uses_its_own_hmr_runtime() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_multiple(
+      vec![NamedBundlerOptions {
+        name: None,
+        options: BundlerOptions {
+          cwd: Some(cwd.clone()),
+          format: Some(OutputFormat::Cjs),
+          // Keeps the runtime from importing `ws`, which the test runtime doesn't need.
+          platform: Some(Platform::Browser),
+          // Tree shaking isn't supported with hmr yet.
+          treeshake: TreeshakeOptions::Boolean(false),
+          experimental: Some(ExperimentalOptions {
+            hmr: Some(HmrOptions {
+              implement: Some(HmrImplement::PerFormat(HmrImplementPerFormat {
+                cjs: Some(include_str!("./cjs-runtime.js").to_string()),
+                ..Default::default()
+              })),
+              ..Default::default()
+            }),
+            ..Default::default()
+          }),
+          ..Default::default()
+        },
+      }],
+      &cwd,
+      vec![],
+    )
+    .await;
+}
@@ -0,0 +1,4 @@
+import accepted from 'virtual:accepted'
+import unaccepted from 'virtual:unaccepted'
+
+console.log(accepted, unaccepted)
@@ -0,0 +1,133 @@
+use std::{
+  borrow::Cow,
+  sync::Arc,
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rolldown::{
+  Bundler, BundlerOptions, ExperimentalOptions, HmrOptions, InputItem, TreeshakeOptions,
+};
+use rolldown_common::FullReloadReason;
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveIdReturn, HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+/// Serves `virtual:accepted`, which accepts its own updates, and `virtual:unaccepted`, which
+/// doesn't. Both export a revision bumped on every load, like data a framework could change.
+#[derive(Debug, Default)]
+struct RevisionedModules {
+  revision: AtomicUsize,
+}
+
+impl Plugin for RevisionedModules {
+  fn name(&self) -> Cow<'static, str> {
+    "revisioned-modules".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(args.specifier.strip_prefix("virtual:").map(|name| HookResolveIdOutput {
+      id: format!("\0virtual:{name}").into(),
+      ..Default::default()
+    }))
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    let Some(name) = args.id.strip_prefix("\0virtual:") else { return Ok(None) };
+    let revision = self.revision.fetch_add(1, Ordering::SeqCst);
+    let accept = if name == "accepted" { "\nimport.meta.hot.accept(() => {})" } else { "" };
+    Ok(Some(HookLoadOutput {
+      code: format!("export default 'revision {revision}'{accept}").into(),
+      ..Default::default()
+    }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveId | HookUsage::Load
+  }
+}
+
+async fn build() -> Bundler {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".into()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(abs_file_dir!()),
+      // Tree shaking isn't supported with hmr yet.
+      treeshake: TreeshakeOptions::Boolean(false),
+      experimental: Some(ExperimentalOptions {
+        hmr: Some(HmrOptions::default()),
+        ..Default::default()
+      }),
+      ..Default::default()
+    },
+    vec![Arc::new(RevisionedModules::default())],
+  );
+  bundler.generate().await.expect("build should succeed");
+  bundler
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn invalidate_patches_the_accepting_boundary() {
+  let mut bundler = build().await;
+
+  let patch = bundler.invalidate("\0virtual:accepted", None).await.expect("should invalidate");
+  assert!(!patch.full_reload);
+  let code = &patch.single_patch().expect("patch should not be split").code;
+  // Both modules were loaded by the initial build.
+  assert!(code.contains("revision 2"), "{}", code);
+  // Stable ids escape the `\0` prefix of virtual modules.
+  assert_eq!(patch.first_invalidated_by.as_deref(), Some(r"\0virtual:accepted"));
+  assert_eq!(patch.hmr_boundaries.len(), 1);
+  assert_eq!(patch.hmr_boundaries[0].boundary, r"\0virtual:accepted");
+
+  let patch = bundler
+    .invalidate("\0virtual:accepted", Some("config.json".to_string()))
+    .await
+    .expect("should invalidate");
+  assert_eq!(patch.first_invalidated_by.as_deref(), Some("config.json"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn invalidate_without_boundary_reloads_the_page() {
+  let mut bundler = build().await;
+
+  let patch = bundler.invalidate("\0virtual:unaccepted", None).await.expect("should invalidate");
+  assert!(patch.full_reload);
+  assert_eq!(
+    patch.full_reload_reason,
+    Some(FullReloadReason::NoAcceptingBoundary { module: r"\0virtual:unaccepted".to_string() })
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn invalidate_back_to_the_first_invalidated_module_reloads_the_page() {
+  let mut bundler = build().await;
+
+  // The boundary accepts the update via the module that already invalidated it for this update.
+  let patch = bundler
+    .invalidate("\0virtual:accepted", Some(r"\0virtual:accepted".to_string()))
+    .await
+    .expect("should invalidate");
+  assert!(patch.full_reload);
+  assert_eq!(patch.full_reload_reason, Some(FullReloadReason::CircularAcceptChain));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn invalidate_unknown_module_is_an_error() {
+  let mut bundler = build().await;
+
+  let Err(errors) = bundler.invalidate("virtual:missing", None).await else {
+    panic!("invalidating an unknown module should fail")
+  };
+  let kinds = errors.iter().map(|error| error.kind().to_string()).collect::<Vec<_>>();
+  assert_eq!(kinds, ["HMR_UNKNOWN_MODULE"]);
+}
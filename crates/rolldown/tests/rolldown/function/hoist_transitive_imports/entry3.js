@@ -0,0 +1,2 @@
+import { b } from './b.js'
+console.log('entry3', b)
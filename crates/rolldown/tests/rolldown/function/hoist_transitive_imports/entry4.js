@@ -0,0 +1,2 @@
+import { c } from './c.js'
+console.log('entry4', c)
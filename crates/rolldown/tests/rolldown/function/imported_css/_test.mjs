@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { lazy } from './dist/main.js'
+
+await lazy
+assert.deepStrictEqual(globalThis.rendered, ['button', 'lazy'])
@@ -0,0 +1,4 @@
+import './reset.css'
+import './button.css'
+
+export const label = 'button'
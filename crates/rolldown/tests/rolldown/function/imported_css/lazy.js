@@ -0,0 +1,3 @@
+import './lazy.css'
+
+globalThis.rendered.push('lazy')
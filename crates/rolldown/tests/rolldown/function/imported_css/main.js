@@ -0,0 +1,5 @@
+import './reset.css'
+import { label } from './button.js'
+
+globalThis.rendered = [label]
+export const lazy = import('./lazy.js')
@@ -0,0 +1,45 @@
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+fn options() -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(abs_file_dir!()),
+    chunk_filenames: Some("[name].js".to_string().into()),
+    css_chunk_filenames: Some("[name].css".to_string().into()),
+    ..Default::default()
+  }
+}
+
+/// The stylesheets reachable from each chunk are concatenated into one css asset, and the js runs
+/// without the imports of them.
+#[tokio::test(flavor = "multi_thread")]
+async fn stylesheets_are_emitted_per_chunk() {
+  IntegrationTest::new(TestMeta::default()).run_with_plugins(options(), vec![]).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn chunks_list_their_css_asset() {
+  let output = Bundler::new(options()).generate().await.expect("build should succeed");
+
+  let imported_css = output
+    .assets
+    .iter()
+    .filter_map(|asset| match asset {
+      Output::Chunk(chunk) => Some((chunk.filename.as_str(), chunk.imported_css.clone())),
+      Output::Asset(_) => None,
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    imported_css,
+    vec![("main.js", vec!["main.css".into()]), ("lazy.js", vec!["lazy.css".into()])]
+  );
+  for css in ["main.css", "lazy.css"] {
+    assert!(output.assets.iter().any(|asset| asset.filename() == css), "{css} should be emitted");
+  }
+}
@@ -0,0 +1,7 @@
+import assert from 'node:assert';
+import { shared } from './shared.js';
+
+assert.strictEqual(shared, 'shared');
+import('./lazy.js').then(({ lazy }) => {
+  assert.strictEqual(lazy, 'shared-lazy');
+});
@@ -0,0 +1,64 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem, Platform};
+use rolldown_common::EmittedAsset;
+use rolldown_plugin::{HookLoadArgs, HookLoadReturn, HookUsage, Plugin, PluginContext};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+/// Emits a text asset for `shared.js`, linked to it through `original_file_name`.
+#[derive(Debug)]
+struct SharedAsset;
+
+impl Plugin for SharedAsset {
+  fn name(&self) -> Cow<'static, str> {
+    "shared-asset".into()
+  }
+
+  async fn load(&self, ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if args.id.ends_with("shared.js") {
+      ctx
+        .emit_file_async(EmittedAsset {
+          name: Some("shared.txt".into()),
+          original_file_name: Some(args.id.to_string()),
+          source: "Emitted for shared.js".to_string().into(),
+          ..Default::default()
+        })
+        .await?;
+    }
+    Ok(None)
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Load
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn manifest_lists_the_chunks_and_assets_of_each_entry() {
+  let cwd = abs_file_dir!();
+  IntegrationTest::new(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![
+          InputItem {
+            name: Some("main".into()),
+            import: "./main.js".to_string(),
+            ..Default::default()
+          },
+          InputItem {
+            name: Some("admin".into()),
+            import: "./admin.js".to_string(),
+            ..Default::default()
+          },
+        ]),
+        cwd: Some(cwd),
+        platform: Some(Platform::Node),
+        entry_filenames: Some("[name]-[hash].js".to_string().into()),
+        chunk_filenames: Some("[name]-[hash].js".to_string().into()),
+        manifest: Some("manifest.json".to_string()),
+        ..Default::default()
+      },
+      vec![Arc::new(SharedAsset)],
+    )
+    .await;
+}
@@ -1,2 +1,20 @@
 pub mod asset_filenames;
+mod cache_dir;
 pub mod chunk_filenames_function;
+mod compact;
+mod experimental;
+mod external_importer;
+mod file_system;
+mod generate_multi;
+mod generate_with_cancellation;
+mod hmr_implement_per_format;
+mod hmr_invalidate;
+mod imported_css;
+mod manifest;
+mod normalize_line_endings;
+mod on_log;
+mod output_dir;
+mod paths_function_error;
+mod sourcemap_debug_ids;
+mod sourcemap_include_sources;
+mod sourcemap_url;
@@ -0,0 +1,12 @@
+import assert from 'node:assert';
+import value from './icon.svg';
+
+const expected =
+  '<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10"><circle cx="5" cy="5" r="4"/></svg>\n';
+
+assert(typeof value === 'string' && value.startsWith('data:image/svg+xml'));
+const [header, payload] = value.split(',');
+const decoded = header.endsWith(';base64')
+  ? Buffer.from(payload, 'base64').toString('utf8')
+  : decodeURIComponent(payload);
+assert.strictEqual(decoded, expected);
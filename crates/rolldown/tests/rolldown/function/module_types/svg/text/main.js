@@ -0,0 +1,7 @@
+import assert from 'node:assert';
+import value from './icon.svg';
+
+assert.strictEqual(
+  value,
+  '<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10"><circle cx="5" cy="5" r="4"/></svg>\n',
+);
@@ -0,0 +1 @@
+// The actual content is provided by the plugin with `\r\n` line endings.
@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::EmittedAsset;
+use rolldown_plugin::{
+  HookBuildStartArgs, HookLoadArgs, HookLoadOutput, HookLoadReturn, HookNoopReturn, HookUsage,
+  Plugin, PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug)]
+struct CrlfPlugin;
+
+impl Plugin for CrlfPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "CrlfPlugin".into()
+  }
+
+  async fn build_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    ctx.emit_file(
+      EmittedAsset {
+        name: None,
+        original_file_name: None,
+        file_name: Some("notes.txt".into()),
+        source: "first line\r\nsecond line\r\n".to_string().into(),
+      },
+      None,
+      None,
+    );
+    Ok(())
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    Ok(args.id.ends_with("main.js").then(|| {
+      HookLoadOutput {
+        code:
+          "export const multiline = `first line\r\nsecond line`;\r\nconsole.log(multiline);\r\n"
+            .into(),
+        ..Default::default()
+      }
+    }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::Load
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn normalize_line_endings_by_default() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(CrlfPlugin)],
+    )
+    .await;
+}
@@ -0,0 +1 @@
+console.log(eval('let a = 100'))
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, LogAction, OnLog};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_fail_the_build_with_logs_escalated_by_on_log() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_error: true, ..Default::default() })
+    .run(BundlerOptions {
+      cwd: Some(cwd),
+      on_log: Some(OnLog::new(Arc::new(|_level, log| {
+        Box::pin(async move {
+          if log.code == "EVAL" { Ok(LogAction::Error) } else { Ok(LogAction::Pass) }
+        })
+      }))),
+      ..Default::default()
+    })
+    .await;
+}
@@ -0,0 +1,91 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem, LogAction, LogFormat, OnLog};
+use rolldown_plugin::{
+  HookNoopReturn, HookRenderStartArgs, HookUsage, Plugin, PluginContext, PluginDiagnostic,
+};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct Checker;
+
+impl Plugin for Checker {
+  fn name(&self) -> Cow<'static, str> {
+    "checker".into()
+  }
+
+  async fn render_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookRenderStartArgs<'_>,
+  ) -> HookNoopReturn {
+    // Reported twice, so the second one is folded into the first.
+    ctx.warn(PluginDiagnostic::new("Checked all modules"));
+    ctx.warn(PluginDiagnostic::new("Checked all modules"));
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::RenderStart
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_pass_json_messages_to_on_log() {
+  let messages = Arc::new(Mutex::new(vec![]));
+  let collected = Arc::clone(&messages);
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".into()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(abs_file_dir!()),
+      log_format: Some(LogFormat::Json),
+      on_log: Some(OnLog::new(Arc::new(move |_level, log| {
+        collected.lock().unwrap().push(log.message);
+        Box::pin(async { Ok(LogAction::Pass) })
+      }))),
+      ..Default::default()
+    },
+    vec![Arc::new(Checker)],
+  );
+
+  let output = bundler.generate().await.expect("build should succeed");
+  assert_eq!(output.warnings.len(), 1);
+
+  let messages = messages.lock().unwrap();
+  assert_eq!(messages.len(), 1);
+  assert!(!messages[0].contains('\n'), "{}", messages[0]);
+  let duplicate = serde_json::json!({
+    "kind": "PLUGIN_WARNING",
+    "severity": "warning",
+    "message": "Checked all modules",
+    "help": null,
+    "id": null,
+    "plugin": "checker",
+    "hook": "renderStart",
+    "spans": [],
+    "occurrences": 1,
+    "duplicates": [],
+  });
+  assert_eq!(
+    serde_json::from_str::<serde_json::Value>(&messages[0]).unwrap(),
+    serde_json::json!({
+      "kind": "PLUGIN_WARNING",
+      "severity": "warning",
+      "message": "Checked all modules",
+      "help": null,
+      "id": null,
+      "plugin": "checker",
+      "hook": "renderStart",
+      "spans": [],
+      "occurrences": 2,
+      "duplicates": [duplicate],
+    })
+  );
+}
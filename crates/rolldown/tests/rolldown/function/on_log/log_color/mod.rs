@@ -0,0 +1,41 @@
+use std::sync::{Arc, Mutex};
+
+use rolldown::{Bundler, BundlerOptions, InputItem, LogAction, LogColor, OnLog};
+use rolldown_testing::abs_file_dir;
+
+async fn collect_log_messages(log_color: LogColor) -> Vec<String> {
+  let messages = Arc::new(Mutex::new(vec![]));
+  let collected = Arc::clone(&messages);
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".into()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(abs_file_dir!()),
+    log_color: Some(log_color),
+    on_log: Some(OnLog::new(Arc::new(move |_level, log| {
+      collected.lock().unwrap().push(log.message);
+      Box::pin(async { Ok(LogAction::Pass) })
+    }))),
+    ..Default::default()
+  });
+
+  bundler.generate().await.expect("build should succeed");
+  let messages = messages.lock().unwrap().clone();
+  assert_eq!(messages.len(), 1, "`eval` should be reported once");
+  messages
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_not_color_log_messages_with_never() {
+  let messages = collect_log_messages(LogColor::Never).await;
+  assert!(!messages[0].contains("\x1b["), "{}", messages[0]);
+  assert!(messages[0].contains("[EVAL] Warning:"), "{}", messages[0]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_color_log_messages_with_always() {
+  let messages = collect_log_messages(LogColor::Always).await;
+  assert!(messages[0].contains("\x1b["), "{}", messages[0]);
+}
@@ -0,0 +1,54 @@
+use rolldown::{Bundler, BundlerOptions, ChecksOptions, InputItem, LogLevel, SeverityOverride};
+use rolldown_testing::abs_file_dir;
+use rustc_hash::FxHashMap;
+
+async fn collect_warning_codes(options: BundlerOptions) -> Vec<String> {
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".into()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(abs_file_dir!()),
+    ..options
+  });
+
+  let output = bundler.generate().await.expect("build should succeed");
+  output.warnings.iter().map(|warning| warning.kind().to_string()).collect()
+}
+
+fn eval_as_info() -> FxHashMap<String, SeverityOverride> {
+  FxHashMap::from_iter([("EVAL".to_string(), SeverityOverride::Info)])
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_drop_info_logs_below_warn_level() {
+  let codes = collect_warning_codes(BundlerOptions {
+    log_level: Some(LogLevel::Warn),
+    severity_overrides: Some(eval_as_info()),
+    ..Default::default()
+  })
+  .await;
+  assert!(codes.is_empty(), "{codes:?}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_keep_info_logs_at_info_level() {
+  let codes = collect_warning_codes(BundlerOptions {
+    log_level: Some(LogLevel::Info),
+    severity_overrides: Some(eval_as_info()),
+    ..Default::default()
+  })
+  .await;
+  assert_eq!(codes, ["EVAL"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_drop_disabled_checks_without_on_log() {
+  let codes = collect_warning_codes(BundlerOptions {
+    checks: Some(ChecksOptions { eval: Some(false), ..Default::default() }),
+    ..Default::default()
+  })
+  .await;
+  assert!(codes.is_empty(), "{codes:?}");
+}
@@ -0,0 +1,5 @@
+mod escalate;
+mod json_format;
+mod log_color;
+mod log_level;
+mod skip;
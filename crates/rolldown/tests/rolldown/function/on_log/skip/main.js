@@ -0,0 +1,2 @@
+import 'virtual-foo'
+console.log(eval('let a = 100'))
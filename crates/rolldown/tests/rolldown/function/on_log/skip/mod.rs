@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, LogAction, OnLog};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_swallow_logs_skipped_by_on_log() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run(BundlerOptions {
+      cwd: Some(cwd),
+      on_log: Some(OnLog::new(Arc::new(|_level, log| {
+        Box::pin(async move {
+          // Known virtual specifier, it's fine to leave it unresolved.
+          if log.code == "UNRESOLVED_IMPORT" { Ok(LogAction::Skip) } else { Ok(LogAction::Pass) }
+        })
+      }))),
+      ..Default::default()
+    })
+    .await;
+}
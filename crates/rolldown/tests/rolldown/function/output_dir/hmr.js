@@ -0,0 +1,3 @@
+export const value = 'initial'
+
+import.meta.hot.accept(() => {})
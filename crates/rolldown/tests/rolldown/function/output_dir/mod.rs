@@ -0,0 +1,41 @@
+use rolldown::{BundlerOptions, ExperimentalOptions, HmrOptions, TreeshakeOptions};
+use rolldown_testing::{
+  abs_file_dir,
+  integration_test::{IntegrationTest, NamedBundlerOptions},
+  test_config::TestMeta,
+};
+
+/// The hmr patches are written next to the outputs, so they have to end up in the same directory
+/// whichever way `dir` points to it.
+#[tokio::test(flavor = "multi_thread")]
+async fn hmr_patches_are_written_to_the_resolved_output_dir() {
+  let cwd = abs_file_dir!();
+  let options = |dir: String| BundlerOptions {
+    cwd: Some(cwd.clone()),
+    dir: Some(dir),
+    // Tree shaking isn't supported with hmr yet.
+    treeshake: TreeshakeOptions::Boolean(false),
+    experimental: Some(ExperimentalOptions {
+      hmr: Some(HmrOptions::default()),
+      ..Default::default()
+    }),
+    ..Default::default()
+  };
+
+  IntegrationTest::new(TestMeta::default())
+    .run_multiple(
+      vec![
+        NamedBundlerOptions {
+          name: Some("relative".into()),
+          options: options("./dist/../dist/relative/".to_string()),
+        },
+        NamedBundlerOptions {
+          name: Some("absolute".into()),
+          options: options(cwd.join("dist").join("absolute").to_string_lossy().into_owned()),
+        },
+      ],
+      &cwd,
+      vec![],
+    )
+    .await;
+}
@@ -0,0 +1,31 @@
+import assert from 'node:assert'
+import { register } from 'node:module'
+
+// Stub the CDN urls the same way an import map would point them at real modules.
+const stubs = {
+  'https://esm.sh/react@18': 'export const version = "18"; export const createElement = () => "element"',
+  'https://cdn.example.com/vendor.js': 'export const vendor = "cdn"',
+}
+const hooks = `
+const stubs = ${JSON.stringify(stubs)}
+export async function resolve(specifier, context, next) {
+  if (specifier in stubs) {
+    return { url: specifier, shortCircuit: true }
+  }
+  return next(specifier, context)
+}
+export async function load(url, context, next) {
+  if (url in stubs) {
+    return { format: 'module', source: stubs[url], shortCircuit: true }
+  }
+  return next(url, context)
+}
+`
+register(`data:text/javascript,${encodeURIComponent(hooks)}`)
+
+const main = await import('./dist/main.js')
+
+assert.strictEqual(main.reactVersion, '18')
+assert.strictEqual(main.createElement(), 'element')
+assert.strictEqual(main.vendor, 'cdn')
+assert.strictEqual((await main.loadReact()).version, '18')
@@ -0,0 +1,6 @@
+import { version } from 'react'
+
+export { createElement } from 'react'
+export * from './vendor.js'
+export const reactVersion = version
+export const loadReact = () => import('react')
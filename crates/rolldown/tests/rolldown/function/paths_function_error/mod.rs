@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, IsExternal, PathsOutputOption};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_fail_the_build_when_the_paths_function_throws() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_error: true, ..Default::default() })
+    .run(BundlerOptions {
+      cwd: Some(cwd),
+      external: Some(IsExternal::from(vec!["react".to_string()])),
+      paths: Some(PathsOutputOption::Fn(Arc::new(|id| {
+        let id = id.to_string();
+        Box::pin(async move { Err(anyhow::anyhow!("Cannot map external \"{id}\"")) })
+      }))),
+      ..Default::default()
+    })
+    .await;
+}
@@ -0,0 +1,6 @@
+import { readFileSync } from 'node:fs'
+import env from 'env-pkg'
+
+export const mode = process.env.NODE_ENV
+
+export { env, readFileSync }
@@ -0,0 +1,4 @@
+import conditions from 'conditions-pkg'
+import main from 'main-pkg'
+
+console.log(conditions, main)
@@ -0,0 +1,6 @@
+import entry from 'pkg'
+import feature from 'pkg/features/a'
+
+export const required = require('pkg')
+
+export { entry, feature }
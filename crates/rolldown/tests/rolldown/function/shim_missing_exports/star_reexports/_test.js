@@ -0,0 +1,6 @@
+import nodeAssert from 'assert'
+import { deep, direct, missing } from './dist/main.js'
+
+nodeAssert.strictEqual(direct, 'direct')
+nodeAssert.strictEqual(deep, 'deep')
+nodeAssert.strictEqual(missing, undefined)
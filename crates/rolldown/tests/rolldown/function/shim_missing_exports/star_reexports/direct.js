@@ -0,0 +1 @@
+export const direct = 'direct'
@@ -0,0 +1,3 @@
+import { deep, direct, missing } from './barrel'
+
+export { deep, direct, missing }
@@ -0,0 +1,79 @@
+use rolldown::{BundleOutput, Bundler, BundlerOptions, InputItem, SourceMapType};
+use rolldown_common::{Output, OutputChunk};
+use rolldown_testing::abs_file_dir;
+
+fn bundler(sourcemap: SourceMapType) -> Bundler {
+  Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(abs_file_dir!()),
+    sourcemap: Some(sourcemap),
+    sourcemap_debug_ids: Some(true),
+    ..Default::default()
+  })
+}
+
+fn main_chunk(output: &BundleOutput) -> &OutputChunk {
+  output
+    .assets
+    .iter()
+    .find_map(|asset| match asset {
+      Output::Chunk(chunk) => Some(chunk.as_ref()),
+      Output::Asset(_) => None,
+    })
+    .expect("should emit the main chunk")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn debug_ids_are_the_same_in_generate_and_write() {
+  let generated = bundler(SourceMapType::File).generate().await.unwrap();
+  let written = bundler(SourceMapType::File).write().await.unwrap();
+
+  let chunk = main_chunk(&generated);
+  let debug_id = chunk.debug_id.as_deref().expect("should have a debug id");
+  assert_eq!(main_chunk(&written).debug_id.as_deref(), Some(debug_id));
+  assert_eq!(chunk.map.as_ref().unwrap().get_debug_id(), Some(debug_id));
+  assert!(
+    chunk.code.ends_with(&format!("\n//# debugId={debug_id}\n//# sourceMappingURL=main.js.map")),
+    "{}",
+    chunk.code
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn inline_sourcemaps_embed_the_debug_id() {
+  let output = bundler(SourceMapType::Inline).generate().await.unwrap();
+
+  let chunk = main_chunk(&output);
+  let debug_id = chunk.debug_id.as_deref().expect("should have a debug id");
+  let map = chunk.map.as_ref().unwrap();
+  assert_eq!(map.get_debug_id(), Some(debug_id));
+  assert!(
+    chunk
+      .code
+      .ends_with(&format!("\n//# debugId={debug_id}\n//# sourceMappingURL={}", map.to_data_url())),
+    "{}",
+    chunk.code
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn chunks_have_no_debug_id_without_the_option() {
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(abs_file_dir!()),
+    sourcemap: Some(SourceMapType::File),
+    ..Default::default()
+  });
+  let output = bundler.generate().await.unwrap();
+
+  assert_eq!(main_chunk(&output).debug_id, None);
+  assert!(!main_chunk(&output).code.contains("debugId"));
+}
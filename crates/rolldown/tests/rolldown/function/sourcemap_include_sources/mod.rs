@@ -0,0 +1,69 @@
+use rolldown::{BundleOutput, Bundler, BundlerOptions, InputItem, SourceMapType};
+use rolldown_common::{Output, OutputChunk};
+use rolldown_testing::abs_file_dir;
+
+async fn generate(sourcemap: SourceMapType, include_sources: Option<bool>) -> BundleOutput {
+  Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(abs_file_dir!()),
+    sourcemap: Some(sourcemap),
+    sourcemap_include_sources: include_sources,
+    ..Default::default()
+  })
+  .generate()
+  .await
+  .unwrap()
+}
+
+fn main_chunk(output: &BundleOutput) -> &OutputChunk {
+  output
+    .assets
+    .iter()
+    .find_map(|asset| match asset {
+      Output::Chunk(chunk) => Some(chunk.as_ref()),
+      Output::Asset(_) => None,
+    })
+    .expect("should emit the main chunk")
+}
+
+fn map_asset(output: &BundleOutput) -> String {
+  output
+    .assets
+    .iter()
+    .find_map(|asset| match asset {
+      Output::Asset(asset) if asset.filename.ends_with(".map") => {
+        Some(String::from_utf8(asset.source.as_bytes().to_vec()).unwrap())
+      }
+      _ => None,
+    })
+    .expect("should emit the sourcemap")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn sources_are_included_by_default() {
+  let output = generate(SourceMapType::Hidden, None).await;
+
+  assert!(map_asset(&output).contains(r#""sourcesContent":["export const answer = 42;"#));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn hidden_sourcemaps_strip_the_sources() {
+  let output = generate(SourceMapType::Hidden, Some(false)).await;
+
+  let map = map_asset(&output);
+  assert!(map.contains(r#""sources":["../main.js"],"sourcesContent":[null]"#), "{map}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn inline_sourcemaps_strip_the_sources() {
+  let output = generate(SourceMapType::Inline, Some(false)).await;
+
+  let chunk = main_chunk(&output);
+  let map = chunk.map.as_ref().unwrap();
+  assert!(map.get_source_contents().all(|content| content.is_none()));
+  assert!(chunk.code.ends_with(&format!("//# sourceMappingURL={}", map.to_data_url())));
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use rolldown::{Bundler, BundlerOptions, InputItem, SourceMapType, SourcemapUrl};
+use rolldown_common::Output;
+use rolldown_testing::abs_file_dir;
+
+async fn generate(sourcemap: SourceMapType, sourcemap_url: SourcemapUrl) -> (String, Vec<String>) {
+  let output = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(abs_file_dir!()),
+    entry_filenames: Some("js/[name].js".to_string().into()),
+    sourcemap: Some(sourcemap),
+    sourcemap_url: Some(sourcemap_url),
+    ..Default::default()
+  })
+  .generate()
+  .await
+  .unwrap();
+  let code = output
+    .assets
+    .iter()
+    .find_map(|asset| match asset {
+      Output::Chunk(chunk) => Some(chunk.code.clone()),
+      Output::Asset(_) => None,
+    })
+    .expect("should emit the main chunk");
+  let filenames = output.assets.iter().map(|asset| asset.filename().to_string()).collect();
+  (code, filenames)
+}
+
+fn with_static_prefix() -> SourcemapUrl {
+  SourcemapUrl::Fn(Arc::new(|map_filename| {
+    let url = format!("https://static.example.com/{map_filename}?v=1");
+    Box::pin(async move { Ok(url) })
+  }))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn function_rewrites_the_url() {
+  let (code, filenames) = generate(SourceMapType::File, with_static_prefix()).await;
+
+  assert!(
+    code.ends_with("//# sourceMappingURL=https://static.example.com/js/main.js.map?v=1"),
+    "{code}"
+  );
+  assert!(filenames.contains(&"js/main.js.map".to_string()), "{filenames:?}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn hidden_sourcemaps_ignore_the_url() {
+  let (code, filenames) = generate(SourceMapType::Hidden, with_static_prefix()).await;
+
+  assert!(!code.contains("sourceMappingURL"), "{code}");
+  assert!(filenames.contains(&"js/main.js.map".to_string()), "{filenames:?}");
+}
@@ -46,6 +46,7 @@ async fn should_rewrite_dynamic_imports_that_import_external_modules() {
         input: Some(vec![InputItem {
           name: Some("entry".to_string()),
           import: "./entry.js".to_string(),
+          ..Default::default()
         }]),
         cwd: Some(cwd),
         ..Default::default()
@@ -0,0 +1,2 @@
+console.log('exiting with code 3')
+process.exit(3)
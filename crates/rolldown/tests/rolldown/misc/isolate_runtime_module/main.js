@@ -0,0 +1,5 @@
+import legacy from './legacy.js'
+import * as ns from './ns.js'
+
+assert.deepStrictEqual(legacy, { name: 'legacy' })
+assert.strictEqual(ns.value, 'value')
@@ -0,0 +1,3 @@
+import file from './image.png';
+
+export default file;
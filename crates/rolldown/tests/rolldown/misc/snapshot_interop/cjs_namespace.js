@@ -0,0 +1 @@
+exports.answer = 42
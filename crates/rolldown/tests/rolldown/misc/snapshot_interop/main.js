@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import greet from './cjs_default.js'
+import * as ns from './cjs_namespace.js'
+
+assert.strictEqual(greet('rolldown'), 'hello rolldown')
+assert.strictEqual(ns.answer, 42)
+assert.strictEqual(require('./esm.js').value, 'esm')
@@ -2,3 +2,4 @@ mod errors;
 mod function;
 mod issues;
 mod plugin;
+mod tree_shaking;
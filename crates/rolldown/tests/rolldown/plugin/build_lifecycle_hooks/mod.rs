@@ -0,0 +1,95 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookBuildEndArgs, HookBuildStartArgs, HookNoopReturn, HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+type Calls = Arc<Mutex<Vec<String>>>;
+
+/// Records the build lifecycle hooks in the order they are called.
+#[derive(Debug)]
+struct Recording {
+  calls: Calls,
+}
+
+impl Plugin for Recording {
+  fn name(&self) -> Cow<'static, str> {
+    "recording".into()
+  }
+
+  async fn build_start(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    self.calls.lock().unwrap().push("buildStart".to_string());
+    Ok(())
+  }
+
+  async fn build_end(
+    &self,
+    _ctx: &PluginContext,
+    args: Option<&HookBuildEndArgs<'_>>,
+  ) -> HookNoopReturn {
+    let call = match args {
+      Some(args) => format!("buildEnd with {} error(s)", args.errors.len()),
+      None => "buildEnd".to_string(),
+    };
+    self.calls.lock().unwrap().push(call);
+    Ok(())
+  }
+
+  async fn close_bundle(&self, _ctx: &PluginContext) -> HookNoopReturn {
+    self.calls.lock().unwrap().push("closeBundle".to_string());
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::BuildEnd | HookUsage::CloseBundle
+  }
+}
+
+fn bundler_with_entry(entry: &str, calls: &Calls) -> Bundler {
+  Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: entry.to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(Recording { calls: Arc::clone(calls) })],
+  )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn close_bundle_runs_at_close() {
+  let calls = Calls::default();
+  let mut bundler = bundler_with_entry("./main.js", &calls);
+
+  bundler.generate().await.expect("build should succeed");
+  assert_eq!(*calls.lock().unwrap(), ["buildStart", "buildEnd"]);
+
+  bundler.close().await.unwrap();
+  bundler.close().await.unwrap();
+  assert_eq!(*calls.lock().unwrap(), ["buildStart", "buildEnd", "closeBundle"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn build_end_receives_the_error_of_a_failed_build() {
+  let calls = Calls::default();
+  let mut bundler = bundler_with_entry("./missing.js", &calls);
+
+  assert!(bundler.generate().await.is_err());
+  assert_eq!(*calls.lock().unwrap(), ["buildStart", "buildEnd with 1 error(s)", "closeBundle"]);
+
+  bundler.close().await.unwrap();
+  assert_eq!(*calls.lock().unwrap(), ["buildStart", "buildEnd with 1 error(s)", "closeBundle"]);
+}
@@ -0,0 +1 @@
+console.log('debug plugin order')
@@ -0,0 +1,83 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{BundlerOptions, InputItem, LogAction, LogLevel, OnLog};
+use rolldown_plugin::{HookUsage, Plugin, PluginHookMeta, PluginOrder};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug)]
+struct Ordered {
+  name: &'static str,
+  build_start: Option<PluginOrder>,
+  transform: Option<PluginOrder>,
+}
+
+impl Plugin for Ordered {
+  fn name(&self) -> Cow<'static, str> {
+    self.name.into()
+  }
+
+  fn build_start_meta(&self) -> Option<PluginHookMeta> {
+    Some(PluginHookMeta { order: self.build_start, ..Default::default() })
+  }
+
+  fn transform_meta(&self) -> Option<PluginHookMeta> {
+    Some(PluginHookMeta { order: self.transform, ..Default::default() })
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::Transform
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reports_the_resolved_order_of_each_hook() {
+  let cwd = abs_file_dir!();
+  let logs = Arc::new(Mutex::new(vec![]));
+  let collected = Arc::clone(&logs);
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        debug_plugin_order: Some(true),
+        on_log: Some(OnLog::new(Arc::new(move |level, log| {
+          collected.lock().unwrap().push((level, log));
+          Box::pin(async { Ok(LogAction::Pass) })
+        }))),
+        ..Default::default()
+      },
+      vec![
+        Arc::new(Ordered { name: "first", build_start: Some(PluginOrder::Post), transform: None }),
+        Arc::new(Ordered { name: "second", build_start: None, transform: Some(PluginOrder::Post) }),
+        Arc::new(Ordered {
+          name: "third",
+          build_start: Some(PluginOrder::Pre),
+          transform: Some(PluginOrder::Pre),
+        }),
+      ],
+    )
+    .await;
+
+  // The builtin plugins of the bundler are registered after the user's.
+  let logs = logs.lock().unwrap();
+  let [(level, log)] = logs.as_slice() else { panic!("expected a single log, got {}", logs.len()) };
+  assert_eq!(*level, LogLevel::Info);
+  assert_eq!(log.code, "PLUGIN_ORDER");
+  assert_eq!(
+    log.message,
+    "\
+[PLUGIN_ORDER] Advice: buildStart: third (pre, #2) -> second (normal, #1) -> first (post, #0)
+resolveId: builtin:data-uri (normal, #3)
+load: builtin:data-uri (normal, #3)
+transform: third (pre, #2) -> first (normal, #0) -> second (post, #1)
+"
+  );
+}
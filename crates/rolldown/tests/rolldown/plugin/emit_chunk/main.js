@@ -0,0 +1,13 @@
+import assert from 'node:assert'
+import { Worker } from 'node:worker_threads'
+import workerUrl from 'worker-url:./worker.js'
+
+// The test runner loads the entry with `--import`, which workers would inherit and re-run.
+const worker = new Worker(new URL(workerUrl), { execArgv: [] })
+let message
+worker.on('message', (data) => {
+  message = data
+})
+worker.on('exit', () => {
+  assert.strictEqual(message, 'pong')
+})
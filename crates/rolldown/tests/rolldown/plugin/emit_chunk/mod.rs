@@ -0,0 +1,201 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex, OnceLock},
+};
+
+use arcstr::ArcStr;
+use rolldown::{Bundler, BundlerOptions, ChunkFilenamesOutputOption, InputItem, Platform};
+use rolldown_common::{EmittedChunk, Output};
+use rolldown_plugin::{
+  HookBuildStartArgs, HookGenerateBundleArgs, HookLoadArgs, HookLoadOutput, HookLoadReturn,
+  HookNoopReturn, HookRenderStartArgs, HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn,
+  HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+const WORKER_URL_PREFIX: &str = "worker-url:";
+
+/// Emits the module imported with `worker-url:` as its own chunk and resolves the import to the
+/// URL of that chunk, like a worker plugin would.
+#[derive(Debug)]
+struct WorkerUrlPlugin;
+
+impl Plugin for WorkerUrlPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "WorkerUrlPlugin".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(args.specifier.starts_with(WORKER_URL_PREFIX).then(|| HookResolveIdOutput {
+      id: format!("\0{}", args.specifier).into(),
+      ..Default::default()
+    }))
+  }
+
+  async fn load(&self, ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    let Some(worker) = args.id.strip_prefix('\0').and_then(|id| id.strip_prefix(WORKER_URL_PREFIX))
+    else {
+      return Ok(None);
+    };
+    let reference_id = ctx
+      .emit_chunk(EmittedChunk {
+        id: worker.to_string(),
+        name: Some("worker".into()),
+        ..Default::default()
+      })
+      .await?;
+    Ok(Some(HookLoadOutput {
+      code: format!("export default import.meta.ROLLUP_FILE_URL_{reference_id}").into(),
+      ..Default::default()
+    }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveId | HookUsage::Load
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn emitted_worker_chunk_is_executed() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        platform: Some(Platform::Node),
+        ..Default::default()
+      },
+      vec![Arc::new(WorkerUrlPlugin)],
+    )
+    .await;
+}
+
+#[derive(Debug)]
+struct EmitChunkInRenderStart;
+
+impl Plugin for EmitChunkInRenderStart {
+  fn name(&self) -> Cow<'static, str> {
+    "EmitChunkInRenderStart".into()
+  }
+
+  async fn render_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookRenderStartArgs<'_>,
+  ) -> HookNoopReturn {
+    ctx.emit_chunk(EmittedChunk { id: "./worker.js".to_string(), ..Default::default() }).await?;
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::RenderStart
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn emitting_chunk_after_build_phase_is_an_error() {
+  let cwd = abs_file_dir!();
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("worker".to_string()),
+        import: "./worker.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(cwd),
+      ..Default::default()
+    },
+    vec![Arc::new(EmitChunkInRenderStart)],
+  );
+
+  let Err(errors) = bundler.generate().await else {
+    panic!("emitting a chunk in `renderStart` should fail");
+  };
+  assert_eq!(errors[0].kind().to_string(), "INVALID_ROLLUP_PHASE");
+}
+
+/// Adds a service worker entry at build time and looks up the hashed name it was given.
+#[derive(Debug, Default)]
+struct ServiceWorkerEntry {
+  reference_id: OnceLock<ArcStr>,
+  file_name: Mutex<Option<ArcStr>>,
+}
+
+impl Plugin for ServiceWorkerEntry {
+  fn name(&self) -> Cow<'static, str> {
+    "ServiceWorkerEntry".into()
+  }
+
+  async fn build_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    let reference_id = ctx
+      .emit_chunk(EmittedChunk {
+        id: "./sw.js".to_string(),
+        name: Some("sw".into()),
+        ..Default::default()
+      })
+      .await?;
+    self.reference_id.set(reference_id).unwrap();
+    Ok(())
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &PluginContext,
+    _args: &mut HookGenerateBundleArgs<'_>,
+  ) -> HookNoopReturn {
+    let file_name = ctx.get_file_name(self.reference_id.get().unwrap())?;
+    *self.file_name.lock().unwrap() = Some(file_name);
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::GenerateBundle
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn chunk_emitted_at_build_start_is_an_extra_entry() {
+  let cwd = abs_file_dir!();
+  let plugin = Arc::new(ServiceWorkerEntry::default());
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("worker".to_string()),
+        import: "./worker.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(cwd),
+      entry_filenames: Some(ChunkFilenamesOutputOption::String("[name]-[hash].js".to_string())),
+      ..Default::default()
+    },
+    vec![Arc::clone(&plugin) as _],
+  );
+
+  let output = bundler.generate().await.expect("build should succeed");
+  let file_name = plugin.file_name.lock().unwrap().clone().expect("generateBundle should run");
+  assert!(file_name.starts_with("sw-") && file_name.ends_with(".js"), "{file_name}");
+  let sw_chunk = output
+    .assets
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) if chunk.filename == file_name => Some(chunk),
+      _ => None,
+    })
+    .unwrap_or_else(|| panic!("{file_name} should be emitted"));
+  assert!(sw_chunk.is_entry);
+  assert!(sw_chunk.code.contains("addEventListener(\"fetch\""), "{}", sw_chunk.code);
+}
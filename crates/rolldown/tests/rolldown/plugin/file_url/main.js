@@ -0,0 +1,5 @@
+import assert from 'node:assert';
+import { readFileSync } from 'node:fs';
+import greetingUrl from 'virtual:greeting';
+
+assert.strictEqual(readFileSync(new URL(greetingUrl), 'utf8'), 'Hello from an emitted asset');
@@ -0,0 +1,89 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem, OutputFormat, Platform};
+use rolldown_common::EmittedAsset;
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveIdReturn, HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::{
+  abs_file_dir,
+  integration_test::{IntegrationTest, NamedBundlerOptions},
+  test_config::TestMeta,
+};
+
+/// Emits a text asset and resolves `virtual:greeting` to its URL.
+#[derive(Debug)]
+struct GreetingAsset;
+
+impl Plugin for GreetingAsset {
+  fn name(&self) -> Cow<'static, str> {
+    "greeting-asset".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(
+      (args.specifier == "virtual:greeting")
+        .then(|| HookResolveIdOutput { id: "\0virtual:greeting".into(), ..Default::default() }),
+    )
+  }
+
+  async fn load(&self, ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if args.id != "\0virtual:greeting" {
+      return Ok(None);
+    }
+    let reference_id = ctx
+      .emit_file_async(EmittedAsset {
+        name: Some("greeting.txt".into()),
+        source: "Hello from an emitted asset".to_string().into(),
+        ..Default::default()
+      })
+      .await?;
+    Ok(Some(HookLoadOutput {
+      code: format!("export default import.meta.ROLLDOWN_FILE_URL_{reference_id}").into(),
+      ..Default::default()
+    }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveId | HookUsage::Load
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn file_url_resolves_to_the_hashed_asset() {
+  let cwd = abs_file_dir!();
+  let options = |format: OutputFormat, dir: &str| BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".into()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(cwd.clone()),
+    dir: Some(dir.to_string()),
+    format: Some(format),
+    platform: Some(Platform::Node),
+    ..Default::default()
+  };
+
+  IntegrationTest::new(TestMeta::default())
+    .run_multiple(
+      vec![
+        NamedBundlerOptions {
+          name: Some("esm".into()),
+          options: options(OutputFormat::Esm, "dist/esm"),
+        },
+        NamedBundlerOptions {
+          name: Some("cjs".into()),
+          options: options(OutputFormat::Cjs, "dist/cjs"),
+        },
+      ],
+      &cwd,
+      vec![Arc::new(GreetingAsset)],
+    )
+    .await;
+}
@@ -0,0 +1,4 @@
+import { Button } from './button.js'
+import { theme } from './theme.js'
+
+console.log(Button(), theme)
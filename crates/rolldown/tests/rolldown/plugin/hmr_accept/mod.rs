@@ -0,0 +1,85 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{ExperimentalOptions, HmrOptions};
+use rolldown_plugin::{
+  HookHmrAcceptArgs, HookHmrAcceptOutput, HookHmrAcceptReturn, HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+use sugar_path::SugarPath;
+
+/// Makes `button.js` accept its own updates, like a fast refresh plugin does for components, and
+/// keeps `theme.js` from accepting them.
+#[derive(Debug)]
+struct FastRefresh;
+
+impl Plugin for FastRefresh {
+  fn name(&self) -> Cow<'static, str> {
+    "FastRefresh".into()
+  }
+
+  async fn hmr_accept(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookHmrAcceptArgs<'_>,
+  ) -> HookHmrAcceptReturn {
+    if args.module_id.ends_with("button.js") {
+      return Ok(Some(HookHmrAcceptOutput::SelfAccept));
+    }
+    if args.module_id.ends_with("theme.js") {
+      assert!(args.is_self_accepting);
+      return Ok(Some(HookHmrAcceptOutput::Invalidate));
+    }
+    Ok(None)
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::HmrAccept
+  }
+}
+
+async fn generate_bundler() -> Bundler {
+  let cwd = abs_file_dir!();
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(cwd),
+      experimental: Some(ExperimentalOptions {
+        hmr: Some(HmrOptions::default()),
+        ..Default::default()
+      }),
+      ..Default::default()
+    },
+    vec![Arc::new(FastRefresh)],
+  );
+  bundler.generate().await.expect("build should succeed");
+  bundler
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn forced_self_accepting_module_is_a_boundary() {
+  let mut bundler = generate_bundler().await;
+  let button = abs_file_dir!().join("button.js").to_slash_lossy().into_owned();
+  let patch =
+    bundler.generate_hmr_patch(vec![button.into()]).await.expect("hmr patch should succeed");
+
+  assert!(!patch.full_reload);
+  assert_eq!(patch.hmr_boundaries.len(), 1);
+  assert!(patch.hmr_boundaries[0].boundary.ends_with("button.js"));
+  assert!(patch.hmr_boundaries[0].accepted_via.ends_with("button.js"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn invalidated_module_propagates_to_its_importers() {
+  let mut bundler = generate_bundler().await;
+  let theme = abs_file_dir!().join("theme.js").to_slash_lossy().into_owned();
+  let patch =
+    bundler.generate_hmr_patch(vec![theme.into()]).await.expect("hmr patch should succeed");
+
+  assert!(patch.full_reload);
+  assert!(patch.hmr_boundaries.is_empty());
+}
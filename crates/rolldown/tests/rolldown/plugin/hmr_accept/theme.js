@@ -0,0 +1,3 @@
+export const theme = 'dark'
+
+import.meta.hot.accept(() => {})
@@ -0,0 +1,3 @@
+import count from 'virtual:count'
+
+console.log(count)
@@ -0,0 +1,90 @@
+use std::{
+  borrow::Cow,
+  sync::Arc,
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rolldown::{
+  Bundler, BundlerOptions, ExperimentalOptions, HmrOptions, InputItem, TreeshakeOptions,
+};
+use rolldown_common::HmrChange;
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveIdReturn, HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+/// Serves `virtual:count`, which exports how many times it has been loaded.
+#[derive(Debug, Default)]
+struct LoadCount {
+  count: AtomicUsize,
+}
+
+impl Plugin for LoadCount {
+  fn name(&self) -> Cow<'static, str> {
+    "load-count".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(
+      (args.specifier == "virtual:count")
+        .then(|| HookResolveIdOutput { id: "\0virtual:count".into(), ..Default::default() }),
+    )
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if args.id != "\0virtual:count" {
+      return Ok(None);
+    }
+    let count = self.count.fetch_add(1, Ordering::SeqCst) + 1;
+    Ok(Some(HookLoadOutput {
+      code: format!("export default 'loaded {count} times'\nimport.meta.hot.accept(() => {{}})")
+        .into(),
+      ..Default::default()
+    }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveId | HookUsage::Load
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn patched_virtual_modules_are_loaded_again() {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".into()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(abs_file_dir!()),
+      // Tree shaking isn't supported with hmr yet.
+      treeshake: TreeshakeOptions::Boolean(false),
+      experimental: Some(ExperimentalOptions {
+        hmr: Some(HmrOptions::default()),
+        ..Default::default()
+      }),
+      ..Default::default()
+    },
+    vec![Arc::new(LoadCount::default())],
+  );
+  bundler.generate().await.expect("build should succeed");
+
+  let patch = bundler
+    .generate_hmr_patch(vec![HmrChange::Patched {
+      id: "\0virtual:count".to_string(),
+      code: "export default 'patched'".to_string(),
+    }])
+    .await
+    .expect("patch should be generated");
+  assert!(!patch.full_reload);
+  let code = &patch.single_patch().expect("patch should not be split").code;
+  // The module is loaded by the plugin, so it is only invalidated and its `load` hook runs again.
+  assert!(code.contains("loaded 2 times"), "{}", code);
+  assert!(!code.contains("patched"));
+}
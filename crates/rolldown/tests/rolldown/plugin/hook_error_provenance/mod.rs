@@ -0,0 +1,56 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_error::ColorMode;
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformReturn, HookUsage, Plugin, SharedTransformPluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+#[derive(Debug)]
+struct FailingTransform;
+
+impl Plugin for FailingTransform {
+  fn name(&self) -> Cow<'static, str> {
+    "failing-transform".into()
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    _args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    Err(anyhow::anyhow!("Cannot transform this module"))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Transform
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_attach_plugin_and_hook_to_hook_errors() {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".into()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(FailingTransform)],
+  );
+
+  let Err(errors) = bundler.generate().await else {
+    panic!("the failing transform hook should fail the build");
+  };
+
+  assert_eq!(errors.len(), 1);
+  assert_eq!(errors[0].plugin().as_deref(), Some("failing-transform"));
+  assert_eq!(errors[0].hook().as_deref(), Some("transform"));
+  let rendered = errors[0].to_diagnostic().to_string_with_color(ColorMode::Never);
+  assert!(rendered.starts_with("[failing-transform:transform] Error: "), "{rendered}");
+  assert!(rendered.contains("Cannot transform this module"), "{rendered}");
+}
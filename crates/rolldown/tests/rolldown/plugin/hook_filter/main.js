@@ -0,0 +1,4 @@
+import { util } from './util.js';
+import { helper } from './helper.ts';
+
+console.log(util, helper);
@@ -0,0 +1,149 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{BundlerOptions, InputItem, ModuleType};
+use rolldown_plugin::{
+  GeneralHookFilter, HookFilter, HookLoadArgs, HookLoadReturn, HookResolveIdArgs,
+  HookResolveIdReturn, HookTransformArgs, HookTransformAstArgs, HookTransformAstReturn,
+  HookTransformReturn, HookUsage, Plugin, PluginContext, SharedTransformPluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use rolldown_utils::{js_regex::HybridRegex, pattern_filter::StringOrRegex};
+
+type Calls = Arc<Mutex<Vec<(&'static str, String)>>>;
+
+/// Records the ids its hooks are called with.
+#[derive(Debug)]
+struct Recording {
+  usage: HookUsage,
+  calls: Calls,
+}
+
+impl Recording {
+  fn record(&self, hook: &'static str, id: &str) {
+    let id = id.rsplit(['/', '\\']).next().unwrap();
+    self.calls.lock().unwrap().push((hook, id.to_string()));
+  }
+}
+
+impl Plugin for Recording {
+  fn name(&self) -> Cow<'static, str> {
+    "recording".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    self.record("resolveId", args.specifier);
+    Ok(None)
+  }
+
+  fn resolve_id_filter(&self) -> Option<HookFilter> {
+    Some(HookFilter {
+      id: Some(GeneralHookFilter {
+        include: Some(vec![StringOrRegex::Regex(HybridRegex::new(r"^\./h").unwrap())]),
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    self.record("load", args.id);
+    Ok(None)
+  }
+
+  fn load_filter(&self) -> Option<HookFilter> {
+    Some(HookFilter {
+      id: Some(GeneralHookFilter {
+        exclude: Some(vec![StringOrRegex::String("**/util.js".to_string())]),
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    self.record("transform", args.id);
+    Ok(None)
+  }
+
+  fn transform_filter(&self) -> Option<HookFilter> {
+    Some(HookFilter { module_type: Some(vec![ModuleType::Ts]), ..Default::default() })
+  }
+
+  async fn transform_ast(
+    &self,
+    _ctx: &PluginContext,
+    args: HookTransformAstArgs<'_>,
+  ) -> HookTransformAstReturn {
+    self.record("transformAst", args.id);
+    Ok(args.ast)
+  }
+
+  fn transform_ast_filter(&self) -> Option<HookFilter> {
+    Some(HookFilter {
+      code: Some(GeneralHookFilter {
+        include: Some(vec![StringOrRegex::String("marker".to_string())]),
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    self.usage
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn hooks_are_only_called_for_matching_modules() {
+  let cwd = abs_file_dir!();
+  let calls = Calls::default();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![
+        Arc::new(Recording {
+          usage: HookUsage::ResolveId
+            | HookUsage::Load
+            | HookUsage::Transform
+            | HookUsage::TransformAst,
+          calls: Arc::clone(&calls),
+        }),
+        // Filters only narrow the hooks a plugin registered, they never enable one.
+        Arc::new(Recording { usage: HookUsage::empty(), calls: Arc::clone(&calls) }),
+      ],
+    )
+    .await;
+
+  let mut calls = calls.lock().unwrap().clone();
+  calls.sort();
+  assert_eq!(
+    calls,
+    [
+      ("load", "helper.ts".to_string()),
+      ("load", "main.js".to_string()),
+      ("resolveId", "helper.ts".to_string()),
+      ("transform", "helper.ts".to_string()),
+      ("transformAst", "util.js".to_string()),
+    ]
+  );
+}
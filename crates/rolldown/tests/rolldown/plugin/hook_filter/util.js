@@ -0,0 +1,2 @@
+// marker
+export const util = 'util';
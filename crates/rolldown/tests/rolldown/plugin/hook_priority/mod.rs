@@ -0,0 +1,98 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookBuildStartArgs, HookNoopReturn, HookTransformArgs, HookTransformReturn, HookUsage, Plugin,
+  PluginContext, PluginHookMeta, PluginOrder, SharedTransformPluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+type Calls = Arc<Mutex<Vec<(&'static str, &'static str)>>>;
+
+/// Records when its hooks are called. `None` means the hook doesn't return a meta at all.
+#[derive(Debug)]
+struct Recording {
+  name: &'static str,
+  build_start: Option<(Option<PluginOrder>, i32)>,
+  transform: Option<(Option<PluginOrder>, i32)>,
+  calls: Calls,
+}
+
+impl Plugin for Recording {
+  fn name(&self) -> Cow<'static, str> {
+    self.name.into()
+  }
+
+  async fn build_start(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    self.calls.lock().unwrap().push(("buildStart", self.name));
+    Ok(())
+  }
+
+  fn build_start_meta(&self) -> Option<PluginHookMeta> {
+    self.build_start.map(|(order, priority)| PluginHookMeta { order, priority })
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if args.id.ends_with("main.js") {
+      self.calls.lock().unwrap().push(("transform", self.name));
+    }
+    Ok(None)
+  }
+
+  fn transform_meta(&self) -> Option<PluginHookMeta> {
+    self.transform.map(|(order, priority)| PluginHookMeta { order, priority })
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::Transform
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn hooks_run_by_order_then_priority_then_registration() {
+  let cwd = abs_file_dir!();
+  let calls = Calls::default();
+  let plugin = |name, build_start, transform| {
+    Arc::new(Recording { name, build_start, transform, calls: Arc::clone(&calls) })
+  };
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![
+        plugin("a", Some((Some(PluginOrder::Pre), 1)), Some((Some(PluginOrder::Post), 0))),
+        plugin("b", Some((Some(PluginOrder::Pre), 1)), None),
+        plugin("c", None, Some((None, -5))),
+        plugin("d", Some((Some(PluginOrder::Pre), 0)), Some((Some(PluginOrder::Post), -1))),
+        plugin("e", Some((None, 0)), Some((Some(PluginOrder::Pre), 10))),
+      ],
+    )
+    .await;
+
+  let calls = calls.lock().unwrap();
+  let order_of =
+    |hook| calls.iter().filter(|(h, _)| *h == hook).map(|(_, name)| *name).collect::<Vec<_>>();
+  // `a` and `b` tie on `(pre, 1)`, so they keep their registration order.
+  assert_eq!(order_of("buildStart"), ["d", "a", "b", "c", "e"]);
+  // `pre` beats any priority, and a lower priority runs first within `normal` and `post`.
+  assert_eq!(order_of("transform"), ["e", "c", "b", "d", "a"]);
+}
@@ -1 +1,19 @@
+mod build_lifecycle_hooks;
+mod debug_plugin_order;
+mod emit_chunk;
+mod file_url;
+mod hmr_accept;
+mod hmr_virtual_module;
+mod hook_error_provenance;
+mod hook_filter;
+mod hook_priority;
+mod module_meta;
+mod module_parsed;
+mod options_hook;
 mod plugin_context;
+mod plugin_log;
+mod render_lifecycle_hooks;
+mod resolve_dynamic_import;
+mod resolve_dynamic_import_external;
+mod resolve_dynamic_import_same_specifier;
+mod watch_hooks;
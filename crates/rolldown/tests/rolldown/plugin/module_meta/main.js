@@ -0,0 +1,4 @@
+import { widget } from './widget.js';
+import { util } from './util.js';
+
+console.log(widget, util);
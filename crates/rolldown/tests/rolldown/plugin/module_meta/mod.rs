@@ -0,0 +1,170 @@
+use std::{borrow::Cow, fmt::Write as _, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::{EmittedAsset, ModuleInfo, NormalModule};
+use rolldown_plugin::{
+  HookGenerateBundleArgs, HookLoadArgs, HookLoadOutput, HookLoadReturn, HookNoopReturn,
+  HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn, HookTransformArgs,
+  HookTransformOutput, HookTransformReturn, HookUsage, Plugin, PluginContext,
+  SharedTransformPluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use rolldown_utils::stabilize_id::stabilize_id;
+use serde_json::json;
+
+fn meta(key: &str, value: serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+  serde_json::Map::from_iter([(key.to_string(), value)])
+}
+
+/// Writes meta for `widget.js` from every hook that can.
+#[derive(Debug)]
+struct Producer;
+
+impl Plugin for Producer {
+  fn name(&self) -> Cow<'static, str> {
+    "producer".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    if args.specifier != "./widget.js" {
+      return Ok(None);
+    }
+    let resolved_id = ctx.resolve(args.specifier, args.importer, None).await??;
+    Ok(Some(HookResolveIdOutput {
+      id: resolved_id.id,
+      meta: Some(meta("resolvedBy", json!("producer"))),
+      ..Default::default()
+    }))
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if !args.id.ends_with("widget.js") {
+      return Ok(None);
+    }
+    Ok(Some(HookLoadOutput {
+      code: std::fs::read_to_string(args.id)?.into(),
+      meta: Some(meta("loadedBy", json!("producer"))),
+      ..Default::default()
+    }))
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if !args.id.ends_with("widget.js") {
+      return Ok(None);
+    }
+    Ok(Some(HookTransformOutput {
+      meta: Some(meta("lines", json!(args.code.lines().count()))),
+      ..Default::default()
+    }))
+  }
+
+  async fn module_parsed(
+    &self,
+    ctx: &PluginContext,
+    module_info: Arc<ModuleInfo>,
+    _normal_module: &NormalModule,
+  ) -> HookNoopReturn {
+    if module_info.id.ends_with("widget.js") {
+      let exports = module_info.exports.iter().map(ToString::to_string).collect::<Vec<_>>();
+      ctx.set_module_meta(&module_info.id, "exports".to_string(), json!(exports));
+    }
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveId | HookUsage::Load | HookUsage::Transform | HookUsage::ModuleParsed
+  }
+}
+
+/// Reads the meta written by `Producer` during the build and render phases.
+#[derive(Debug)]
+struct Consumer;
+
+impl Plugin for Consumer {
+  fn name(&self) -> Cow<'static, str> {
+    "consumer".into()
+  }
+
+  async fn module_parsed(
+    &self,
+    _ctx: &PluginContext,
+    module_info: Arc<ModuleInfo>,
+    _normal_module: &NormalModule,
+  ) -> HookNoopReturn {
+    if module_info.id.ends_with("widget.js") {
+      let meta = &module_info.meta;
+      assert_eq!(meta.get("resolvedBy").as_deref(), Some(&json!("producer")));
+      assert_eq!(meta.get("loadedBy").as_deref(), Some(&json!("producer")));
+      assert_eq!(meta.get("lines").as_deref(), Some(&json!(1)));
+      // `Producer` runs first, so its `module_parsed` has written its meta already.
+      assert_eq!(meta.get("exports").as_deref(), Some(&json!(["widget"])));
+    }
+    Ok(())
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &PluginContext,
+    _args: &mut HookGenerateBundleArgs<'_>,
+  ) -> HookNoopReturn {
+    let mut module_ids = ctx.module_ids()?.collect::<Vec<_>>();
+    module_ids.sort();
+    let mut report = String::new();
+    for module_id in module_ids {
+      let info = ctx.get_module_info(&module_id).unwrap();
+      let mut meta = info
+        .meta
+        .iter()
+        .map(|entry| (entry.key().clone(), entry.value().clone()))
+        .collect::<Vec<_>>();
+      meta.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+      let meta = meta.into_iter().collect::<serde_json::Map<_, _>>();
+      writeln!(
+        report,
+        "{}: {}",
+        stabilize_id(&module_id, ctx.cwd()),
+        serde_json::Value::Object(meta)
+      )?;
+    }
+    ctx
+      .emit_file_async(EmittedAsset {
+        file_name: Some("meta.txt".into()),
+        source: report.into(),
+        ..Default::default()
+      })
+      .await?;
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ModuleParsed | HookUsage::GenerateBundle
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn module_meta_is_shared_between_plugins() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".into()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(Producer), Arc::new(Consumer)],
+    )
+    .await;
+}
@@ -0,0 +1 @@
+export const widget = 'widget';
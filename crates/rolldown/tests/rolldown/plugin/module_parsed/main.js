@@ -0,0 +1,5 @@
+import { a } from './a.js'
+import './side-effect.js'
+
+console.log(a)
+import('./b.js').then(({ b }) => console.log(b))
@@ -0,0 +1,88 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::{ImportKind, ModuleInfo, NormalModule};
+use rolldown_plugin::{HookNoopReturn, HookUsage, Plugin, PluginContext};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use rustc_hash::FxHashMap;
+
+#[derive(Debug, Default)]
+struct CollectModuleInfo {
+  infos: std::sync::Mutex<Vec<Arc<ModuleInfo>>>,
+}
+
+impl Plugin for CollectModuleInfo {
+  fn name(&self) -> Cow<'static, str> {
+    "CollectModuleInfo".into()
+  }
+
+  async fn module_parsed(
+    &self,
+    _ctx: &PluginContext,
+    module_info: Arc<ModuleInfo>,
+    _normal_module: &NormalModule,
+  ) -> HookNoopReturn {
+    self.infos.lock().unwrap().push(module_info);
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ModuleParsed
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn module_parsed_receives_module_info() {
+  let cwd = abs_file_dir!();
+  let plugin = Arc::new(CollectModuleInfo::default());
+
+  IntegrationTest::new(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::<CollectModuleInfo>::clone(&plugin)],
+    )
+    .await;
+
+  let infos = plugin.infos.lock().unwrap();
+  let by_stable_id =
+    infos.iter().map(|info| (info.stable_id.as_str(), info)).collect::<FxHashMap<_, _>>();
+  // Called exactly once per module.
+  assert_eq!(infos.len(), 4);
+  assert_eq!(by_stable_id.len(), 4);
+
+  let main = by_stable_id["main.js"];
+  assert!(main.is_entry);
+  assert!(main.has_side_effects);
+  assert_eq!(main.module_type, Some(rolldown_common::ModuleType::Js));
+  let imports = main
+    .import_records
+    .iter()
+    .map(|record| {
+      (record.specifier.as_str(), record.resolved_id.stabilize(&abs_file_dir!()), record.kind)
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    imports,
+    vec![
+      ("./a.js", "a.js".to_string(), ImportKind::Import),
+      ("./side-effect.js", "side-effect.js".to_string(), ImportKind::Import),
+      ("./b.js", "b.js".to_string(), ImportKind::DynamicImport),
+    ]
+  );
+
+  let a = by_stable_id["a.js"];
+  assert!(!a.is_entry);
+  assert!(!a.has_side_effects);
+  assert!(a.import_records.is_empty());
+
+  assert!(by_stable_id["side-effect.js"].has_side_effects);
+  assert!(!by_stable_id["b.js"].is_entry);
+}
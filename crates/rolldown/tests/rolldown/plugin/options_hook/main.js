@@ -0,0 +1,3 @@
+import { version } from 'peer-dep';
+
+export const message = `built against ${version}`;
@@ -0,0 +1,100 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem, IsExternal, OutputFormat, OutputOptions};
+use rolldown_common::Output;
+use rolldown_plugin::{HookUsage, Plugin};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+/// Defaults of a library preset: a commonjs build that leaves the peer dependencies to the user.
+#[derive(Debug)]
+struct LibraryPreset;
+
+impl Plugin for LibraryPreset {
+  fn name(&self) -> Cow<'static, str> {
+    "library-preset".into()
+  }
+
+  fn options(&self, options: &mut BundlerOptions) {
+    options.format = Some(OutputFormat::Cjs);
+    let peer_deps = IsExternal::from_vec(vec!["peer-dep".to_string()]);
+    options.external = Some(options.external.take().unwrap_or_default().or(peer_deps));
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::empty()
+  }
+}
+
+/// Names commonjs entries `.cjs`, relying on the format set by the plugins before it.
+/// With multiple outputs, esm entries are named `.mjs` so they don't clash.
+#[derive(Debug)]
+struct CjsExtension;
+
+impl Plugin for CjsExtension {
+  fn name(&self) -> Cow<'static, str> {
+    "cjs-extension".into()
+  }
+
+  fn options(&self, options: &mut BundlerOptions) {
+    if matches!(options.format, Some(OutputFormat::Cjs)) {
+      options.entry_filenames = Some("[name].cjs".to_string().into());
+    }
+  }
+
+  fn output_options(&self, options: &mut OutputOptions) {
+    let extension = match options.format {
+      Some(OutputFormat::Cjs) => "cjs",
+      Some(OutputFormat::Esm) => "mjs",
+      _ => return,
+    };
+    options.entry_filenames = Some(format!("[name].{extension}").into());
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::empty()
+  }
+}
+
+fn options() -> BundlerOptions {
+  BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".into()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(abs_file_dir!()),
+    ..Default::default()
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn options_hook_applies_preset_defaults() {
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(options(), vec![Arc::new(LibraryPreset), Arc::new(CjsExtension)])
+    .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn options_reflect_the_changes_of_plugins() {
+  let mut bundler =
+    Bundler::with_plugins(options(), vec![Arc::new(LibraryPreset), Arc::new(CjsExtension)]);
+  assert!(matches!(bundler.options().format, OutputFormat::Cjs));
+  assert!(bundler.options().external.call("peer-dep", None, false).await.unwrap());
+
+  let outputs = bundler
+    .generate_multi(vec![
+      OutputOptions { format: Some(OutputFormat::Esm), ..Default::default() },
+      OutputOptions { format: Some(OutputFormat::Cjs), ..Default::default() },
+    ])
+    .await
+    .expect("build should succeed");
+  let entry_filenames = outputs
+    .iter()
+    .flat_map(|output| &output.assets)
+    .filter_map(|asset| match asset {
+      Output::Chunk(chunk) => Some(chunk.filename.to_string()),
+      Output::Asset(_) => None,
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(entry_filenames, vec!["main.mjs".to_string(), "main.cjs".to_string()]);
+}
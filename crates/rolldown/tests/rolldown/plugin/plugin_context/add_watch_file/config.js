@@ -0,0 +1,3 @@
+export const config = __CONFIG__
+
+import.meta.hot.accept(() => {})
@@ -0,0 +1,85 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{ExperimentalOptions, HmrOptions};
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, HookUsage, Plugin,
+  SharedTransformPluginContext,
+};
+use rolldown_testing::abs_file_dir;
+use sugar_path::SugarPath;
+
+/// Inlines the content of `config.txt` into `config.js`, like a plugin reading a config file would.
+#[derive(Debug)]
+struct InlineConfig {
+  config_path: String,
+  config: Mutex<String>,
+}
+
+impl Plugin for InlineConfig {
+  fn name(&self) -> Cow<'static, str> {
+    "InlineConfig".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if !args.id.ends_with("config.js") {
+      return Ok(None);
+    }
+    ctx.add_watch_file_dependency(&self.config_path);
+    let config = self.config.lock().unwrap();
+    Ok(Some(HookTransformOutput {
+      code: Some(args.code.replace("__CONFIG__", &format!("{:?}", config.as_str()))),
+      ..Default::default()
+    }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Transform
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn watch_file_change_invalidates_dependent_module() {
+  let cwd = abs_file_dir!();
+  let config_path = cwd.join("config.txt").to_slash_lossy().into_owned();
+  let plugin = Arc::new(InlineConfig {
+    config_path: config_path.clone(),
+    config: Mutex::new("first".to_string()),
+  });
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(cwd),
+      experimental: Some(ExperimentalOptions {
+        hmr: Some(HmrOptions::default()),
+        ..Default::default()
+      }),
+      ..Default::default()
+    },
+    vec![Arc::<InlineConfig>::clone(&plugin)],
+  );
+
+  let output = bundler.generate().await.expect("build should succeed");
+  assert!(String::from_utf8_lossy(output.assets[0].content_as_bytes()).contains("\"first\""));
+  assert!(bundler.get_watch_files().contains(config_path.as_str()));
+
+  *plugin.config.lock().unwrap() = "second".to_string();
+  let patch =
+    bundler.generate_hmr_patch(vec![config_path.into()]).await.expect("hmr patch should succeed");
+
+  assert!(!patch.full_reload);
+  let code = &patch.single_patch().expect("patch should not be split").code;
+  assert!(code.contains("\"second\""), "{}", code);
+  assert!(patch.hmr_boundaries.iter().any(|boundary| boundary.boundary.ends_with("config.js")));
+}
@@ -0,0 +1,115 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+  time::Duration,
+};
+
+use rolldown::{BundleEvent, Bundler, BundlerOptions, InputItem, Watcher, WatcherEvent};
+use rolldown_common::WatcherChangeKind;
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+use sugar_path::SugarPath;
+
+/// Inlines `theme.json`, which isn't part of the module graph, as the content of `theme.js`. The
+/// theme is kept in memory, so the test can change it without touching the fixture.
+#[derive(Debug)]
+struct ThemeLoader {
+  theme_path: String,
+  theme: Mutex<String>,
+}
+
+impl Plugin for ThemeLoader {
+  fn name(&self) -> Cow<'static, str> {
+    "ThemeLoader".into()
+  }
+
+  async fn load(&self, ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if !args.id.ends_with("theme.js") {
+      return Ok(None);
+    }
+    ctx.add_watch_file(&self.theme_path);
+    let theme = self.theme.lock().unwrap();
+    Ok(Some(HookLoadOutput {
+      code: format!("export default {theme}").into(),
+      ..Default::default()
+    }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Load
+  }
+}
+
+/// Waits for the next `BUNDLE_END` event, returning the changed files reported before it.
+async fn wait_for_bundle_end(watcher: &Watcher) -> Vec<String> {
+  let rx = Arc::clone(&watcher.emitter().rx);
+  tokio::task::spawn_blocking(move || {
+    let rx = rx.blocking_lock();
+    let mut changes = vec![];
+    loop {
+      match rx.recv_timeout(Duration::from_secs(10)).expect("should receive watcher events") {
+        WatcherEvent::Change(data) => changes.push(data.path.to_string()),
+        WatcherEvent::Event(BundleEvent::BundleEnd(_)) => return changes,
+        WatcherEvent::Event(BundleEvent::Error(data)) => panic!("{:?}", data.error.diagnostics),
+        _ => {}
+      }
+    }
+  })
+  .await
+  .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn watch_file_added_in_load_triggers_rebuild() {
+  let cwd = abs_file_dir!();
+  let theme_path = cwd.join("theme.json").to_slash_lossy().into_owned();
+  let out_dir = cwd.join("dist");
+
+  let options = BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+      ..Default::default()
+    }]),
+    cwd: Some(cwd),
+    dir: Some(out_dir.to_string_lossy().into_owned()),
+    ..Default::default()
+  };
+  let plugin = Arc::new(ThemeLoader {
+    theme_path: theme_path.clone(),
+    theme: Mutex::new(r#"{ "color": "red" }"#.to_string()),
+  });
+
+  let mut bundler =
+    Bundler::with_plugins(options.clone(), vec![Arc::<ThemeLoader>::clone(&plugin)]);
+  let output = bundler.generate().await.expect("build should succeed");
+  assert!(
+    output.watch_files.iter().any(|file| file.as_str() == theme_path),
+    "{:?}",
+    output.watch_files
+  );
+
+  let bundler = Bundler::with_plugins(options, vec![Arc::<ThemeLoader>::clone(&plugin)]);
+  let watcher =
+    Arc::new(Watcher::new(vec![Arc::new(tokio::sync::Mutex::new(bundler))], None).unwrap());
+  let handle = tokio::spawn({
+    let watcher = Arc::clone(&watcher);
+    async move { watcher.start().await }
+  });
+
+  wait_for_bundle_end(&watcher).await;
+  let main = out_dir.join("main.js");
+  assert!(std::fs::read_to_string(&main).unwrap().contains("\"red\""));
+
+  // Report the change directly instead of editing the fixture and waiting for `notify`.
+  *plugin.theme.lock().unwrap() = r#"{ "color": "blue" }"#.to_string();
+  watcher.invalidate(&theme_path, WatcherChangeKind::Update);
+  let changes = wait_for_bundle_end(&watcher).await;
+  assert_eq!(changes, [theme_path]);
+  assert!(std::fs::read_to_string(&main).unwrap().contains("\"blue\""));
+
+  watcher.close().await.unwrap();
+  handle.await.unwrap();
+}
@@ -0,0 +1,2 @@
+// The content is replaced by the `ThemeLoader` plugin.
+export default {}
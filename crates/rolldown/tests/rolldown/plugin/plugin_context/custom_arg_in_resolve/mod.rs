@@ -93,6 +93,7 @@ async fn allow_pass_custom_arg() {
         input: Some(vec![InputItem {
           name: Some("entry".to_string()),
           import: "./entry.js".to_string(),
+          ..Default::default()
         }]),
         cwd: Some(cwd),
         ..Default::default()
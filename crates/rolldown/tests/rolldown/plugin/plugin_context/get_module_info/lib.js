@@ -0,0 +1,3 @@
+export const a = 'a'
+export function b() {}
+export default 'lib'
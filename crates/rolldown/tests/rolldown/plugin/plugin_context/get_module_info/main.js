@@ -0,0 +1,4 @@
+import { a } from './lib.js'
+import './other.js'
+
+console.log(a)
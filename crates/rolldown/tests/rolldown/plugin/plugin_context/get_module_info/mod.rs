@@ -0,0 +1,76 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, atomic::AtomicBool},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem, ModuleType};
+use rolldown_common::ModuleId;
+use rolldown_plugin::{HookBuildEndArgs, HookNoopReturn, HookUsage, Plugin, PluginContext};
+use rolldown_testing::abs_file_dir;
+use rolldown_utils::indexmap::FxIndexSet;
+use rolldown_utils::stabilize_id::stabilize_id;
+
+/// Checks the module info of the fixture once the graph is built.
+#[derive(Debug, Default)]
+struct ModuleInfoAssertions {
+  checked: AtomicBool,
+}
+
+impl Plugin for ModuleInfoAssertions {
+  fn name(&self) -> Cow<'static, str> {
+    "ModuleInfoAssertions".into()
+  }
+
+  async fn build_end(
+    &self,
+    ctx: &PluginContext,
+    _args: Option<&HookBuildEndArgs<'_>>,
+  ) -> HookNoopReturn {
+    let stable_ids = |ids: &FxIndexSet<ModuleId>| {
+      let mut ids = ids.iter().map(|id| stabilize_id(id, ctx.cwd())).collect::<Vec<_>>();
+      ids.sort();
+      ids
+    };
+    let module_id = |name: &str| ctx.cwd().join(name).to_string_lossy().into_owned();
+
+    let main = ctx.get_module_info(&module_id("main.js")).expect("main.js should be loaded");
+    assert!(main.is_entry);
+    assert_eq!(main.module_type, Some(ModuleType::Js));
+    assert!(main.importers.is_empty());
+    assert_eq!(stable_ids(&main.imported_ids), ["lib.js", "other.js"]);
+
+    let lib = ctx.get_module_info(&module_id("lib.js")).expect("lib.js should be loaded");
+    assert!(!lib.is_entry);
+    assert_eq!(stable_ids(&lib.importers), ["main.js", "other.js"]);
+    let mut exports = lib.exports.iter().map(ToString::to_string).collect::<Vec<_>>();
+    exports.sort();
+    assert_eq!(exports, ["a", "b", "default"]);
+
+    self.checked.store(true, std::sync::atomic::Ordering::Relaxed);
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildEnd
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_module_info_reports_importers_and_exports() {
+  let plugin = Arc::new(ModuleInfoAssertions::default());
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".into()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::clone(&plugin) as _],
+  );
+
+  bundler.generate().await.expect("build should succeed");
+  assert!(plugin.checked.load(std::sync::atomic::Ordering::Relaxed));
+}
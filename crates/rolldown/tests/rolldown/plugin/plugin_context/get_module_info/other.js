@@ -0,0 +1,3 @@
+import { b } from './lib.js'
+
+console.log(b)
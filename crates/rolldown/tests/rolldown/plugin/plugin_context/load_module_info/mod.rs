@@ -0,0 +1,119 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::ModuleType;
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformReturn, HookUsage, LoadedModule, Plugin,
+  PluginContextLoadOptions, SharedTransformPluginContext,
+};
+use rolldown_testing::abs_file_dir;
+use serde_json::json;
+use sugar_path::SugarPath;
+
+/// Loads modules from `transform` and records what `PluginContext::load` returned, keyed by
+/// `<caller> -> <loaded>`.
+#[derive(Debug, Default)]
+struct LoadInspector {
+  loaded: std::sync::Mutex<Vec<(String, LoadedModule)>>,
+}
+
+impl LoadInspector {
+  async fn load(
+    &self,
+    ctx: &SharedTransformPluginContext,
+    caller: &str,
+    file: &str,
+    options: Option<PluginContextLoadOptions>,
+  ) -> anyhow::Result<()> {
+    let id = ctx.inner.cwd().join(file).to_slash_lossy().into_owned();
+    let loaded = ctx.inner.load(&id, options).await?;
+    self.loaded.lock().unwrap().push((format!("{caller} -> {file}"), loaded));
+    Ok(())
+  }
+
+  fn get(&self, key: &str) -> (bool, Vec<String>, Option<serde_json::Value>) {
+    let loaded = self.loaded.lock().unwrap();
+    let (_, loaded) = loaded.iter().find(|(k, _)| k == key).unwrap_or_else(|| panic!("{key}"));
+    (
+      loaded.is_cycle,
+      loaded.info.exports.iter().map(ToString::to_string).collect(),
+      loaded.info.meta.get("loadedBy").map(|value| value.clone()),
+    )
+  }
+}
+
+impl Plugin for LoadInspector {
+  fn name(&self) -> Cow<'static, str> {
+    "LoadInspector".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if args.id.ends_with("main.js") {
+      let mut meta = serde_json::Map::new();
+      meta.insert("loadedBy".to_string(), json!("main.js"));
+      self
+        .load(
+          &ctx,
+          "main.js",
+          "greeting.txt",
+          Some(PluginContextLoadOptions {
+            module_type: Some(ModuleType::Js),
+            meta: Some(meta),
+            ..Default::default()
+          }),
+        )
+        .await?;
+      self.load(&ctx, "main.js", "main.js", None).await?;
+      self.load(&ctx, "main.js", "a.js", None).await?;
+    } else if args.id.ends_with("a.js") {
+      // `main.js` is waiting for `a.js` at this point.
+      self.load(&ctx, "a.js", "main.js", None).await?;
+    }
+    Ok(None)
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Transform
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn load_resolves_to_module_info() {
+  let plugin = Arc::new(LoadInspector::default());
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::<LoadInspector>::clone(&plugin)],
+  );
+  bundler.generate().await.expect("build should succeed");
+
+  // The module type is forced, so the text file is parsed as js.
+  let (is_cycle, exports, meta) = plugin.get("main.js -> greeting.txt");
+  assert!(!is_cycle);
+  assert_eq!(exports, vec!["greeting".to_string()]);
+  assert_eq!(meta, Some(json!("main.js")));
+
+  // Loading itself would never finish, so the module info isn't complete yet.
+  let (is_cycle, exports, _) = plugin.get("main.js -> main.js");
+  assert!(is_cycle);
+  assert!(exports.is_empty());
+
+  let (is_cycle, exports, _) = plugin.get("a.js -> main.js");
+  assert!(is_cycle);
+  assert!(exports.is_empty());
+
+  let (is_cycle, exports, _) = plugin.get("main.js -> a.js");
+  assert!(!is_cycle);
+  assert_eq!(exports, vec!["a".to_string()]);
+}
@@ -1 +1,8 @@
+mod add_watch_file;
+mod add_watch_file_in_load;
 mod custom_arg_in_resolve;
+mod get_module_info;
+mod load_module_info;
+mod module_graph_queries;
+mod skip_plugins_in_resolve;
+mod skip_self_in_resolve;
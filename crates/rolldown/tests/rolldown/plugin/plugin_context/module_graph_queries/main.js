@@ -0,0 +1,6 @@
+import { a } from './a.js';
+import { unused } from './unused.js';
+import './side-effect.js';
+
+console.log(a);
+import('./lazy.js').then((m) => console.log(m.lazy));
@@ -0,0 +1,138 @@
+use std::{borrow::Cow, collections::BTreeMap, fmt::Write as _, sync::Arc};
+
+use rolldown::{BundlerOptions, ChunkFilenamesOutputOption, InputItem};
+use rolldown_common::{EmittedAsset, Output};
+use rolldown_error::BuildDiagnostic;
+use rolldown_plugin::{
+  HookBuildStartArgs, HookGenerateBundleArgs, HookNoopReturn, HookRenderChunkArgs,
+  HookRenderChunkReturn, HookRenderStartArgs, HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use rolldown_utils::stabilize_id::stabilize_id;
+
+fn assert_invalid_rollup_phase(err: &anyhow::Error) {
+  let diagnostic = err.downcast_ref::<BuildDiagnostic>().expect("should be a BuildDiagnostic");
+  assert_eq!(diagnostic.kind().to_string(), "INVALID_ROLLUP_PHASE");
+}
+
+/// Emits `report.txt` listing, for every module, who imports it and where it ends up.
+#[derive(Debug, Default)]
+struct ReverseDependencyReport;
+
+impl Plugin for ReverseDependencyReport {
+  fn name(&self) -> Cow<'static, str> {
+    "ReverseDependencyReport".into()
+  }
+
+  async fn build_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    assert_invalid_rollup_phase(&ctx.module_ids().err().expect("graph isn't linked yet"));
+    assert_invalid_rollup_phase(
+      &ctx.is_module_included("main.js").expect_err("graph isn't linked yet"),
+    );
+    Ok(())
+  }
+
+  async fn render_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookRenderStartArgs<'_>,
+  ) -> HookNoopReturn {
+    assert_eq!(ctx.module_ids()?.count(), 6);
+    let main = ctx.module_ids()?.find(|id| id.ends_with("main.js")).unwrap();
+    assert!(ctx.is_module_included(&main)?);
+    assert_invalid_rollup_phase(
+      &ctx.get_module_chunk_filename(&main).expect_err("chunks aren't generated yet"),
+    );
+    Ok(())
+  }
+
+  async fn render_chunk(
+    &self,
+    ctx: &PluginContext,
+    args: &HookRenderChunkArgs<'_>,
+  ) -> HookRenderChunkReturn {
+    // The filename still contains the hash placeholder here.
+    for module_id in &args.chunk.module_ids {
+      assert_invalid_rollup_phase(
+        &ctx.get_module_chunk_filename(module_id).expect_err("chunk filenames aren't final yet"),
+      );
+    }
+    Ok(None)
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &PluginContext,
+    args: &mut HookGenerateBundleArgs<'_>,
+  ) -> HookNoopReturn {
+    let chunk_filenames = args
+      .bundle
+      .iter()
+      .filter_map(|output| match output {
+        Output::Chunk(chunk) => Some(chunk),
+        Output::Asset(_) => None,
+      })
+      .flat_map(|chunk| chunk.module_ids.iter().map(|id| (id.to_string(), chunk.filename.clone())))
+      .collect::<BTreeMap<_, _>>();
+    let mut module_ids = ctx.module_ids()?.collect::<Vec<_>>();
+    module_ids.sort();
+    let mut report = String::new();
+    for module_id in module_ids {
+      let info = ctx.get_module_info(&module_id).unwrap();
+      let stable_id = stabilize_id(&module_id, ctx.cwd());
+      let chunk = ctx.get_module_chunk_filename(&module_id)?;
+      assert_eq!(chunk.as_ref(), chunk_filenames.get(module_id.as_str()));
+      let importers = info
+        .importers
+        .iter()
+        .chain(&info.dynamic_importers)
+        .map(|id| stabilize_id(id, ctx.cwd()))
+        .collect::<Vec<_>>();
+      writeln!(report, "{stable_id}")?;
+      writeln!(report, "  importers: [{}]", importers.join(", "))?;
+      writeln!(report, "  included: {}", ctx.is_module_included(&module_id)?)?;
+      writeln!(report, "  side effects: {}", info.has_side_effects)?;
+      writeln!(report, "  chunk: {}", chunk.as_deref().unwrap_or("-"))?;
+    }
+    ctx
+      .emit_file_async(EmittedAsset {
+        file_name: Some("report.txt".into()),
+        source: report.into(),
+        ..Default::default()
+      })
+      .await?;
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart
+      | HookUsage::RenderStart
+      | HookUsage::RenderChunk
+      | HookUsage::GenerateBundle
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn module_graph_queries() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".into()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        chunk_filenames: Some(ChunkFilenamesOutputOption::String("[name]-[hash].js".to_string())),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(ReverseDependencyReport)],
+    )
+    .await;
+}
@@ -0,0 +1,132 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookBuildStartArgs, HookLoadArgs, HookLoadReturn, HookNoopReturn, HookResolveIdArgs,
+  HookResolveIdReturn, HookUsage, Plugin, PluginContext, PluginContextResolveOptions,
+};
+use rolldown_testing::abs_file_dir;
+
+type Log = Arc<Mutex<Vec<&'static str>>>;
+type ResolveCase = (&'static str, fn() -> PluginContextResolveOptions);
+
+/// Records its `resolveId` and `load` calls for `dep.js`, without resolving or loading anything.
+#[derive(Debug)]
+struct Recording {
+  name: &'static str,
+  log: Log,
+}
+
+impl Plugin for Recording {
+  fn name(&self) -> Cow<'static, str> {
+    self.name.into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    if args.specifier == "./dep.js" {
+      self.log.lock().unwrap().push(self.name);
+    }
+    Ok(None)
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if args.id.ends_with("dep.js") {
+      self.log.lock().unwrap().push("load");
+    }
+    Ok(None)
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveId | HookUsage::Load
+  }
+}
+
+/// Resolves `./dep.js` from `buildStart` with each of `cases`, recording which `resolveId` hooks
+/// took part in each call.
+#[derive(Debug)]
+struct Analyzing {
+  recording: Recording,
+  cases: Vec<ResolveCase>,
+  results: Mutex<Vec<(&'static str, Vec<&'static str>)>>,
+}
+
+impl Plugin for Analyzing {
+  fn name(&self) -> Cow<'static, str> {
+    self.recording.name()
+  }
+
+  async fn build_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    let importer = ctx.cwd().join("entry.js");
+    for (case, options) in &self.cases {
+      let resolved = ctx.resolve("./dep.js", importer.to_str(), Some(options())).await??;
+      assert!(resolved.id.ends_with("dep.js"));
+      let participants = std::mem::take(&mut *self.recording.log.lock().unwrap());
+      self.results.lock().unwrap().push((case, participants));
+    }
+    Ok(())
+  }
+
+  async fn resolve_id(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    self.recording.resolve_id(ctx, args).await
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::ResolveId
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resolve_options_control_hook_participation() {
+  let log = Log::default();
+  let analyzing = Arc::new(Analyzing {
+    recording: Recording { name: "Analyzing", log: Arc::clone(&log) },
+    cases: vec![
+      ("all", || PluginContextResolveOptions { skip_self: false, ..Default::default() }),
+      ("skip self", PluginContextResolveOptions::default),
+      ("skip plugins", || PluginContextResolveOptions { skip_plugins: true, ..Default::default() }),
+    ],
+    results: Mutex::default(),
+  });
+  let other = Arc::new(Recording { name: "Other", log: Arc::clone(&log) });
+
+  Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("entry".to_string()),
+        import: "./entry.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::clone(&analyzing) as _, other],
+  )
+  .generate()
+  .await
+  .expect("build should succeed");
+
+  assert_eq!(
+    *analyzing.results.lock().unwrap(),
+    vec![
+      ("all", vec!["Analyzing", "Other"]),
+      ("skip self", vec!["Other"]),
+      ("skip plugins", vec![]),
+    ]
+  );
+  assert!(log.lock().unwrap().is_empty(), "resolving `dep.js` should never load it");
+}
@@ -0,0 +1 @@
+import 'virtual:config'
@@ -0,0 +1,117 @@
+use std::{
+  borrow::Cow,
+  sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+  },
+};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::ImportKind;
+use rolldown_plugin::{
+  CustomField, HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn, HookUsage, Plugin,
+  PluginContext, PluginContextResolveOptions, typedmap::TypedMapKey,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Hash, PartialEq, Eq)]
+struct Caller;
+
+impl TypedMapKey for Caller {
+  type Value = &'static str;
+}
+
+/// Delegates `virtual:*` to the other plugins by calling `ctx.resolve` with the same specifier,
+/// which would recurse forever without `skip_self`.
+#[derive(Debug, Default)]
+struct Delegating {
+  calls: AtomicUsize,
+}
+
+impl Plugin for Delegating {
+  fn name(&self) -> Cow<'static, str> {
+    "Delegating".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    if !args.specifier.starts_with("virtual:") {
+      return Ok(None);
+    }
+    self.calls.fetch_add(1, Ordering::SeqCst);
+    let custom = CustomField::default();
+    custom.insert(Caller, "Delegating");
+    let resolved = ctx
+      .resolve(
+        args.specifier,
+        args.importer,
+        Some(PluginContextResolveOptions {
+          import_kind: ImportKind::Require,
+          custom: Arc::new(custom),
+          ..Default::default()
+        }),
+      )
+      .await??;
+    Ok(Some(HookResolveIdOutput {
+      id: resolved.id,
+      external: Some(true.into()),
+      ..Default::default()
+    }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveId
+  }
+}
+
+#[derive(Debug)]
+struct Receiving;
+
+impl Plugin for Receiving {
+  fn name(&self) -> Cow<'static, str> {
+    "Receiving".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    if !args.specifier.starts_with("virtual:") {
+      return Ok(None);
+    }
+    assert_eq!(args.kind, ImportKind::Require);
+    assert_eq!(args.custom.get(&Caller).as_deref(), Some(&"Delegating"));
+    Ok(Some(HookResolveIdOutput { id: "resolved-by-receiving".into(), ..Default::default() }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveId
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn skip_self_prevents_recursion() {
+  let cwd = abs_file_dir!();
+  let delegating = Arc::new(Delegating::default());
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("entry".to_string()),
+          import: "./entry.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::clone(&delegating) as _, Arc::new(Receiving)],
+    )
+    .await;
+
+  assert_eq!(delegating.calls.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,3 @@
+import { greet } from './util.js';
+
+console.log(greet('world'));
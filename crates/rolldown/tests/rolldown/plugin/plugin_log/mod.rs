@@ -0,0 +1,68 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookNoopReturn, HookRenderStartArgs, HookTransformArgs, HookTransformReturn, HookUsage, Plugin,
+  PluginContext, PluginDiagnostic, SharedTransformPluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+/// Warns about every `debugger` statement, pointing at it in the module.
+#[derive(Debug)]
+struct NoDebugger;
+
+impl Plugin for NoDebugger {
+  fn name(&self) -> Cow<'static, str> {
+    "no-debugger".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    for (offset, matched) in args.code.match_indices("debugger") {
+      ctx.inner.warn(
+        PluginDiagnostic::new("Unexpected debugger statement")
+          .with_id(args.id)
+          .with_span(u32::try_from(offset)?, u32::try_from(matched.len())?)
+          .with_hint("Remove it before shipping"),
+      );
+    }
+    Ok(None)
+  }
+
+  async fn render_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &HookRenderStartArgs<'_>,
+  ) -> HookNoopReturn {
+    // Without a span, there's no code frame.
+    ctx.warn(PluginDiagnostic::new("Checked all modules for debugger statements"));
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Transform | HookUsage::RenderStart
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn plugin_warnings_render_code_frames() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".into()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(NoDebugger)],
+    )
+    .await;
+}
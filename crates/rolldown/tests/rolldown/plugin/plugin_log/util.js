@@ -0,0 +1,4 @@
+export function greet(name) {
+  debugger;
+  return `Hello, ${name}!`;
+}
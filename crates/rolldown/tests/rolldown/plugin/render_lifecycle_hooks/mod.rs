@@ -0,0 +1,100 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_plugin::{
+  HookNoopReturn, HookRenderChunkArgs, HookRenderChunkReturn, HookRenderErrorArgs,
+  HookRenderStartArgs, HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+type Calls = Arc<Mutex<Vec<String>>>;
+
+/// Records the hooks bracketing chunk rendering, and fails it if `fail_render_chunk` is set.
+#[derive(Debug)]
+struct Recording {
+  calls: Calls,
+  fail_render_chunk: bool,
+}
+
+impl Plugin for Recording {
+  fn name(&self) -> Cow<'static, str> {
+    "recording".into()
+  }
+
+  async fn render_start(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderStartArgs<'_>,
+  ) -> HookNoopReturn {
+    self.calls.lock().unwrap().push(format!("renderStart with {:?}", args.options.format));
+    Ok(())
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderChunkArgs<'_>,
+  ) -> HookRenderChunkReturn {
+    if self.fail_render_chunk {
+      return Err(anyhow::anyhow!("cannot render {}", args.chunk.filename));
+    }
+    Ok(None)
+  }
+
+  async fn render_error(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookRenderErrorArgs<'_>,
+  ) -> HookNoopReturn {
+    let errors = args
+      .errors
+      .iter()
+      .map(|error| format!("{:?} from {:?}", error.hook(), error.plugin()))
+      .collect::<Vec<_>>();
+    self.calls.lock().unwrap().push(format!("renderError: {}", errors.join(", ")));
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::RenderStart | HookUsage::RenderChunk | HookUsage::RenderError
+  }
+}
+
+fn bundler(calls: &Calls, fail_render_chunk: bool) -> Bundler {
+  Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(Recording { calls: Arc::clone(calls), fail_render_chunk })],
+  )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn render_start_runs_once_for_a_successful_render() {
+  let calls = Calls::default();
+  let mut bundler = bundler(&calls, false);
+
+  bundler.generate().await.expect("render should succeed");
+  assert_eq!(*calls.lock().unwrap(), ["renderStart with Esm"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn render_error_receives_the_error_of_a_failed_render() {
+  let calls = Calls::default();
+  let mut bundler = bundler(&calls, true);
+
+  assert!(bundler.generate().await.is_err());
+  assert_eq!(
+    *calls.lock().unwrap(),
+    ["renderStart with Esm", r#"renderError: Some("renderChunk") from Some("recording")"#]
+  );
+}
@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+
+const lang = 'fr'
+const { default: messages } = await import(`./locales/${lang}.js`)
+assert.strictEqual(messages[lang], 'bonjour')
+
+// Not handled by the plugin, so it's kept as it is.
+export const loadPlugin = (name) => import(name)
@@ -0,0 +1,64 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_plugin::{
+  DynamicImportSpecifier, HookLoadArgs, HookLoadOutput, HookLoadReturn,
+  HookResolveDynamicImportArgs, HookResolveIdOutput, HookResolveIdReturn, HookUsage, Plugin,
+  PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+const VIRTUAL_LOCALES: &str = "\0virtual:locales";
+
+#[derive(Debug)]
+struct LocalesPlugin;
+
+impl Plugin for LocalesPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "LocalesPlugin".into()
+  }
+
+  async fn resolve_dynamic_import(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveDynamicImportArgs<'_>,
+  ) -> HookResolveIdReturn {
+    match args.specifier {
+      DynamicImportSpecifier::Expression("`./locales/${lang}.js`") => {
+        Ok(Some(HookResolveIdOutput { id: VIRTUAL_LOCALES.into(), ..Default::default() }))
+      }
+      _ => Ok(None),
+    }
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    Ok((args.id == VIRTUAL_LOCALES).then(|| HookLoadOutput {
+      code: "export default { en: 'hello', fr: 'bonjour' }".into(),
+      ..Default::default()
+    }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveDynamicImport | HookUsage::Load
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resolve_non_static_dynamic_import() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(LocalesPlugin)],
+    )
+    .await;
+}
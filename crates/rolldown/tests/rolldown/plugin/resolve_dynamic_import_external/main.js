@@ -0,0 +1,5 @@
+import { render } from './chart.js'
+
+render()
+
+export const loadChart = () => import('./chart.js')
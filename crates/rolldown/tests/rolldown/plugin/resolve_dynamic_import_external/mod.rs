@@ -0,0 +1,75 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_plugin::{
+  DynamicImportSpecifier, HookResolveDynamicImportArgs, HookResolveIdOutput, HookResolveIdReturn,
+  HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::abs_file_dir;
+
+const CHART_CDN_URL: &str = "https://cdn.example.com/chart.js";
+
+/// Loads `./chart.js` from a CDN when it's imported dynamically.
+#[derive(Debug)]
+struct DynamicChartFromCdn;
+
+impl Plugin for DynamicChartFromCdn {
+  fn name(&self) -> Cow<'static, str> {
+    "DynamicChartFromCdn".into()
+  }
+
+  async fn resolve_dynamic_import(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveDynamicImportArgs<'_>,
+  ) -> HookResolveIdReturn {
+    match args.specifier {
+      DynamicImportSpecifier::Static("./chart.js") => Ok(Some(HookResolveIdOutput {
+        id: CHART_CDN_URL.into(),
+        external: Some(true.into()),
+        ..Default::default()
+      })),
+      _ => Ok(None),
+    }
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveDynamicImport
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_only_externalize_dynamic_imports() {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".into()),
+        import: "./main.js".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(DynamicChartFromCdn)],
+  );
+
+  let output = bundler.generate().await.expect("build should succeed");
+  let chunks = output
+    .assets
+    .iter()
+    .filter_map(|asset| match asset {
+      Output::Chunk(chunk) => Some(chunk),
+      Output::Asset(_) => None,
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(chunks.len(), 1);
+
+  let main = chunks[0];
+  assert!(
+    main.module_ids.iter().any(|id| id.ends_with("chart.js")),
+    "the static import should be bundled: {:?}",
+    main.module_ids
+  );
+  assert!(main.code.contains(&format!("import(\"{CHART_CDN_URL}\")")), "{}", main.code);
+}
@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+
+const lang = 'fr'
+const { default: messages } = await import(`./locales/${lang}.js`)
+assert.strictEqual(messages[lang], 'bonjour')
+
+// Same string as the id the expression above resolves to, but resolved by `resolve_id`.
+const { default: fallback } = await import('virtual:locales')
+assert.strictEqual(fallback, 'fallback')
@@ -0,0 +1,78 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_plugin::{
+  DynamicImportSpecifier, HookLoadArgs, HookLoadOutput, HookLoadReturn,
+  HookResolveDynamicImportArgs, HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn,
+  HookUsage, Plugin, PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+const RESOLVED_LOCALES: &str = "virtual:locales";
+const FALLBACK_LOCALES: &str = "\0virtual:fallback-locales";
+
+#[derive(Debug)]
+struct LocalesPlugin;
+
+impl Plugin for LocalesPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "LocalesPlugin".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(
+      (args.specifier == RESOLVED_LOCALES)
+        .then(|| HookResolveIdOutput { id: FALLBACK_LOCALES.into(), ..Default::default() }),
+    )
+  }
+
+  async fn resolve_dynamic_import(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveDynamicImportArgs<'_>,
+  ) -> HookResolveIdReturn {
+    match args.specifier {
+      DynamicImportSpecifier::Expression("`./locales/${lang}.js`") => {
+        Ok(Some(HookResolveIdOutput { id: RESOLVED_LOCALES.into(), ..Default::default() }))
+      }
+      _ => Ok(None),
+    }
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    let code = match args.id {
+      RESOLVED_LOCALES => "export default { en: 'hello', fr: 'bonjour' }",
+      FALLBACK_LOCALES => "export default 'fallback'",
+      _ => return Ok(None),
+    };
+    Ok(Some(HookLoadOutput { code: code.into(), ..Default::default() }))
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveId | HookUsage::ResolveDynamicImport | HookUsage::Load
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn static_import_with_the_resolved_id_as_specifier_is_resolved_on_its_own() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+          ..Default::default()
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(LocalesPlugin)],
+    )
+    .await;
+}
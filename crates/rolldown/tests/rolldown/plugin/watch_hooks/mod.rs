@@ -0,0 +1,158 @@
+use std::{
+  borrow::Cow,
+  path::Path,
+  sync::{Arc, Mutex},
+  time::Duration,
+};
+
+use rolldown::{
+  BundleEvent, Bundler, BundlerOptions, ExperimentalOptions, InputItem, Watcher, WatcherEvent,
+};
+use rolldown_common::WatcherChangeKind;
+use rolldown_plugin::{
+  HookBuildEndArgs, HookBuildStartArgs, HookNoopReturn, HookUsage, Plugin, PluginContext,
+};
+use rolldown_plugin_isolated_declaration::IsolatedDeclarationPlugin;
+use rolldown_testing::utils::TempDir;
+
+type Calls = Arc<Mutex<Vec<String>>>;
+
+/// Records the build and watch hooks in the order they are called.
+#[derive(Debug)]
+struct Recording {
+  calls: Calls,
+}
+
+impl Plugin for Recording {
+  fn name(&self) -> Cow<'static, str> {
+    "recording".into()
+  }
+
+  async fn build_start(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    self.calls.lock().unwrap().push("buildStart".to_string());
+    Ok(())
+  }
+
+  async fn build_end(
+    &self,
+    _ctx: &PluginContext,
+    _args: Option<&HookBuildEndArgs<'_>>,
+  ) -> HookNoopReturn {
+    self.calls.lock().unwrap().push("buildEnd".to_string());
+    Ok(())
+  }
+
+  async fn watch_change(
+    &self,
+    _ctx: &PluginContext,
+    path: &str,
+    event: WatcherChangeKind,
+  ) -> HookNoopReturn {
+    let file_name = Path::new(path).file_name().unwrap().to_string_lossy();
+    self.calls.lock().unwrap().push(format!("watchChange {file_name} {event}"));
+    Ok(())
+  }
+
+  async fn close_watcher(&self, _ctx: &PluginContext) -> HookNoopReturn {
+    self.calls.lock().unwrap().push("closeWatcher".to_string());
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::BuildEnd | HookUsage::WatchChange | HookUsage::CloseWatcher
+  }
+}
+
+/// Waits for the next `BUNDLE_END` event.
+async fn wait_for_bundle_end(watcher: &Watcher) {
+  let rx = Arc::clone(&watcher.emitter().rx);
+  tokio::task::spawn_blocking(move || {
+    let rx = rx.blocking_lock();
+    loop {
+      match rx.recv_timeout(Duration::from_secs(10)).expect("should receive watcher events") {
+        WatcherEvent::Event(BundleEvent::BundleEnd(_)) => return,
+        WatcherEvent::Event(BundleEvent::Error(data)) => panic!("{:?}", data.error.diagnostics),
+        _ => {}
+      }
+    }
+  })
+  .await
+  .unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn watch_change_runs_before_the_rebuild_and_close_watcher_at_close() {
+  let temp_dir = TempDir::new("watch-hooks");
+  let temp_dir = temp_dir.path();
+  let main_path = temp_dir.join("main.ts");
+  std::fs::write(
+    &main_path,
+    "import { helper } from './helper';\nexport const value: number = helper + 1;\n",
+  )
+  .unwrap();
+  std::fs::write(temp_dir.join("helper.ts"), "export const helper: number = 1;\n").unwrap();
+  let out_dir = temp_dir.join("dist");
+
+  let calls = Calls::default();
+  let bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.ts".to_string(),
+        ..Default::default()
+      }]),
+      cwd: Some(temp_dir.to_path_buf()),
+      dir: Some(out_dir.to_string_lossy().into_owned()),
+      experimental: Some(ExperimentalOptions {
+        incremental_build: Some(true),
+        ..Default::default()
+      }),
+      ..Default::default()
+    },
+    vec![
+      Arc::new(Recording { calls: Arc::clone(&calls) }),
+      Arc::new(IsolatedDeclarationPlugin::new(false, false)),
+    ],
+  );
+  let watcher =
+    Arc::new(Watcher::new(vec![Arc::new(tokio::sync::Mutex::new(bundler))], None).unwrap());
+  let handle = tokio::spawn({
+    let watcher = Arc::clone(&watcher);
+    async move { watcher.start().await }
+  });
+
+  wait_for_bundle_end(&watcher).await;
+  assert_eq!(*calls.lock().unwrap(), ["buildStart", "buildEnd"]);
+  assert!(out_dir.join("helper.d.ts").exists());
+
+  // Only `main.ts` is transformed again, `helper.d.ts` has to come from the plugin's cache.
+  std::fs::remove_dir_all(&out_dir).unwrap();
+  std::fs::write(
+    &main_path,
+    "import { helper } from './helper';\nexport const value: string = String(helper);\n",
+  )
+  .unwrap();
+  wait_for_bundle_end(&watcher).await;
+  {
+    let calls = calls.lock().unwrap();
+    let rebuild = &calls[2..];
+    let first_build_start = rebuild.iter().position(|call| call == "buildStart").unwrap();
+    assert!(first_build_start > 0, "{calls:?}");
+    assert!(
+      rebuild[..first_build_start].iter().all(|call| call == "watchChange main.ts update"),
+      "{calls:?}"
+    );
+    assert_eq!(rebuild[first_build_start + 1], "buildEnd", "{calls:?}");
+  }
+  let main_declaration = std::fs::read_to_string(out_dir.join("main.d.ts")).unwrap();
+  assert!(main_declaration.contains("value: string"), "{main_declaration}");
+  assert!(out_dir.join("helper.d.ts").exists());
+
+  watcher.close().await.unwrap();
+  handle.await.unwrap();
+  assert_eq!(calls.lock().unwrap().last().map(String::as_str), Some("closeWatcher"));
+}
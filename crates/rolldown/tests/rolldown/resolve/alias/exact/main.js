@@ -0,0 +1,7 @@
+import assert from 'node:assert';
+import config from 'config';
+import { readConfig } from './nested/reader.js';
+
+// The relative replacement points at the same module from every importer.
+assert.strictEqual(readConfig(), config);
+assert.strictEqual(config.name, 'app');
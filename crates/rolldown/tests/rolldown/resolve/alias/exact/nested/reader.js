@@ -0,0 +1,5 @@
+import config from 'config';
+
+export function readConfig() {
+  return config;
+}
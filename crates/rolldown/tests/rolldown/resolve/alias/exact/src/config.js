@@ -0,0 +1 @@
+export default { name: 'app' };
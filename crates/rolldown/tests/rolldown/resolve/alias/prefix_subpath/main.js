@@ -0,0 +1,6 @@
+import assert from 'node:assert';
+import { add } from '@/utils/math.js';
+import { title } from '@/pages/home.js';
+
+assert.strictEqual(add(1, 2), 3);
+assert.strictEqual(title, 'Home 3');
@@ -0,0 +1,3 @@
+import { add } from '@/utils/math.js';
+
+export const title = `Home ${add(1, 2)}`;
@@ -0,0 +1 @@
+export default 'libs/fake.js';
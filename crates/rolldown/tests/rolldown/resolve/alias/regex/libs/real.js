@@ -0,0 +1 @@
+export default 'libs/real.js';
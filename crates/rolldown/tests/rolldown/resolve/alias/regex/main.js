@@ -0,0 +1,7 @@
+import assert from 'node:assert';
+import fake from 'lib-fake';
+import real from 'lib-real';
+
+assert.strictEqual(fake, 'libs/fake.js');
+// `lib-real` resolves as it is, so it isn't rewritten.
+assert.strictEqual(real, 'node_modules/lib-real');
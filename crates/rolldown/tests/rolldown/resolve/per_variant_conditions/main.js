@@ -0,0 +1,3 @@
+import flavor from 'dual-dep'
+
+export { flavor }
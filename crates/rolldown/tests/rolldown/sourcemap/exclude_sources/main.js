@@ -0,0 +1,3 @@
+import { greet } from './lib/greet.js'
+
+console.log(greet('rolldown'))
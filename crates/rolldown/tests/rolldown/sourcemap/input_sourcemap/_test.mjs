@@ -0,0 +1,9 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+
+const sourceMap = JSON.parse(
+  fs.readFileSync(path.resolve(import.meta.dirname, 'dist/main.js.map'), 'utf8'),
+);
+// The maps of the inputs lead back to the TypeScript sources they were compiled from.
+assert.deepStrictEqual(sourceMap.sources, ['../src/greet.ts', '../src/double.ts', '../main.js']);
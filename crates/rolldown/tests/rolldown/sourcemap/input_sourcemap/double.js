@@ -0,0 +1,2 @@
+export const double = (n) => n * 2;
+//# sourceMappingURL=data:application/json;base64,eyJ2ZXJzaW9uIjogMywgImZpbGUiOiAiZG91YmxlLmpzIiwgInNvdXJjZXMiOiBbInNyYy9kb3VibGUudHMiXSwgInNvdXJjZXNDb250ZW50IjogWyJleHBvcnQgY29uc3QgZG91YmxlID0gKG46IG51bWJlcik6IG51bWJlciA9PiBuICogMlxuIl0sICJuYW1lcyI6IFtdLCAibWFwcGluZ3MiOiAiQUFBQSxhQUFhLFNBQVMsQ0FBQyxNQUFzQiJ9
@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import { greet } from './greet.js'
+import { double } from './double.js'
+
+assert.strictEqual(greet('rolldown'), 'Hello, rolldown!')
+assert.strictEqual(double(2), 4)
@@ -0,0 +1,5 @@
+import { added } from './added.js'
+
+export const value = added
+
+import.meta.hot.accept(() => {})
@@ -0,0 +1 @@
+import './hmr.js'
@@ -0,0 +1,2 @@
+// `child.js` calls `import.meta.hot.invalidate()`, and the update comes back to it through
+// `parent.js`.
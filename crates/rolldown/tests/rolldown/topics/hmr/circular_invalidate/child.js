@@ -0,0 +1,5 @@
+import './parent.js'
+
+export const child = 'child'
+
+import.meta.hot.accept(() => {})
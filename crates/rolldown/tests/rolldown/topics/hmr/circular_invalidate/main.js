@@ -0,0 +1,3 @@
+import './parent.js'
+
+import.meta.hot.accept('./parent.js', () => {})
@@ -0,0 +1,3 @@
+import './child.js'
+
+export const parent = 'parent'
@@ -0,0 +1,4 @@
+import './style.css'
+import { value } from './value'
+
+console.log(value)
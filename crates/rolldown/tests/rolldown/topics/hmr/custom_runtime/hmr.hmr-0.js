@@ -0,0 +1,3 @@
+export const foo = 'world'
+
+import.meta.hot.accept(() => {})
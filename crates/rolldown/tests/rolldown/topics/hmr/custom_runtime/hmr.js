@@ -0,0 +1,3 @@
+export const foo = 'hello'
+
+import.meta.hot.accept(() => {})
@@ -0,0 +1,3 @@
+export const value = 'no dep'
+
+import.meta.hot.accept(() => {})
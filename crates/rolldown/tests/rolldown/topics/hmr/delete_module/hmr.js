@@ -0,0 +1,5 @@
+import { dep } from './dep.js'
+
+export const value = dep
+
+import.meta.hot.accept(() => {})
@@ -0,0 +1,6 @@
+import { load } from './parent.js'
+
+const { value } = await load()
+if (value !== 'lazy') {
+  throw new Error(`unexpected value: ${value}`)
+}
@@ -0,0 +1,3 @@
+export const load = () => import('./lazy.js')
+
+import.meta.hot.accept('./lazy.js', () => {})
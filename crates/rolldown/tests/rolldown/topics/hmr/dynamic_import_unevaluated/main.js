@@ -0,0 +1 @@
+import './parent.js'
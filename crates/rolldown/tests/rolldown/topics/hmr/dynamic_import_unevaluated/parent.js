@@ -0,0 +1,9 @@
+// `lazy.js` is never loaded, so its update must not run this module again.
+if (globalThis.parentEvaluated) {
+  throw new Error('parent.js should not be evaluated again')
+}
+globalThis.parentEvaluated = true
+
+export const load = () => import('./lazy.js')
+
+import.meta.hot.accept('./lazy.js', () => {})
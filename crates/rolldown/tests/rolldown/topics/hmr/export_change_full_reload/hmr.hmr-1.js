@@ -0,0 +1,5 @@
+// Step 0 reloaded the page, so `bar` is still new to the exports it runs.
+export const foo = 'foo again'
+export const bar = 'bar'
+
+import.meta.hot.accept(() => {})
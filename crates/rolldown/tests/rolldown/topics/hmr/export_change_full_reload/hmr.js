@@ -0,0 +1,3 @@
+export const foo = 'foo'
+
+import.meta.hot.accept(() => {})
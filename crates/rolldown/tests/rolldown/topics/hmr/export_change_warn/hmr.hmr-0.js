@@ -0,0 +1,4 @@
+export const foo = 'foo'
+export const bar = 'bar'
+
+import.meta.hot.accept(() => {})
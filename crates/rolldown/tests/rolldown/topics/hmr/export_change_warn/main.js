@@ -0,0 +1,3 @@
+import { foo } from './hmr.js'
+
+console.log(foo)
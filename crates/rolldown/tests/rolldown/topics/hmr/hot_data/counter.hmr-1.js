@@ -0,0 +1,10 @@
+// The count is carried across updates through `hot.data`.
+export const count = (import.meta.hot.data.count ?? 0) + 1
+if (count !== 3) {
+  throw new Error(`unexpected count: ${count}`)
+}
+
+import.meta.hot.dispose((data) => {
+  data.count = count
+})
+import.meta.hot.accept(() => {})
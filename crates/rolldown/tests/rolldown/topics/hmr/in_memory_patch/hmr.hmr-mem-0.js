@@ -0,0 +1,3 @@
+export const foo = 'in memory'
+
+import.meta.hot.accept(() => {})
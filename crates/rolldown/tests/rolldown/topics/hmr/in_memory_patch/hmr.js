@@ -0,0 +1,3 @@
+export const foo = 'on disk'
+
+import.meta.hot.accept(() => {})
@@ -0,0 +1,3 @@
+export const bar = 'bar edited'
+
+import.meta.hot.accept(() => {})
@@ -0,0 +1,3 @@
+export const bar = 'bar'
+
+import.meta.hot.accept(() => {})
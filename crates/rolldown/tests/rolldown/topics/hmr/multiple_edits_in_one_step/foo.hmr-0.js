@@ -0,0 +1,3 @@
+export const foo = 'foo edited'
+
+import.meta.hot.accept(() => {})
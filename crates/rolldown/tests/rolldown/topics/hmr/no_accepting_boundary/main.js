@@ -0,0 +1,3 @@
+import { foo } from './foo.js'
+
+console.log(foo)
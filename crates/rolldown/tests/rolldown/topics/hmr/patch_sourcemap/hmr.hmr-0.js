@@ -0,0 +1,3 @@
+export const foo = 'hello world'
+
+import.meta.hot.accept(() => {})
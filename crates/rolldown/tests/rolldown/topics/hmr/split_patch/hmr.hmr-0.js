@@ -0,0 +1,10 @@
+import { a } from './a.js'
+import { b } from './b.js'
+
+export const value = a + b
+
+if (value !== 'a updatedb updated') {
+  throw new Error(`unexpected value: ${value}`)
+}
+
+import.meta.hot.accept(() => {})
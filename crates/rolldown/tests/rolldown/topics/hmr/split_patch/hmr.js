@@ -0,0 +1,6 @@
+import { a } from './a.js'
+import { b } from './b.js'
+
+export const value = a + b
+
+import.meta.hot.accept(() => {})
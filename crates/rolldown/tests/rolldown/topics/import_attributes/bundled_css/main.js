@@ -0,0 +1,4 @@
+import './theme.style' with { type: 'css' }
+// Same as `import('./dialog.css')`: the styles go to `dialog-[hash].css` and the dynamic entry is an
+// empty JS chunk, so loading the stylesheet is up to the host, e.g. a CSS plugin of a dev server.
+import('./dialog.style', { with: { type: 'css' } })
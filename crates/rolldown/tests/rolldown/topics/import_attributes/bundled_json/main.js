@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+import config, { name } from './config.data' with { type: 'json' }
+
+assert.deepStrictEqual(config, { name: 'config', enabled: true })
+assert.strictEqual(name, 'config')
+
+import('./config.data', { with: { type: 'json' } }).then((mod) => {
+  assert.strictEqual(mod.enabled, true)
+})
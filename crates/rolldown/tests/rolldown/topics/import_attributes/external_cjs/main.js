@@ -0,0 +1,4 @@
+import data from 'virtual:data.json' with { type: 'json' }
+import style from 'virtual:style.css' with { type: 'css' }
+
+console.log(data, style)
@@ -0,0 +1,26 @@
+import assert from 'node:assert'
+import { register } from 'node:module'
+
+// Node refuses to load a json module without `with { type: 'json' }`, so the output only runs if
+// the attributes are kept.
+const hooks = `
+export async function resolve(specifier, context, next) {
+  if (specifier === 'virtual:data.json') {
+    return { url: specifier, importAttributes: context.importAttributes, shortCircuit: true }
+  }
+  return next(specifier, context)
+}
+export async function load(url, context, next) {
+  if (url === 'virtual:data.json') {
+    return { format: 'json', source: '{ "answer": 42 }', shortCircuit: true }
+  }
+  return next(url, context)
+}
+`
+register(`data:text/javascript,${encodeURIComponent(hooks)}`)
+
+const main = await import('./dist/main.js')
+
+assert.strictEqual(main.answer, 42)
+assert.deepStrictEqual(main.reexported, { answer: 42 })
+assert.deepStrictEqual((await main.loadData()).default, { answer: 42 })
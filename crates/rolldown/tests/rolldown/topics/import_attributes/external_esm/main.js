@@ -0,0 +1,7 @@
+import data from 'virtual:data.json' with { type: 'json' }
+
+export { default as reexported } from 'virtual:data.json' with { type: 'json' }
+
+export const answer = data.answer
+
+export const loadData = () => import('virtual:data.json', { with: { type: 'json' } })
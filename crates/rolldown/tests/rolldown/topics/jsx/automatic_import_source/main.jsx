@@ -0,0 +1,26 @@
+import assert from 'node:assert'
+import { Fragment } from 'my-jsx/jsx-runtime'
+
+const single = <div id="a">hi</div>
+const multiple = (
+  <>
+    <span />
+    <span />
+  </>
+)
+
+assert.deepStrictEqual(single, {
+  factory: 'jsx',
+  type: 'div',
+  props: { id: 'a', children: 'hi' },
+})
+assert.deepStrictEqual(multiple, {
+  factory: 'jsxs',
+  type: Fragment,
+  props: {
+    children: [
+      { factory: 'jsx', type: 'span', props: {} },
+      { factory: 'jsx', type: 'span', props: {} },
+    ],
+  },
+})
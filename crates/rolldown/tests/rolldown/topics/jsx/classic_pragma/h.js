@@ -0,0 +1,5 @@
+export const Fragment = Symbol('Fragment')
+
+export function h(type, props, ...children) {
+  return { type, props, children }
+}
@@ -0,0 +1,14 @@
+import assert from 'node:assert'
+import { h, Fragment } from './h.js'
+
+const tree = (
+  <>
+    <div id="a">hi</div>
+  </>
+)
+
+assert.deepStrictEqual(tree, {
+  type: Fragment,
+  props: null,
+  children: [{ type: 'div', props: { id: 'a' }, children: ['hi'] }],
+})
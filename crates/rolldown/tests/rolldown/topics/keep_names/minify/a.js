@@ -0,0 +1,5 @@
+export function myFunc() {}
+
+export class MyClass {}
+
+export const myArrow = () => {}
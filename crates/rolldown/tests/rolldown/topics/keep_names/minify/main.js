@@ -0,0 +1,11 @@
+import assert from 'node:assert'
+import { myFunc, MyClass, myArrow } from './a.js'
+
+assert.strictEqual(myFunc.name, 'myFunc')
+assert.strictEqual(MyClass.name, 'MyClass')
+assert.strictEqual(myArrow.name, 'myArrow')
+
+function localFunc() {}
+class LocalClass {}
+assert.strictEqual(localFunc.name, 'localFunc')
+assert.strictEqual(LocalClass.name, 'LocalClass')
@@ -0,0 +1 @@
+mod runtime_diff;
@@ -0,0 +1,13 @@
+export const used = 'used';
+
+export const unused = 'unused';
+
+export function format(value) {
+  return `[${value}]`;
+}
+
+export function unusedFormat(value) {
+  return `(${value})`;
+}
+
+console.log('lib evaluated');
@@ -0,0 +1,5 @@
+import { format, used } from './lib.js';
+
+const pure = /* @__PURE__ */ String('dropped');
+
+console.log(format(used));
@@ -0,0 +1,19 @@
+use rolldown::BundlerOptions;
+use rolldown_common::TreeshakeOptions;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tree_shaking_keeps_the_runtime_behavior() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_diff(
+      BundlerOptions { cwd: Some(cwd.clone()), ..Default::default() },
+      BundlerOptions {
+        cwd: Some(cwd),
+        treeshake: TreeshakeOptions::Boolean(false),
+        ..Default::default()
+      },
+    )
+    .await;
+}
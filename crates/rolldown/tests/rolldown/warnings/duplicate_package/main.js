@@ -0,0 +1,5 @@
+// pnpm links `a` and `b` into `node_modules`, the relative paths stand in for the symlinks.
+import { a } from './node_modules/.pnpm/a@1.0.0/node_modules/a/index.js'
+import { b } from './node_modules/.pnpm/b@1.0.0/node_modules/b/index.js'
+
+console.log(a, b)
@@ -13,10 +13,8 @@ use crate::{
 };
 use napi::{Env, tokio::sync::Mutex};
 use napi_derive::napi;
-use rolldown::{Bundler as NativeBundler, LogLevel, NormalizedBundlerOptions};
-use rolldown_error::{
-  BuildDiagnostic, BuildResult, DiagnosticOptions, filter_out_disabled_diagnostics,
-};
+use rolldown::{Bundler as NativeBundler, NormalizedBundlerOptions};
+use rolldown_error::{BuildDiagnostic, BuildResult};
 
 #[napi(object, object_to_js = false)]
 pub struct BindingBundlerOptions<'env> {
@@ -111,7 +109,8 @@ impl Bundler {
     changed_files: Vec<String>,
   ) -> napi::Result<BindingHmrOutput> {
     let mut bundler_core = self.inner.lock().await;
-    let result = bundler_core.generate_hmr_patch(changed_files).await;
+    let result =
+      bundler_core.generate_hmr_patch(changed_files.into_iter().map(Into::into).collect()).await;
     match result {
       Ok(output) => Ok(output.into()),
       Err(errs) => {
@@ -145,7 +144,12 @@ impl Bundler {
 
     match output {
       Ok(output) => {
-        self.handle_warnings(output.warnings, bundler_core.options()).await;
+        // `scan` isn't routed through `on_log` by the core bundler like `write` and `generate` are.
+        if let Err(errs) =
+          NativeBundler::apply_on_log(bundler_core.options(), output.warnings).await
+        {
+          return Ok(Self::handle_errors(errs.into_vec(), bundler_core.options()));
+        }
       }
       Err(outputs) => {
         return Ok(outputs);
@@ -164,8 +168,6 @@ impl Bundler {
       Err(errs) => return Ok(Self::handle_errors(errs.into_vec(), bundler_core.options())),
     };
 
-    self.handle_warnings(outputs.warnings, bundler_core.options()).await;
-
     Ok(outputs.assets.into())
   }
 
@@ -178,8 +180,6 @@ impl Bundler {
       Err(errs) => return Ok(Self::handle_errors(errs.into_vec(), bundler_core.options())),
     };
 
-    self.handle_warnings(bundle_output.warnings, bundler_core.options()).await;
-
     Ok(bundle_output.assets.into())
   }
 
@@ -216,32 +216,4 @@ impl Bundler {
   ) -> Result<T, BindingOutputs> {
     result.map_err(|e| Self::handle_errors(e.into_vec(), options))
   }
-
-  #[allow(clippy::print_stdout, unused_must_use)]
-  async fn handle_warnings(
-    &self,
-    warnings: Vec<BuildDiagnostic>,
-    options: &NormalizedBundlerOptions,
-  ) {
-    if options.log_level == Some(LogLevel::Silent) {
-      return;
-    }
-    if let Some(on_log) = options.on_log.as_ref() {
-      for warning in filter_out_disabled_diagnostics(warnings, &options.checks) {
-        on_log
-          .call(
-            LogLevel::Warn,
-            rolldown::Log {
-              id: warning.id(),
-              exporter: warning.exporter(),
-              code: warning.kind().to_string(),
-              message: warning
-                .to_diagnostic_with(&DiagnosticOptions { cwd: options.cwd.clone() })
-                .to_color_string(),
-            },
-          )
-          .await;
-      }
-    }
-  }
 }
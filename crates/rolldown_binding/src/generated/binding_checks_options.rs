@@ -15,6 +15,12 @@ pub struct BindingChecksOptions {
   pub common_js_variable_in_esm: Option<bool>,
   pub import_is_undefined: Option<bool>,
   pub configuration_field_conflict: Option<bool>,
+  pub unsupported_import_attributes: Option<bool>,
+  pub shimmed_export: Option<bool>,
+  pub plugin_warning: Option<bool>,
+  pub hmr_export_change: Option<bool>,
+  pub duplicate_package: Option<bool>,
+  pub plugin_order: Option<bool>,
 }
 impl From<BindingChecksOptions> for rolldown_common::ChecksOptions {
   fn from(value: BindingChecksOptions) -> Self {
@@ -30,6 +36,12 @@ impl From<BindingChecksOptions> for rolldown_common::ChecksOptions {
       common_js_variable_in_esm: value.common_js_variable_in_esm,
       import_is_undefined: value.import_is_undefined,
       configuration_field_conflict: value.configuration_field_conflict,
+      unsupported_import_attributes: value.unsupported_import_attributes,
+      shimmed_export: value.shimmed_export,
+      plugin_warning: value.plugin_warning,
+      hmr_export_change: value.hmr_export_change,
+      duplicate_package: value.duplicate_package,
+      plugin_order: value.plugin_order,
     }
   }
 }
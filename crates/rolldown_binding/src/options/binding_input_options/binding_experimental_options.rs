@@ -1,3 +1,5 @@
+use napi::Either;
+
 #[napi_derive::napi(object)]
 #[derive(Debug, Default)]
 pub struct BindingExperimentalOptions {
@@ -7,6 +9,7 @@ pub struct BindingExperimentalOptions {
   pub resolve_new_url_to_asset: Option<bool>,
   pub hmr: Option<BindingExperimentalHmrOptions>,
   pub attach_debug_info: Option<BindingAttachDebugInfo>,
+  pub deterministic: Option<bool>,
 }
 
 impl From<BindingExperimentalOptions> for rolldown_common::ExperimentalOptions {
@@ -20,6 +23,7 @@ impl From<BindingExperimentalOptions> for rolldown_common::ExperimentalOptions {
       incremental_build: None,
       hmr: value.hmr.map(Into::into),
       attach_debug_info: value.attach_debug_info.map(Into::into),
+      deterministic: value.deterministic,
     }
   }
 }
@@ -29,12 +33,43 @@ impl From<BindingExperimentalOptions> for rolldown_common::ExperimentalOptions {
 pub struct BindingExperimentalHmrOptions {
   pub host: Option<String>,
   pub port: Option<u16>,
-  pub implement: Option<String>,
+  pub implement: Option<Either<String, BindingHmrImplementPerFormat>>,
+  #[napi(ts_type = "'full-reload' | 'warn'")]
+  pub export_change: Option<String>,
+  pub max_patch_size: Option<u32>,
 }
 
 impl From<BindingExperimentalHmrOptions> for rolldown_common::HmrOptions {
   fn from(value: BindingExperimentalHmrOptions) -> Self {
-    Self { host: value.host, port: value.port, implement: value.implement }
+    Self {
+      host: value.host,
+      port: value.port,
+      implement: value.implement.map(|implement| match implement {
+        Either::A(implement) => rolldown_common::HmrImplement::All(implement),
+        Either::B(per_format) => rolldown_common::HmrImplement::PerFormat(per_format.into()),
+      }),
+      // The value is validated on the js side.
+      export_change: value.export_change.map(|export_change| match export_change.as_str() {
+        "warn" => rolldown_common::HmrExportChange::Warn,
+        _ => rolldown_common::HmrExportChange::FullReload,
+      }),
+      max_patch_size: value.max_patch_size.map(|size| size as usize),
+    }
+  }
+}
+
+#[napi_derive::napi(object)]
+#[derive(Debug, Default)]
+pub struct BindingHmrImplementPerFormat {
+  pub esm: Option<String>,
+  pub cjs: Option<String>,
+  pub iife: Option<String>,
+  pub umd: Option<String>,
+}
+
+impl From<BindingHmrImplementPerFormat> for rolldown_common::HmrImplementPerFormat {
+  fn from(value: BindingHmrImplementPerFormat) -> Self {
+    Self { esm: value.esm, cjs: value.cjs, iife: value.iife, umd: value.umd }
   }
 }
 
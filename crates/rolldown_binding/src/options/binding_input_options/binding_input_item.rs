@@ -3,10 +3,17 @@
 pub struct BindingInputItem {
   pub name: Option<String>,
   pub import: String,
+  pub file_name: Option<String>,
+  pub banner: Option<String>,
 }
 
 impl From<BindingInputItem> for rolldown::InputItem {
   fn from(value: BindingInputItem) -> Self {
-    Self { name: value.name, import: value.import }
+    Self {
+      name: value.name,
+      import: value.import,
+      file_name: value.file_name,
+      banner: value.banner,
+    }
   }
 }
@@ -100,6 +100,7 @@ pub struct BindingInputOptions<'env> {
   pub defer_sync_scan_data: Option<BindingDeferSyncScanDataOption>,
   pub make_absolute_externals_relative: Option<BindingMakeAbsoluteExternalsRelative>,
   pub debug: Option<BindingDebugOptions>,
+  pub debug_plugin_order: Option<bool>,
   #[debug(skip)]
   #[napi(ts_type = "() => void")]
   // TODO: The `FnArgs<()>` is not supported.
@@ -0,0 +1,27 @@
+use rolldown::{GeneratedCodeOptions, GeneratedCodePreset};
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingGeneratedCodeOptions {
+  #[napi(ts_type = "'es5' | 'es2015'")]
+  pub preset: Option<String>,
+  pub arrow_functions: Option<bool>,
+  pub const_bindings: Option<bool>,
+  pub object_shorthand: Option<bool>,
+  pub symbols: Option<bool>,
+}
+
+impl From<BindingGeneratedCodeOptions> for GeneratedCodeOptions {
+  fn from(value: BindingGeneratedCodeOptions) -> Self {
+    Self {
+      preset: value.preset.map(|preset| match preset.as_str() {
+        "es5" => GeneratedCodePreset::Es5,
+        _ => GeneratedCodePreset::Es2015,
+      }),
+      arrow_functions: value.arrow_functions,
+      const_bindings: value.const_bindings,
+      object_shorthand: value.object_shorthand,
+      symbols: value.symbols,
+    }
+  }
+}
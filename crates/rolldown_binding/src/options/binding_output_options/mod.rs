@@ -1,4 +1,5 @@
 mod binding_advanced_chunks_options;
+mod binding_generated_code_options;
 mod binding_pre_rendered_asset;
 mod binding_pre_rendered_chunk;
 use binding_pre_rendered_asset::BindingPreRenderedAsset;
@@ -9,6 +10,7 @@ use napi_derive::napi;
 use rustc_hash::FxHashMap;
 
 use binding_advanced_chunks_options::BindingAdvancedChunksOptions;
+use binding_generated_code_options::BindingGeneratedCodeOptions;
 use binding_pre_rendered_chunk::PreRenderedChunk;
 
 use super::plugin::BindingPluginOrParallelJsPluginPlaceholder;
@@ -25,6 +27,9 @@ pub type AssetFileNamesOutputOption =
   Either<String, JsCallback<FnArgs<(BindingPreRenderedAsset,)>, String>>;
 pub type GlobalsOutputOption =
   Either<FxHashMap<String, String>, JsCallback<FnArgs<(String,)>, String>>;
+pub type PathsOutputOption =
+  Either<FxHashMap<String, String>, JsCallback<FnArgs<(String,)>, String>>;
+pub type SourcemapUrlOutputOption = Either<String, JsCallback<FnArgs<(String,)>, String>>;
 pub type SanitizeFileName = Either<bool, JsCallback<FnArgs<(String,)>, String>>;
 
 #[napi(object, object_to_js = false)]
@@ -57,7 +62,7 @@ pub struct BindingOutputOptions<'env> {
   #[debug(skip)]
   #[napi(ts_type = "(chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>")]
   pub banner: Option<AddonOutputOption>,
-  // compact: boolean;
+  pub compact: Option<bool>,
   pub dir: Option<String>,
   pub file: Option<String>,
   #[napi(ts_type = "boolean | 'if-default-prop'")]
@@ -72,13 +77,13 @@ pub struct BindingOutputOptions<'env> {
   #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd'")]
   pub format: Option<String>,
   // freeze: boolean;
-  // generatedCode: NormalizedGeneratedCodeOptions;
+  pub generated_code: Option<BindingGeneratedCodeOptions>,
   #[debug(skip)]
   #[napi(ts_type = "Record<string, string> | ((name: string) => string)")]
   pub globals: Option<GlobalsOutputOption>,
   #[napi(ts_type = "'base64' | 'base36' | 'hex'")]
   pub hash_characters: Option<String>,
-  // hoistTransitiveImports: boolean;
+  pub hoist_transitive_imports: Option<bool>,
   // indent: true | string;
   pub inline_dynamic_imports: Option<bool>,
   // interop: GetInterop;
@@ -92,7 +97,9 @@ pub struct BindingOutputOptions<'env> {
   #[debug(skip)]
   #[napi(ts_type = "(chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>")]
   pub outro: Option<AddonOutputOption>,
-  // paths: OptionsPaths;
+  #[debug(skip)]
+  #[napi(ts_type = "Record<string, string> | ((id: string) => string)")]
+  pub paths: Option<PathsOutputOption>,
   #[napi(ts_type = "(BindingBuiltinPlugin | BindingPluginOptions | undefined)[]")]
   pub plugins: Vec<BindingPluginOrParallelJsPluginPlaceholder<'env>>,
   // preferConst: boolean;
@@ -102,10 +109,14 @@ pub struct BindingOutputOptions<'env> {
   #[napi(ts_type = "(source: string, sourcemapPath: string) => boolean")]
   pub sourcemap_ignore_list: Option<JsCallback<FnArgs<(String, String)>, bool>>,
   pub sourcemap_debug_ids: Option<bool>,
+  pub sourcemap_include_sources: Option<bool>,
+  #[debug(skip)]
+  #[napi(ts_type = "string | ((mapFileName: string) => string)")]
+  pub sourcemap_url: Option<SourcemapUrlOutputOption>,
   #[debug(skip)]
   #[napi(ts_type = "(source: string, sourcemapPath: string) => string")]
   pub sourcemap_path_transform: Option<JsCallback<FnArgs<(String, String)>, String>>,
-  // sourcemapExcludeSources: boolean;
+  pub manifest: Option<String>,
   // sourcemapFile: string | undefined;
   // strict: boolean;
   // systemNullSetters: boolean;
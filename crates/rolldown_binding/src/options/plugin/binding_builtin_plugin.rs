@@ -24,6 +24,7 @@ use rolldown_plugin_transform::TransformPlugin;
 use rolldown_plugin_vite_resolve::ViteResolvePlugin;
 use rolldown_plugin_wasm_fallback::WasmFallbackPlugin;
 use rolldown_plugin_wasm_helper::WasmHelperPlugin;
+use rolldown_plugin_web_worker::WebWorkerPlugin;
 use rolldown_plugin_web_worker_post::WebWorkerPostPlugin;
 
 use crate::options::plugin::config::BindingModulePreloadPolyfillPluginConfig;
@@ -201,6 +202,7 @@ impl TryFrom<BindingBuiltinPlugin<'_>> for Arc<dyn Pluginable> {
       }
       BindingBuiltinPluginName::WasmFallback => Arc::new(WasmFallbackPlugin),
       BindingBuiltinPluginName::WasmHelper => Arc::new(WasmHelperPlugin),
+      BindingBuiltinPluginName::WebWorker => Arc::new(WebWorkerPlugin::default()),
       BindingBuiltinPluginName::WebWorkerPost => Arc::new(WebWorkerPostPlugin),
     })
   }
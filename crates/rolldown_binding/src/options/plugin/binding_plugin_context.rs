@@ -1,6 +1,6 @@
 use napi_derive::napi;
 
-use rolldown_plugin::{PluginContext, SharedNativePluginContext};
+use rolldown_plugin::{PluginContext, PluginContextLoadOptions, SharedNativePluginContext};
 
 use super::types::{
   binding_emitted_asset::BindingEmittedAsset, binding_emitted_chunk::BindingEmittedChunk,
@@ -26,7 +26,13 @@ impl BindingPluginContext {
   ) -> napi::Result<()> {
     self
       .inner
-      .load(&specifier, side_effects.map(Into::into))
+      .fetch_module(
+        &specifier,
+        PluginContextLoadOptions {
+          side_effects: side_effects.map(Into::into),
+          ..Default::default()
+        },
+      )
       .await
       .map_err(|program_err| napi_error::load_error(&specifier, program_err))
   }
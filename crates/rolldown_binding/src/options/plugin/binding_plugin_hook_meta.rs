@@ -20,10 +20,14 @@ impl From<BindingPluginOrder> for rolldown_plugin::PluginOrder {
 #[derive(Default)]
 pub struct BindingPluginHookMeta {
   pub order: Option<BindingPluginOrder>,
+  pub priority: Option<i32>,
 }
 
 impl From<&BindingPluginHookMeta> for rolldown_plugin::PluginHookMeta {
   fn from(value: &BindingPluginHookMeta) -> Self {
-    rolldown_plugin::PluginHookMeta { order: value.order.map(Into::into) }
+    rolldown_plugin::PluginHookMeta {
+      order: value.order.map(Into::into),
+      priority: value.priority.unwrap_or_default(),
+    }
   }
 }
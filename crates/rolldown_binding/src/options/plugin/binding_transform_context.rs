@@ -20,6 +20,11 @@ impl BindingTransformPluginContext {
     self.inner.get_combined_sourcemap().to_json_string()
   }
 
+  #[napi]
+  pub fn add_watch_file(&self, file: String) {
+    self.inner.add_watch_file_dependency(&file);
+  }
+
   #[napi]
   pub fn inner(&self) -> BindingPluginContext {
     self.inner.inner.clone().into()
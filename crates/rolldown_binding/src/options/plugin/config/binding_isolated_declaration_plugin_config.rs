@@ -4,10 +4,13 @@ use rolldown_plugin_isolated_declaration::IsolatedDeclarationPlugin;
 #[derive(Debug, Default)]
 pub struct BindingIsolatedDeclarationPluginConfig {
   pub strip_internal: Option<bool>,
+  /// Only emit declarations for entry modules and the modules their declarations import or
+  /// re-export from.
+  pub entries_only: Option<bool>,
 }
 
 impl From<BindingIsolatedDeclarationPluginConfig> for IsolatedDeclarationPlugin {
   fn from(value: BindingIsolatedDeclarationPluginConfig) -> Self {
-    Self { strip_internal: value.strip_internal.unwrap_or_default() }
+    Self::new(value.strip_internal.unwrap_or_default(), value.entries_only.unwrap_or_default())
   }
 }
@@ -7,6 +7,8 @@ pub struct BindingJsonPluginConfig {
   pub minify: Option<bool>,
   pub named_exports: Option<bool>,
   pub stringify: Option<BindingJsonPluginStringify>,
+  pub json5: Option<bool>,
+  pub json_lines: Option<bool>,
 }
 
 impl TryFrom<BindingJsonPluginConfig> for JsonPlugin {
@@ -17,6 +19,8 @@ impl TryFrom<BindingJsonPluginConfig> for JsonPlugin {
       minify: config.minify.unwrap_or_default(),
       named_exports: config.named_exports.unwrap_or_default(),
       stringify: config.stringify.map(TryInto::try_into).transpose()?.unwrap_or_default(),
+      json5: config.json5.unwrap_or_default(),
+      json_lines: config.json_lines.unwrap_or_default(),
     })
   }
 }
@@ -133,13 +133,16 @@ impl Plugin for JsPlugin {
   async fn resolve_dynamic_import(
     &self,
     ctx: &rolldown_plugin::PluginContext,
-    args: &rolldown_plugin::HookResolveIdArgs<'_>,
+    args: &rolldown_plugin::HookResolveDynamicImportArgs<'_>,
   ) -> rolldown_plugin::HookResolveIdReturn {
+    // Only static specifiers are passed to the js side for now.
+    let rolldown_plugin::DynamicImportSpecifier::Static(specifier) = args.specifier else {
+      return Ok(None);
+    };
     match &self.resolve_dynamic_import {
       Some(cb) => Ok(
         cb.await_call(
-          (ctx.clone().into(), args.specifier.to_string(), args.importer.map(str::to_string))
-            .into(),
+          (ctx.clone().into(), specifier.to_string(), args.importer.map(str::to_string)).into(),
         )
         .instrument(debug_span!("resolve_dynamic_import_hook", plugin_name = self.name))
         .await?
@@ -41,6 +41,8 @@ pub enum BindingBuiltinPluginName {
   WasmFallback,
   #[napi(value = "builtin:wasm-helper")]
   WasmHelper,
+  #[napi(value = "builtin:web-worker")]
+  WebWorker,
   #[napi(value = "builtin:web-worker-post")]
   WebWorkerPost,
 }
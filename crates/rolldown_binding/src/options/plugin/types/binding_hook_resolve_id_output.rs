@@ -21,6 +21,7 @@ impl TryFrom<BindingHookResolveIdOutput> for rolldown_plugin::HookResolveIdOutpu
       external: value.external.map(TryInto::try_into).transpose()?,
       normalize_external_id: value.normalize_external_id,
       side_effects: value.side_effects.map(Into::into),
+      meta: None,
     })
   }
 }
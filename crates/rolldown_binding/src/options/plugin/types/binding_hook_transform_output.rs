@@ -22,6 +22,7 @@ impl TryFrom<BindingHookTransformOutput> for HookTransformOutput {
       map: value.map.map(TryInto::try_into).transpose()?,
       side_effects: value.side_effects.map(Into::into),
       module_type: value.module_type.map(|ty| ModuleType::from_str_with_fallback(ty.as_str())),
+      meta: None,
     })
   }
 }
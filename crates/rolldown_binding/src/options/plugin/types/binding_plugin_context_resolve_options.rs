@@ -34,7 +34,9 @@ impl TryFrom<BindingPluginContextResolveOptions> for PluginContextResolveOptions
     Ok(Self {
       import_kind: value.import_kind.as_deref().unwrap_or("import-statement").try_into()?,
       skip_self: value.skip_self.unwrap_or(true),
+      skip_plugins: false,
       custom: Arc::new(custom),
+      conditions: vec![],
     })
   }
 }
@@ -43,29 +43,63 @@ impl From<rolldown_common::HmrOutput> for BindingHmrOutput {
 #[napi_derive::napi(object)]
 #[derive(Debug)]
 pub struct BindingHmrOutputPatch {
+  /// Code of the patch if it wasn't split, empty otherwise.
   pub code: String,
   pub filename: String,
   pub sourcemap: Option<String>,
   pub sourcemap_filename: Option<String>,
+  /// Scripts to run in order, more than one when the patch exceeds `maxPatchSize`.
+  pub patches: Vec<BindingHmrPatch>,
   pub hmr_boundaries: Vec<BindingHmrBoundaryOutput>,
   pub full_reload: bool,
   pub first_invalidated_by: Option<String>,
+  pub all_invalidated_by: Vec<String>,
   pub is_self_accepting: bool,
+  /// Modules re-executed by the patch, in the order their `dispose` handlers are called.
+  pub modules_to_dispose: Vec<String>,
+  /// The full reload reason rendered as a message.
   pub full_reload_reason: Option<String>,
+  pub full_reload_reason_detail: Option<BindingFullReloadReason>,
 }
 
 impl From<rolldown_common::HmrOutput> for BindingHmrOutputPatch {
   fn from(value: rolldown_common::HmrOutput) -> Self {
+    let BindingHmrPatch { code, filename, sourcemap, sourcemap_filename } =
+      value.single_patch().map(Into::into).unwrap_or_default();
     Self {
-      code: value.code,
-      filename: value.filename,
-      sourcemap: value.sourcemap,
-      sourcemap_filename: value.sourcemap_filename,
+      code,
+      filename,
+      sourcemap,
+      sourcemap_filename,
+      patches: value.patches.iter().map(Into::into).collect(),
       hmr_boundaries: value.hmr_boundaries.into_iter().map(Into::into).collect(),
       full_reload: value.full_reload,
       first_invalidated_by: value.first_invalidated_by,
+      all_invalidated_by: value.all_invalidated_by,
       is_self_accepting: value.is_self_accepting,
-      full_reload_reason: value.full_reload_reason,
+      modules_to_dispose: value.modules_to_dispose,
+      full_reload_reason: value.full_reload_reason.as_ref().map(ToString::to_string),
+      full_reload_reason_detail: value.full_reload_reason.map(Into::into),
+    }
+  }
+}
+
+#[napi_derive::napi(object)]
+#[derive(Debug, Default)]
+pub struct BindingHmrPatch {
+  pub code: String,
+  pub filename: String,
+  pub sourcemap: Option<String>,
+  pub sourcemap_filename: Option<String>,
+}
+
+impl From<&rolldown_common::HmrPatch> for BindingHmrPatch {
+  fn from(value: &rolldown_common::HmrPatch) -> Self {
+    Self {
+      code: value.code.clone(),
+      filename: value.filename.clone(),
+      sourcemap: value.map.as_ref().map(rolldown_sourcemap::SourceMap::to_json_string),
+      sourcemap_filename: value.sourcemap_filename.clone(),
     }
   }
 }
@@ -75,10 +109,33 @@ impl From<rolldown_common::HmrOutput> for BindingHmrOutputPatch {
 pub struct BindingHmrBoundaryOutput {
   pub boundary: String,
   pub accepted_via: String,
+  /// `acceptedVia` is dynamically imported by `boundary`. If it wasn't evaluated yet, the patch
+  /// leaves it alone and only its cached copy needs to be dropped.
+  pub unevaluated_ok: bool,
 }
 
 impl From<rolldown_common::HmrBoundaryOutput> for BindingHmrBoundaryOutput {
   fn from(value: rolldown_common::HmrBoundaryOutput) -> Self {
-    Self { boundary: value.boundary.to_string(), accepted_via: value.accepted_via.to_string() }
+    Self {
+      boundary: value.boundary.to_string(),
+      accepted_via: value.accepted_via.to_string(),
+      unevaluated_ok: value.unevaluated_ok,
+    }
+  }
+}
+
+#[napi_derive::napi(object)]
+#[derive(Debug)]
+pub struct BindingFullReloadReason {
+  #[napi(
+    ts_type = "'no-accepting-boundary' | 'circular-accept-chain' | 'new-module-added' | 'html-or-config-change' | 'exports-changed' | 'module-deleted' | 'unknown'"
+  )]
+  pub kind: String,
+  pub module: Option<String>,
+}
+
+impl From<rolldown_common::FullReloadReason> for BindingFullReloadReason {
+  fn from(value: rolldown_common::FullReloadReason) -> Self {
+    Self { kind: value.kind().to_string(), module: value.module().map(ToString::to_string) }
   }
 }
@@ -230,6 +230,16 @@ impl BindingNormalizedOptions {
     self.inner.sourcemap_debug_ids
   }
 
+  #[napi(getter)]
+  pub fn sourcemap_include_sources(&self) -> bool {
+    self.inner.sourcemap_include_sources
+  }
+
+  #[napi(getter)]
+  pub fn compact(&self) -> bool {
+    self.inner.compact
+  }
+
   #[napi(getter, ts_return_type = "false | BindingMinifyOptions")]
   pub fn minify(&self) -> Either<bool, BindingMinifyOptions> {
     match &self.inner.minify {
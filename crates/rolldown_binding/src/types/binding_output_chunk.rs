@@ -69,6 +69,23 @@ impl BindingOutputChunk {
     self.inner.dynamic_imports.iter().map(ArcStr::to_string).collect()
   }
 
+  #[napi(getter)]
+  pub fn imported_bindings(&self) -> HashMap<String, Vec<String>, FxBuildHasher> {
+    self
+      .inner
+      .imported_bindings
+      .iter()
+      .map(|(importee, bindings)| {
+        (importee.to_string(), bindings.iter().map(ToString::to_string).collect())
+      })
+      .collect()
+  }
+
+  #[napi(getter)]
+  pub fn imported_css(&self) -> Vec<String> {
+    self.inner.imported_css.iter().map(ArcStr::to_string).collect()
+  }
+
   // OutputChunk
   #[napi(getter)]
   pub fn code(&self) -> String {
@@ -85,6 +102,11 @@ impl BindingOutputChunk {
     self.inner.sourcemap_filename.clone()
   }
 
+  #[napi(getter)]
+  pub fn debug_id(&self) -> Option<String> {
+    self.inner.debug_id.clone()
+  }
+
   #[napi(getter)]
   pub fn preliminary_file_name(&self) -> String {
     self.inner.preliminary_filename.to_string()
@@ -3,7 +3,7 @@ use super::{
   binding_output_chunk::{BindingOutputChunk, JsOutputChunk, update_output_chunk},
 };
 use napi_derive::napi;
-use rolldown_error::{BuildDiagnostic, DiagnosticOptions};
+use rolldown_error::{BuildDiagnostic, ColorMode, DiagnosticOptions};
 
 // The `BindingOutputs` take the data to js side, the rust side will not use it anymore.
 #[napi]
@@ -110,7 +110,9 @@ pub fn to_js_diagnostic(
     }
     Err(error) => napi::Either::B(BindingError {
       kind: error.kind().to_string(),
-      message: error.to_diagnostic_with(&DiagnosticOptions { cwd }).to_color_string(),
+      message: error
+        .to_diagnostic_with(&DiagnosticOptions::new(cwd, ColorMode::Auto))
+        .to_string_with_color(ColorMode::Auto),
     }),
   }
 }
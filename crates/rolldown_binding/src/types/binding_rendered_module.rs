@@ -24,6 +24,21 @@ impl BindingRenderedModule {
   pub fn rendered_exports(&self) -> Vec<String> {
     self.inner.rendered_exports.iter().map(std::string::ToString::to_string).collect()
   }
+
+  #[napi(getter)]
+  pub fn original_length(&self) -> u32 {
+    self.inner.original_length
+  }
+
+  #[napi(getter)]
+  pub fn rendered_length(&self) -> u32 {
+    self.inner.rendered_length
+  }
+
+  #[napi(getter)]
+  pub fn minified_length(&self) -> Option<u32> {
+    self.inner.minified_length
+  }
 }
 
 impl Debug for BindingRenderedModule {
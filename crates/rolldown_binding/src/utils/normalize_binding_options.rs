@@ -116,6 +116,36 @@ fn normalize_globals_option(
   })
 }
 
+fn normalize_paths_option(
+  option: Option<crate::options::PathsOutputOption>,
+) -> Option<rolldown_common::PathsOutputOption> {
+  option.map(move |value| match value {
+    Either::A(hash_map) => {
+      rolldown_common::PathsOutputOption::FxHashMap(hash_map.into_iter().collect())
+    }
+    Either::B(func) => rolldown_common::PathsOutputOption::Fn(Arc::new(move |id| {
+      let func = Arc::clone(&func);
+      let id = id.to_string();
+      Box::pin(async move { func.invoke_async((id,).into()).await.map_err(anyhow::Error::from) })
+    })),
+  })
+}
+
+fn normalize_sourcemap_url_option(
+  option: Option<crate::options::SourcemapUrlOutputOption>,
+) -> Option<rolldown_common::SourcemapUrl> {
+  option.map(move |value| match value {
+    Either::A(base) => rolldown_common::SourcemapUrl::String(base),
+    Either::B(func) => rolldown_common::SourcemapUrl::Fn(Arc::new(move |map_filename| {
+      let func = Arc::clone(&func);
+      let map_filename = map_filename.to_string();
+      Box::pin(async move {
+        func.invoke_async((map_filename,).into()).await.map_err(anyhow::Error::from)
+      })
+    })),
+  })
+}
+
 fn normalize_es_target(target: Option<&Either<String, Vec<String>>>) -> ESTarget {
   target.map_or(ESTarget::ESNext, |target| {
     let targets = match target {
@@ -237,9 +267,12 @@ pub fn normalize_binding_options(
     rolldown::OnLog::new(Arc::new(move |level, log| {
       let ts_fn = Arc::clone(&ts_fn);
       Box::pin(async move {
+        // The JS side takes care of the log completely, including escalating it to an error, so it
+        // never flows back to rust.
         ts_fn
           .invoke_async((level.to_string(), log.into()).into())
           .await
+          .map(|()| rolldown::LogAction::Skip)
           .map_err(anyhow::Error::from)
       })
     }))
@@ -307,6 +340,7 @@ pub fn normalize_binding_options(
       None => rolldown::TreeshakeOptions::Boolean(false),
     },
     resolve: input_options.resolve.map(Into::into),
+    alias: None,
     platform: input_options
       .platform
       .as_deref()
@@ -335,6 +369,9 @@ pub fn normalize_binding_options(
     sourcemap_ignore_list,
     sourcemap_path_transform,
     sourcemap_debug_ids: output_options.sourcemap_debug_ids,
+    sourcemap_include_sources: output_options.sourcemap_include_sources,
+    sourcemap_url: normalize_sourcemap_url_option(output_options.sourcemap_url),
+    manifest: output_options.manifest,
     exports: output_options.exports.map(|format_str| match format_str.as_str() {
       "auto" => OutputExports::Auto,
       "default" => OutputExports::Default,
@@ -356,8 +393,11 @@ pub fn normalize_binding_options(
       _ => panic!("Invalid hash characters: {format_str}"),
     }),
     globals: normalize_globals_option(output_options.globals),
+    paths: normalize_paths_option(output_options.paths),
+    generated_code: output_options.generated_code.map(Into::into),
     module_types,
     experimental: input_options.experimental.map(Into::into),
+    compact: output_options.compact,
     minify: output_options
       .minify
       .map(|opts| match opts {
@@ -430,6 +470,7 @@ pub fn normalize_binding_options(
     drop_labels: input_options.drop_labels,
     keep_names: input_options.keep_names,
     polyfill_require: output_options.polyfill_require,
+    hoist_transitive_imports: output_options.hoist_transitive_imports,
     defer_sync_scan_data: get_defer_sync_scan_data,
     transform,
     make_absolute_externals_relative: input_options
@@ -439,7 +480,14 @@ pub fn normalize_binding_options(
     invalidate_js_side_cache,
     mark_module_loaded,
     log_level: Some(input_options.log_level.into()),
+    log_format: None,
+    log_color: None,
+    severity_overrides: None,
+    dedupe_diagnostics: None,
     on_log,
+    debug_plugin_order: input_options.debug_plugin_order,
+    cache_dir: None,
+    file_system: None,
     preserve_modules: output_options.preserve_modules,
     virtual_dirname: output_options.virtual_dirname,
     preserve_modules_root: output_options.preserve_modules_root,
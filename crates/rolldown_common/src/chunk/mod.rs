@@ -41,6 +41,8 @@ pub struct Chunk {
   pub name: Option<ArcStr>,
   // emitted chunk specified filename, used to generate chunk filename
   pub file_name: Option<ArcStr>,
+  // user defined entry specified banner, replacing the `banner` option
+  pub banner: Option<ArcStr>,
   // emitted chunk corresponding reference_id, used to `PluginContext#getFileName` to search the emitted chunk name
   pub pre_rendered_chunk: Option<RollupPreRenderedChunk>,
   pub preliminary_filename: Option<PreliminaryFilename>,
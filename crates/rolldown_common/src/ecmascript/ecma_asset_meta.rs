@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
 use arcstr::ArcStr;
+use rolldown_rstr::Rstr;
+use rustc_hash::FxHashMap;
 
 use crate::RollupRenderedChunk;
 
@@ -12,4 +14,8 @@ pub struct EcmaAssetMeta {
   // The updated fields of rendered_chunk after the final render
   pub imports: Vec<ArcStr>,
   pub dynamic_imports: Vec<ArcStr>,
+  pub imported_bindings: FxHashMap<ArcStr, Vec<Rstr>>,
+  pub imported_css: Vec<ArcStr>,
+  /// The length of the chunk before it was minified, if it was.
+  pub unminified_length: Option<usize>,
 }
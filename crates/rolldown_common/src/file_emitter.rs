@@ -2,7 +2,6 @@ use crate::{
   AddEntryModuleMsg, FilenameTemplate, ModuleLoaderMsg, NormalizedBundlerOptions, Output,
   OutputAsset, PreserveEntrySignatures, StrOrBytes,
 };
-use anyhow::Context;
 use arcstr::ArcStr;
 use dashmap::{DashMap, DashSet};
 use rolldown_error::BuildDiagnostic;
@@ -44,6 +43,16 @@ pub struct EmittedChunkInfo {
   pub filename: ArcStr,
 }
 
+/// The files emitted before the generate stage. They are shared by every output of a multi-output
+/// build instead of being emitted again for each output.
+#[derive(Debug, Default, Clone)]
+pub struct BuildPhaseFiles {
+  files: Vec<(ArcStr, OutputAsset)>,
+  names: Vec<(ArcStr, u32)>,
+  source_hash_to_reference_id: Vec<(ArcStr, ArcStr)>,
+  base_reference_id: usize,
+}
+
 #[derive(Debug)]
 pub struct FileEmitter {
   tx: Arc<Mutex<Option<tokio::sync::mpsc::Sender<ModuleLoaderMsg>>>>,
@@ -52,7 +61,6 @@ pub struct FileEmitter {
   files: FxDashMap<ArcStr, OutputAsset>,
   chunks: FxDashMap<ArcStr, Arc<EmittedChunk>>,
   base_reference_id: AtomicUsize,
-  #[allow(dead_code)]
   options: Arc<NormalizedBundlerOptions>,
   /// Mark the files that have been emitted to bundle.
   emitted_files: FxDashSet<ArcStr>,
@@ -83,16 +91,14 @@ impl FileEmitter {
   }
 
   pub async fn emit_chunk(&self, chunk: Arc<EmittedChunk>) -> anyhow::Result<ArcStr> {
+    let tx = self.tx.lock().await;
+    let tx = tx.as_ref().ok_or_else(BuildDiagnostic::invalid_rollup_phase_for_chunk_emission)?;
     let reference_id = self.assign_reference_id(chunk.name.clone());
-    self
-    .tx
-    .lock()
-    .await
-    .as_ref()
-    .context(
-      "The `PluginContext.emitFile` with `type: 'chunk'` only work at `buildStart/resolveId/load/transform/moduleParsed` hooks.",
-    )?
-    .send(ModuleLoaderMsg::AddEntryModule(Box::new(AddEntryModuleMsg { chunk: Arc::clone(&chunk), reference_id: reference_id.clone(), preserve_entry_signatures: chunk.preserve_entry_signatures })))
+    tx.send(ModuleLoaderMsg::AddEntryModule(Box::new(AddEntryModuleMsg {
+      chunk: Arc::clone(&chunk),
+      reference_id: reference_id.clone(),
+      preserve_entry_signatures: chunk.preserve_entry_signatures,
+    })))
     .await?;
     self.chunks.insert(reference_id.clone(), chunk);
     Ok(reference_id)
@@ -207,7 +213,12 @@ impl FileEmitter {
     bundle: &mut Vec<Output>,
     warnings: &mut Vec<BuildDiagnostic>,
   ) {
-    self.files.iter_mut().for_each(|mut file| {
+    let mut files = self.files.iter_mut().collect::<Vec<_>>();
+    if self.options.experimental.is_deterministic_enabled() {
+      // The files are stored by reference id, which depends on the order plugins emitted them in.
+      files.sort_unstable_by(|a, b| a.filename.cmp(&b.filename));
+    }
+    files.into_iter().for_each(|mut file| {
       let (key, value) = file.pair_mut();
       if self.emitted_files.contains(key) {
         return;
@@ -243,6 +254,40 @@ impl FileEmitter {
     *tx_guard = tx;
   }
 
+  pub fn snapshot_build_phase_files(&self) -> BuildPhaseFiles {
+    BuildPhaseFiles {
+      files: self.files.iter().map(|item| (item.key().clone(), item.value().clone())).collect(),
+      names: self.names.iter().map(|item| (item.key().clone(), *item.value())).collect(),
+      source_hash_to_reference_id: self
+        .source_hash_to_reference_id
+        .iter()
+        .map(|item| (item.key().clone(), item.value().clone()))
+        .collect(),
+      base_reference_id: self.base_reference_id.load(Ordering::Relaxed),
+    }
+  }
+
+  /// Drop the files emitted while rendering the previous output and make the build phase files
+  /// available to be added to the next output.
+  pub fn restore_build_phase_files(&self, snapshot: &BuildPhaseFiles) {
+    self.files.clear();
+    for (reference_id, file) in &snapshot.files {
+      self.files.insert(reference_id.clone(), file.clone());
+    }
+    self.names.clear();
+    for (name, count) in &snapshot.names {
+      self.names.insert(name.clone(), *count);
+    }
+    self.source_hash_to_reference_id.clear();
+    for (hash, reference_id) in &snapshot.source_hash_to_reference_id {
+      self.source_hash_to_reference_id.insert(hash.clone(), reference_id.clone());
+    }
+    self.base_reference_id.store(snapshot.base_reference_id, Ordering::Relaxed);
+    self.emitted_files.clear();
+    self.emitted_chunks.clear();
+    self.emitted_filenames.clear();
+  }
+
   pub fn clear(&self) {
     self.chunks.clear();
     self.files.clear();
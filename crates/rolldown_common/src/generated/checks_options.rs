@@ -23,6 +23,12 @@ pub struct ChecksOptions {
   pub common_js_variable_in_esm: Option<bool>,
   pub import_is_undefined: Option<bool>,
   pub configuration_field_conflict: Option<bool>,
+  pub unsupported_import_attributes: Option<bool>,
+  pub shimmed_export: Option<bool>,
+  pub plugin_warning: Option<bool>,
+  pub hmr_export_change: Option<bool>,
+  pub duplicate_package: Option<bool>,
+  pub plugin_order: Option<bool>,
 }
 impl From<ChecksOptions> for rolldown_error::EventKindSwitcher {
   fn from(value: ChecksOptions) -> Self {
@@ -65,6 +71,23 @@ impl From<ChecksOptions> for rolldown_error::EventKindSwitcher {
       rolldown_error::EventKindSwitcher::ConfigurationFieldConflict,
       value.configuration_field_conflict.unwrap_or(true),
     );
+    flag.set(
+      rolldown_error::EventKindSwitcher::UnsupportedImportAttributes,
+      value.unsupported_import_attributes.unwrap_or(true),
+    );
+    flag
+      .set(rolldown_error::EventKindSwitcher::ShimmedExport, value.shimmed_export.unwrap_or(true));
+    flag
+      .set(rolldown_error::EventKindSwitcher::PluginWarning, value.plugin_warning.unwrap_or(true));
+    flag.set(
+      rolldown_error::EventKindSwitcher::HmrExportChange,
+      value.hmr_export_change.unwrap_or(true),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::DuplicatePackage,
+      value.duplicate_package.unwrap_or(true),
+    );
+    flag.set(rolldown_error::EventKindSwitcher::PluginOrder, value.plugin_order.unwrap_or(true));
     flag
   }
 }
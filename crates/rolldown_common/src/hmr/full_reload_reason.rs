@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+/// Why an HMR update falls back to reloading the whole page.
+///
+/// `NewModuleAdded`, `HtmlOrConfigChange` and `Unknown` are never reported by the bundler itself.
+/// They're reserved for dev servers and clients that decide on a reload of their own, so every
+/// reason reaches the client through the same field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FullReloadReason {
+  /// The update of `module` propagated to an entry without reaching an `import.meta.hot.accept`.
+  NoAcceptingBoundary { module: String },
+  /// `import.meta.hot.invalidate()` propagated back to the module that started the update.
+  CircularAcceptChain,
+  /// `module` was added and can't be reached from the modules already running. Reserved for dev
+  /// servers, the bundler patches added modules in.
+  NewModuleAdded { module: String },
+  /// A file outside the module graph, like an html page or the config, changed. Reserved for dev
+  /// servers, which own those files.
+  HtmlOrConfigChange,
+  /// Exports of `module`, which accepts its own updates, changed while its importers still hold
+  /// the old bindings.
+  ExportsChanged { module: String, added: Vec<String>, removed: Vec<String> },
+  /// `module` was deleted and no hmr boundary accepts the update of the modules importing it.
+  ModuleDeleted { module: String },
+  /// Any other reason, described by a message. Reserved for dev servers and clients.
+  Unknown(String),
+}
+
+impl FullReloadReason {
+  /// Stable name of the variant for clients that categorize reasons, e.g. in telemetry.
+  pub fn kind(&self) -> &'static str {
+    match self {
+      Self::NoAcceptingBoundary { .. } => "no-accepting-boundary",
+      Self::CircularAcceptChain => "circular-accept-chain",
+      Self::NewModuleAdded { .. } => "new-module-added",
+      Self::HtmlOrConfigChange => "html-or-config-change",
+      Self::ExportsChanged { .. } => "exports-changed",
+      Self::ModuleDeleted { .. } => "module-deleted",
+      Self::Unknown(_) => "unknown",
+    }
+  }
+
+  /// The module the reason is about, if any.
+  pub fn module(&self) -> Option<&str> {
+    match self {
+      Self::NoAcceptingBoundary { module }
+      | Self::NewModuleAdded { module }
+      | Self::ExportsChanged { module, .. }
+      | Self::ModuleDeleted { module } => Some(module),
+      Self::CircularAcceptChain | Self::HtmlOrConfigChange | Self::Unknown(_) => None,
+    }
+  }
+}
+
+impl Display for FullReloadReason {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::NoAcceptingBoundary { module } => {
+        write!(f, "no hmr boundary accepts the update of {module}")
+      }
+      Self::CircularAcceptChain => write!(f, "circular import invalidate"),
+      Self::NewModuleAdded { module } => write!(f, "new module {module} was added"),
+      Self::HtmlOrConfigChange => write!(f, "html or config changed"),
+      Self::ExportsChanged { module, added, removed } => write!(
+        f,
+        "exports of {module} changed (added: [{}], removed: [{}])",
+        added.join(", "),
+        removed.join(", ")
+      ),
+      Self::ModuleDeleted { module } => write!(f, "module {module} was deleted"),
+      Self::Unknown(reason) => write!(f, "{reason}"),
+    }
+  }
+}
@@ -4,4 +4,7 @@ use crate::ModuleIdx;
 pub struct HmrBoundary {
   pub boundary: ModuleIdx,
   pub accepted_via: ModuleIdx,
+  /// `boundary` only reaches `accepted_via` through `import()`, so the update can be skipped while
+  /// `accepted_via` hasn't been evaluated yet.
+  pub unevaluated_ok: bool,
 }
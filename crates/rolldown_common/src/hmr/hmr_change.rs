@@ -0,0 +1,16 @@
+/// A change that triggers an HMR update.
+#[derive(Debug, Clone)]
+pub enum HmrChange {
+  /// The file at this absolute path was edited on disk.
+  Path(String),
+  /// The module with this id should be rebuilt from `code` instead of reading it from disk. Modules
+  /// that aren't backed by a file, like virtual modules, are loaded by a plugin, so they're only
+  /// invalidated and `code` is ignored for them: their `load` hook runs again instead.
+  Patched { id: String, code: String },
+}
+
+impl From<String> for HmrChange {
+  fn from(path: String) -> Self {
+    Self::Path(path)
+  }
+}
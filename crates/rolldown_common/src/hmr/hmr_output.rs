@@ -1,20 +1,52 @@
 use arcstr::ArcStr;
+use rolldown_error::BuildDiagnostic;
+use rolldown_sourcemap::SourceMap;
+
+use crate::FullReloadReason;
 
 #[derive(Default)]
 pub struct HmrOutput {
-  pub code: String,
-  pub filename: String,
-  pub sourcemap: Option<String>,
-  pub sourcemap_filename: Option<String>,
+  /// Scripts to run in order to apply the update. Patches larger than
+  /// `experimental.hmr.max_patch_size` are split into parts followed by a manifest calling them.
+  pub patches: Vec<HmrPatch>,
   pub hmr_boundaries: Vec<HmrBoundaryOutput>,
   pub full_reload: bool,
   pub first_invalidated_by: Option<String>,
-  pub is_self_accepting: bool,            // only for hmr invalidate
-  pub full_reload_reason: Option<String>, // only for hmr invalidate
+  /// Modules whose edit or invalidation started this update, in the order they were reported.
+  pub all_invalidated_by: Vec<String>,
+  pub is_self_accepting: bool, // only for hmr invalidate
+  /// Stable ids of the modules re-executed by the patch, ordered from the changed modules up to
+  /// the boundaries. The patch disposes them in this order before running them again.
+  pub modules_to_dispose: Vec<String>,
+  pub full_reload_reason: Option<FullReloadReason>,
+  pub warnings: Vec<BuildDiagnostic>,
+}
+
+impl HmrOutput {
+  /// The patch of an update that wasn't split. `None` for split patches and updates without code,
+  /// like full reloads.
+  pub fn single_patch(&self) -> Option<&HmrPatch> {
+    match self.patches.as_slice() {
+      [patch] => Some(patch),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Default)]
+pub struct HmrPatch {
+  pub filename: String,
+  pub code: String,
+  /// Only present for `file` and `hidden` sourcemaps. Inline ones are appended to `code`.
+  pub map: Option<SourceMap>,
+  pub sourcemap_filename: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct HmrBoundaryOutput {
   pub boundary: ArcStr,
   pub accepted_via: ArcStr,
+  /// `accepted_via` is dynamically imported by `boundary`. If the client hasn't evaluated it yet,
+  /// the patch doesn't touch it and the client only needs to drop its cached copy of the module.
+  pub unevaluated_ok: bool,
 }
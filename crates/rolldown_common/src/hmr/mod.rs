@@ -1,2 +1,4 @@
+pub mod full_reload_reason;
 pub mod hmr_boundary;
+pub mod hmr_change;
 pub mod hmr_output;
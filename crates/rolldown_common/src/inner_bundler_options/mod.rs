@@ -1,20 +1,25 @@
 use rolldown_utils::indexmap::FxIndexMap;
 use rustc_hash::FxHashMap;
-use std::{fmt::Debug, path::PathBuf};
+use std::{fmt::Debug, path::PathBuf, sync::Arc};
 use types::advanced_chunks_options::AdvancedChunksOptions;
+use types::alias_entry::AliasEntry;
 use types::debug_options::DebugOptions;
 use types::inject_import::InjectImport;
 use types::invalidate_js_side_cache::InvalidateJsSideCache;
 use types::legal_comments::LegalComments;
+use types::log_color::LogColor;
+use types::log_format::LogFormat;
 use types::log_level::LogLevel;
 use types::make_absolute_externals_relative::MakeAbsoluteExternalsRelative;
 use types::mark_module_loaded::MarkModuleLoaded;
 use types::minify_options::RawMinifyOptions;
 use types::on_log::OnLog;
 use types::output_option::{
-  AssetFilenamesOutputOption, GlobalsOutputOption, PreserveEntrySignatures,
+  AssetFilenamesOutputOption, GeneratedCodeOptions, GlobalsOutputOption, PathsOutputOption,
+  PreserveEntrySignatures,
 };
 use types::sanitize_filename::SanitizeFilename;
+use types::severity_override::SeverityOverride;
 use types::watch_option::WatchOption;
 
 #[cfg(feature = "deserialize_bundler_options")]
@@ -31,11 +36,12 @@ use self::types::{
   hash_characters::HashCharacters, input_item::InputItem, is_external::IsExternal,
   output_exports::OutputExports, output_format::OutputFormat, output_option::AddonOutputOption,
   platform::Platform, resolve_options::ResolveOptions, source_map_type::SourceMapType,
-  sourcemap_path_transform::SourceMapPathTransform,
+  sourcemap_path_transform::SourceMapPathTransform, sourcemap_url::SourcemapUrl,
 };
 
 use crate::{
-  ChecksOptions, ChunkFilenamesOutputOption, ModuleType, SourceMapIgnoreList, TransformOptions,
+  BundlerFileSystem, ChecksOptions, ChunkFilenamesOutputOption, ModuleType, SourceMapIgnoreList,
+  TransformOptions,
 };
 
 pub mod types;
@@ -106,6 +112,13 @@ pub struct BundlerOptions {
     schemars(with = "Option<FxHashMap<String, String>>")
   )]
   pub globals: Option<GlobalsOutputOption>,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_paths"),
+    schemars(with = "Option<FxHashMap<String, String>>")
+  )]
+  pub paths: Option<PathsOutputOption>,
+  pub generated_code: Option<GeneratedCodeOptions>,
   pub sourcemap: Option<SourceMapType>,
   pub es_module: Option<EsModuleFlag>,
   pub drop_labels: Option<Vec<String>>,
@@ -147,11 +160,27 @@ pub struct BundlerOptions {
   )]
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
   pub sourcemap_debug_ids: Option<bool>,
+  /// Rewrites the URL in the `//# sourceMappingURL=` comment, e.g. to serve the maps from a CDN.
+  /// Only used with `sourcemap: File`, as `Hidden` leaves the comment out.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_sourcemap_url"),
+    schemars(with = "Option<String>")
+  )]
+  pub sourcemap_url: Option<SourcemapUrl>,
+  /// Whether the sourcemaps embed the code of their sources as `sourcesContent`. Defaults to `true`.
+  pub sourcemap_include_sources: Option<bool>,
+  /// File name of a JSON asset mapping the stabilized id of each entry to its hashed file name, the
+  /// chunks it imports statically and dynamically, and the assets emitted for its modules.
+  pub manifest: Option<String>,
 
   /// Key is the file extension. The extension should start with a `.`. E.g. `".txt"`.
   pub module_types: Option<FxHashMap<String, ModuleType>>,
   // --- options for resolve
   pub resolve: Option<ResolveOptions>,
+  /// Applied before the specifiers are resolved, unlike `resolve.alias`. The first matching entry
+  /// is used.
+  pub alias: Option<Vec<AliasEntry>>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(deserialize_with = "deserialize_treeshake", default)
@@ -159,6 +188,9 @@ pub struct BundlerOptions {
   pub treeshake: TreeshakeOptions,
   pub experimental: Option<ExperimentalOptions>,
   pub minify: Option<RawMinifyOptions>,
+  /// Prints the code without the whitespace that isn't needed, but unlike `minify`, keeps the
+  /// identifiers and the syntax as they are. Defaults to `false`.
+  pub compact: Option<bool>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     schemars(with = "Option<FxHashMap<String, String>>")
@@ -181,6 +213,7 @@ pub struct BundlerOptions {
   pub watch: Option<WatchOption>,
   pub legal_comments: Option<LegalComments>,
   pub polyfill_require: Option<bool>,
+  pub hoist_transitive_imports: Option<bool>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, skip_deserializing),
@@ -202,12 +235,37 @@ pub struct BundlerOptions {
   )]
   pub mark_module_loaded: Option<MarkModuleLoaded>,
   pub log_level: Option<LogLevel>,
+  /// Severity of diagnostics by kind, e.g. `CIRCULAR_DEPENDENCY`. Warnings promoted to errors fail
+  /// the build after all of them are collected.
+  pub severity_overrides: Option<FxHashMap<String, SeverityOverride>>,
+  /// Format of the `message` of the logs passed to `on_log`. `json` lets embedders emit NDJSON.
+  pub log_format: Option<LogFormat>,
+  /// Whether `text` messages of the logs passed to `on_log` contain ANSI colors.
+  pub log_color: Option<LogColor>,
+  /// Fold warnings with the same kind, message and primary file into one carrying an occurrence
+  /// count. Defaults to `true`; turn it off to see every report while debugging.
+  pub dedupe_diagnostics: Option<bool>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, skip_deserializing),
     schemars(skip)
   )]
   pub on_log: Option<OnLog>,
+  /// Report the order in which plugins run for each hook as a `PLUGIN_ORDER` info diagnostic of
+  /// the build.
+  pub debug_plugin_order: Option<bool>,
+  /// Directory to persist the `load` and `transform` results of modules in, so later builds skip
+  /// them for the files whose content didn't change. Relative paths are resolved against `cwd`.
+  /// Plugins can opt modules out with `Plugin::should_cache_module`.
+  pub cache_dir: Option<PathBuf>,
+  /// Where modules are read from and `Bundler::write` writes to, instead of the disk. The
+  /// resolver uses it too, so the ids of the modules are paths in it.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub file_system: Option<Arc<dyn BundlerFileSystem>>,
   pub preserve_modules: Option<bool>,
   pub virtual_dirname: Option<String>,
   pub preserve_modules_root: Option<String>,
@@ -258,6 +316,15 @@ where
   Ok(deserialized.map(From::from))
 }
 
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_sourcemap_url<'de, D>(deserializer: D) -> Result<Option<SourcemapUrl>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<String>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
 fn deserialize_asset_filenames<'de, D>(
   deserializer: D,
@@ -289,6 +356,15 @@ where
   Ok(deserialized.map(From::from))
 }
 
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_paths<'de, D>(deserializer: D) -> Result<Option<PathsOutputOption>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<FxHashMap<String, String>>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
 fn deserialize_treeshake<'de, D>(deserializer: D) -> Result<TreeshakeOptions, D::Error>
 where
@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+
+use rolldown_utils::pattern_filter::StringOrRegex;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::{Deserialize, Deserializer};
+
+/// Rewrites the specifiers matching `find` to `replacement` before they are resolved.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct AliasEntry {
+  /// A string matches the specifier equal to it and the ones it prefixes followed by a `/`, like
+  /// `@` for `@/utils`. A regex, given as `{ "regex": "..." }`, matches anywhere in the specifier
+  /// and the matched part is replaced, with `$1` and alike referring to its groups.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(deserialize_with = "deserialize_find"),
+    schemars(with = "RawFind")
+  )]
+  pub find: StringOrRegex,
+  /// A relative replacement is resolved from `cwd`, so it points at the same file from every
+  /// importer.
+  pub replacement: String,
+  /// Rewrites the specifier even when it already resolves as it is.
+  #[cfg_attr(feature = "deserialize_bundler_options", serde(default))]
+  pub force: bool,
+}
+
+impl AliasEntry {
+  /// The specifier rewritten by this entry, or `None` if `find` doesn't match it.
+  pub fn rewrite<'a>(&self, specifier: &'a str) -> Option<Cow<'a, str>> {
+    match &self.find {
+      StringOrRegex::String(find) => {
+        let rest = specifier.strip_prefix(find.as_str())?;
+        (rest.is_empty() || rest.starts_with('/') || find.ends_with('/'))
+          .then(|| Cow::Owned(format!("{}{rest}", self.replacement)))
+      }
+      StringOrRegex::Regex(find) => {
+        find.matches(specifier).then(|| find.replace_all(specifier, &self.replacement))
+      }
+    }
+  }
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged, deny_unknown_fields)]
+enum RawFind {
+  String(String),
+  Regex { regex: String },
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_find<'de, D>(deserializer: D) -> Result<StringOrRegex, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  match RawFind::deserialize(deserializer)? {
+    RawFind::String(find) => Ok(StringOrRegex::String(find)),
+    RawFind::Regex { regex } => rolldown_utils::js_regex::HybridRegex::new(&regex)
+      .map(StringOrRegex::Regex)
+      .map_err(|e| serde::de::Error::custom(format!("failed to deserialize {e:?} to HybridRegex"))),
+  }
+}
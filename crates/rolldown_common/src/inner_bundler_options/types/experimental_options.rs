@@ -22,6 +22,9 @@ pub struct ExperimentalOptions {
   pub incremental_build: Option<bool>,
   pub hmr: Option<HmrOptions>,
   pub attach_debug_info: Option<AttachDebugInfo>,
+  /// Order modules, chunks, emitted assets and hash inputs by stable id rather than by the order
+  /// they were discovered in, so outputs are byte-identical across runs and machines.
+  pub deterministic: Option<bool>,
 }
 
 impl ExperimentalOptions {
@@ -54,4 +57,8 @@ impl ExperimentalOptions {
   pub fn is_attach_debug_info_full(&self) -> bool {
     self.attach_debug_info.is_some_and(|info| info.is_full())
   }
+
+  pub fn is_deterministic_enabled(&self) -> bool {
+    self.deterministic.unwrap_or(false)
+  }
 }
@@ -3,6 +3,8 @@ use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
 use serde::Deserialize;
 
+use crate::OutputFormat;
+
 #[derive(Debug, Default, Clone)]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
@@ -14,6 +16,73 @@ pub struct HmrOptions {
   pub host: Option<String>,
   /// Port that `DevRuntime` will connect to using WebSocket.
   pub port: Option<u16>,
-  /// Custom hmr runtime implementation.
-  pub implement: Option<String>,
+  /// Source of a custom hmr runtime, replacing the default one that receives patches over WebSocket.
+  /// It extends `DevRuntime` of `runtime-extra-dev-common.js`, which describes the methods to implement.
+  pub implement: Option<HmrImplement>,
+  /// What to do when an edit adds or removes exports of a module accepting its own updates. Its
+  /// importers keep the bindings they imported before, so it triggers a full reload by default.
+  pub export_change: Option<HmrExportChange>,
+  /// Size in bytes above which a patch is split into several scripts, so updates touching many
+  /// modules are transferred and evaluated in smaller pieces. Patches are never split by default.
+  pub max_patch_size: Option<usize>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "kebab-case", deny_unknown_fields)
+)]
+pub enum HmrExportChange {
+  #[default]
+  FullReload,
+  /// Applies the update and emits a `HMR_EXPORT_CHANGE` warning.
+  Warn,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(untagged)
+)]
+pub enum HmrImplement {
+  /// The same runtime is used for every output format.
+  All(String),
+  /// Runtimes for specific output formats, e.g. because cjs output has no `import.meta.hot`.
+  PerFormat(HmrImplementPerFormat),
+}
+
+impl HmrImplement {
+  /// The runtime to use when building `format`, `None` if none was given for it.
+  pub fn for_format(&self, format: OutputFormat) -> Option<&str> {
+    match self {
+      Self::All(implement) => Some(implement),
+      Self::PerFormat(per_format) => match format {
+        OutputFormat::Esm => per_format.esm.as_deref(),
+        OutputFormat::Cjs => per_format.cjs.as_deref(),
+        OutputFormat::Iife => per_format.iife.as_deref(),
+        OutputFormat::Umd => per_format.umd.as_deref(),
+      },
+    }
+  }
+}
+
+impl From<String> for HmrImplement {
+  fn from(value: String) -> Self {
+    Self::All(value)
+  }
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct HmrImplementPerFormat {
+  pub esm: Option<String>,
+  pub cjs: Option<String>,
+  pub iife: Option<String>,
+  pub umd: Option<String>,
 }
@@ -4,14 +4,23 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 
 #[derive(Debug, Default, Clone)]
-#[cfg_attr(feature = "deserialize_bundler_options", derive(Deserialize, JsonSchema))]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
 pub struct InputItem {
   pub name: Option<String>,
   pub import: String,
+  /// Emits this entry under the given path relative to the output directory, instead of the one
+  /// rendered from `entry_filenames`. The path is used as is, so no hash is inserted.
+  pub file_name: Option<String>,
+  /// Replaces `banner` for the chunk of this entry, e.g. to start an executable with a shebang.
+  pub banner: Option<String>,
 }
 
 impl From<String> for InputItem {
   fn from(value: String) -> Self {
-    Self { name: None, import: value }
+    Self { name: None, import: value, ..Default::default() }
   }
 }
@@ -37,6 +37,22 @@ impl IsExternal {
     })
   }
 
+  /// Treats a module as external if either `self` or `other` does, e.g. to add externals to the ones
+  /// already configured.
+  #[must_use]
+  pub fn or(self, other: Self) -> Self {
+    match (self.0, other.0) {
+      (Some(is_external), Some(other)) => {
+        Self::from_closure(move |specifier, importer, is_resolved| {
+          let is_external = is_external(specifier, importer, is_resolved);
+          let other = other(specifier, importer, is_resolved);
+          Box::pin(async move { Ok(is_external.await? || other.await?) })
+        })
+      }
+      (is_external, None) | (None, is_external) => Self(is_external),
+    }
+  }
+
   pub async fn call(
     &self,
     specifier: &str,
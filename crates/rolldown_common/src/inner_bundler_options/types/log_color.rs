@@ -0,0 +1,32 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use rolldown_error::ColorMode;
+
+/// Whether the diagnostics in the `message` of the logs passed to `on_log` contain ANSI colors.
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum LogColor {
+  /// Colored unless `NO_COLOR` is set, forced by `FORCE_COLOR`, and otherwise only when stderr is
+  /// a terminal.
+  #[default]
+  Auto,
+  Always,
+  Never,
+}
+
+impl From<LogColor> for ColorMode {
+  fn from(value: LogColor) -> Self {
+    match value {
+      LogColor::Auto => ColorMode::Auto,
+      LogColor::Always => ColorMode::Always,
+      LogColor::Never => ColorMode::Never,
+    }
+  }
+}
@@ -0,0 +1,19 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// How diagnostics are rendered in the `message` of the logs passed to `on_log`.
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum LogFormat {
+  /// The colored code frame, as printed to terminals.
+  #[default]
+  Text,
+  /// A single line of JSON, see `BuildDiagnostic::to_json`.
+  Json,
+}
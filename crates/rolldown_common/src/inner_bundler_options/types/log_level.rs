@@ -9,7 +9,9 @@ use std::fmt::{self, Display, Formatter};
   derive(Deserialize, JsonSchema),
   serde(rename_all = "camelCase", deny_unknown_fields)
 )]
-#[derive(Debug, PartialEq, Clone, Copy, Default)]
+/// Ordered from the least to the most verbose, so a log is shown if its level is at most the
+/// configured one.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
 pub enum LogLevel {
   Silent,
   Warn,
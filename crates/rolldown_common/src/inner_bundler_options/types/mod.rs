@@ -1,4 +1,5 @@
 pub mod advanced_chunks_options;
+pub mod alias_entry;
 pub mod attach_debug_info;
 pub mod debug_options;
 pub mod defer_sync_scan_data_option;
@@ -12,6 +13,8 @@ pub mod input_item;
 pub mod invalidate_js_side_cache;
 pub mod is_external;
 pub mod legal_comments;
+pub mod log_color;
+pub mod log_format;
 pub mod log_level;
 pub mod make_absolute_externals_relative;
 pub mod mark_module_loaded;
@@ -22,12 +25,15 @@ pub mod on_log;
 pub mod output_exports;
 pub mod output_format;
 pub mod output_option;
+pub mod output_options;
 pub mod platform;
 pub mod resolve_options;
 pub mod sanitize_filename;
+pub mod severity_override;
 pub mod source_map_type;
 pub mod sourcemap_ignore_list;
 pub mod sourcemap_path_transform;
+pub mod sourcemap_url;
 pub mod target;
 pub mod transform_options;
 pub mod treeshake;
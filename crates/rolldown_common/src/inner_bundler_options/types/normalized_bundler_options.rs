@@ -7,10 +7,12 @@ use std::sync::Arc;
 
 use arcstr::ArcStr;
 use oxc::transformer_plugins::InjectGlobalVariablesConfig;
-use rolldown_error::EventKindSwitcher;
+use rolldown_error::{BuildDiagnostic, ColorMode, DiagnosticOptions, EventKindSwitcher, Severity};
 use rustc_hash::{FxHashMap, FxHashSet};
+use sugar_path::SugarPath;
 
 use super::advanced_chunks_options::AdvancedChunksOptions;
+use super::alias_entry::AliasEntry;
 use super::experimental_options::ExperimentalOptions;
 use super::legal_comments::LegalComments;
 use super::minify_options::MinifyOptions;
@@ -24,16 +26,17 @@ use super::{
   is_external::IsExternal, output_exports::OutputExports, output_format::OutputFormat,
   output_option::AddonOutputOption, platform::Platform, source_map_type::SourceMapType,
   sourcemap_ignore_list::SourceMapIgnoreList, sourcemap_path_transform::SourceMapPathTransform,
+  sourcemap_url::SourcemapUrl,
 };
 use crate::{
   DeferSyncScanDataOption, EmittedAsset, EsModuleFlag, FilenameTemplate, GlobalsOutputOption,
-  HashCharacters, InjectImport, InputItem, InvalidateJsSideCache, LogLevel,
-  MakeAbsoluteExternalsRelative, MarkModuleLoaded, ModuleType, OnLog, RollupPreRenderedAsset,
-  TransformOptions,
+  HashCharacters, InjectImport, InputItem, InvalidateJsSideCache, LogFormat, LogLevel,
+  MakeAbsoluteExternalsRelative, MarkModuleLoaded, ModuleType, NormalizedGeneratedCodeOptions,
+  OnLog, PathsOutputOption, RollupPreRenderedAsset, TransformOptions,
 };
 
 #[allow(clippy::struct_excessive_bools)] // Using raw booleans is more clear in this case
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NormalizedBundlerOptions {
   // --- Input
   pub input: Vec<InputItem>,
@@ -45,6 +48,7 @@ pub struct NormalizedBundlerOptions {
   pub shim_missing_exports: bool,
   /// The key is the extension. Unlike `BundlerOptions`, the extension doesn't start with a dot.
   pub module_types: FxHashMap<Cow<'static, str>, ModuleType>,
+  pub alias: Vec<AliasEntry>,
   // --- Output
   pub name: Option<String>,
   pub css_entry_filenames: ChunkFilenamesOutputOption,
@@ -55,7 +59,8 @@ pub struct NormalizedBundlerOptions {
   pub sanitize_filename: SanitizeFilename,
   // The user specified output directory config
   pub dir: Option<String>,
-  // The rolldown resolved output directory from `dir` or `file`.
+  // The rolldown resolved output directory from `dir` or `file`, relative to `cwd` unless it's absolute.
+  // Use `resolved_out_dir` for the path it points to.
   pub out_dir: String,
   pub file: Option<String>,
   pub format: OutputFormat,
@@ -63,6 +68,8 @@ pub struct NormalizedBundlerOptions {
   pub es_module: EsModuleFlag,
   pub hash_characters: HashCharacters,
   pub globals: GlobalsOutputOption,
+  pub paths: PathsOutputOption,
+  pub generated_code: NormalizedGeneratedCodeOptions,
   pub sourcemap: Option<SourceMapType>,
   pub banner: Option<AddonOutputOption>,
   pub footer: Option<AddonOutputOption>,
@@ -71,8 +78,12 @@ pub struct NormalizedBundlerOptions {
   pub sourcemap_ignore_list: Option<SourceMapIgnoreList>,
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
   pub sourcemap_debug_ids: bool,
+  pub sourcemap_url: Option<SourcemapUrl>,
+  pub sourcemap_include_sources: bool,
+  pub manifest: Option<String>,
   pub experimental: ExperimentalOptions,
   pub minify: MinifyOptions,
+  pub compact: bool,
   pub extend: bool,
   pub define: Vec<(/* Target to be replaced */ String, /* Replacement */ String)>,
   pub keep_names: bool,
@@ -87,13 +98,21 @@ pub struct NormalizedBundlerOptions {
   pub legal_comments: LegalComments,
   pub drop_labels: FxHashSet<String>,
   pub polyfill_require: bool,
+  /// If `false`, chunks only import the chunks of their direct dependencies and leave the transitive ones to be loaded by them.
+  pub hoist_transitive_imports: bool,
   pub defer_sync_scan_data: Option<DeferSyncScanDataOption>,
   pub transform_options: TransformOptions,
   pub make_absolute_externals_relative: MakeAbsoluteExternalsRelative,
   pub invalidate_js_side_cache: Option<InvalidateJsSideCache>,
   pub mark_module_loaded: Option<MarkModuleLoaded>,
   pub log_level: Option<LogLevel>,
+  pub log_format: LogFormat,
+  pub log_color: ColorMode,
+  pub severity_overrides: FxHashMap<String, Severity>,
+  pub dedupe_diagnostics: bool,
   pub on_log: Option<OnLog>,
+  pub debug_plugin_order: bool,
+  pub cache_dir: Option<PathBuf>,
   pub preserve_modules: bool,
   pub virtual_dirname: String,
   pub preserve_modules_root: Option<String>,
@@ -112,6 +131,7 @@ impl Default for NormalizedBundlerOptions {
       platform: Platform::Neutral,
       shim_missing_exports: Default::default(),
       module_types: Default::default(),
+      alias: Default::default(),
       name: Default::default(),
       css_entry_filenames: ChunkFilenamesOutputOption::String(String::new()),
       css_chunk_filenames: ChunkFilenamesOutputOption::String(String::new()),
@@ -127,6 +147,8 @@ impl Default for NormalizedBundlerOptions {
       es_module: Default::default(),
       hash_characters: Default::default(),
       globals: GlobalsOutputOption::FxHashMap(FxHashMap::default()),
+      paths: PathsOutputOption::default(),
+      generated_code: NormalizedGeneratedCodeOptions::default(),
       sourcemap: Default::default(),
       banner: Default::default(),
       footer: Default::default(),
@@ -135,8 +157,12 @@ impl Default for NormalizedBundlerOptions {
       sourcemap_ignore_list: Default::default(),
       sourcemap_path_transform: Default::default(),
       sourcemap_debug_ids: Default::default(),
+      sourcemap_url: Default::default(),
+      sourcemap_include_sources: true,
+      manifest: Default::default(),
       experimental: Default::default(),
       minify: MinifyOptions::Disabled,
+      compact: false,
       extend: Default::default(),
       define: Default::default(),
       keep_names: Default::default(),
@@ -151,13 +177,20 @@ impl Default for NormalizedBundlerOptions {
       legal_comments: LegalComments::None,
       drop_labels: Default::default(),
       polyfill_require: Default::default(),
+      hoist_transitive_imports: true,
       defer_sync_scan_data: Default::default(),
       transform_options: Default::default(),
       make_absolute_externals_relative: Default::default(),
       invalidate_js_side_cache: Default::default(),
       mark_module_loaded: Default::default(),
       log_level: Default::default(),
+      log_format: Default::default(),
+      log_color: Default::default(),
+      severity_overrides: FxHashMap::default(),
+      dedupe_diagnostics: true,
       on_log: Default::default(),
+      debug_plugin_order: Default::default(),
+      cache_dir: Default::default(),
       preserve_modules: false,
       virtual_dirname: "_virtual".into(),
       preserve_modules_root: Default::default(),
@@ -181,6 +214,26 @@ impl NormalizedBundlerOptions {
     self.experimental.hmr.is_some()
   }
 
+  /// The absolute path of the output directory: `out_dir` resolved against `cwd`, with `.` and `..`
+  /// segments and trailing slashes removed. An absolute `out_dir` is kept as it is.
+  pub fn resolved_out_dir(&self) -> PathBuf {
+    self.cwd.join(&self.out_dir).normalize()
+  }
+
+  /// The `message` of the log passed to `on_log` for a diagnostic, following `log_format` and
+  /// `log_color`.
+  pub fn log_message(&self, diagnostic: &BuildDiagnostic) -> String {
+    let opts = self.diagnostic_options();
+    match self.log_format {
+      LogFormat::Text => diagnostic.to_diagnostic_with(&opts).to_string_with_color(opts.color),
+      LogFormat::Json => diagnostic.to_json(&opts).to_string(),
+    }
+  }
+
+  pub fn diagnostic_options(&self) -> DiagnosticOptions {
+    DiagnosticOptions::new(self.cwd.clone(), self.log_color)
+  }
+
   /// make sure the `polyfill_require` is only valid for `esm` format with `node` platform
   #[inline]
   pub fn polyfill_require_for_esm_format_with_node_platform(&self) -> bool {
@@ -5,10 +5,21 @@ use derive_more::Debug;
 
 use super::log_level::LogLevel;
 
-pub type OnLogFn = dyn Fn(LogLevel, Log) -> Pin<Box<(dyn Future<Output = anyhow::Result<()>> + Send + 'static)>>
+pub type OnLogFn = dyn Fn(LogLevel, Log) -> Pin<Box<(dyn Future<Output = anyhow::Result<LogAction>> + Send + 'static)>>
   + Send
   + Sync;
 
+/// Decides what happens to a log after it has been passed to [OnLog].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogAction {
+  /// Keep the log at its original level.
+  Pass,
+  /// Swallow the log.
+  Skip,
+  /// Re-emit the log as an error, which fails the build.
+  Error,
+}
+
 #[derive(Clone, Debug)]
 #[debug("OnLogFn::Fn(...)")]
 pub struct OnLog(Arc<OnLogFn>);
@@ -18,7 +29,7 @@ impl OnLog {
     Self(f)
   }
 
-  pub async fn call(&self, log_level: LogLevel, log: Log) -> anyhow::Result<()> {
+  pub async fn call(&self, log_level: LogLevel, log: Log) -> anyhow::Result<LogAction> {
     self.0(log_level, log).await
   }
 }
@@ -0,0 +1,81 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Syntax used by the code rolldown generates itself, i.e. runtime helpers and chunk wrappers.
+/// User code is left to the transformer and minifier.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct GeneratedCodeOptions {
+  /// Base syntax level. Granular options take precedence over it. Defaults to `es2015`.
+  pub preset: Option<GeneratedCodePreset>,
+  /// Use arrow functions in runtime helpers and wrappers. Defaults to `true`.
+  pub arrow_functions: Option<bool>,
+  /// Use `const` for the bindings created by cjs chunk wrappers. Defaults to `true`.
+  pub const_bindings: Option<bool>,
+  /// Use shorthand properties in runtime helpers. Defaults to `true`.
+  pub object_shorthand: Option<bool>,
+  /// Mark exports of cjs, iife and umd chunks with `Symbol.toStringTag`. Defaults to `false`.
+  pub symbols: Option<bool>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "lowercase")
+)]
+pub enum GeneratedCodePreset {
+  Es5,
+  #[default]
+  Es2015,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct NormalizedGeneratedCodeOptions {
+  pub arrow_functions: bool,
+  pub const_bindings: bool,
+  pub object_shorthand: bool,
+  pub symbols: bool,
+}
+
+impl Default for NormalizedGeneratedCodeOptions {
+  fn default() -> Self {
+    Self::from_preset(GeneratedCodePreset::default())
+  }
+}
+
+impl From<GeneratedCodeOptions> for NormalizedGeneratedCodeOptions {
+  fn from(value: GeneratedCodeOptions) -> Self {
+    let default = Self::from_preset(value.preset.unwrap_or_default());
+    Self {
+      arrow_functions: value.arrow_functions.unwrap_or(default.arrow_functions),
+      const_bindings: value.const_bindings.unwrap_or(default.const_bindings),
+      object_shorthand: value.object_shorthand.unwrap_or(default.object_shorthand),
+      symbols: value.symbols.unwrap_or(default.symbols),
+    }
+  }
+}
+
+impl NormalizedGeneratedCodeOptions {
+  pub fn from_preset(preset: GeneratedCodePreset) -> Self {
+    let es2015 = matches!(preset, GeneratedCodePreset::Es2015);
+    Self {
+      arrow_functions: es2015,
+      const_bindings: es2015,
+      object_shorthand: es2015,
+      symbols: false,
+    }
+  }
+
+  /// The keyword for bindings that are never reassigned.
+  pub fn const_keyword(&self) -> &'static str {
+    if self.const_bindings { "const" } else { "var" }
+  }
+}
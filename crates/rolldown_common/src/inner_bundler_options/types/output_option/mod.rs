@@ -1,11 +1,17 @@
 mod addon;
 mod asset_filenames;
 mod chunk_filenames;
+mod generated_code;
 mod globals;
+mod paths;
 mod preserve_entry_signatures;
 
 pub use addon::{AddonFunction, AddonOutputOption};
 pub use asset_filenames::AssetFilenamesOutputOption;
 pub use chunk_filenames::ChunkFilenamesOutputOption;
+pub use generated_code::{
+  GeneratedCodeOptions, GeneratedCodePreset, NormalizedGeneratedCodeOptions,
+};
 pub use globals::GlobalsOutputOption;
+pub use paths::PathsOutputOption;
 pub use preserve_entry_signatures::PreserveEntrySignatures;
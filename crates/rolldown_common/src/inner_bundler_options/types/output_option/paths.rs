@@ -0,0 +1,42 @@
+use derive_more::Debug;
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use rustc_hash::FxHashMap;
+
+pub type PathsFunction = dyn Fn(&str) -> Pin<Box<(dyn Future<Output = anyhow::Result<String>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// Maps external module ids to the specifiers written into the output, similar to rollup's `output.paths`.
+#[derive(Clone, Debug)]
+pub enum PathsOutputOption {
+  #[debug("PathsOutputOption::FxHashMap({_0:?})")]
+  FxHashMap(FxHashMap<String, String>),
+  #[debug("PathsOutputOption::Fn(...)")]
+  Fn(Arc<PathsFunction>),
+}
+
+impl PathsOutputOption {
+  pub async fn call(&self, id: &str) -> anyhow::Result<Option<String>> {
+    match self {
+      Self::FxHashMap(value) => Ok(value.get(id).cloned()),
+      Self::Fn(value) => Ok(Some(value(id).await?)),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    matches!(self, Self::FxHashMap(value) if value.is_empty())
+  }
+}
+
+impl Default for PathsOutputOption {
+  fn default() -> Self {
+    Self::FxHashMap(FxHashMap::default())
+  }
+}
+
+impl From<FxHashMap<String, String>> for PathsOutputOption {
+  fn from(value: FxHashMap<String, String>) -> Self {
+    Self::FxHashMap(value)
+  }
+}
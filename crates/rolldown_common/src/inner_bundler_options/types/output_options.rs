@@ -0,0 +1,32 @@
+use super::{
+  minify_options::RawMinifyOptions,
+  output_format::OutputFormat,
+  output_option::{
+    AddonOutputOption, AssetFilenamesOutputOption, ChunkFilenamesOutputOption, PathsOutputOption,
+  },
+  source_map_type::SourceMapType,
+};
+
+/// Options of a single output for `Bundler::generate_multi` and `Bundler::write_multi`.
+///
+/// Only options that affect rendering are listed here. Fields left as `None` fall back to the
+/// values of the `BundlerOptions` the bundler was created with.
+#[derive(Default, Debug, Clone)]
+pub struct OutputOptions {
+  pub format: Option<OutputFormat>,
+  pub dir: Option<String>,
+  pub file: Option<String>,
+  pub entry_filenames: Option<ChunkFilenamesOutputOption>,
+  pub chunk_filenames: Option<ChunkFilenamesOutputOption>,
+  pub css_entry_filenames: Option<ChunkFilenamesOutputOption>,
+  pub css_chunk_filenames: Option<ChunkFilenamesOutputOption>,
+  pub asset_filenames: Option<AssetFilenamesOutputOption>,
+  pub sourcemap: Option<SourceMapType>,
+  pub banner: Option<AddonOutputOption>,
+  pub footer: Option<AddonOutputOption>,
+  pub intro: Option<AddonOutputOption>,
+  pub outro: Option<AddonOutputOption>,
+  pub paths: Option<PathsOutputOption>,
+  pub minify: Option<RawMinifyOptions>,
+  pub compact: Option<bool>,
+}
@@ -10,6 +10,9 @@ pub enum Platform {
   /// Represents the Node.js platform.
   Node,
   Browser,
+  /// Makes no assumption about the environment. Node.js built-in modules aren't resolved, so `node:`
+  /// imports are left external with an `UNRESOLVED_IMPORT` warning, and no platform-specific `define`s
+  /// are applied.
   Neutral,
 }
 
@@ -14,6 +14,9 @@ use serde::Deserialize;
 pub struct ResolveOptions {
   pub alias: Option<Vec<(String, Vec<String>)>>,
   pub alias_fields: Option<Vec<Vec<String>>>,
+  /// Extra conditions to match against `exports` and `imports` fields of `package.json`, e.g.
+  /// `development` or `production`. They are always active, on top of `default`, `import` or
+  /// `require` depending on the import kind, and `node` or `browser` depending on the platform.
   pub condition_names: Option<Vec<String>>,
   pub exports_fields: Option<Vec<Vec<String>>>,
   pub extensions: Option<Vec<String>>,
@@ -0,0 +1,31 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use rolldown_error::Severity;
+
+/// Severity a diagnostic kind is reported with, overriding the default one.
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SeverityOverride {
+  /// Fails the build once all the warnings of the build are collected.
+  Error,
+  Warning,
+  /// Kept in the output warnings, but passed to `on_log` with the `info` level.
+  Info,
+}
+
+impl From<SeverityOverride> for Severity {
+  fn from(value: SeverityOverride) -> Self {
+    match value {
+      SeverityOverride::Error => Severity::Error,
+      SeverityOverride::Warning => Severity::Warning,
+      SeverityOverride::Info => Severity::Info,
+    }
+  }
+}
@@ -0,0 +1,37 @@
+use derive_more::Debug;
+use std::{future::Future, pin::Pin, sync::Arc};
+
+type SourcemapUrlFn = dyn Fn(&str) -> Pin<Box<(dyn Future<Output = anyhow::Result<String>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// The URL written into the `//# sourceMappingURL=` comment of chunks with `sourcemap: File`.
+///
+/// Without it, the comment points at the map next to the chunk.
+#[derive(Clone, Debug)]
+pub enum SourcemapUrl {
+  /// A base URL, such as `https://cdn.example.com/`. The path of the map in the output directory
+  /// is appended to it.
+  #[debug("SourcemapUrl::String({_0:?})")]
+  String(String),
+  /// Called with the path of the map in the output directory, returns the URL of the map.
+  #[debug("SourcemapUrl::Fn(...)")]
+  Fn(Arc<SourcemapUrlFn>),
+}
+
+impl SourcemapUrl {
+  pub async fn call(&self, map_filename: &str) -> anyhow::Result<String> {
+    match self {
+      Self::String(base) => {
+        Ok(format!("{}/{}", base.trim_end_matches('/'), map_filename.trim_start_matches('/')))
+      }
+      Self::Fn(value) => value(map_filename).await,
+    }
+  }
+}
+
+impl From<String> for SourcemapUrl {
+  fn from(value: String) -> Self {
+    Self::String(value)
+  }
+}
@@ -19,7 +19,7 @@ pub enum TreeshakeOptions {
   Option(InnerOptions),
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct NormalizedTreeshakeOptions(Option<InnerOptions>);
 
 impl Deref for NormalizedTreeshakeOptions {
@@ -20,6 +20,7 @@ pub mod bundler_options {
     BundlerOptions,
     types::{
       advanced_chunks_options::{AdvancedChunksOptions, MatchGroup, MatchGroupTest},
+      alias_entry::AliasEntry,
       attach_debug_info::AttachDebugInfo,
       debug_options::DebugOptions,
       defer_sync_scan_data_option::DeferSyncScanDataOption,
@@ -27,31 +28,37 @@ pub mod bundler_options {
       experimental_options::ExperimentalOptions,
       filename_template::FilenameTemplate,
       hash_characters::HashCharacters,
-      hmr_options::HmrOptions,
+      hmr_options::{HmrExportChange, HmrImplement, HmrImplementPerFormat, HmrOptions},
       inject_import::InjectImport,
       input_item::InputItem,
       invalidate_js_side_cache::InvalidateJsSideCache,
       is_external::IsExternal,
       legal_comments::LegalComments,
+      log_color::LogColor,
+      log_format::LogFormat,
       log_level::LogLevel,
       make_absolute_externals_relative::MakeAbsoluteExternalsRelative,
       mark_module_loaded::MarkModuleLoaded,
       minify_options::{MinifyOptions, MinifyOptionsObject, RawMinifyOptions},
       module_type::ModuleType,
       normalized_bundler_options::{NormalizedBundlerOptions, SharedNormalizedBundlerOptions},
-      on_log::{Log, OnLog},
+      on_log::{Log, LogAction, OnLog},
       output_exports::OutputExports,
       output_format::OutputFormat,
       output_option::{
         AddonFunction, AddonOutputOption, AssetFilenamesOutputOption, ChunkFilenamesOutputOption,
-        GlobalsOutputOption, PreserveEntrySignatures,
+        GeneratedCodeOptions, GeneratedCodePreset, GlobalsOutputOption,
+        NormalizedGeneratedCodeOptions, PathsOutputOption, PreserveEntrySignatures,
       },
+      output_options::OutputOptions,
       platform::Platform,
       resolve_options::ResolveOptions,
       sanitize_filename::SanitizeFilename,
+      severity_override::SeverityOverride,
       source_map_type::SourceMapType,
       sourcemap_ignore_list::SourceMapIgnoreList,
       sourcemap_path_transform::SourceMapPathTransform,
+      sourcemap_url::SourcemapUrl,
       target::ESTarget,
       transform_options::{JsxPreset, TransformOptions},
       treeshake::{InnerOptions, ModuleSideEffects, ModuleSideEffectsRule, TreeshakeOptions},
@@ -86,10 +93,14 @@ pub use crate::{
     module_idx::ModuleIdx,
     node_builtin_modules::is_existing_node_builtin_modules,
   },
-  file_emitter::{EmittedAsset, EmittedChunk, EmittedChunkInfo, FileEmitter, SharedFileEmitter},
+  file_emitter::{
+    BuildPhaseFiles, EmittedAsset, EmittedChunk, EmittedChunkInfo, FileEmitter, SharedFileEmitter,
+  },
   hmr::{
+    full_reload_reason::FullReloadReason,
     hmr_boundary::HmrBoundary,
-    hmr_output::{HmrBoundaryOutput, HmrOutput},
+    hmr_change::HmrChange,
+    hmr_output::{HmrBoundaryOutput, HmrOutput, HmrPatch},
   },
   module::{
     Module,
@@ -97,7 +108,7 @@ pub use crate::{
     normal_module::{ModuleRenderArgs, NormalModule},
   },
   module_loader::{
-    AddEntryModuleMsg, ModuleLoaderMsg,
+    AddEntryModuleMsg, FetchModuleMsg, ModuleLoaderMsg,
     runtime_module_brief::{RUNTIME_MODULE_ID, RUNTIME_MODULE_KEY, RuntimeModuleBrief},
     runtime_task_result::RuntimeModuleTaskResult,
     task_result::{EcmaRelated, ExternalModuleTaskResult, NormalModuleTaskResult},
@@ -108,8 +119,10 @@ pub use crate::{
   types::asset_meta::InstantiationKind,
   types::ast_scope_idx::AstScopeIdx,
   types::ast_scopes::AstScopes,
-  types::bundler_file_system::BundlerFileSystem,
+  types::bundler_file_system::{BundlerFileSystem, SharedFileSystem},
+  types::cancellation_token::CancellationToken,
   types::chunk_idx::ChunkIdx,
+  types::chunk_interop::{ChunkInterop, InteropHelper},
   types::chunk_kind::ChunkKind,
   types::deconflict::ModuleScopeSymbolIdMap,
   types::defer_sync_scan_data::DeferSyncScanData,
@@ -121,7 +134,8 @@ pub use crate::{
   types::hybrid_index_vec::HybridIndexVec,
   types::import_kind::ImportKind,
   types::import_record::{
-    ImportRecordIdx, ImportRecordMeta, RawImportRecord, ResolvedImportRecord,
+    ImportAttributes, ImportRecordIdx, ImportRecordMeta, RawImportRecord, ResolvedImportRecord,
+    render_import_attributes,
   },
   types::importer_record::ImporterRecord,
   types::instantiated_chunk::InstantiatedChunk,
@@ -131,7 +145,7 @@ pub use crate::{
   types::module_def_format::ModuleDefFormat,
   types::module_id::ModuleId,
   types::module_idx::LegacyModuleIdx,
-  types::module_info::ModuleInfo,
+  types::module_info::{ModuleInfo, ModuleInfoImportRecord, ModuleMeta},
   types::module_render_output::ModuleRenderOutput,
   types::module_table::{IndexExternalModules, IndexModules, ModuleTable},
   types::module_view::ModuleView,
@@ -1,7 +1,7 @@
 use std::path::Path;
 
 use crate::side_effects::DeterminedSideEffects;
-use crate::{Chunk, ImportRecordIdx, ModuleIdx, ResolvedImportRecord, SymbolRef};
+use crate::{Chunk, ImportAttributes, ImportRecordIdx, ModuleIdx, ResolvedImportRecord, SymbolRef};
 use arcstr::ArcStr;
 use oxc_index::IndexVec;
 use rolldown_utils::concat_string;
@@ -24,6 +24,10 @@ pub struct ExternalModule {
   pub import_records: IndexVec<ImportRecordIdx, ResolvedImportRecord>,
   pub side_effects: DeterminedSideEffects,
   pub need_renormalize_render_path: bool,
+  /// The specifier set by `output.paths`. It's rendered as is and takes precedence over `name`.
+  pub mapped_path: Option<ArcStr>,
+  /// The import attributes that static imports of this module are rendered with in esm output.
+  pub import_attributes: Option<ImportAttributes>,
 }
 
 impl ExternalModule {
@@ -46,10 +50,15 @@ impl ExternalModule {
       import_records: IndexVec::default(),
       side_effects,
       need_renormalize_render_path,
+      mapped_path: None,
+      import_attributes: None,
     }
   }
 
   pub fn get_import_path(&self, chunk: &Chunk) -> ArcStr {
+    if let Some(mapped_path) = &self.mapped_path {
+      return mapped_path.clone();
+    }
     if !self.need_renormalize_render_path {
       return self.name.clone();
     }
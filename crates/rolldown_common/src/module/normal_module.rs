@@ -4,8 +4,8 @@ use crate::css::css_view::CssView;
 use crate::types::module_render_output::ModuleRenderOutput;
 use crate::{
   AssetView, DebugStmtInfoForTreeShaking, ExportsKind, ImportRecordIdx, ImportRecordMeta,
-  LegalComments, ModuleId, ModuleIdx, ModuleInfo, NormalizedBundlerOptions, RawImportRecord,
-  ResolvedId, StmtInfo,
+  LegalComments, ModuleId, ModuleIdx, ModuleInfo, ModuleInfoImportRecord, ModuleMeta,
+  NormalizedBundlerOptions, RawImportRecord, ResolvedId, StmtInfo,
 };
 use crate::{EcmaAstIdx, EcmaView, IndexModules, Interop, Module, ModuleType};
 use std::ops::{Deref, DerefMut};
@@ -72,11 +72,17 @@ impl NormalModule {
   pub fn to_module_info(
     &self,
     raw_import_records: Option<&IndexVec<ImportRecordIdx, RawImportRecord>>,
+    import_records: Vec<ModuleInfoImportRecord>,
+    meta: ModuleMeta,
   ) -> ModuleInfo {
     ModuleInfo {
       code: Some(self.ecma_view.source.clone()),
       id: self.id.clone(),
+      stable_id: self.stable_id.clone(),
+      module_type: Some(self.module_type.clone()),
       is_entry: self.is_user_defined_entry,
+      has_side_effects: self.ecma_view.side_effects.has_side_effects(),
+      import_records,
       importers: {
         let mut value = self.ecma_view.importers.clone();
         value.sort_unstable();
@@ -110,6 +116,7 @@ impl NormalModule {
         }
         exports
       },
+      meta,
     }
   }
 
@@ -231,6 +238,7 @@ impl NormalModule {
             sourcemap: enable_sourcemap,
             filename: self.id.to_string(),
             print_legal_comments,
+            compact: options.compact,
           },
         );
         if !self.ecma_view.mutations.is_empty() {
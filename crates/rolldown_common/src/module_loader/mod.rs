@@ -5,7 +5,7 @@ use rolldown_error::BuildDiagnostic;
 use runtime_task_result::RuntimeModuleTaskResult;
 use task_result::{ExternalModuleTaskResult, NormalModuleTaskResult};
 
-use crate::{EmittedChunk, PreserveEntrySignatures, ResolvedId};
+use crate::{EmittedChunk, ModuleType, PreserveEntrySignatures, ResolvedId};
 
 pub mod runtime_module_brief;
 pub mod runtime_task_result;
@@ -15,11 +15,17 @@ pub enum ModuleLoaderMsg {
   NormalModuleDone(Box<NormalModuleTaskResult>),
   ExternalModuleDone(Box<ExternalModuleTaskResult>),
   RuntimeNormalModuleDone(Box<RuntimeModuleTaskResult>),
-  FetchModule(Box<ResolvedId>),
+  FetchModule(Box<FetchModuleMsg>),
   AddEntryModule(Box<AddEntryModuleMsg>),
   BuildErrors(Box<[BuildDiagnostic]>),
 }
 
+pub struct FetchModuleMsg {
+  pub resolved_id: ResolvedId,
+  /// Loads the module as this module type, regardless of what the `load` hook or its extension says.
+  pub asserted_module_type: Option<ModuleType>,
+}
+
 pub struct AddEntryModuleMsg {
   pub chunk: Arc<EmittedChunk>,
   pub reference_id: ArcStr,
@@ -126,4 +126,12 @@ impl AstScopes {
   pub fn clone_facade_only(&self) -> AstScopes {
     AstScopes { scoping: Scoping::default(), facade_scoping: self.facade_scoping.clone() }
   }
+
+  #[must_use]
+  pub fn clone_with_another_arena(&self) -> AstScopes {
+    AstScopes {
+      scoping: self.scoping.clone_in_with_semantic_ids_with_another_arena(),
+      facade_scoping: self.facade_scoping.clone(),
+    }
+  }
 }
@@ -1,12 +1,82 @@
-use rolldown_fs::FileSystem;
+use std::{
+  fmt::Debug,
+  io,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
-/// You don't need impl this trait manually, it's already implemented for all types that implement `FileSystem + Default + Clone + 'static`.
-///
-/// ## Notice on `Clone` constraint
+use oxc_resolver::{FileMetadata, FileSystem as OxcResolverFileSystem};
+use rolldown_fs::{FileSystem, OsFileSystem};
+
+/// The file system passed as `BundlerOptions#file_system`, e.g. an in-memory one to bundle a
+/// project without touching the disk.
 ///
-/// Rolldown will access the passing file system from multiple places, which means rolldown will clone the file system multiple times.
-/// so it's important to make sure these file system is unique. Rolldown could wrap the file system with `Arc`, but it will
-/// cause unnecessary overhead while using OS file system. So, it's your responsibility to make sure that the file system
-/// is unique after cloning.
-pub trait BundlerFileSystem: FileSystem + Default + Clone + 'static {}
-impl<T: FileSystem + Default + Clone + 'static> BundlerFileSystem for T {}
+/// You don't need impl this trait manually, it's already implemented for all types that implement
+/// `FileSystem + Debug + 'static`.
+pub trait BundlerFileSystem: FileSystem + Debug + 'static {}
+impl<T: FileSystem + Debug + 'static> BundlerFileSystem for T {}
+
+/// The file system of a build, shared by the resolver, the module loader and `Bundler::write`.
+/// Defaults to [`OsFileSystem`].
+#[derive(Debug, Clone)]
+pub struct SharedFileSystem(Arc<dyn BundlerFileSystem>);
+
+impl SharedFileSystem {
+  pub fn new(fs: Arc<dyn BundlerFileSystem>) -> Self {
+    Self(fs)
+  }
+}
+
+impl Default for SharedFileSystem {
+  fn default() -> Self {
+    Self(Arc::new(OsFileSystem))
+  }
+}
+
+impl FileSystem for SharedFileSystem {
+  fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+    self.0.remove_dir_all(path)
+  }
+
+  fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+    self.0.create_dir_all(path)
+  }
+
+  fn write(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+    self.0.write(path, content)
+  }
+
+  fn exists(&self, path: &Path) -> bool {
+    self.0.exists(path)
+  }
+
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    self.0.read(path)
+  }
+
+  fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+    self.0.rename(from, to)
+  }
+
+  fn remove_file(&self, path: &Path) -> io::Result<()> {
+    self.0.remove_file(path)
+  }
+}
+
+impl OxcResolverFileSystem for SharedFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    self.0.read_to_string(path)
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.0.metadata(path)
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.0.symlink_metadata(path)
+  }
+
+  fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+    self.0.read_link(path)
+  }
+}
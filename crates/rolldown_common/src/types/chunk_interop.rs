@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+use crate::ModuleId;
+
+/// Runtime helpers bridging CommonJS and ESM modules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InteropHelper {
+  /// `__toESM`, an ESM view of a CommonJS module, whose `default` is `module.exports` unless the
+  /// module is flagged with `__esModule`.
+  ToEsm,
+  /// `__toCommonJS`, the namespace of an ESM module rebuilt as `module.exports` for `require`.
+  ToCommonJs,
+  /// `__reExport`, copies the exports only known at runtime for `export *`.
+  ReExport,
+  /// `__toDynamicImportESM`, unwraps the `default` of a CommonJS module loaded with `import()`.
+  ToDynamicImportEsm,
+}
+
+impl Display for InteropHelper {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::ToEsm => write!(f, "__toESM"),
+      Self::ToCommonJs => write!(f, "__toCommonJS"),
+      Self::ReExport => write!(f, "__reExport"),
+      Self::ToDynamicImportEsm => write!(f, "__toDynamicImportESM"),
+    }
+  }
+}
+
+/// An import of a module in a chunk, which the chunk bridges with an interop helper.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChunkInterop {
+  pub helper: InteropHelper,
+  pub importer: ModuleId,
+  pub importee: ModuleId,
+}
@@ -9,6 +9,8 @@ pub struct EntryPoint {
   pub kind: EntryPointKind,
   /// emitted chunk specified filename, used to generate chunk filename
   pub file_name: Option<ArcStr>,
+  /// user defined entry specified banner, replacing the `banner` option for its chunk
+  pub banner: Option<ArcStr>,
   /// which stmts create this entry point
   pub related_stmt_infos: Vec<(ModuleIdx, StmtInfoIdx)>,
 }
@@ -5,6 +5,7 @@ use std::{
 
 use oxc::span::Span;
 use rolldown_rstr::Rstr;
+use rolldown_utils::{concat_string, ecmascript::is_validate_identifier_name};
 
 use crate::{ImportKind, ModuleIdx, ModuleType, StmtInfoIdx, SymbolRef};
 
@@ -57,6 +58,24 @@ bitflags::bitflags! {
   }
 }
 
+/// Key-value pairs of the `with { ... }` clause of an import, in source order.
+pub type ImportAttributes = Box<[(Rstr, Rstr)]>;
+
+/// Renders import attributes as an object literal, e.g. `{ type: "json" }`.
+pub fn render_import_attributes(attributes: &ImportAttributes) -> String {
+  let entries = attributes
+    .iter()
+    .map(|(key, value)| {
+      if is_validate_identifier_name(key) {
+        concat_string!(key, ": \"", value, "\"")
+      } else {
+        concat_string!("\"", key, "\": \"", value, "\"")
+      }
+    })
+    .collect::<Vec<_>>();
+  concat_string!("{ ", entries.join(", "), " }")
+}
+
 #[derive(Debug, Clone)]
 pub struct ImportRecord<State: Debug + Clone> {
   pub state: State,
@@ -68,12 +87,24 @@ pub struct ImportRecord<State: Debug + Clone> {
   pub namespace_ref: SymbolRef,
   pub meta: ImportRecordMeta,
   pub related_stmt_info_idx: Option<StmtInfoIdx>,
+  /// `{ type: 'json' }` in `import data from './data.json' with { type: 'json' }`. It's only
+  /// `Some` if the attributes are statically known.
+  pub attributes: Option<ImportAttributes>,
 }
 
 impl<State: Debug + Clone> ImportRecord<State> {
   pub fn is_unspanned(&self) -> bool {
     self.meta.contains(ImportRecordMeta::IS_UNSPANNED_IMPORT)
   }
+
+  /// The value of the `type` import attribute, e.g. `json` for `with { type: 'json' }`.
+  pub fn attribute_type(&self) -> Option<&str> {
+    self
+      .attributes
+      .as_ref()?
+      .iter()
+      .find_map(|(key, value)| (key.as_str() == "type").then_some(value.as_str()))
+  }
 }
 
 impl<T: Debug + Clone> Deref for ImportRecord<T> {
@@ -108,6 +139,7 @@ impl RawImportRecord {
       meta: ImportRecordMeta::empty(),
       state: ImportRecordStateInit { span, asserted_module_type: assert_module_type },
       related_stmt_info_idx,
+      attributes: None,
     }
   }
 
@@ -124,6 +156,7 @@ impl RawImportRecord {
       namespace_ref: self.namespace_ref,
       meta: self.meta,
       related_stmt_info_idx: self.related_stmt_info_idx,
+      attributes: self.attributes,
     }
   }
 }
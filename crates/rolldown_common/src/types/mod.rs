@@ -4,7 +4,9 @@ pub mod asset_meta;
 pub mod ast_scope_idx;
 pub mod ast_scopes;
 pub mod bundler_file_system;
+pub mod cancellation_token;
 pub mod chunk_idx;
+pub mod chunk_interop;
 pub mod chunk_kind;
 pub mod deconflict;
 pub mod defer_sync_scan_data;
@@ -1,17 +1,38 @@
+use std::sync::Arc;
+
 use arcstr::ArcStr;
 use rolldown_rstr::Rstr;
-use rolldown_utils::indexmap::FxIndexSet;
+use rolldown_utils::{dashmap::FxDashMap, indexmap::FxIndexSet};
 
-use crate::ModuleId;
+use crate::{ImportKind, ModuleId, ModuleType};
 
 #[derive(Debug)]
 pub struct ModuleInfo {
   pub code: Option<ArcStr>,
   pub id: ModuleId,
+  pub stable_id: String,
+  /// `None` if the module is external or hasn't been loaded yet.
+  pub module_type: Option<ModuleType>,
   pub is_entry: bool,
+  pub has_side_effects: bool,
+  /// Import records of the module with their resolved ids, in source order. Empty until the module is parsed.
+  pub import_records: Vec<ModuleInfoImportRecord>,
   pub importers: FxIndexSet<ModuleId>,
   pub dynamic_importers: FxIndexSet<ModuleId>,
   pub imported_ids: FxIndexSet<ModuleId>,
   pub dynamically_imported_ids: FxIndexSet<ModuleId>,
   pub exports: Vec<Rstr>,
+  pub meta: ModuleMeta,
+}
+
+/// Custom data that plugins attach to a module, like `meta` in Rollup. It's shared by every
+/// `ModuleInfo` of the module, so values set after an info was handed out are visible through it.
+pub type ModuleMeta = Arc<FxDashMap<String, serde_json::Value>>;
+
+#[derive(Debug, Clone)]
+pub struct ModuleInfoImportRecord {
+  /// `./lib.js` in `import { foo } from './lib.js';`
+  pub specifier: Rstr,
+  pub resolved_id: ModuleId,
+  pub kind: ImportKind,
 }
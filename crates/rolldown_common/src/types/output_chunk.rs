@@ -5,7 +5,7 @@ use rolldown_rstr::Rstr;
 use rolldown_sourcemap::SourceMap;
 use rustc_hash::FxHashMap;
 
-use crate::ModuleId;
+use crate::{ChunkInterop, ModuleId};
 
 use super::rendered_module::RenderedModule;
 
@@ -23,10 +23,18 @@ pub struct OutputChunk {
   pub modules: Modules,
   pub imports: Vec<ArcStr>,
   pub dynamic_imports: Vec<ArcStr>,
+  pub imported_bindings: FxHashMap<ArcStr, Vec<Rstr>>,
+  /// The css assets holding the stylesheets imported by the modules of the chunk, to be loaded
+  /// along with it.
+  pub imported_css: Vec<ArcStr>,
+  /// Imports bridged with an interop helper, sorted.
+  pub interop: Vec<ChunkInterop>,
   // OutputChunk
   pub code: String,
   pub map: Option<SourceMap>,
   pub sourcemap_filename: Option<String>,
+  /// The id written to the sourcemap and the `//# debugId=` comment, with `sourcemap_debug_ids`.
+  pub debug_id: Option<String>,
   pub preliminary_filename: String,
 }
 
@@ -36,6 +44,29 @@ pub struct Modules {
   pub values: Vec<Arc<RenderedModule>>,
 }
 
+impl Modules {
+  /// Sets the `minified_length` of each module, given the length of the chunk before and after
+  /// minifying. Minifying works on the chunk as a whole, so the rendered length of each module is
+  /// scaled by how much the chunk shrank.
+  #[must_use]
+  pub fn with_minified_lengths(&self, unminified_length: usize, minified_length: usize) -> Self {
+    let values = self
+      .values
+      .iter()
+      .map(|module| {
+        let mut module = RenderedModule::clone(module);
+        if unminified_length > 0 {
+          let estimate =
+            u64::from(module.rendered_length) * minified_length as u64 / unminified_length as u64;
+          module.minified_length = Some(u32::try_from(estimate).unwrap_or(u32::MAX));
+        }
+        Arc::new(module)
+      })
+      .collect();
+    Self { keys: self.keys.clone(), values }
+  }
+}
+
 impl From<FxHashMap<ModuleId, RenderedModule>> for Modules {
   fn from(value: FxHashMap<ModuleId, RenderedModule>) -> Self {
     let mut kvs = value.into_iter().collect::<Vec<_>>();
@@ -10,6 +10,12 @@ pub struct RenderedModule {
   inner_code: Option<Arc<[Box<dyn Source + Send + Sync>]>>,
   pub rendered_exports: Vec<Rstr>,
   pub exec_order: u32,
+  /// Bytes of the code of the module after the load and transform hooks.
+  pub original_length: u32,
+  /// Bytes of the code of the module in the chunk, after tree shaking and before minifying.
+  pub rendered_length: u32,
+  /// Estimated bytes of the module in the minified chunk, set when `minify` is enabled.
+  pub minified_length: Option<u32>,
 }
 
 impl RenderedModule {
@@ -17,8 +23,21 @@ impl RenderedModule {
     sources: Option<Arc<[Box<dyn Source + Send + Sync>]>>,
     rendered_exports: Vec<Rstr>,
     exec_order: u32,
+    original_length: u32,
   ) -> Self {
-    Self { inner_code: sources, rendered_exports, exec_order }
+    // The length of the code `Self::code` joins, without joining it.
+    let rendered_length = sources.as_ref().map_or(0, |sources| {
+      sources.iter().map(|source| source.content().len()).sum::<usize>()
+        + sources.len().saturating_sub(1)
+    });
+    Self {
+      inner_code: sources,
+      rendered_exports,
+      exec_order,
+      original_length,
+      rendered_length: u32::try_from(rendered_length).unwrap_or(u32::MAX),
+      minified_length: None,
+    }
   }
 
   pub fn code(&self) -> Option<String> {
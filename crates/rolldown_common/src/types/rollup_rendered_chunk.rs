@@ -1,7 +1,8 @@
 use arcstr::ArcStr;
 use rolldown_rstr::Rstr;
+use rustc_hash::FxHashMap;
 
-use crate::ModuleId;
+use crate::{ChunkInterop, ModuleId};
 
 use super::output_chunk::Modules;
 
@@ -20,4 +21,8 @@ pub struct RollupRenderedChunk {
   pub modules: Modules,
   pub imports: Vec<ArcStr>,
   pub dynamic_imports: Vec<ArcStr>,
+  /// Names imported from each dependency, keyed the same way as `imports`. Namespace imports are recorded as `*`.
+  pub imported_bindings: FxHashMap<ArcStr, Vec<Rstr>>,
+  /// Imports bridged with an interop helper, sorted.
+  pub interop: Vec<ChunkInterop>,
 }
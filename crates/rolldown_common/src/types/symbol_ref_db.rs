@@ -131,6 +131,22 @@ impl SymbolRefDb {
     }
     Self { inner: vec }
   }
+
+  /// Unlike [SymbolRefDb::clone_without_scoping], the `Scoping` of each module is cloned as well.
+  #[must_use]
+  pub fn clone_with_another_arena(&self) -> SymbolRefDb {
+    let mut vec = IndexVec::with_capacity(self.inner.len());
+    for inner in &self.inner {
+      vec.push(inner.as_ref().map(|inner| SymbolRefDbForModule {
+        owner_idx: inner.owner_idx,
+        root_scope_id: inner.root_scope_id,
+        ast_scopes: inner.ast_scopes.clone_with_another_arena(),
+        flags: inner.flags.clone(),
+        classic_data: inner.classic_data.clone(),
+      }));
+    }
+    Self { inner: vec }
+  }
 }
 
 impl std::ops::Index<ModuleIdx> for SymbolRefDb {
@@ -13,6 +13,9 @@ pub struct Module {
   pub is_external: bool,
   pub imports: Option<Vec<ModuleImport>>,
   pub importers: Option<Vec<String>>,
+  /// Custom data attached to the module by plugins.
+  #[ts(type = "Record<string, unknown>")]
+  pub meta: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(ts_rs::TS, serde::Serialize)]
@@ -91,6 +91,8 @@ impl EcmaCompiler {
         // https://esbuild.github.io/api/#ignore-annotations
         annotation_comments: true,
         legal_comments,
+        // Only drops the whitespace, the identifiers are kept as they are.
+        minify: options.compact,
         source_map_path: options.sourcemap.then(|| PathBuf::from(options.filename)),
         ..CodegenOptions::default()
       })
@@ -171,4 +173,5 @@ pub struct PrintOptions {
   pub print_legal_comments: bool,
   pub filename: String,
   pub sourcemap: bool,
+  pub compact: bool,
 }
@@ -17,11 +17,27 @@ type PassedStr<'a> = &'a str;
 // `AstBuilder` is more suitable name, but it's already used in oxc.
 pub struct AstSnippet<'ast> {
   pub builder: AstBuilder<'ast>,
+  /// Whether to use arrow functions in generated code, or plain function expressions otherwise.
+  pub arrow_functions: bool,
+  /// Whether to use method shorthands in generated code, or `key: function` properties otherwise.
+  pub object_shorthand: bool,
 }
 
 impl<'ast> AstSnippet<'ast> {
   pub fn new(alloc: &'ast Allocator) -> Self {
-    Self { builder: AstBuilder::new(alloc) }
+    Self { builder: AstBuilder::new(alloc), arrow_functions: true, object_shorthand: true }
+  }
+
+  #[must_use]
+  pub fn with_arrow_functions(mut self, arrow_functions: bool) -> Self {
+    self.arrow_functions = arrow_functions;
+    self
+  }
+
+  #[must_use]
+  pub fn with_object_shorthand(mut self, object_shorthand: bool) -> Self {
+    self.object_shorthand = object_shorthand;
+    self
   }
 
   #[inline]
@@ -332,16 +348,14 @@ impl<'ast> AstSnippet<'ast> {
             NONE,
             Some(body),
           ),
-          true,
+          self.object_shorthand,
           false,
           false,
         )),
       );
       commonjs_call_expr.arguments.push(ast::Argument::ObjectExpression(obj_expr));
     } else {
-      let arrow_expr =
-        self.builder.alloc_arrow_function_expression(SPAN, false, false, NONE, params, NONE, body);
-      commonjs_call_expr.arguments.push(ast::Argument::ArrowFunctionExpression(arrow_expr));
+      commonjs_call_expr.arguments.push(ast::Argument::from(self.fn_expr(false, params, body)));
     }
 
     // var require_foo = ...
@@ -400,17 +414,14 @@ impl<'ast> AstSnippet<'ast> {
             NONE,
             Some(body),
           ),
-          true,
+          self.object_shorthand,
           false,
           false,
         )),
       );
       esm_call_expr.arguments.push(ast::Argument::ObjectExpression(obj_expr));
     } else {
-      let arrow_expr = self
-        .builder
-        .alloc_arrow_function_expression(SPAN, false, is_async, NONE, params, NONE, body);
-      esm_call_expr.arguments.push(ast::Argument::ArrowFunctionExpression(arrow_expr));
+      esm_call_expr.arguments.push(ast::Argument::from(self.fn_expr(is_async, params, body)));
     }
 
     // var init_foo = __esm(...)
@@ -462,25 +473,67 @@ impl<'ast> AstSnippet<'ast> {
 
   /// ```js
   /// () => xx
+  /// // or
+  /// function() { return xx; }
   /// ```
-  pub fn only_return_arrow_expr(&self, expr: ast::Expression<'ast>) -> ast::Expression<'ast> {
-    let statements = self.builder.vec1(ast::Statement::ExpressionStatement(
-      self.builder.alloc_expression_statement(SPAN, expr),
-    ));
-    ast::Expression::ArrowFunctionExpression(self.builder.alloc_arrow_function_expression(
+  pub fn only_return_fn_expr(&self, expr: ast::Expression<'ast>) -> ast::Expression<'ast> {
+    let params = self.builder.formal_parameters(
       SPAN,
-      true,
-      false,
+      ast::FormalParameterKind::Signature,
+      self.builder.vec(),
       NONE,
-      self.builder.formal_parameters(
+    );
+    if self.arrow_functions {
+      let statements = self.builder.vec1(ast::Statement::ExpressionStatement(
+        self.builder.alloc_expression_statement(SPAN, expr),
+      ));
+      ast::Expression::ArrowFunctionExpression(self.builder.alloc_arrow_function_expression(
         SPAN,
-        ast::FormalParameterKind::Signature,
-        self.builder.vec(),
+        true,
+        false,
         NONE,
-      ),
-      NONE,
-      self.builder.function_body(SPAN, self.builder.vec(), statements),
-    ))
+        params,
+        NONE,
+        self.builder.function_body(SPAN, self.builder.vec(), statements),
+      ))
+    } else {
+      let statements = self.builder.vec1(self.builder.statement_return(SPAN, Some(expr)));
+      self.fn_expr(false, params, self.builder.function_body(SPAN, self.builder.vec(), statements))
+    }
+  }
+
+  /// ```js
+  /// (...) => { ... }
+  /// // or
+  /// function(...) { ... }
+  /// ```
+  fn fn_expr(
+    &self,
+    is_async: bool,
+    params: ast::FormalParameters<'ast>,
+    body: ast::FunctionBody<'ast>,
+  ) -> ast::Expression<'ast> {
+    if self.arrow_functions {
+      ast::Expression::ArrowFunctionExpression(
+        self
+          .builder
+          .alloc_arrow_function_expression(SPAN, false, is_async, NONE, params, NONE, body),
+      )
+    } else {
+      self.builder.expression_function(
+        SPAN,
+        FunctionType::FunctionExpression,
+        None,
+        false,
+        is_async,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+      )
+    }
   }
 
   #[inline]
@@ -565,7 +618,7 @@ impl<'ast> AstSnippet<'ast> {
         false,
       )),
       NONE,
-      self.builder.vec1(Argument::from(self.only_return_arrow_expr(expr))),
+      self.builder.vec1(Argument::from(self.only_return_fn_expr(expr))),
       false,
     ))
   }
@@ -584,7 +637,7 @@ impl<'ast> AstSnippet<'ast> {
         false,
       )),
       NONE,
-      self.builder.vec1(Argument::from(self.only_return_arrow_expr(return_expr))),
+      self.builder.vec1(Argument::from(self.only_return_fn_expr(return_expr))),
       false,
     ))
   }
@@ -608,7 +661,7 @@ impl<'ast> AstSnippet<'ast> {
       } else {
         self.builder.property_key_static_identifier(SPAN, self.builder.atom(key))
       },
-      self.only_return_arrow_expr(expr),
+      self.only_return_fn_expr(expr),
       true,
       false,
       computed,
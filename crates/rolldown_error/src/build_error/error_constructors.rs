@@ -18,10 +18,13 @@ use crate::events::illegal_identifier_as_name::IllegalIdentifierAsName;
 use crate::events::import_is_undefined::ImportIsUndefined;
 use crate::events::invalid_define_config::InvalidDefineConfig;
 use crate::events::invalid_option::{InvalidOption, InvalidOptionType};
+use crate::events::invalid_rollup_phase::InvalidRollupPhase;
 use crate::events::json_parse::JsonParse;
 use crate::events::missing_global_name::MissingGlobalName;
 use crate::events::missing_name_option_for_iife_export::MissingNameOptionForIifeExport;
 use crate::events::missing_name_option_for_umd_export::MissingNameOptionForUmdExport;
+use crate::events::plugin_log::{PluginDiagnostic, PluginLog};
+use crate::events::plugin_order::PluginOrder;
 use crate::events::resolve_error::DiagnosableResolveError;
 use crate::events::unhandleable_error::UnhandleableError;
 use crate::events::unloadable_dependency::{UnloadableDependency, UnloadableDependencyContext};
@@ -30,14 +33,20 @@ use crate::events::{
   ambiguous_external_namespace::{AmbiguousExternalNamespace, AmbiguousExternalNamespaceModule},
   circular_dependency::CircularDependency,
   commonjs_variable_in_esm::{CjsExportSpan, CommonJsVariableInEsm},
+  duplicate_package::DuplicatePackage,
   eval::Eval,
   external_entry::ExternalEntry,
   forbid_const_assign::ForbidConstAssign,
+  hmr_export_change::HmrExportChange,
+  hmr_unknown_module::HmrUnknownModule,
   invalid_export_option::InvalidExportOption,
   missing_export::MissingExport,
   mixed_export::MixedExport,
   parse_error::ParseError,
+  shimmed_export::ShimmedExport,
   unresolved_entry::UnresolvedEntry,
+  unresolved_type_import::UnresolvedTypeImport,
+  unsupported_import_attributes::UnsupportedImportAttributes,
 };
 use crate::line_column_to_byte_offset;
 
@@ -245,6 +254,18 @@ impl BuildDiagnostic {
     Self::new_inner(InvalidOption { invalid_option_type })
   }
 
+  pub fn invalid_rollup_phase_for_chunk_emission() -> Self {
+    Self::new_inner(InvalidRollupPhase::ChunkEmission)
+  }
+
+  pub fn invalid_rollup_phase_for_module_graph_query(api: &'static str) -> Self {
+    Self::new_inner(InvalidRollupPhase::ModuleGraphQuery { api })
+  }
+
+  pub fn invalid_rollup_phase_for_chunk_query(api: &'static str) -> Self {
+    Self::new_inner(InvalidRollupPhase::ChunkQuery { api })
+  }
+
   #[cfg(feature = "napi")]
   pub fn napi_error(err: napi::Error) -> Self {
     let mut diagnostic = Self::new_inner(NapiError {});
@@ -301,8 +322,74 @@ impl BuildDiagnostic {
     Self::new_inner(InvalidDefineConfig { message })
   }
 
+  pub fn unresolved_type_import(
+    importer: String,
+    specifier: String,
+    reason: String,
+    source: ArcStr,
+    span: Span,
+  ) -> Self {
+    Self::new_inner(UnresolvedTypeImport { importer, specifier, reason, source, span })
+  }
+
+  pub fn plugin_warning(log: PluginLog) -> Self {
+    Self::new_inner(PluginDiagnostic { log, is_warning: true }).with_severity_warning()
+  }
+
+  pub fn plugin_error(log: PluginLog) -> Self {
+    Self::new_inner(PluginDiagnostic { log, is_warning: false })
+  }
+
+  pub fn shimmed_export(
+    importer: String,
+    stable_importer: String,
+    exporter: String,
+    stable_exporter: String,
+    binding: String,
+  ) -> Self {
+    Self::new_inner(ShimmedExport { importer, stable_importer, exporter, stable_exporter, binding })
+  }
+
+  pub fn hmr_export_change(
+    module_id: String,
+    stable_module_id: String,
+    added: Vec<String>,
+    removed: Vec<String>,
+  ) -> Self {
+    Self::new_inner(HmrExportChange { module_id, stable_module_id, added, removed })
+  }
+
+  pub fn duplicate_package(
+    name: String,
+    versions: Vec<(String, Vec<String>)>,
+    duplicated_bytes: usize,
+  ) -> Self {
+    Self::new_inner(DuplicatePackage { name, versions, duplicated_bytes })
+  }
+
+  /// Reported with [`Severity::Info`], since it's a report rather than a problem.
+  pub fn plugin_order(description: String) -> Self {
+    Self::new_inner(PluginOrder { description }).with_severity(Severity::Info)
+  }
+
+  pub fn hmr_unknown_module(module_id: String) -> Self {
+    Self::new_inner(HmrUnknownModule { module_id })
+  }
+
+  pub fn unsupported_import_attributes(
+    importer: String,
+    specifier: String,
+    attributes: String,
+    format: String,
+  ) -> Self {
+    Self::new_inner(UnsupportedImportAttributes { importer, specifier, attributes, format })
+  }
+
   pub fn unhandleable_error(err: anyhow::Error) -> Self {
-    downcast_napi_error_diagnostics(err)
+    // Plugins could return a specific `BuildDiagnostic` wrapped in `anyhow::Error`. Keep it as it is.
+    err
+      .downcast::<BuildDiagnostic>()
+      .or_else(downcast_napi_error_diagnostics)
       .unwrap_or_else(|err| Self::new_inner(UnhandleableError(err)))
   }
 }
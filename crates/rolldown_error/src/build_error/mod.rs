@@ -1,12 +1,15 @@
 pub mod error_constructors;
 pub mod severity;
 use std::{
-  fmt::Display,
+  fmt::{Display, Write as _},
   ops::{Deref, DerefMut},
 };
 
+use ariadne::Span as _;
+
 use crate::{
-  diagnostic::Diagnostic, events::BuildEvent, types::diagnostic_options::DiagnosticOptions,
+  diagnostic::Diagnostic, events::BuildEvent, structured_diagnostic::StructuredDiagnostic,
+  types::diagnostic_options::DiagnosticOptions,
 };
 
 use self::severity::Severity;
@@ -18,8 +21,22 @@ pub struct BuildDiagnostic {
   #[cfg(feature = "napi")]
   napi_error: Option<napi::Error>,
   severity: Severity,
+  /// Identical diagnostics folded into this one by `dedupe_diagnostics`, in reporting order.
+  duplicates: Vec<BuildDiagnostic>,
+  /// Plugin whose hook reported the diagnostic, attached by the plugin driver. Boxed to keep
+  /// `BuildResult` small, since most diagnostics have none.
+  provenance: Option<Box<Provenance>>,
+}
+
+#[derive(Debug)]
+struct Provenance {
+  plugin_name: String,
+  hook: Option<String>,
 }
 
+/// How many locations of the duplicates are listed in the rendered text before summing up the rest.
+const MAX_LISTED_DUPLICATES: usize = 3;
+
 fn _assert_build_error_send_sync() {
   fn assert_send_sync<T: Send + Sync>() {}
   assert_send_sync::<BuildDiagnostic>();
@@ -31,6 +48,8 @@ impl Display for BuildDiagnostic {
   }
 }
 
+impl std::error::Error for BuildDiagnostic {}
+
 impl BuildDiagnostic {
   pub fn kind(&self) -> crate::event_kind::EventKind {
     self.inner.kind()
@@ -46,22 +65,95 @@ impl BuildDiagnostic {
   }
 
   #[must_use]
-  pub fn with_severity_warning(mut self) -> Self {
-    self.severity = Severity::Warning;
+  pub fn with_severity_warning(self) -> Self {
+    self.with_severity(Severity::Warning)
+  }
+
+  #[must_use]
+  pub fn with_severity_error(self) -> Self {
+    self.with_severity(Severity::Error)
+  }
+
+  #[must_use]
+  pub fn with_severity(mut self, severity: Severity) -> Self {
+    self.severity = severity;
+    self.duplicates =
+      std::mem::take(&mut self.duplicates).into_iter().map(|d| d.with_severity(severity)).collect();
+    self
+  }
+
+  pub fn severity(&self) -> Severity {
+    self.severity
+  }
+
+  /// Records the plugin and the hook the diagnostic comes from, rendered as `[plugin:hook]` in
+  /// place of the kind. The first provenance is kept, so an error bubbling up through the hooks of other
+  /// plugins still points at the one that reported it.
+  #[must_use]
+  pub fn with_provenance(mut self, plugin_name: impl Into<String>, hook: Option<&str>) -> Self {
+    if self.provenance.is_none() {
+      self.provenance = Some(Box::new(Provenance {
+        plugin_name: plugin_name.into(),
+        hook: hook.map(ToString::to_string),
+      }));
+    }
     self
   }
 
+  /// How many times this diagnostic was reported, counting the duplicates folded into it.
+  pub fn occurrences(&self) -> usize {
+    1 + self.duplicates.len()
+  }
+
+  pub fn duplicates(&self) -> &[BuildDiagnostic] {
+    &self.duplicates
+  }
+
   pub fn to_diagnostic(&self) -> Diagnostic {
     self.to_diagnostic_with(&DiagnosticOptions::default())
   }
 
   pub fn to_diagnostic_with(&self, opts: &DiagnosticOptions) -> Diagnostic {
-    let mut diagnostic =
-      Diagnostic::new(self.kind().to_string(), self.inner.message(opts), self.severity);
-    self.inner.on_diagnostic(&mut diagnostic, opts);
+    let mut diagnostic = self.to_plain_diagnostic(opts);
+    if let Some(Provenance { plugin_name, hook }) = self.provenance.as_deref() {
+      diagnostic.kind = match hook {
+        Some(hook) => format!("{plugin_name}:{hook}"),
+        None => plugin_name.clone(),
+      };
+    }
+    if !self.duplicates.is_empty() {
+      diagnostic.title.push_str(&self.describe_duplicates(opts));
+    }
     diagnostic
   }
 
+  /// Structured counterpart of `to_diagnostic_with`, exposing the code, severity, message and the
+  /// labeled source ranges instead of the rendered text. Columns are byte offsets, see
+  /// [`StructuredLabel`](crate::StructuredLabel).
+  pub fn to_structured(&self, opts: &DiagnosticOptions) -> StructuredDiagnostic {
+    self.to_diagnostic_with(opts).to_structured()
+  }
+
+  /// Machine-readable form of the diagnostic, printed on a single line with `to_string`, so a list
+  /// of them can be dumped as NDJSON. Files in `spans` are relative to `opts.cwd`, like in the
+  /// rendered text, and `startColumn`/`endColumn` count bytes.
+  pub fn to_json(&self, opts: &DiagnosticOptions) -> serde_json::Value {
+    // The provenance and the duplicates have fields of their own, rather than being in the message.
+    let structured = self.to_plain_diagnostic(opts).to_structured();
+    serde_json::json!({
+      "kind": self.kind().to_string(),
+      "severity": self.severity.as_str(),
+      "message": structured.message,
+      "help": structured.help,
+      "id": self.id().map(|id| opts.stabilize_path(id)),
+      "plugin": self.plugin(),
+      "hook": self.hook(),
+      "spans": structured.spans_to_json(),
+      "occurrences": self.occurrences(),
+      "duplicates": self.duplicates.iter().map(|d| d.to_json(opts)).collect::<Vec<_>>(),
+    })
+  }
+
   #[cfg(feature = "napi")]
   pub fn downcast_napi_error(&self) -> Result<&napi::Error, &Self> {
     match &self.napi_error {
@@ -78,8 +170,74 @@ impl BuildDiagnostic {
     self.inner.exporter()
   }
 
+  pub fn plugin(&self) -> Option<String> {
+    match self.provenance.as_deref() {
+      Some(provenance) => Some(provenance.plugin_name.clone()),
+      None => self.inner.plugin(),
+    }
+  }
+
+  /// Hook of `plugin` the diagnostic was reported from, such as `transform`.
+  pub fn hook(&self) -> Option<String> {
+    self.provenance.as_deref().and_then(|provenance| provenance.hook.clone())
+  }
+
+  /// Diagnostics with the same key are reported for the same cause and are folded together.
+  pub(crate) fn dedupe_key(&self, opts: &DiagnosticOptions) -> (u32, String, Option<String>) {
+    (self.kind() as u32, self.inner.message(opts), self.primary_file(opts))
+  }
+
+  pub(crate) fn add_duplicate(&mut self, duplicate: BuildDiagnostic) {
+    self.duplicates.push(duplicate);
+  }
+
   // --- private
 
+  /// The diagnostic without the provenance and the summary of the duplicates.
+  fn to_plain_diagnostic(&self, opts: &DiagnosticOptions) -> Diagnostic {
+    let mut diagnostic =
+      Diagnostic::new(self.kind().to_string(), self.inner.message(opts), self.severity);
+    diagnostic.context_lines = opts.context_lines;
+    diagnostic.max_line_width = opts.max_line_width;
+    self.inner.on_diagnostic(&mut diagnostic, opts);
+    diagnostic
+  }
+
+  /// The module the diagnostic is about, falling back to the file of the first label. Only the
+  /// labels are collected for that, the message isn't built.
+  fn primary_file(&self, opts: &DiagnosticOptions) -> Option<String> {
+    self.id().map(|id| opts.stabilize_path(id)).or_else(|| {
+      let mut diagnostic = Diagnostic::new(self.kind().to_string(), String::new(), self.severity);
+      self.inner.on_diagnostic(&mut diagnostic, opts);
+      diagnostic.labels.first().map(|label| label.span().source().to_string())
+    })
+  }
+
+  /// `file:line:column` of the first label, falling back to the module the diagnostic is about.
+  fn location(&self, opts: &DiagnosticOptions) -> Option<String> {
+    let structured = self.to_structured(opts);
+    structured
+      .primary_label()
+      .map(|label| format!("{}:{}:{}", label.file, label.start_line, label.start_column))
+      .or_else(|| self.id().map(|id| opts.stabilize_path(id)))
+  }
+
+  fn describe_duplicates(&self, opts: &DiagnosticOptions) -> String {
+    let mut text = String::new();
+    let mut listed = 0;
+    for location in
+      self.duplicates.iter().filter_map(|d| d.location(opts)).take(MAX_LISTED_DUPLICATES)
+    {
+      write!(text, "\n - also reported at {location}").expect("Failed to write duplicates");
+      listed += 1;
+    }
+    let rest = self.duplicates.len() - listed;
+    if rest > 0 {
+      write!(text, "\n - and {rest} more").expect("Failed to write duplicates");
+    }
+    text
+  }
+
   fn new_inner(inner: impl Into<Box<dyn BuildEvent>>) -> Self {
     Self {
       inner: inner.into(),
@@ -87,6 +245,8 @@ impl BuildDiagnostic {
       #[cfg(feature = "napi")]
       napi_error: None,
       severity: Severity::Error,
+      duplicates: Vec::new(),
+      provenance: None,
     }
   }
 }
@@ -1,5 +1,17 @@
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Severity {
   Error,
   Warning,
+  /// Only reachable through `severity_overrides`, for diagnostics demoted below warnings.
+  Info,
+}
+
+impl Severity {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::Error => "error",
+      Self::Warning => "warning",
+      Self::Info => "info",
+    }
+  }
 }
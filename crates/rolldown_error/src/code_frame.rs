@@ -0,0 +1,298 @@
+use std::ops::Range;
+
+use arcstr::ArcStr;
+use ariadne::{Label, Source, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{
+  diagnostic::{DiagnosticFileId, RolldownLabelSpan},
+  locator::line_starts,
+};
+
+const ELLIPSIS: &str = "…";
+
+/// The sources ariadne renders a diagnostic from, cut down to the lines of its code frames.
+///
+/// Ariadne only prints the lines with labels, so the context lines get labels without a message,
+/// which show the line and nothing else. They cover the first character of the line, since an
+/// empty span at the start of a line counts as the end of the line before.
+#[derive(Default)]
+pub struct CodeFrame {
+  /// Excerpt of each file. The lines before it are left empty, so the lines keep their numbers.
+  pub sources: Vec<(DiagnosticFileId, Source<String>)>,
+  pub labels: Vec<Label<RolldownLabelSpan>>,
+  /// Ariadne takes the column in the header of a file from its excerpt. For windowed lines, it's
+  /// replaced by the column in the file, as `(in excerpt, in file)`.
+  pub locations: Vec<(String, String)>,
+}
+
+impl CodeFrame {
+  pub fn new(
+    files: &FxHashMap<DiagnosticFileId, ArcStr>,
+    labels: &[Label<RolldownLabelSpan>],
+    context_lines: usize,
+    max_line_width: Option<usize>,
+  ) -> Self {
+    let mut frame = Self::default();
+    let mut context_labels = vec![];
+    let mut file_ids: Vec<&DiagnosticFileId> = vec![];
+    for label in labels {
+      if !file_ids.contains(&label.span().source()) {
+        file_ids.push(label.span().source());
+      }
+    }
+    for file_id in file_ids {
+      let file_labels =
+        labels.iter().filter(|label| label.span().source() == file_id).collect::<Vec<_>>();
+      let excerpt = Excerpt::new(&files[file_id], &file_labels, context_lines, max_line_width);
+
+      for label in &file_labels {
+        let span = excerpt.map_span(label.span().start()..label.span().end());
+        let mut mapped = Label::new(RolldownLabelSpan::from((file_id.clone(), span)));
+        if let Some(msg) = label.display_info().msg() {
+          mapped = mapped.with_message(msg);
+        }
+        frame.labels.push(mapped);
+      }
+
+      context_labels.extend(
+        excerpt
+          .context_line_spans()
+          .map(|span| Label::new(RolldownLabelSpan::from((file_id.clone(), span)))),
+      );
+      if let Some(location) = excerpt.location(file_id, file_labels[0].span().start()) {
+        frame.locations.push(location);
+      }
+      frame.sources.push((file_id.clone(), Source::from(excerpt.text)));
+    }
+    // After the labels with messages, so the header of each file still points at its first one.
+    frame.labels.extend(context_labels);
+    frame
+  }
+}
+
+/// A line of the file as it's shown in the excerpt.
+struct ExcerptLine {
+  /// Offset of the line in the file.
+  start: usize,
+  /// Bytes of the line shown, relative to `start`.
+  window: Range<usize>,
+  /// Offset of the line in the excerpt.
+  excerpt_start: usize,
+  /// Whether the line starts with an ellipsis.
+  cut_before: bool,
+}
+
+struct Excerpt<'a> {
+  source: &'a str,
+  line_starts: Vec<usize>,
+  first_line: usize,
+  lines: Vec<ExcerptLine>,
+  /// Lines of the file, which aren't labelled, shown around the labelled ones.
+  context_lines: Vec<usize>,
+  text: String,
+}
+
+impl<'a> Excerpt<'a> {
+  fn new(
+    source: &'a str,
+    labels: &[&Label<RolldownLabelSpan>],
+    context_lines: usize,
+    max_line_width: Option<usize>,
+  ) -> Self {
+    let line_starts = line_starts(source).collect::<Vec<_>>();
+    let line_of = |offset: usize| line_starts.partition_point(|&start| start <= offset) - 1;
+    // A trailing line break doesn't start another line.
+    let last_line_in_file = line_starts.len() - 1 - usize::from(source.ends_with('\n'));
+
+    let label_lines = labels
+      .iter()
+      .map(|label| {
+        let span = label.span();
+        (line_of(span.start()), line_of(span.end().saturating_sub(1).max(span.start())))
+      })
+      .collect::<Vec<_>>();
+    let first_line = label_lines.iter().map(|(start, _)| start).min().copied().unwrap_or_default();
+    let first_line = first_line.saturating_sub(context_lines);
+    let last_label_line = label_lines.iter().map(|(_, end)| end).max().copied().unwrap_or_default();
+    let last_line = (last_label_line + context_lines).min(last_line_in_file).max(last_label_line);
+
+    let is_labelled =
+      |line: usize| label_lines.iter().any(|&(start, end)| (start..=end).contains(&line));
+    let context = (first_line..=last_line)
+      .filter(|&line| {
+        !is_labelled(line)
+          && label_lines.iter().any(|&(start, end)| {
+            line.abs_diff(start) <= context_lines || line.abs_diff(end) <= context_lines
+          })
+      })
+      .collect::<Vec<_>>();
+
+    // The column to keep in view on each line, so the carets stay next to the code they point at.
+    let primary_column = labels.first().map_or(0, |label| {
+      let start = label.span().start();
+      start - line_starts[line_of(start)]
+    });
+    let focus = |line: usize| {
+      labels
+        .iter()
+        .map(|label| label.span().start())
+        .chain(labels.iter().map(|label| label.span().end()))
+        .find(|&offset| line_of(offset) == line)
+        .map_or(primary_column, |offset| offset - line_starts[line])
+    };
+
+    let mut text = "\n".repeat(first_line);
+    let mut lines = Vec::with_capacity(last_line - first_line + 1);
+    for line in first_line..=last_line {
+      let start = line_starts[line];
+      let end = line_starts.get(line + 1).copied().unwrap_or(source.len());
+      let content = source[start..end].trim_end_matches(['\n', '\r']);
+      let window = match max_line_width {
+        Some(max_width) if content.chars().count() > max_width => {
+          window_around(content, focus(line), max_width)
+        }
+        // Labels may cover the line break, which is kept.
+        _ => 0..end - start,
+      };
+      let cut_before = window.start > 0;
+      let cut_after = window.end < content.len();
+      let excerpt_start = text.len();
+      if cut_before {
+        text.push_str(ELLIPSIS);
+      }
+      if cut_before || cut_after {
+        text.push_str(&content[window.clone()]);
+        if cut_after {
+          text.push_str(ELLIPSIS);
+        }
+        text.push('\n');
+      } else {
+        text.push_str(&source[start..end]);
+      }
+      lines.push(ExcerptLine { start, window, excerpt_start, cut_before });
+    }
+
+    Self { source, line_starts, first_line, lines, context_lines: context, text }
+  }
+
+  fn line_of(&self, offset: usize) -> usize {
+    self.line_starts.partition_point(|&start| start <= offset) - 1
+  }
+
+  /// Offset in the excerpt of `offset` on `line` of the file, moved into the window of the line.
+  fn map_offset(&self, line: usize, offset: usize) -> usize {
+    let line = &self.lines
+      [line.clamp(self.first_line, self.first_line + self.lines.len() - 1) - self.first_line];
+    let column = offset.saturating_sub(line.start).clamp(line.window.start, line.window.end);
+    line.excerpt_start
+      + if line.cut_before { ELLIPSIS.len() } else { 0 }
+      + (column - line.window.start)
+  }
+
+  fn map_span(&self, span: Range<usize>) -> Range<usize> {
+    let start = self.map_offset(self.line_of(span.start), span.start);
+    let end_line = self.line_of(span.end.saturating_sub(1).max(span.start));
+    start..self.map_offset(end_line, span.end).max(start)
+  }
+
+  /// The first character of each context line in the excerpt.
+  fn context_line_spans(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+    self.context_lines.iter().map(|&line| {
+      let start = self.lines[line - self.first_line].excerpt_start;
+      start..start + self.text[start..].chars().next().map_or(1, char::len_utf8)
+    })
+  }
+
+  /// The location ariadne shows for a label at `offset`, and where it actually is, if they differ.
+  fn location(&self, file_id: &DiagnosticFileId, offset: usize) -> Option<(String, String)> {
+    let line = self.line_of(offset);
+    let excerpt_line = &self.lines[line - self.first_line];
+    if !excerpt_line.cut_before {
+      return None;
+    }
+    let column = self.source[excerpt_line.start..offset].chars().count();
+    let mapped = self.map_offset(line, offset);
+    let excerpt_column = self.text[excerpt_line.excerpt_start..mapped].chars().count();
+    Some((
+      format!("{file_id}:{}:{}", line + 1, excerpt_column + 1),
+      format!("{file_id}:{}:{}", line + 1, column + 1),
+    ))
+  }
+}
+
+/// Bytes of `content` in a window of `max_width` characters around the byte `focus`.
+fn window_around(content: &str, focus: usize, max_width: usize) -> Range<usize> {
+  let boundaries = content.char_indices().map(|(index, _)| index).collect::<Vec<_>>();
+  let focus = boundaries.partition_point(|&index| index < focus);
+  let start = focus.saturating_sub(max_width / 2).min(boundaries.len() - max_width);
+  let end = start + max_width;
+  boundaries[start]..boundaries.get(end).copied().unwrap_or(content.len())
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{fmt::Write as _, ops::Range};
+
+  use crate::{build_error::severity::Severity, diagnostic::Diagnostic};
+
+  fn render(
+    source: &str,
+    span: Range<u32>,
+    context_lines: usize,
+    max_line_width: Option<usize>,
+  ) -> String {
+    let mut diagnostic = Diagnostic::new("TEST".into(), "Something is off".into(), Severity::Error);
+    diagnostic.context_lines = context_lines;
+    diagnostic.max_line_width = max_line_width;
+    let file_id = diagnostic.add_file("main.js", source);
+    diagnostic.add_label(&file_id, span, "here".into());
+    diagnostic.to_string()
+  }
+
+  fn minified() -> String {
+    let source = format!("{}oops{}", "a".repeat(2500), "b".repeat(2496));
+    assert_eq!(source.len(), 5000);
+    source
+  }
+
+  #[test]
+  fn windows_long_lines_around_the_span() {
+    let rendered = render(&minified(), 2500..2504, 2, Some(80));
+
+    assert!(rendered.contains("[ main.js:1:2501 ]"), "{rendered}");
+    assert!(rendered.lines().all(|line| line.chars().count() < 100), "{rendered}");
+    let code = rendered.lines().find(|line| line.contains("oops")).unwrap();
+    assert!(code.contains("│ …a") && code.ends_with("b…"), "{rendered}");
+    let underline = rendered.lines().find(|line| line.contains('┬')).unwrap();
+    let column = |line: &str, pattern: &str| line[..line.find(pattern).unwrap()].chars().count();
+    assert_eq!(column(code, "oops"), column(underline, "─"), "{rendered}");
+  }
+
+  #[test]
+  fn lists_spans_on_long_lines_without_max_line_width() {
+    let rendered = render(&minified(), 2500..2504, 2, None);
+
+    assert!(rendered.contains(" - here in main.js at 2500..2504"), "{rendered}");
+    assert!(!rendered.contains("oops"), "{rendered}");
+  }
+
+  #[test]
+  fn shows_context_lines_around_the_span() {
+    let mut source = String::new();
+    for i in 1..=9 {
+      writeln!(source, "const line{i} = {i};").unwrap();
+    }
+    let span = source.find("line5").unwrap();
+    let span = u32::try_from(span).unwrap()..u32::try_from(span + 5).unwrap();
+
+    let rendered = render(&source, span.clone(), 2, Some(80));
+    for line in 3..=7 {
+      assert!(rendered.contains(&format!(" {line} │ const line{line} = ")), "{rendered}");
+    }
+    assert!(!rendered.contains("line2") && !rendered.contains("line8"), "{rendered}");
+
+    let rendered = render(&source, span, 0, Some(80));
+    assert!(!rendered.contains("line4") && !rendered.contains("line6"), "{rendered}");
+  }
+}
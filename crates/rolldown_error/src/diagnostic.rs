@@ -1,14 +1,23 @@
-use std::{fmt::Display, fmt::Write as _, ops::Range};
+use std::{collections::HashMap, fmt::Display, fmt::Write as _, ops::Range};
 
 use arcstr::ArcStr;
-use ariadne::{Config, Label, Report, ReportBuilder, ReportKind, Span, sources};
+use ariadne::{Config, FnCache, Label, Report, ReportBuilder, ReportKind, Span};
 use rustc_hash::FxHashMap;
 
-use crate::{build_error::severity::Severity, utils::is_context_too_long};
+use crate::{
+  build_error::severity::Severity, code_frame::CodeFrame, types::color_mode::ColorMode,
+  utils::is_context_too_long,
+};
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq)]
 pub struct DiagnosticFileId(ArcStr);
 
+impl DiagnosticFileId {
+  pub fn as_arcstr(&self) -> &ArcStr {
+    &self.0
+  }
+}
+
 impl Display for DiagnosticFileId {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     self.0.fmt(f)
@@ -54,6 +63,8 @@ pub struct Diagnostic {
   pub(crate) labels: Vec<Label<RolldownLabelSpan>>,
   pub(crate) help: Option<String>,
   pub(crate) severity: Severity,
+  pub(crate) context_lines: usize,
+  pub(crate) max_line_width: Option<usize>,
 }
 
 type AriadneReportBuilder = ReportBuilder<'static, RolldownLabelSpan>;
@@ -68,6 +79,8 @@ impl Diagnostic {
       labels: Vec::default(),
       help: None,
       severity,
+      context_lines: 0,
+      max_line_width: None,
     }
   }
 
@@ -102,19 +115,22 @@ impl Diagnostic {
     self
   }
 
-  fn init_report_builder(&self) -> AriadneReportBuilder {
+  fn init_report_builder(&self) -> (AriadneReportBuilder, CodeFrame) {
     let mut message = self.title.clone();
     let mut builder = AriadneReport::build(
       match self.severity {
         Severity::Error => ReportKind::Error,
         Severity::Warning => ReportKind::Warning,
+        Severity::Info => ReportKind::Advice,
       },
       RolldownLabelSpan(ArcStr::default().into(), 0..0),
     )
     .with_code(self.kind.clone());
 
+    let mut labels = vec![];
     for label in self.labels.clone() {
-      if is_context_too_long(&label, &self.files) {
+      // Unless long lines are windowed, a label on one is listed in the message instead.
+      if self.max_line_width.is_none() && is_context_too_long(&label, &self.files) {
         let span = label.span();
         write!(
           message,
@@ -125,9 +141,11 @@ impl Diagnostic {
         )
         .expect("Failed to write report message");
       } else {
-        builder = builder.with_label(label);
+        labels.push(label);
       }
     }
+    let frame = CodeFrame::new(&self.files, &labels, self.context_lines, self.max_line_width);
+    builder = builder.with_labels(frame.labels.clone());
 
     if let Some(help) = &self.help {
       builder = builder.with_help(help);
@@ -135,24 +153,37 @@ impl Diagnostic {
 
     builder = builder.with_message(message);
 
-    builder
+    (builder, frame)
   }
 
   pub fn convert_to_string(&self, color: bool) -> String {
-    let builder = self.init_report_builder();
+    let (builder, frame) = self.init_report_builder();
+    let cache = FnCache::new(
+      (|id: &DiagnosticFileId| Err(format!("Failed to fetch source '{id}'"))) as fn(&_) -> _,
+    )
+    .with_sources(frame.sources.into_iter().collect::<HashMap<_, _>>());
     let mut output = Vec::new();
     builder
       .with_config(Config::default().with_color(color).with_index_type(ariadne::IndexType::Byte))
       .finish()
-      .write_for_stdout(sources(self.files.clone()), &mut output)
+      .write_for_stdout(cache, &mut output)
       .unwrap();
-    String::from_utf8(output).expect("Diagnostic should be valid utf8")
+    let mut output = String::from_utf8(output).expect("Diagnostic should be valid utf8");
+    for (in_excerpt, in_file) in frame.locations {
+      output = output.replacen(&in_excerpt, &in_file, 1);
+    }
+    output
   }
 
   pub fn to_color_string(&self) -> String {
     self.convert_to_string(true)
   }
 
+  /// Renders the diagnostic with ANSI colors only if `mode` asks for them.
+  pub fn to_string_with_color(&self, mode: ColorMode) -> String {
+    self.convert_to_string(mode.should_colorize())
+  }
+
   pub fn with_kind(mut self, kind: String) -> Self {
     self.kind = kind;
     self
@@ -41,6 +41,66 @@ pub enum EventKind {
   IoError = 26,
   NapiError = 27,
   ConfigurationFieldConflict = 28,
+  UnresolvedTypeImportError = 29,
+  /// Whether to emit warning when import attributes of an external module can't be kept in the output format
+  UnsupportedImportAttributes = 30,
+  /// Whether to emit warning when a missing export is shimmed by `shimMissingExports`
+  ShimmedExport = 31,
+  InvalidRollupPhaseError = 32,
+  /// Whether to emit warnings reported by plugins through `PluginContext::warn`
+  PluginWarning = 33,
+  PluginError = 34,
+  /// Whether to emit warning when a hot update changes the exports of a module accepting it, with `experimental.hmr.exportChange: 'warn'`
+  HmrExportChange = 35,
+  HmrUnknownModuleError = 36,
+  /// Whether to emit warning when a package is bundled in more than one version
+  DuplicatePackage = 37,
+  /// Whether to emit the order plugin hooks run in, with `debugPluginOrder: true`
+  PluginOrder = 38,
+}
+
+impl EventKind {
+  pub const ALL: [EventKind; 39] = [
+    EventKind::AmbiguousExternalNamespaceError,
+    EventKind::CircularDependency,
+    EventKind::Eval,
+    EventKind::IllegalIdentifierAsNameError,
+    EventKind::InvalidExportOptionError,
+    EventKind::InvalidOptionError,
+    EventKind::MissingExportError,
+    EventKind::MissingGlobalName,
+    EventKind::MissingNameOptionForIifeExport,
+    EventKind::MissingNameOptionForUmdExportError,
+    EventKind::MixedExport,
+    EventKind::ParseError,
+    EventKind::UnresolvedEntry,
+    EventKind::UnresolvedImport,
+    EventKind::FilenameConflict,
+    EventKind::AssignToImportError,
+    EventKind::CommonJsVariableInEsm,
+    EventKind::ExportUndefinedVariableError,
+    EventKind::ImportIsUndefined,
+    EventKind::UnsupportedFeatureError,
+    EventKind::JsonParseError,
+    EventKind::IllegalReassignmentError,
+    EventKind::InvalidDefineConfigError,
+    EventKind::ResolveError,
+    EventKind::UnhandleableError,
+    EventKind::UnloadableDependencyError,
+    EventKind::IoError,
+    EventKind::NapiError,
+    EventKind::ConfigurationFieldConflict,
+    EventKind::UnresolvedTypeImportError,
+    EventKind::UnsupportedImportAttributes,
+    EventKind::ShimmedExport,
+    EventKind::InvalidRollupPhaseError,
+    EventKind::PluginWarning,
+    EventKind::PluginError,
+    EventKind::HmrExportChange,
+    EventKind::HmrUnknownModuleError,
+    EventKind::DuplicatePackage,
+    EventKind::PluginOrder,
+  ];
 }
 
 impl Display for EventKind {
@@ -64,6 +124,14 @@ impl Display for EventKind {
       EventKind::UnresolvedEntry => write!(f, "UNRESOLVED_ENTRY"),
       EventKind::UnresolvedImport => write!(f, "UNRESOLVED_IMPORT"),
       EventKind::FilenameConflict => write!(f, "FILE_NAME_CONFLICT"),
+      EventKind::ShimmedExport => write!(f, "SHIMMED_EXPORT"),
+      EventKind::InvalidRollupPhaseError => write!(f, "INVALID_ROLLUP_PHASE"),
+      EventKind::PluginWarning => write!(f, "PLUGIN_WARNING"),
+      EventKind::PluginError => write!(f, "PLUGIN_ERROR"),
+      EventKind::HmrExportChange => write!(f, "HMR_EXPORT_CHANGE"),
+      EventKind::HmrUnknownModuleError => write!(f, "HMR_UNKNOWN_MODULE"),
+      EventKind::DuplicatePackage => write!(f, "DUPLICATE_PACKAGE"),
+      EventKind::PluginOrder => write!(f, "PLUGIN_ORDER"),
 
       // --- Derived from esbuild
       EventKind::AssignToImportError => write!(f, "ASSIGN_TO_IMPORT"),
@@ -83,6 +151,8 @@ impl Display for EventKind {
       EventKind::IoError => write!(f, "IO_ERROR"),
       EventKind::NapiError => write!(f, "NAPI_ERROR"),
       EventKind::ConfigurationFieldConflict => write!(f, "CONFIGURATION_FIELD_CONFLICT"),
+      EventKind::UnresolvedTypeImportError => write!(f, "UNRESOLVED_TYPE_IMPORT"),
+      EventKind::UnsupportedImportAttributes => write!(f, "UNSUPPORTED_IMPORT_ATTRIBUTES"),
     }
   }
 }
@@ -0,0 +1,37 @@
+use super::BuildEvent;
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct DuplicatePackage {
+  pub(crate) name: String,
+  /// Each version of the package, with the ids of the modules on one import path from an entry
+  /// to it.
+  pub(crate) versions: Vec<(String, Vec<String>)>,
+  /// Size of the code of every version but the largest one.
+  pub(crate) duplicated_bytes: usize,
+}
+
+impl BuildEvent for DuplicatePackage {
+  fn kind(&self) -> EventKind {
+    EventKind::DuplicatePackage
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    let versions = self
+      .versions
+      .iter()
+      .map(|(version, importer_chain)| {
+        let chain =
+          importer_chain.iter().map(|id| opts.stabilize_path(id)).collect::<Vec<_>>().join(" -> ");
+        format!("  - {version}, imported through {chain}")
+      })
+      .collect::<Vec<_>>();
+    format!(
+      "Package \"{}\" is bundled in {} versions, duplicating {} bytes:\n{}",
+      self.name,
+      self.versions.len(),
+      self.duplicated_bytes,
+      versions.join("\n")
+    )
+  }
+}
@@ -0,0 +1,41 @@
+use crate::types::diagnostic_options::DiagnosticOptions;
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct HmrExportChange {
+  pub(crate) module_id: String,
+  pub(crate) stable_module_id: String,
+  pub(crate) added: Vec<String>,
+  pub(crate) removed: Vec<String>,
+}
+
+impl BuildEvent for HmrExportChange {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::HmrExportChange
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    let mut changes = vec![];
+    if !self.added.is_empty() {
+      changes.push(format!("added {}", describe_exports(&self.added)));
+    }
+    if !self.removed.is_empty() {
+      changes.push(format!("removed {}", describe_exports(&self.removed)));
+    }
+    format!(
+      r#"Hot update of "{}" {}. Its importers keep using the previous exports until the page is reloaded."#,
+      self.stable_module_id,
+      changes.join(" and ")
+    )
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.module_id.clone())
+  }
+}
+
+fn describe_exports(names: &[String]) -> String {
+  let quoted = names.iter().map(|name| format!(r#""{name}""#)).collect::<Vec<_>>().join(", ");
+  if names.len() == 1 { format!("export {quoted}") } else { format!("exports {quoted}") }
+}
@@ -0,0 +1,22 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct HmrUnknownModule {
+  pub(crate) module_id: String,
+}
+
+impl BuildEvent for HmrUnknownModule {
+  fn kind(&self) -> EventKind {
+    EventKind::HmrUnknownModuleError
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(r#"Cannot invalidate "{}", it isn't a module of the bundle."#, self.module_id)
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.module_id.clone())
+  }
+}
@@ -8,6 +8,8 @@ pub enum InvalidOptionType {
   InvalidOutputFile,
   InvalidOutputDirOption,
   NoEntryPoint,
+  MissingHmrImplementForFormat(String),
+  UnknownSeverityOverrideKind(String),
 }
 
 #[derive(Debug)]
@@ -30,6 +32,11 @@ impl BuildEvent for InvalidOption {
         }
         InvalidOptionType::InvalidOutputFile => "Invalid value for option \"output.file\" - When building multiple chunks, the \"output.dir\" option must be used, not \"output.file\". You may set `output.inlineDynamicImports` to `true` when using dynamic imports.".to_string(),
         InvalidOptionType::InvalidOutputDirOption => "Invalid value for option \"output.dir\" - you must set either \"output.file\" for a single-file build or \"output.dir\" when generating multiple chunks.".to_string(),
+        InvalidOptionType::MissingHmrImplementForFormat(format) => format!("Invalid value for option \"experimental.hmr.implement\" - no hmr runtime is provided for the \"{format}\" output format."),
+        InvalidOptionType::UnknownSeverityOverrideKind(kind) => format!(
+          "Invalid key \"{kind}\" for option \"severityOverrides\" - expected one of {}.",
+          EventKind::ALL.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+        ),
         InvalidOptionType::NoEntryPoint =>"You must supply `options.input` to rolldown, you should at least provide one entrypoint via `options.input` or `this.emitFile({type: 'chunk', ...})` (https://rollupjs.org/plugin-development/#this-emitfile)".to_string(),
     }
   }
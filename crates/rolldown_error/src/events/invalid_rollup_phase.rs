@@ -0,0 +1,28 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub enum InvalidRollupPhase {
+  ChunkEmission,
+  ModuleGraphQuery { api: &'static str },
+  ChunkQuery { api: &'static str },
+}
+
+impl BuildEvent for InvalidRollupPhase {
+  fn kind(&self) -> EventKind {
+    EventKind::InvalidRollupPhaseError
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    match self {
+      Self::ChunkEmission => "Cannot emit chunks after module loading has finished. It only works at `buildStart/resolveId/load/transform/moduleParsed` hooks.".to_string(),
+      Self::ModuleGraphQuery { api } => format!(
+        "Cannot call `{api}` before the module graph is linked. It only works at `renderStart` and later hooks."
+      ),
+      Self::ChunkQuery { api } => format!(
+        "Cannot call `{api}` before chunk filenames are final. It only works at `generateBundle` and later hooks."
+      ),
+    }
+  }
+}
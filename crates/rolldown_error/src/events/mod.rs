@@ -12,16 +12,20 @@ pub mod assign_to_import;
 pub mod circular_dependency;
 pub mod commonjs_variable_in_esm;
 pub mod configuration_field_conflict;
+pub mod duplicate_package;
 pub mod eval;
 pub mod export_undefined_variable;
 pub mod external_entry;
 pub mod filename_conflict;
 pub mod forbid_const_assign;
+pub mod hmr_export_change;
+pub mod hmr_unknown_module;
 pub mod illegal_identifier_as_name;
 pub mod import_is_undefined;
 pub mod invalid_define_config;
 pub mod invalid_export_option;
 pub mod invalid_option;
+pub mod invalid_rollup_phase;
 pub mod json_parse;
 pub mod missing_export;
 pub mod missing_global_name;
@@ -29,11 +33,16 @@ pub mod missing_name_option_for_iife_export;
 pub mod missing_name_option_for_umd_export;
 pub mod mixed_export;
 pub mod parse_error;
+pub mod plugin_log;
+pub mod plugin_order;
 pub mod resolve_error;
+pub mod shimmed_export;
 pub mod unhandleable_error;
 pub mod unloadable_dependency;
 pub mod unresolved_entry;
+pub mod unresolved_type_import;
 pub mod unsupported_feature;
+pub mod unsupported_import_attributes;
 
 pub trait BuildEvent: Debug + Sync + Send {
   fn kind(&self) -> EventKind;
@@ -51,6 +60,11 @@ pub trait BuildEvent: Debug + Sync + Send {
   fn exporter(&self) -> Option<String> {
     None
   }
+
+  /// Name of the plugin reporting the event, if it comes from one.
+  fn plugin(&self) -> Option<String> {
+    None
+  }
 }
 
 impl<T: BuildEvent + 'static> From<T> for Box<dyn BuildEvent>
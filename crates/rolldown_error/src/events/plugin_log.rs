@@ -0,0 +1,64 @@
+use std::ops::Range;
+
+use arcstr::ArcStr;
+
+use crate::{
+  diagnostic::Diagnostic, event_kind::EventKind, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+/// A warning or an error reported by a plugin through its `PluginContext`.
+#[derive(Debug)]
+pub struct PluginLog {
+  pub plugin: String,
+  pub message: String,
+  /// Id of the module the log is about.
+  pub id: Option<String>,
+  /// Source of the module, used to render a code frame for `span`.
+  pub source: Option<ArcStr>,
+  /// Byte range into `source`.
+  pub span: Option<Range<u32>>,
+  pub hint: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct PluginDiagnostic {
+  pub(crate) log: PluginLog,
+  pub(crate) is_warning: bool,
+}
+
+impl BuildEvent for PluginDiagnostic {
+  fn kind(&self) -> EventKind {
+    if self.is_warning { EventKind::PluginWarning } else { EventKind::PluginError }
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    match &self.log.id {
+      // Without a code frame, the module is only visible in the message.
+      Some(id) if self.log.span.is_none() || self.log.source.is_none() => {
+        format!("{} in {}", self.log.message, opts.stabilize_path(id))
+      }
+      _ => self.log.message.clone(),
+    }
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.kind.clone_from(&self.log.plugin);
+    if let (Some(id), Some(source), Some(span)) = (&self.log.id, &self.log.source, &self.log.span) {
+      let file_id = diagnostic.add_file(opts.stabilize_path(id), source.clone());
+      diagnostic.add_label(&file_id, span.clone(), self.log.message.clone());
+    }
+    if let Some(hint) = &self.log.hint {
+      diagnostic.add_help(hint.clone());
+    }
+  }
+
+  fn id(&self) -> Option<String> {
+    self.log.id.clone()
+  }
+
+  fn plugin(&self) -> Option<String> {
+    Some(self.log.plugin.clone())
+  }
+}
@@ -0,0 +1,18 @@
+use super::BuildEvent;
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct PluginOrder {
+  /// One line per hook, listing the plugins in the order they run.
+  pub(crate) description: String,
+}
+
+impl BuildEvent for PluginOrder {
+  fn kind(&self) -> EventKind {
+    EventKind::PluginOrder
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    self.description.clone()
+  }
+}
@@ -0,0 +1,33 @@
+use crate::types::diagnostic_options::DiagnosticOptions;
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct ShimmedExport {
+  pub(crate) importer: String,
+  pub(crate) stable_importer: String,
+  pub(crate) exporter: String,
+  pub(crate) stable_exporter: String,
+  pub(crate) binding: String,
+}
+
+impl BuildEvent for ShimmedExport {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::ShimmedExport
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"Missing export "{}" imported by "{}" has been shimmed in module "{}"."#,
+      self.binding, self.stable_importer, self.stable_exporter
+    )
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.importer.clone())
+  }
+
+  fn exporter(&self) -> Option<String> {
+    Some(self.exporter.clone())
+  }
+}
@@ -0,0 +1,44 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnresolvedTypeImport {
+  pub(crate) importer: String,
+  pub(crate) specifier: String,
+  pub(crate) reason: String,
+  /// Source of the importer the span of the specifier points into.
+  pub(crate) source: ArcStr,
+  pub(crate) span: Span,
+}
+
+impl BuildEvent for UnresolvedTypeImport {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::UnresolvedTypeImportError
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Could not resolve type-only import '{}' in {} - {}. The generated declaration may be incomplete.",
+      self.specifier,
+      opts.stabilize_path(&self.importer),
+      self.reason
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let file_id = diagnostic.add_file(opts.stabilize_path(&self.importer), self.source.clone());
+    diagnostic.add_label(&file_id, self.span.start..self.span.end, self.reason.clone());
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.importer.clone())
+  }
+
+  fn exporter(&self) -> Option<String> {
+    Some(self.specifier.clone())
+  }
+}
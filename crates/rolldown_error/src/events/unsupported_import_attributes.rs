@@ -0,0 +1,35 @@
+use crate::types::diagnostic_options::DiagnosticOptions;
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnsupportedImportAttributes {
+  pub(crate) importer: String,
+  pub(crate) specifier: String,
+  pub(crate) attributes: String,
+  pub(crate) format: String,
+}
+
+impl BuildEvent for UnsupportedImportAttributes {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::UnsupportedImportAttributes
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Import attributes `{}` of external module '{}' in {} can't be represented in {} output and are dropped.",
+      self.attributes,
+      self.specifier,
+      opts.stabilize_path(&self.importer),
+      self.format
+    )
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.importer.clone())
+  }
+
+  fn exporter(&self) -> Option<String> {
+    Some(self.specifier.clone())
+  }
+}
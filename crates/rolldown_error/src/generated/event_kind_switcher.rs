@@ -4,7 +4,7 @@
 use bitflags::bitflags;
 bitflags! {
   #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-  pub struct EventKindSwitcher: u32 {
+  pub struct EventKindSwitcher: u64 {
     const AmbiguousExternalNamespaceError = 1 << 0;
     const CircularDependency = 1 << 1;
     const Eval = 1 << 2;
@@ -34,5 +34,15 @@ bitflags! {
     const IoError = 1 << 26;
     const NapiError = 1 << 27;
     const ConfigurationFieldConflict = 1 << 28;
+    const UnresolvedTypeImportError = 1 << 29;
+    const UnsupportedImportAttributes = 1 << 30;
+    const ShimmedExport = 1 << 31;
+    const InvalidRollupPhaseError = 1 << 32;
+    const PluginWarning = 1 << 33;
+    const PluginError = 1 << 34;
+    const HmrExportChange = 1 << 35;
+    const HmrUnknownModuleError = 1 << 36;
+    const DuplicatePackage = 1 << 37;
+    const PluginOrder = 1 << 38;
   }
 }
@@ -1,9 +1,11 @@
 mod build_error;
+mod code_frame;
 mod diagnostic;
 mod event_kind;
 mod events;
 mod generated;
 mod locator;
+mod structured_diagnostic;
 mod type_aliases;
 mod types;
 mod utils;
@@ -17,12 +19,15 @@ pub use crate::{
   events::ambiguous_external_namespace::AmbiguousExternalNamespaceModule,
   events::commonjs_variable_in_esm::CjsExportSpan,
   events::invalid_option::InvalidOptionType,
+  events::plugin_log::PluginLog,
   events::unloadable_dependency::UnloadableDependencyContext,
   generated::event_kind_switcher::EventKindSwitcher,
   locator::line_column_to_byte_offset,
+  structured_diagnostic::{StructuredDiagnostic, StructuredLabel},
   type_aliases::{BuildResult, SingleBuildResult},
+  types::color_mode::ColorMode,
   types::diagnostic_options::DiagnosticOptions,
-  utils::filter_out_disabled_diagnostics,
+  utils::{dedupe_diagnostics, filter_out_disabled_diagnostics},
 };
 
 fn _usage_should_able_to_auto_convert_outside_errors() -> BuildResult<()> {
@@ -16,6 +16,12 @@ impl ByteLocator {
   pub fn byte_offset(&self, line: usize, column: usize) -> usize {
     self.line_starts[line] + column
   }
+
+  /// Returns the 0-based `(line, column)` of a byte offset, the inverse of `byte_offset`.
+  pub fn line_column(&self, offset: usize) -> (usize, usize) {
+    let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+    (line, offset - self.line_starts[line])
+  }
 }
 
 /// Creating a ByteLocator is none trivial, if you want to query offset multiple times for same
@@ -32,4 +38,22 @@ mod test_locator {
     assert_eq!(ByteLocator::new(source).byte_offset(0, 0), 0);
     assert_eq!(ByteLocator::new(source).byte_offset(1, 0), 4);
   }
+
+  #[test]
+  fn byte_offset_to_line_column() {
+    use super::ByteLocator;
+    let locator = ByteLocator::new("abc\ndef\ncghi");
+    assert_eq!(locator.line_column(0), (0, 0));
+    assert_eq!(locator.line_column(3), (0, 3));
+    assert_eq!(locator.line_column(4), (1, 0));
+    assert_eq!(locator.line_column(10), (2, 2));
+  }
+
+  #[test]
+  fn byte_offset_to_line_column_counts_bytes() {
+    use super::ByteLocator;
+    // `é` takes two bytes, so `x` is at column 11 rather than at the 10th character of its line.
+    let locator = ByteLocator::new("// é\nconst é = x");
+    assert_eq!(locator.line_column(17), (1, 11));
+  }
 }
@@ -0,0 +1,100 @@
+use arcstr::ArcStr;
+use ariadne::Span;
+
+use crate::{build_error::severity::Severity, diagnostic::Diagnostic, locator::ByteLocator};
+
+/// A location-aware, render-free view of a [`Diagnostic`] for tooling such as editors and language
+/// servers.
+#[derive(Debug, Clone)]
+pub struct StructuredDiagnostic {
+  pub code: String,
+  pub severity: Severity,
+  pub message: String,
+  pub help: Option<String>,
+  /// Labels in the order they were added. The first one is the primary location.
+  pub labels: Vec<StructuredLabel>,
+}
+
+impl StructuredDiagnostic {
+  pub fn primary_label(&self) -> Option<&StructuredLabel> {
+    self.labels.first()
+  }
+
+  pub(crate) fn spans_to_json(&self) -> serde_json::Value {
+    self
+      .labels
+      .iter()
+      .map(|label| {
+        serde_json::json!({
+          "file": label.file.as_str(),
+          "start": label.start,
+          "end": label.end,
+          "startLine": label.start_line,
+          "startColumn": label.start_column,
+          "endLine": label.end_line,
+          "endColumn": label.end_column,
+          "message": label.message,
+        })
+      })
+      .collect()
+  }
+}
+
+/// A labeled source range. Lines and columns are 1-based and columns count bytes, matching the
+/// `file:line:column` header of the rendered diagnostic, which ariadne computes with
+/// `IndexType::Byte`. Tools expecting UTF-16 positions, like the LSP, need to convert columns on
+/// lines with non-ASCII characters using the file content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructuredLabel {
+  pub file: ArcStr,
+  /// Byte offset of the start of the range in `file`.
+  pub start: usize,
+  /// Byte offset of the end of the range in `file`, exclusive.
+  pub end: usize,
+  pub start_line: usize,
+  /// 1-based byte offset of `start` in its line.
+  pub start_column: usize,
+  pub end_line: usize,
+  /// 1-based byte offset of `end` in its line.
+  pub end_column: usize,
+  pub message: Option<String>,
+}
+
+impl Diagnostic {
+  pub fn to_structured(&self) -> StructuredDiagnostic {
+    let labels = self
+      .labels
+      .iter()
+      .filter_map(|label| {
+        let span = label.span();
+        let file_id = span.source();
+        let content = self.files.get(file_id)?;
+        let locator = ByteLocator::new(content);
+        let (start_line, start_column) = locator.line_column(span.start());
+        let (end_line, end_column) = locator.line_column(span.end());
+        Some(StructuredLabel {
+          file: file_id.as_arcstr().clone(),
+          start: span.start(),
+          end: span.end(),
+          start_line: start_line + 1,
+          start_column: start_column + 1,
+          end_line: end_line + 1,
+          end_column: end_column + 1,
+          message: label
+            .display_info()
+            .msg()
+            .filter(|msg| !msg.is_empty())
+            .map(ToString::to_string),
+        })
+      })
+      .collect();
+
+    StructuredDiagnostic {
+      code: self.kind.clone(),
+      severity: self.severity,
+      message: self.title.clone(),
+      help: self.help.clone(),
+      labels,
+    }
+  }
+}
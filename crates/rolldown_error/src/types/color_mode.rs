@@ -0,0 +1,31 @@
+use std::io::IsTerminal;
+
+/// Whether rendered diagnostics contain ANSI color escapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+  /// Colored unless `NO_COLOR` is set, forced by `FORCE_COLOR`, and otherwise only when stderr is a
+  /// terminal.
+  #[default]
+  Auto,
+  Always,
+  Never,
+}
+
+impl ColorMode {
+  pub fn should_colorize(self) -> bool {
+    match self {
+      Self::Always => true,
+      Self::Never => false,
+      Self::Auto => {
+        // https://no-color.org: any non-empty value disables colors.
+        if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+          return false;
+        }
+        match std::env::var("FORCE_COLOR") {
+          Ok(value) => !matches!(value.as_str(), "0" | "false"),
+          Err(_) => std::io::stderr().is_terminal(),
+        }
+      }
+    }
+  }
+}
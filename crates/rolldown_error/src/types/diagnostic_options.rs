@@ -1,24 +1,77 @@
 use std::path::{Path, PathBuf};
 
-use sugar_path::SugarPath;
+use rolldown_utils::stabilize_id::stabilize_absolute_path;
+
+use super::color_mode::ColorMode;
 
 pub struct DiagnosticOptions {
   pub cwd: PathBuf,
+  /// Whether diagnostics rendered with these options are colored.
+  pub color: ColorMode,
+  /// How many lines of source are shown above and below the lines the code frames point at.
+  pub context_lines: usize,
+  /// Lines longer than this many characters are cut to a window around the span they show, with
+  /// `…` marking the cut ends. `None` shows the lines in full, but lists the spans in lines too
+  /// long to be read in the message instead.
+  pub max_line_width: Option<usize>,
 }
 
 impl Default for DiagnosticOptions {
   fn default() -> Self {
-    Self { cwd: std::env::current_dir().expect("Failed to get current directory") }
+    Self::new(
+      std::env::current_dir().expect("Failed to get current directory"),
+      ColorMode::default(),
+    )
   }
 }
 
 impl DiagnosticOptions {
+  /// Options with the default code frames.
+  pub fn new(cwd: PathBuf, color: ColorMode) -> Self {
+    Self { cwd, color, context_lines: 2, max_line_width: Some(120) }
+  }
+
+  /// Absolute paths, including the Windows ones on every platform, are shown relative to `cwd`.
   pub fn stabilize_path(&self, path: impl AsRef<Path>) -> String {
-    let path = path.as_ref();
-    if path.is_absolute() {
-      path.relative(&self.cwd).to_slash_lossy().into_owned()
-    } else {
-      path.to_string_lossy().to_string()
-    }
+    let path = path.as_ref().to_string_lossy();
+    stabilize_absolute_path(&path, &self.cwd).unwrap_or_else(|| path.into_owned())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use crate::{
+    build_error::BuildDiagnostic, events::DiagnosableArcstr, types::color_mode::ColorMode,
+  };
+
+  use super::DiagnosticOptions;
+
+  fn windows_options() -> DiagnosticOptions {
+    DiagnosticOptions::new(PathBuf::from(r"C:\work\proj"), ColorMode::Never)
+  }
+
+  #[test]
+  fn renders_windows_ids_relative_to_cwd() {
+    let diagnostic = BuildDiagnostic::resolve_error(
+      "import './missing'\n".into(),
+      r"\\?\C:\work\proj\src\a.ts".into(),
+      DiagnosableArcstr::String("./missing".into()),
+      "Module not found".into(),
+      crate::event_kind::EventKind::UnresolvedImport,
+      None,
+    );
+
+    let rendered = diagnostic.to_diagnostic_with(&windows_options()).to_string();
+    assert!(rendered.contains("src/a.ts"), "{rendered}");
+    assert!(!rendered.contains(r"\\?\") && !rendered.contains("C:"), "{rendered}");
+  }
+
+  #[test]
+  fn stabilizes_unc_paths() {
+    let opts = DiagnosticOptions::new(PathBuf::from(r"\\server\share\proj"), ColorMode::Never);
+    assert_eq!(opts.stabilize_path(r"\\server\share\proj\main.ts"), "main.ts");
+    assert_eq!(windows_options().stabilize_path("virtual:entry"), "virtual:entry");
   }
 }
@@ -1,2 +1,3 @@
+pub mod color_mode;
 pub mod diagnostic_options;
 pub mod result_ext;
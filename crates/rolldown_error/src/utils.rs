@@ -3,7 +3,7 @@ use ariadne::{Label, Span};
 use rustc_hash::FxHashMap;
 
 use crate::{
-  BuildDiagnostic, EventKindSwitcher,
+  BuildDiagnostic, DiagnosticOptions, EventKindSwitcher,
   diagnostic::{DiagnosticFileId, RolldownLabelSpan},
 };
 
@@ -69,3 +69,25 @@ pub fn filter_out_disabled_diagnostics(
     .into_iter()
     .filter(|d| switcher.contains(EventKindSwitcher::from_bits_truncate(1 << d.kind() as u32)))
 }
+
+/// Folds diagnostics with the same kind, message and primary file into the first of them, so a
+/// single cause reported in many places shows up once. The order of first occurrences is kept.
+pub fn dedupe_diagnostics(
+  diagnostics: Vec<BuildDiagnostic>,
+  opts: &DiagnosticOptions,
+) -> Vec<BuildDiagnostic> {
+  let mut deduped: Vec<BuildDiagnostic> = Vec::with_capacity(diagnostics.len());
+  let mut first_of_group: FxHashMap<_, usize> = FxHashMap::default();
+  for diagnostic in diagnostics {
+    match first_of_group.entry(diagnostic.dedupe_key(opts)) {
+      std::collections::hash_map::Entry::Occupied(entry) => {
+        deduped[*entry.get()].add_duplicate(diagnostic);
+      }
+      std::collections::hash_map::Entry::Vacant(entry) => {
+        entry.insert(deduped.len());
+        deduped.push(diagnostic);
+      }
+    }
+  }
+  deduped
+}
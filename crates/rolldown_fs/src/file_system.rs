@@ -27,4 +27,14 @@ pub trait FileSystem: Send + Sync + OxcResolverFileSystem {
   ///
   /// * See [std::fs::read]
   fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+  /// # Errors
+  ///
+  /// * See [std::fs::rename]
+  fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+
+  /// # Errors
+  ///
+  /// * See [std::fs::remove_file]
+  fn remove_file(&self, path: &Path) -> io::Result<()>;
 }
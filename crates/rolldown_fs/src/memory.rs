@@ -85,6 +85,19 @@ impl FileSystem for MemoryFileSystem {
       .read_to_end(&mut buf)?;
     Ok(buf)
   }
+
+  fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+    let content = self.read(from)?;
+    self.write(to, &content)?;
+    self.remove_file(from)
+  }
+
+  fn remove_file(&self, path: &Path) -> io::Result<()> {
+    self
+      .fs
+      .remove_file(&path.to_string_lossy())
+      .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+  }
 }
 
 impl OxcResolverFileSystem for MemoryFileSystem {
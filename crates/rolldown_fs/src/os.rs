@@ -31,6 +31,14 @@ impl FileSystem for OsFileSystem {
   fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
     std::fs::read(path)
   }
+
+  fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+    std::fs::rename(from, to)
+  }
+
+  fn remove_file(&self, path: &Path) -> io::Result<()> {
+    std::fs::remove_file(path)
+  }
 }
 
 impl OxcResolverFileSystem for OsFileSystem {
@@ -26,5 +26,6 @@ bitflags! {
     const Footer = 1 << 18;
     const Intro = 1 << 19;
     const Outro = 1 << 20;
+    const HmrAccept = 1 << 21;
   }
 }
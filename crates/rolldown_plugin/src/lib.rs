@@ -18,9 +18,9 @@ pub mod __inner {
 pub use crate::{
   generated::hook_usage::HookUsage,
   plugin::{
-    HookAugmentChunkHashReturn, HookInjectionOutputReturn, HookLoadReturn, HookNoopReturn,
-    HookRenderChunkReturn, HookResolveIdReturn, HookTransformAstReturn, HookTransformReturn,
-    Plugin,
+    HookAugmentChunkHashReturn, HookHmrAcceptReturn, HookInjectionOutputReturn, HookLoadReturn,
+    HookNoopReturn, HookRenderChunkReturn, HookResolveIdReturn, HookTransformAstReturn,
+    HookTransformReturn, Plugin,
   },
   plugin_context::{
     PluginContext, SharedNativePluginContext, SharedTransformPluginContext, TransformPluginContext,
@@ -31,20 +31,26 @@ pub use crate::{
   types::hook_addon_args::HookAddonArgs,
   types::hook_build_end_args::HookBuildEndArgs,
   types::hook_build_start_args::HookBuildStartArgs,
+  types::hook_filter::{GeneralHookFilter, HookFilter},
   types::hook_generate_bundle_args::HookGenerateBundleArgs,
+  types::hook_hmr_accept_args::{HookHmrAcceptArgs, HookHmrAcceptOutput},
   types::hook_load_args::HookLoadArgs,
   types::hook_load_output::HookLoadOutput,
   types::hook_render_chunk_args::HookRenderChunkArgs,
   types::hook_render_chunk_output::HookRenderChunkOutput,
   types::hook_render_error::HookRenderErrorArgs,
   types::hook_render_start_args::HookRenderStartArgs,
+  types::hook_resolve_dynamic_import_args::{DynamicImportSpecifier, HookResolveDynamicImportArgs},
   types::hook_resolve_id_args::HookResolveIdArgs,
   types::hook_resolve_id_output::HookResolveIdOutput,
   types::hook_transform_args::HookTransformArgs,
   types::hook_transform_ast_args::HookTransformAstArgs,
   types::hook_transform_output::HookTransformOutput,
   types::hook_write_bundle_args::HookWriteBundleArgs,
+  types::linked_module_graph::LinkedModuleGraph,
+  types::plugin_context_load_options::{LoadedModule, PluginContextLoadOptions},
   types::plugin_context_resolve_options::PluginContextResolveOptions,
+  types::plugin_diagnostic::PluginDiagnostic,
   types::plugin_hook_meta::{PluginHookMeta, PluginOrder},
 };
 
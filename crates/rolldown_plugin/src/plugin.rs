@@ -2,9 +2,10 @@ use std::{any::Any, borrow::Cow, fmt::Debug, sync::Arc};
 
 use super::plugin_context::PluginContext;
 use crate::{
-  HookAddonArgs, HookBuildEndArgs, HookGenerateBundleArgs, HookLoadArgs, HookLoadOutput,
-  HookRenderChunkArgs, HookRenderChunkOutput, HookResolveIdArgs, HookResolveIdOutput,
-  HookTransformArgs, HookUsage, HookWriteBundleArgs, PluginHookMeta, SharedTransformPluginContext,
+  HookAddonArgs, HookBuildEndArgs, HookFilter, HookGenerateBundleArgs, HookHmrAcceptArgs,
+  HookHmrAcceptOutput, HookLoadArgs, HookLoadOutput, HookRenderChunkArgs, HookRenderChunkOutput,
+  HookResolveDynamicImportArgs, HookResolveIdArgs, HookResolveIdOutput, HookTransformArgs,
+  HookUsage, HookWriteBundleArgs, PluginHookMeta, SharedTransformPluginContext,
   types::{
     hook_build_start_args::HookBuildStartArgs, hook_render_error::HookRenderErrorArgs,
     hook_render_start_args::HookRenderStartArgs, hook_transform_ast_args::HookTransformAstArgs,
@@ -12,7 +13,9 @@ use crate::{
   },
 };
 use anyhow::Result;
-use rolldown_common::{ModuleInfo, NormalModule, RollupRenderedChunk, WatcherChangeKind};
+use rolldown_common::{
+  BundlerOptions, ModuleInfo, NormalModule, OutputOptions, RollupRenderedChunk, WatcherChangeKind,
+};
 use rolldown_ecmascript::EcmaAst;
 
 pub type HookResolveIdReturn = Result<Option<HookResolveIdOutput>>;
@@ -23,11 +26,19 @@ pub type HookNoopReturn = Result<()>;
 pub type HookRenderChunkReturn = Result<Option<HookRenderChunkOutput>>;
 pub type HookAugmentChunkHashReturn = Result<Option<String>>;
 pub type HookInjectionOutputReturn = Result<Option<String>>;
+pub type HookHmrAcceptReturn = Result<Option<HookHmrAcceptOutput>>;
 
 pub trait Plugin: Any + Debug + Send + Sync + 'static {
   fn name(&self) -> Cow<'static, str>;
 
-  // The `option` hook consider call at node side.
+  /// Adjusts the options before they're normalized, e.g. to apply the defaults of a preset. Called
+  /// once, in the order plugins are registered, so a plugin sees the changes of the plugins before it.
+  /// Plugins on the js side have their `options` hook called by the js side instead.
+  fn options(&self, _options: &mut BundlerOptions) {}
+
+  /// Same as [`Plugin::options`], for the options of each output of `Bundler::generate_multi` and
+  /// `Bundler::write_multi`.
+  fn output_options(&self, _options: &mut OutputOptions) {}
 
   // --- Build hooks ---
 
@@ -55,13 +66,18 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
-  #[deprecated(
-    note = "This hook is only for rollup compatibility, please use `resolve_id` instead."
-  )]
+  /// Skips calling `resolve_id` for the arguments that don't match, see [`HookFilter`].
+  fn resolve_id_filter(&self) -> Option<HookFilter> {
+    None
+  }
+
+  /// Called for `import()` expressions before `resolve_id`. Unlike `resolve_id`, it also receives the specifiers
+  /// that can't be determined statically, see `DynamicImportSpecifier::Expression`.
+  /// Returning `None` for such a specifier leaves the `import()` expression untouched.
   fn resolve_dynamic_import(
     &self,
     _ctx: &PluginContext,
-    _args: &HookResolveIdArgs<'_>,
+    _args: &HookResolveDynamicImportArgs<'_>,
   ) -> impl std::future::Future<Output = HookResolveIdReturn> + Send {
     async { Ok(None) }
   }
@@ -82,6 +98,11 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Skips calling `load` for the arguments that don't match, see [`HookFilter`].
+  fn load_filter(&self) -> Option<HookFilter> {
+    None
+  }
+
   fn transform(
     &self,
     _ctx: SharedTransformPluginContext,
@@ -94,6 +115,19 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Skips calling `transform` for the arguments that don't match, see [`HookFilter`].
+  fn transform_filter(&self) -> Option<HookFilter> {
+    None
+  }
+
+  /// Whether the `load` and `transform` results of the module can be reused from `cache_dir` in a
+  /// later build. Return `false` for the modules this plugin transforms based on anything other
+  /// than their content, e.g. environment variables or other files, or whose transform has side
+  /// effects like emitting files, so they're always loaded and transformed again.
+  fn should_cache_module(&self, _id: &str) -> bool {
+    true
+  }
+
   fn module_parsed(
     &self,
     _ctx: &PluginContext,
@@ -278,6 +312,23 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  // hmr hooks
+
+  /// Overrides whether a module an update propagates through accepts it, e.g. to make components
+  /// self-accepting for fast refresh. The first plugin returning `Some` decides, and `None` keeps
+  /// what `import.meta.hot.accept()` calls in the module say.
+  fn hmr_accept(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookHmrAcceptArgs<'_>,
+  ) -> impl std::future::Future<Output = HookHmrAcceptReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn hmr_accept_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   // --- experimental hooks ---
   fn transform_ast(
     &self,
@@ -291,5 +342,10 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Skips calling `transform_ast` for the arguments that don't match, see [`HookFilter`].
+  fn transform_ast_filter(&self) -> Option<HookFilter> {
+    None
+  }
+
   fn register_hook_usage(&self) -> HookUsage;
 }
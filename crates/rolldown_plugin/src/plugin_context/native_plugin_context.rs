@@ -1,4 +1,5 @@
 use std::{
+  borrow::Cow,
   path::PathBuf,
   sync::{Arc, Weak},
 };
@@ -7,18 +8,21 @@ use anyhow::Context;
 use arcstr::ArcStr;
 use derive_more::Debug;
 use rolldown_common::{
-  ModuleInfo, ModuleLoaderMsg, ResolvedId, SharedFileEmitter, SharedNormalizedBundlerOptions,
-  side_effects::HookSideEffects,
+  FetchModuleMsg, ModuleInfo, ModuleLoaderMsg, ModuleMeta, ResolvedId, SharedFileEmitter,
+  SharedNormalizedBundlerOptions,
 };
+use rolldown_error::{BuildDiagnostic, PluginLog};
 use rolldown_resolver::{ResolveError, Resolver};
 use rolldown_utils::dashmap::{FxDashMap, FxDashSet};
 use tokio::sync::Mutex;
 
 use crate::{
-  PluginDriver,
+  LoadedModule, PluginContextLoadOptions, PluginDriver,
+  plugin_driver::running_hook,
   types::{
-    hook_resolve_id_skipped::HookResolveIdSkipped,
-    plugin_context_resolve_options::PluginContextResolveOptions, plugin_idx::PluginIdx,
+    hook_resolve_id_skipped::HookResolveIdSkipped, linked_module_graph::LinkedModuleGraph,
+    plugin_context_resolve_options::PluginContextResolveOptions,
+    plugin_diagnostic::PluginDiagnostic, plugin_idx::PluginIdx,
   },
   utils::resolve_id_check_external::resolve_id_check_external,
 };
@@ -29,36 +33,67 @@ pub type SharedNativePluginContext = Arc<NativePluginContextImpl>;
 pub struct NativePluginContextImpl {
   pub(crate) skipped_resolve_calls: Vec<Arc<HookResolveIdSkipped>>,
   pub(crate) plugin_idx: PluginIdx,
+  pub(crate) plugin_name: Cow<'static, str>,
   pub(crate) resolver: Arc<Resolver>,
   pub(crate) plugin_driver: Weak<PluginDriver>,
   pub(crate) file_emitter: SharedFileEmitter,
   pub(crate) options: SharedNormalizedBundlerOptions,
   pub(crate) watch_files: Arc<FxDashSet<ArcStr>>,
+  pub(crate) watch_file_dependents: Arc<FxDashMap<ArcStr, FxDashSet<ArcStr>>>,
   pub(crate) modules: Arc<FxDashMap<ArcStr, Arc<ModuleInfo>>>,
+  pub(crate) module_meta: Arc<FxDashMap<ArcStr, ModuleMeta>>,
+  pub(crate) linked_module_graph: Arc<std::sync::Mutex<Option<LinkedModuleGraph>>>,
+  pub(crate) warnings: Arc<std::sync::Mutex<Vec<BuildDiagnostic>>>,
   pub(crate) tx: Arc<Mutex<Option<tokio::sync::mpsc::Sender<ModuleLoaderMsg>>>>,
 }
 
 impl NativePluginContextImpl {
+  /// Loads the module and waits until it's parsed. Options only take effect if the module isn't
+  /// loaded yet. See [`LoadedModule::is_cycle`] for a module that's waiting for the calling module.
   pub async fn load(
     &self,
     specifier: &str,
-    side_effects: Option<HookSideEffects>,
+    options: Option<PluginContextLoadOptions>,
+  ) -> anyhow::Result<LoadedModule> {
+    self.fetch_module(specifier, options.unwrap_or_default()).await?;
+    let plugin_driver = self
+      .plugin_driver
+      .upgrade()
+      .ok_or_else(|| anyhow::anyhow!("Plugin driver is already dropped."))?;
+    plugin_driver.wait_for_module(&specifier.into()).await
+  }
+
+  /// Adds the module to the graph without waiting for it, the js side waits through
+  /// `mark_module_loaded` instead.
+  pub async fn fetch_module(
+    &self,
+    specifier: &str,
+    options: PluginContextLoadOptions,
   ) -> anyhow::Result<()> {
+    let PluginContextLoadOptions { module_type, side_effects, meta } = options;
+    if let Some(meta) = meta {
+      let module_meta = self.module_meta.entry(specifier.into()).or_default();
+      for (key, value) in meta {
+        module_meta.insert(key, value);
+      }
+    }
     // Clone out the sender under the lock, then drop the lock before awaiting.
     let sender = {
       let guard = self.tx.lock().await.clone();
       guard.context("The `PluginContext.load` only work at `resolveId/load/transform/moduleParsed` hooks. If you using it at resolveId hook, please make sure it could not load the entry module.")?
     };
     sender
-      .send(ModuleLoaderMsg::FetchModule(Box::new(ResolvedId {
-        id: specifier.into(),
-        side_effects,
-        ..Default::default()
+      .send(ModuleLoaderMsg::FetchModule(Box::new(FetchModuleMsg {
+        resolved_id: ResolvedId { id: specifier.into(), side_effects, ..Default::default() },
+        asserted_module_type: module_type,
       })))
       .await?;
     Ok(())
   }
 
+  /// Resolves `specifier` like an import in `importer` would be, without loading the resolved
+  /// module, so no `load` or `transform` hook runs and the module isn't added to the graph. Use
+  /// [`Self::load`] with the resolved id to do that.
   #[tracing::instrument(skip_all, fields(CONTEXT_hook_resolve_id_trigger = "manual"))]
   pub async fn resolve(
     &self,
@@ -72,7 +107,16 @@ impl NativePluginContextImpl {
       .ok_or_else(|| anyhow::anyhow!("Plugin driver is already dropped."))?;
 
     let normalized_extra_options = extra_options.unwrap_or_default();
-    let skipped_resolve_calls = if normalized_extra_options.skip_self {
+    let skipped_resolve_calls = if normalized_extra_options.skip_plugins {
+      let skipped_call = |plugin_idx| {
+        Arc::new(HookResolveIdSkipped {
+          plugin_idx,
+          importer: importer.map(Into::into),
+          specifier: specifier.into(),
+        })
+      };
+      Some(plugin_driver.plugin_indices().map(skipped_call).collect())
+    } else if normalized_extra_options.skip_self {
       let mut skipped_resolve_calls = Vec::with_capacity(self.skipped_resolve_calls.len() + 1);
       skipped_resolve_calls.extend(self.skipped_resolve_calls.clone());
       skipped_resolve_calls.push(Arc::new(HookResolveIdSkipped {
@@ -97,6 +141,7 @@ impl NativePluginContextImpl {
       skipped_resolve_calls,
       normalized_extra_options.custom,
       false,
+      &normalized_extra_options.conditions,
       &self.options,
     )
     .await
@@ -139,10 +184,96 @@ impl NativePluginContextImpl {
     self.modules.get(module_id).map(|v| Arc::<rolldown_common::ModuleInfo>::clone(v.value()))
   }
 
+  /// Attaches `value` to the module under `key`, for other plugins to read from `ModuleInfo::meta`.
+  /// The module doesn't have to be loaded yet, e.g. a `resolve_id` hook may set it for the id it resolved to.
+  pub fn set_module_meta(&self, module_id: &str, key: String, value: serde_json::Value) {
+    self.module_meta.entry(module_id.into()).or_default().insert(key, value);
+  }
+
   pub fn get_module_ids(&self) -> Vec<String> {
     self.modules.iter().map(|v| v.key().to_string()).collect()
   }
 
+  /// Ids of all modules in the linked module graph, in a stable order.
+  pub fn module_ids(&self) -> anyhow::Result<impl Iterator<Item = ArcStr>> {
+    self.with_linked_module_graph("module_ids", |graph| graph.module_ids.clone().into_iter())
+  }
+
+  /// Whether the code of the module ends up in the output. Always `false` for external modules.
+  pub fn is_module_included(&self, module_id: &str) -> anyhow::Result<bool> {
+    self.with_linked_module_graph("is_module_included", |graph| {
+      graph.included_module_ids.contains(module_id)
+    })
+  }
+
+  /// Filename of the chunk that contains the module, or `None` if it isn't included. It's only known
+  /// once hashes are computed, so it's available from `generateBundle` on.
+  pub fn get_module_chunk_filename(&self, module_id: &str) -> anyhow::Result<Option<ArcStr>> {
+    let api = "get_module_chunk_filename";
+    self.with_linked_module_graph(api, |graph| {
+      let filenames = graph
+        .module_chunk_filenames
+        .as_ref()
+        .ok_or_else(|| BuildDiagnostic::invalid_rollup_phase_for_chunk_query(api))?;
+      Ok(filenames.get(module_id).cloned())
+    })?
+  }
+
+  fn with_linked_module_graph<T>(
+    &self,
+    api: &'static str,
+    f: impl FnOnce(&LinkedModuleGraph) -> T,
+  ) -> anyhow::Result<T> {
+    let graph = self.linked_module_graph.lock().expect("should not be poisoned");
+    let graph = graph
+      .as_ref()
+      .ok_or_else(|| BuildDiagnostic::invalid_rollup_phase_for_module_graph_query(api))?;
+    Ok(f(graph))
+  }
+
+  /// Whether the running build has been cancelled. Long running hooks could check it to bail out
+  /// early, the result is discarded anyway.
+  pub fn is_cancelled(&self) -> bool {
+    self.plugin_driver.upgrade().is_some_and(|plugin_driver| plugin_driver.is_cancelled())
+  }
+
+  /// Reports a warning, which ends up in `BundleOutput::warnings` with the plugin name as kind and
+  /// the running hook as provenance.
+  pub fn warn(&self, diagnostic: PluginDiagnostic) {
+    let warning = BuildDiagnostic::plugin_warning(self.to_plugin_log(diagnostic))
+      .with_provenance(self.plugin_name.as_ref(), running_hook());
+    self.warnings.lock().expect("should not be poisoned").push(warning);
+  }
+
+  /// Creates an error to return from the current hook, which aborts the build.
+  pub fn error(&self, diagnostic: PluginDiagnostic) -> anyhow::Error {
+    BuildDiagnostic::plugin_error(self.to_plugin_log(diagnostic))
+      .with_provenance(self.plugin_name.as_ref(), running_hook())
+      .into()
+  }
+
+  fn to_plugin_log(&self, diagnostic: PluginDiagnostic) -> PluginLog {
+    let PluginDiagnostic { message, id, span, source, hint } = diagnostic;
+    // The code frame needs the source the span points into.
+    let source = match (&id, span, source) {
+      (_, _, Some(source)) => Some(source),
+      (Some(id), Some(_), None) => self
+        .modules
+        .get(id.as_str())
+        .and_then(|module| module.code.clone())
+        .or_else(|| std::fs::read_to_string(id).ok().map(Into::into)),
+      _ => None,
+    };
+    PluginLog {
+      plugin: self.plugin_name.to_string(),
+      message,
+      id,
+      source,
+      span: span.map(|(offset, length)| offset..offset + length),
+      hint,
+    }
+  }
+
   pub fn cwd(&self) -> &PathBuf {
     self.resolver.cwd()
   }
@@ -150,4 +281,9 @@ impl NativePluginContextImpl {
   pub fn add_watch_file(&self, file: &str) {
     self.watch_files.insert(file.into());
   }
+
+  pub fn add_watch_file_dependency(&self, file: &str, module_id: &ArcStr) {
+    self.add_watch_file(file);
+    self.watch_file_dependents.entry(file.into()).or_default().insert(module_id.clone());
+  }
 }
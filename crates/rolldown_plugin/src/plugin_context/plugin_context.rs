@@ -5,9 +5,12 @@ use std::{
 
 use arcstr::ArcStr;
 use derive_more::Debug;
-use rolldown_common::{ResolvedId, side_effects::HookSideEffects};
+use rolldown_common::ResolvedId;
 
-use crate::{PluginContextResolveOptions, types::hook_resolve_id_skipped::HookResolveIdSkipped};
+use crate::{
+  LoadedModule, PluginContextLoadOptions, PluginContextResolveOptions,
+  types::{hook_resolve_id_skipped::HookResolveIdSkipped, plugin_diagnostic::PluginDiagnostic},
+};
 
 use super::NativePluginContextImpl;
 
@@ -36,12 +39,17 @@ impl PluginContext {
       PluginContext::Native(ctx) => Self::Native(Arc::new(NativePluginContextImpl {
         skipped_resolve_calls,
         plugin_idx: ctx.plugin_idx,
+        plugin_name: ctx.plugin_name.clone(),
         plugin_driver: Weak::clone(&ctx.plugin_driver),
         resolver: Arc::clone(&ctx.resolver),
         file_emitter: Arc::clone(&ctx.file_emitter),
         options: Arc::clone(&ctx.options),
         watch_files: Arc::clone(&ctx.watch_files),
+        watch_file_dependents: Arc::clone(&ctx.watch_file_dependents),
         modules: Arc::clone(&ctx.modules),
+        module_meta: Arc::clone(&ctx.module_meta),
+        linked_module_graph: Arc::clone(&ctx.linked_module_graph),
+        warnings: Arc::clone(&ctx.warnings),
         tx: Arc::clone(&ctx.tx),
       })),
     }
@@ -50,11 +58,11 @@ impl PluginContext {
   pub async fn load(
     &self,
     specifier: &str,
-    side_effects: Option<HookSideEffects>,
-  ) -> anyhow::Result<()> {
+    options: Option<PluginContextLoadOptions>,
+  ) -> anyhow::Result<LoadedModule> {
     match self {
       PluginContext::Napi(_) => unimplemented!("Can't call `load` on PluginContext::Napi"),
-      PluginContext::Native(ctx) => ctx.load(specifier, side_effects).await,
+      PluginContext::Native(ctx) => ctx.load(specifier, options).await,
     }
   }
 
@@ -119,6 +127,15 @@ impl PluginContext {
     }
   }
 
+  pub fn set_module_meta(&self, module_id: &str, key: String, value: serde_json::Value) {
+    match self {
+      PluginContext::Napi(_) => {
+        unimplemented!("Can't call `set_module_meta` on PluginContext::Napi")
+      }
+      PluginContext::Native(ctx) => ctx.set_module_meta(module_id, key, value),
+    }
+  }
+
   pub fn get_module_ids(&self) -> Vec<String> {
     match self {
       PluginContext::Napi(_) => {
@@ -128,6 +145,53 @@ impl PluginContext {
     }
   }
 
+  pub fn module_ids(&self) -> anyhow::Result<impl Iterator<Item = ArcStr>> {
+    match self {
+      PluginContext::Napi(_) => unimplemented!("Can't call `module_ids` on PluginContext::Napi"),
+      PluginContext::Native(ctx) => ctx.module_ids(),
+    }
+  }
+
+  pub fn is_module_included(&self, module_id: &str) -> anyhow::Result<bool> {
+    match self {
+      PluginContext::Napi(_) => {
+        unimplemented!("Can't call `is_module_included` on PluginContext::Napi")
+      }
+      PluginContext::Native(ctx) => ctx.is_module_included(module_id),
+    }
+  }
+
+  pub fn get_module_chunk_filename(&self, module_id: &str) -> anyhow::Result<Option<ArcStr>> {
+    match self {
+      PluginContext::Napi(_) => {
+        unimplemented!("Can't call `get_module_chunk_filename` on PluginContext::Napi")
+      }
+      PluginContext::Native(ctx) => ctx.get_module_chunk_filename(module_id),
+    }
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    match self {
+      PluginContext::Napi(_) => unimplemented!("Can't call `is_cancelled` on PluginContext::Napi"),
+      PluginContext::Native(ctx) => ctx.is_cancelled(),
+    }
+  }
+
+  pub fn warn(&self, diagnostic: PluginDiagnostic) {
+    match self {
+      PluginContext::Napi(_) => unimplemented!("Can't call `warn` on PluginContext::Napi"),
+      PluginContext::Native(ctx) => ctx.warn(diagnostic),
+    }
+  }
+
+  /// Returns the error instead of aborting, e.g. `return Err(ctx.error(diagnostic))`.
+  pub fn error(&self, diagnostic: PluginDiagnostic) -> anyhow::Error {
+    match self {
+      PluginContext::Napi(_) => unimplemented!("Can't call `error` on PluginContext::Napi"),
+      PluginContext::Native(ctx) => ctx.error(diagnostic),
+    }
+  }
+
   pub fn cwd(&self) -> &PathBuf {
     match self {
       PluginContext::Napi(_) => unimplemented!("Can't call `cwd` on PluginContext::Napi"),
@@ -144,6 +208,16 @@ impl PluginContext {
     }
   }
 
+  /// Watch `file` and treat the module `module_id` as changed whenever `file` changes.
+  pub fn add_watch_file_dependency(&self, file: &str, module_id: &ArcStr) {
+    match self {
+      PluginContext::Napi(_) => {
+        unimplemented!("Can't call `add_watch_file_dependency` on PluginContext::Napi")
+      }
+      PluginContext::Native(ctx) => ctx.add_watch_file_dependency(file, module_id),
+    }
+  }
+
   pub fn options(&self) -> &rolldown_common::NormalizedBundlerOptions {
     match self {
       PluginContext::Napi(_) => unimplemented!("Can't call `options` on PluginContext::Napi"),
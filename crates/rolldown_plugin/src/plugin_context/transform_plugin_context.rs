@@ -39,6 +39,12 @@ impl TransformPluginContext {
     })
   }
 
+  /// Like [`PluginContext::add_watch_file`], but a change of `file` also invalidates the module
+  /// being transformed, in both watch mode and HMR.
+  pub fn add_watch_file_dependency(&self, file: &str) {
+    self.inner.add_watch_file_dependency(file, &self.id);
+  }
+
   fn create_sourcemap(&self) -> SourceMap {
     let magic_string = MagicString::new(self.original_code.as_str());
     magic_string.source_map(SourceMapOptions {
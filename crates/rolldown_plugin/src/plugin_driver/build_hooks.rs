@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
 use crate::{
-  HookBuildEndArgs, HookLoadArgs, HookLoadReturn, HookNoopReturn, HookResolveIdArgs,
-  HookResolveIdReturn, HookTransformArgs, HookUsage, PluginContext, PluginDriver,
-  TransformPluginContext,
+  DynamicImportSpecifier, HookBuildEndArgs, HookFilter, HookLoadArgs, HookLoadReturn,
+  HookNoopReturn, HookResolveDynamicImportArgs, HookResolveIdArgs, HookResolveIdReturn,
+  HookTransformArgs, HookUsage, PluginContext, PluginDriver, TransformPluginContext,
   pluginable::HookTransformAstReturn,
   types::{
     hook_resolve_id_skipped::HookResolveIdSkipped, hook_transform_ast_args::HookTransformAstArgs,
@@ -60,7 +60,12 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::BuildStart) {
         continue;
       }
-      plugin.call_build_start(ctx, &crate::HookBuildStartArgs { options: opts }).await?;
+      Self::run_hook(
+        &plugin.call_name(),
+        "buildStart",
+        plugin.call_build_start(ctx, &crate::HookBuildStartArgs { options: opts }),
+      )
+      .await?;
     }
 
     Ok(())
@@ -95,7 +100,14 @@ impl PluginDriver {
     for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_resolve_id_meta)
     {
-      if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::ResolveId) {
+      if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::ResolveId)
+        || !self.matches_hook_filter(
+          self.plugin_hook_filters[plugin_idx].resolve_id.as_ref(),
+          args.specifier,
+          None,
+          None,
+        )
+      {
         continue;
       }
       // TODO: Maybe we could optimize this a little
@@ -113,21 +125,25 @@ impl PluginDriver {
           trigger: "${hook_resolve_id_trigger}",
           call_id: "${call_id}",
         });
-        if let Some(r) = plugin
-          .call_resolve_id(
-            &skipped_resolve_calls.map_or_else(
-              || ctx.clone(),
-              |skipped_resolve_calls| {
-                PluginContext::new_shared_with_skipped_resolve_calls(
-                  ctx,
-                  skipped_resolve_calls.clone(),
-                )
-              },
-            ),
-            args,
-          )
-          .instrument(debug_span!("resolve_id_hook", plugin_name = plugin.call_name().as_ref()))
-          .await?
+        if let Some(r) = Self::run_hook(
+          &plugin.call_name(),
+          "resolveId",
+          plugin
+            .call_resolve_id(
+              &skipped_resolve_calls.map_or_else(
+                || ctx.clone(),
+                |skipped_resolve_calls| {
+                  PluginContext::new_shared_with_skipped_resolve_calls(
+                    ctx,
+                    skipped_resolve_calls.clone(),
+                  )
+                },
+              ),
+              args,
+            )
+            .instrument(debug_span!("resolve_id_hook", plugin_name = plugin.call_name().as_ref())),
+        )
+        .await?
         {
           trace_action!(action::HookResolveIdCallEnd {
             action: "HookResolveIdCallEnd",
@@ -158,22 +174,25 @@ impl PluginDriver {
           format!("{}_{}", args.specifier, rolldown_utils::time::current_utc_timestamp_ms())
       ))
       .await?;
-      if ret.is_some() {
-        return Ok(ret);
+      if let Some(mut r) = ret {
+        self.merge_module_meta(&r.id, r.meta.take());
+        return Ok(Some(r));
       }
     }
     Ok(None)
   }
 
-  #[allow(deprecated)]
-  // Only for rollup compatibility
   pub async fn resolve_dynamic_import(
     &self,
-    args: &HookResolveIdArgs<'_>,
+    args: &HookResolveDynamicImportArgs<'_>,
     skipped_resolve_calls: Option<&Vec<Arc<HookResolveIdSkipped>>>,
   ) -> HookResolveIdReturn {
-    let skipped_plugins =
-      Self::get_resolve_call_skipped_plugins(args.specifier, args.importer, skipped_resolve_calls);
+    let skipped_plugins = match args.specifier {
+      DynamicImportSpecifier::Static(specifier) => {
+        Self::get_resolve_call_skipped_plugins(specifier, args.importer, skipped_resolve_calls)
+      }
+      DynamicImportSpecifier::Expression(_) => vec![],
+    };
     for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_resolve_dynamic_import_meta)
     {
@@ -183,25 +202,30 @@ impl PluginDriver {
       if skipped_plugins.contains(&plugin_idx) {
         continue;
       }
-      if let Some(r) = plugin
-        .call_resolve_dynamic_import(
-          &skipped_resolve_calls.map_or_else(
-            || ctx.clone(),
-            |skipped_resolve_calls| {
-              PluginContext::new_shared_with_skipped_resolve_calls(
-                ctx,
-                skipped_resolve_calls.clone(),
-              )
-            },
-          ),
-          args,
-        )
-        .instrument(debug_span!(
-          "resolve_dynamic_import_hook",
-          plugin_name = plugin.call_name().as_ref()
-        ))
-        .await?
+      if let Some(mut r) = Self::run_hook(
+        &plugin.call_name(),
+        "resolveDynamicImport",
+        plugin
+          .call_resolve_dynamic_import(
+            &skipped_resolve_calls.map_or_else(
+              || ctx.clone(),
+              |skipped_resolve_calls| {
+                PluginContext::new_shared_with_skipped_resolve_calls(
+                  ctx,
+                  skipped_resolve_calls.clone(),
+                )
+              },
+            ),
+            args,
+          )
+          .instrument(debug_span!(
+            "resolve_dynamic_import_hook",
+            plugin_name = plugin.call_name().as_ref()
+          )),
+      )
+      .await?
       {
+        self.merge_module_meta(&r.id, r.meta.take());
         return Ok(Some(r));
       }
     }
@@ -212,7 +236,14 @@ impl PluginDriver {
     for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_load_meta)
     {
-      if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::Load) {
+      if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::Load)
+        || !self.matches_hook_filter(
+          self.plugin_hook_filters[plugin_idx].load.as_ref(),
+          args.id,
+          None,
+          None,
+        )
+      {
         continue;
       }
       let ret = async {
@@ -223,11 +254,16 @@ impl PluginDriver {
           plugin_index: plugin_idx.raw(),
           call_id: "${call_id}",
         });
-        if let Some(r) = plugin
-          .call_load(ctx, args)
-          .instrument(debug_span!("load_hook", plugin_name = plugin.call_name().as_ref()))
-          .await?
+        if let Some(mut r) = Self::run_hook(
+          &plugin.call_name(),
+          "load",
+          plugin
+            .call_load(ctx, args)
+            .instrument(debug_span!("load_hook", plugin_name = plugin.call_name().as_ref())),
+        )
+        .await?
         {
+          self.merge_module_meta(args.id, r.meta.take());
           trace_action!(action::HookLoadCallEnd {
             action: "HookLoadCallEnd",
             module_id: args.id.to_string(),
@@ -276,7 +312,14 @@ impl PluginDriver {
     for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_transform_meta)
     {
-      if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::Transform) {
+      if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::Transform)
+        || !self.matches_hook_filter(
+          self.plugin_hook_filters[plugin_idx].transform.as_ref(),
+          id,
+          Some(module_type),
+          Some(&code),
+        )
+      {
         continue;
       }
       let call_id = tracing::enabled!(tracing::Level::TRACE).then(|| {
@@ -295,19 +338,24 @@ impl PluginDriver {
         plugin_index: plugin_idx.raw(),
         call_id: call_id.clone().unwrap_or_default(),
       });
-      if let Some(r) = plugin
-        .call_transform(
-          Arc::new(TransformPluginContext::new(
-            ctx.clone(),
-            plugin_sourcemap_chain.weak_ref(),
-            code.as_str().into(),
-            id.into(),
-          )),
-          &HookTransformArgs { id, code: &code, module_type: &*module_type },
-        )
-        .instrument(debug_span!("transform_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?
+      if let Some(r) = Self::run_hook(
+        &plugin.call_name(),
+        "transform",
+        plugin
+          .call_transform(
+            Arc::new(TransformPluginContext::new(
+              ctx.clone(),
+              plugin_sourcemap_chain.weak_ref(),
+              code.as_str().into(),
+              id.into(),
+            )),
+            &HookTransformArgs { id, code: &code, module_type: &*module_type },
+          )
+          .instrument(debug_span!("transform_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?
       {
+        self.merge_module_meta(id, r.meta);
         original_sourcemap_chain = plugin_sourcemap_chain.into_inner();
         if let Some(map) = Self::normalize_transform_sourcemap(r.map, id, &code, r.code.as_ref()) {
           original_sourcemap_chain.push(map);
@@ -345,6 +393,20 @@ impl PluginDriver {
     Ok(code)
   }
 
+  /// Hooks without a filter are called for everything.
+  #[inline]
+  fn matches_hook_filter(
+    &self,
+    filter: Option<&HookFilter>,
+    id: &str,
+    module_type: Option<&ModuleType>,
+    code: Option<&str>,
+  ) -> bool {
+    filter.is_none_or(|filter| {
+      filter.matches(&self.options.cwd.to_string_lossy(), id, module_type, code)
+    })
+  }
+
   #[inline]
   fn normalize_transform_sourcemap(
     map: Option<SourceMap>,
@@ -388,23 +450,34 @@ impl PluginDriver {
     for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_transform_ast_meta)
     {
-      if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::TransformAst) {
+      if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::TransformAst)
+        || !self.matches_hook_filter(
+          self.plugin_hook_filters[plugin_idx].transform_ast.as_ref(),
+          args.id,
+          Some(args.module_type),
+          Some(args.ast.source()),
+        )
+      {
         continue;
       }
-      args.ast = plugin
-        .call_transform_ast(
-          ctx,
-          HookTransformAstArgs {
-            cwd: args.cwd,
-            ast: args.ast,
-            id: args.id,
-            stable_id: args.stable_id,
-            is_user_defined_entry: args.is_user_defined_entry,
-            module_type: args.module_type,
-          },
-        )
-        .instrument(debug_span!("transform_ast_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?;
+      args.ast = Self::run_hook(
+        &plugin.call_name(),
+        "transformAst",
+        plugin
+          .call_transform_ast(
+            ctx,
+            HookTransformAstArgs {
+              cwd: args.cwd,
+              ast: args.ast,
+              id: args.id,
+              stable_id: args.stable_id,
+              is_user_defined_entry: args.is_user_defined_entry,
+              module_type: args.module_type,
+            },
+          )
+          .instrument(debug_span!("transform_ast_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?;
     }
     Ok(args.ast)
   }
@@ -420,10 +493,14 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::ModuleParsed) {
         continue;
       }
-      plugin
-        .call_module_parsed(ctx, Arc::clone(&module_info), normal_module)
-        .instrument(debug_span!("module_parsed_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?;
+      Self::run_hook(
+        &plugin.call_name(),
+        "moduleParsed",
+        plugin
+          .call_module_parsed(ctx, Arc::clone(&module_info), normal_module)
+          .instrument(debug_span!("module_parsed_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?;
     }
     Ok(())
   }
@@ -435,10 +512,14 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::BuildEnd) {
         continue;
       }
-      plugin
-        .call_build_end(ctx, args)
-        .instrument(debug_span!("build_end_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?;
+      Self::run_hook(
+        &plugin.call_name(),
+        "buildEnd",
+        plugin
+          .call_build_end(ctx, args)
+          .instrument(debug_span!("build_end_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?;
     }
     Ok(())
   }
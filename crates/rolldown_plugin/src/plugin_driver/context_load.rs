@@ -0,0 +1,77 @@
+use std::{future::Future, pin::pin, sync::Arc};
+
+use arcstr::ArcStr;
+use rolldown_common::ModuleId;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{LoadedModule, PluginDriver};
+
+tokio::task_local! {
+  /// Id of the module processed by the current task, whose hooks may call `PluginContext::load`.
+  static PROCESSING_MODULE: ArcStr;
+}
+
+impl PluginDriver {
+  /// Runs the processing of a module, so `PluginContext::load` calls from its hooks know which module
+  /// is waiting.
+  pub async fn with_processing_module<F: Future>(module_id: ArcStr, f: F) -> F::Output {
+    PROCESSING_MODULE.scope(module_id, f).await
+  }
+
+  /// Called once a module task starts, so `wait_for_module` doesn't see the result of a previous build.
+  pub fn mark_context_load_module_loading(&self, module_id: &ModuleId) {
+    self.loaded_modules.remove(module_id.resource_id());
+  }
+
+  /// Waits until the module finished processing. If it's waiting for the calling module, directly or
+  /// through other modules, it never would, and the partial info is returned instead.
+  pub(crate) async fn wait_for_module(&self, module_id: &ArcStr) -> anyhow::Result<LoadedModule> {
+    let waiting_module = PROCESSING_MODULE.try_with(ArcStr::clone).ok();
+    if let Some(waiting_module) = &waiting_module {
+      let mut load_waits = self.load_waits.lock().expect("should not be poisoned");
+      if waiting_module == module_id || is_waiting_for(&load_waits, module_id, waiting_module) {
+        drop(load_waits);
+        let info = self.modules.get(module_id).map(|info| Arc::clone(info.value()));
+        let info = info.ok_or_else(|| anyhow::anyhow!("Module {module_id} is not loaded yet."))?;
+        return Ok(LoadedModule { info, is_cycle: true });
+      }
+      load_waits.entry(ArcStr::clone(waiting_module)).or_default().push(ArcStr::clone(module_id));
+    }
+
+    let loaded = loop {
+      // Register for the notification before checking, so a module finished in between isn't missed.
+      let mut notified = pin!(self.loaded_modules_notify.notified());
+      notified.as_mut().enable();
+      if let Some(loaded) = self.loaded_modules.get(module_id) {
+        break loaded.clone();
+      }
+      notified.await;
+    };
+
+    if let Some(waiting_module) = &waiting_module {
+      let mut load_waits = self.load_waits.lock().expect("should not be poisoned");
+      if let Some(waits) = load_waits.get_mut(waiting_module) {
+        if let Some(index) = waits.iter().position(|id| id == module_id) {
+          waits.swap_remove(index);
+        }
+      }
+    }
+
+    let info = loaded.ok_or_else(|| anyhow::anyhow!("Failed to load module {module_id}."))?;
+    Ok(LoadedModule { info, is_cycle: false })
+  }
+}
+
+fn is_waiting_for(load_waits: &FxHashMap<ArcStr, Vec<ArcStr>>, from: &ArcStr, to: &ArcStr) -> bool {
+  let mut visited = FxHashSet::default();
+  let mut stack = vec![from];
+  while let Some(id) = stack.pop() {
+    if id == to {
+      return true;
+    }
+    if visited.insert(id) {
+      stack.extend(load_waits.get(id).into_iter().flatten());
+    }
+  }
+  false
+}
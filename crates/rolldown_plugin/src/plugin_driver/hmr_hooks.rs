@@ -0,0 +1,22 @@
+use crate::HookHmrAcceptArgs;
+use crate::HookHmrAcceptReturn;
+use crate::HookUsage;
+use crate::PluginDriver;
+
+impl PluginDriver {
+  pub async fn hmr_accept(&self, args: &HookHmrAcceptArgs<'_>) -> HookHmrAcceptReturn {
+    for (plugin_idx, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_hmr_accept_meta)
+    {
+      if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::HmrAccept) {
+        continue;
+      }
+      if let Some(output) =
+        Self::run_hook(&plugin.call_name(), "hmrAccept", plugin.call_hmr_accept(ctx, args)).await?
+      {
+        return Ok(Some(output));
+      }
+    }
+    Ok(None)
+  }
+}
@@ -0,0 +1,106 @@
+use crate::{__inner::SharedPluginable, HookUsage, PluginHookMeta, PluginOrder};
+
+use super::PluginDriver;
+
+type GetHookMeta = fn(&SharedPluginable) -> Option<PluginHookMeta>;
+
+impl PluginDriver {
+  /// Renders the resolved execution order of every hook used by at least one plugin, one hook per
+  /// line, e.g. `transform: a (pre, #2) -> b (normal, priority -1, #0) -> c (post, #1)`. `#n` is
+  /// the index at which the plugin was registered, and non-zero priorities are shown.
+  pub fn describe_hook_order(&self) -> String {
+    let hooks: [(&str, HookUsage, &[_], GetHookMeta); 22] = [
+      ("buildStart", HookUsage::BuildStart, &self.order_by_build_start_meta, |p| {
+        p.call_build_start_meta()
+      }),
+      ("resolveId", HookUsage::ResolveId, &self.order_by_resolve_id_meta, |p| {
+        p.call_resolve_id_meta()
+      }),
+      (
+        "resolveDynamicImport",
+        HookUsage::ResolveDynamicImport,
+        &self.order_by_resolve_dynamic_import_meta,
+        |p| p.call_resolve_dynamic_import_meta(),
+      ),
+      ("load", HookUsage::Load, &self.order_by_load_meta, |p| p.call_load_meta()),
+      ("transform", HookUsage::Transform, &self.order_by_transform_meta, |p| {
+        p.call_transform_meta()
+      }),
+      ("transformAst", HookUsage::TransformAst, &self.order_by_transform_ast_meta, |p| {
+        p.call_transform_ast_meta()
+      }),
+      ("moduleParsed", HookUsage::ModuleParsed, &self.order_by_module_parsed_meta, |p| {
+        p.call_module_parsed_meta()
+      }),
+      ("buildEnd", HookUsage::BuildEnd, &self.order_by_build_end_meta, |p| p.call_build_end_meta()),
+      ("renderStart", HookUsage::RenderStart, &self.order_by_render_start_meta, |p| {
+        p.call_render_start_meta()
+      }),
+      ("banner", HookUsage::Banner, &self.order_by_banner_meta, |p| p.call_banner_meta()),
+      ("footer", HookUsage::Footer, &self.order_by_footer_meta, |p| p.call_footer_meta()),
+      ("intro", HookUsage::Intro, &self.order_by_intro_meta, |p| p.call_intro_meta()),
+      ("outro", HookUsage::Outro, &self.order_by_outro_meta, |p| p.call_outro_meta()),
+      ("renderChunk", HookUsage::RenderChunk, &self.order_by_render_chunk_meta, |p| {
+        p.call_render_chunk_meta()
+      }),
+      (
+        "augmentChunkHash",
+        HookUsage::AugmentChunkHash,
+        &self.order_by_augment_chunk_hash_meta,
+        |p| p.call_augment_chunk_hash_meta(),
+      ),
+      ("renderError", HookUsage::RenderError, &self.order_by_render_error_meta, |p| {
+        p.call_render_error_meta()
+      }),
+      ("generateBundle", HookUsage::GenerateBundle, &self.order_by_generate_bundle_meta, |p| {
+        p.call_generate_bundle_meta()
+      }),
+      ("writeBundle", HookUsage::WriteBundle, &self.order_by_write_bundle_meta, |p| {
+        p.call_write_bundle_meta()
+      }),
+      ("closeBundle", HookUsage::CloseBundle, &self.order_by_close_bundle_meta, |p| {
+        p.call_close_bundle_meta()
+      }),
+      ("watchChange", HookUsage::WatchChange, &self.order_by_watch_change_meta, |p| {
+        p.call_watch_change_meta()
+      }),
+      ("closeWatcher", HookUsage::CloseWatcher, &self.order_by_close_watcher_meta, |p| {
+        p.call_close_watcher_meta()
+      }),
+      ("hmrAccept", HookUsage::HmrAccept, &self.order_by_hmr_accept_meta, |p| {
+        p.call_hmr_accept_meta()
+      }),
+    ];
+
+    hooks
+      .into_iter()
+      .filter_map(|(hook_name, usage, order, get_hook_meta)| {
+        let plugins = order
+          .iter()
+          .filter(|idx| self.plugin_usage_vec[**idx].contains(usage))
+          .map(|idx| {
+            let plugin = &self.plugins[*idx];
+            let meta = get_hook_meta(plugin).unwrap_or_default();
+            let phase = match meta.order {
+              Some(PluginOrder::Pre) => "pre",
+              Some(PluginOrder::Post) => "post",
+              None => "normal",
+            };
+            if meta.priority == 0 {
+              format!("{} ({phase}, #{})", plugin.call_name(), idx.index())
+            } else {
+              format!(
+                "{} ({phase}, priority {}, #{})",
+                plugin.call_name(),
+                meta.priority,
+                idx.index()
+              )
+            }
+          })
+          .collect::<Vec<_>>();
+        (!plugins.is_empty()).then(|| format!("{hook_name}: {}", plugins.join(" -> ")))
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
@@ -0,0 +1,30 @@
+use std::future::Future;
+
+use rolldown_error::BuildDiagnostic;
+
+use crate::PluginDriver;
+
+tokio::task_local! {
+  /// Name of the hook the current task is running, so diagnostics reported through
+  /// `PluginContext::warn` and `PluginContext::error` know where they come from.
+  static RUNNING_HOOK: &'static str;
+}
+
+impl PluginDriver {
+  /// Runs a hook of a plugin, attaching the plugin and the hook to the error it returns and to the
+  /// diagnostics it reports through its `PluginContext`.
+  pub(crate) async fn run_hook<T>(
+    plugin_name: &str,
+    hook: &'static str,
+    f: impl Future<Output = anyhow::Result<T>>,
+  ) -> anyhow::Result<T> {
+    RUNNING_HOOK.scope(hook, f).await.map_err(|err| {
+      BuildDiagnostic::unhandleable_error(err).with_provenance(plugin_name, Some(hook)).into()
+    })
+  }
+}
+
+/// The hook running in the current task, if any.
+pub fn running_hook() -> Option<&'static str> {
+  RUNNING_HOOK.try_with(|hook| *hook).ok()
+}
@@ -1,4 +1,5 @@
 use std::{
+  borrow::Cow,
   ops::Deref,
   sync::{Arc, Weak},
   vec,
@@ -8,24 +9,35 @@ use arcstr::ArcStr;
 use dashmap::{DashMap, DashSet};
 use oxc_index::IndexVec;
 use rolldown_common::{
-  ModuleId, ModuleInfo, ModuleLoaderMsg, SharedFileEmitter, SharedNormalizedBundlerOptions,
+  CancellationToken, ModuleId, ModuleInfo, ModuleLoaderMsg, ModuleMeta, OutputOptions,
+  SharedFileEmitter, SharedNormalizedBundlerOptions,
 };
+use rolldown_error::BuildDiagnostic;
 use rolldown_resolver::Resolver;
 use rolldown_utils::dashmap::{FxDashMap, FxDashSet};
+use rustc_hash::FxHashMap;
 use tokio::sync::Mutex;
 
 use crate::{
   __inner::SharedPluginable,
-  HookUsage, PluginContext, PluginHookMeta, PluginOrder,
+  HookUsage, PluginContext, PluginHookMeta,
   plugin_context::NativePluginContextImpl,
   type_aliases::{IndexPluginContext, IndexPluginable},
-  types::plugin_idx::PluginIdx,
+  types::{
+    hook_filter::PluginHookFilters, linked_module_graph::LinkedModuleGraph, plugin_idx::PluginIdx,
+  },
 };
 
 mod build_hooks;
+mod context_load;
+mod hmr_hooks;
+mod hook_order_report;
+mod hook_provenance;
 mod output_hooks;
 mod watch_hooks;
 
+pub use hook_provenance::running_hook;
+
 pub type SharedPluginDriver = Arc<PluginDriver>;
 
 pub struct PluginDriver {
@@ -34,9 +46,26 @@ pub struct PluginDriver {
   order_indicates: HookOrderIndicates,
   pub file_emitter: SharedFileEmitter,
   pub watch_files: Arc<FxDashSet<ArcStr>>,
+  /// Watch files registered while transforming a module, mapped to the ids of those modules.
+  pub watch_file_dependents: Arc<FxDashMap<ArcStr, FxDashSet<ArcStr>>>,
   pub modules: Arc<FxDashMap<ArcStr, Arc<ModuleInfo>>>,
+  /// Kept across builds like `watch_file_dependents`, see `clear`.
+  module_meta: Arc<FxDashMap<ArcStr, ModuleMeta>>,
+  pub(crate) linked_module_graph: Arc<std::sync::Mutex<Option<LinkedModuleGraph>>>,
+  /// Warnings reported by plugins through `PluginContext::warn`, see `take_warnings`.
+  warnings: Arc<std::sync::Mutex<Vec<BuildDiagnostic>>>,
   pub(crate) tx: Arc<Mutex<Option<tokio::sync::mpsc::Sender<ModuleLoaderMsg>>>>,
+  /// Modules that finished processing, with their final info or `None` if they failed. Kept across
+  /// builds, so `PluginContext::load` doesn't wait for cached modules of an incremental build.
+  loaded_modules: FxDashMap<ArcStr, Option<Arc<ModuleInfo>>>,
+  loaded_modules_notify: tokio::sync::Notify,
+  /// Modules that `PluginContext::load` calls made while processing a module wait for, keyed by the
+  /// module, to detect cycles.
+  load_waits: std::sync::Mutex<FxHashMap<ArcStr, Vec<ArcStr>>>,
+  /// Token of the running build, if it's cancellable. See `is_cancelled`.
+  cancellation_token: std::sync::Mutex<Option<CancellationToken>>,
   pub(crate) plugin_usage_vec: IndexVec<PluginIdx, HookUsage>,
+  pub(crate) plugin_hook_filters: IndexVec<PluginIdx, PluginHookFilters>,
   options: SharedNormalizedBundlerOptions,
 }
 
@@ -48,9 +77,14 @@ impl PluginDriver {
     options: &SharedNormalizedBundlerOptions,
   ) -> SharedPluginDriver {
     let watch_files = Arc::new(DashSet::default());
+    let watch_file_dependents = Arc::new(DashMap::default());
     let modules = Arc::new(DashMap::default());
+    let module_meta = Arc::new(DashMap::default());
+    let linked_module_graph = Arc::default();
+    let warnings = Arc::default();
     let tx = Arc::new(Mutex::new(None));
     let mut plugin_usage_vec = IndexVec::new();
+    let mut plugin_hook_filters = IndexVec::new();
 
     Arc::new_cyclic(|plugin_driver| {
       let mut index_plugins = IndexPluginable::with_capacity(plugins.len());
@@ -59,15 +93,21 @@ impl PluginDriver {
       plugins.into_iter().for_each(|plugin| {
         let plugin_idx = index_plugins.push(Arc::clone(&plugin));
         plugin_usage_vec.push(plugin.call_hook_usage());
+        plugin_hook_filters.push(PluginHookFilters::new(&plugin));
         index_contexts.push(PluginContext::Native(Arc::new(NativePluginContextImpl {
           skipped_resolve_calls: vec![],
           plugin_idx,
+          plugin_name: plugin.call_name(),
           plugin_driver: Weak::clone(plugin_driver),
           resolver: Arc::clone(resolver),
           file_emitter: Arc::clone(file_emitter),
           modules: Arc::clone(&modules),
+          module_meta: Arc::clone(&module_meta),
+          linked_module_graph: Arc::clone(&linked_module_graph),
           options: Arc::clone(options),
           watch_files: Arc::clone(&watch_files),
+          watch_file_dependents: Arc::clone(&watch_file_dependents),
+          warnings: Arc::clone(&warnings),
           tx: Arc::clone(&tx),
         })));
       });
@@ -78,20 +118,139 @@ impl PluginDriver {
         contexts: index_contexts,
         file_emitter: Arc::clone(file_emitter),
         watch_files,
+        watch_file_dependents,
         modules,
+        module_meta,
+        linked_module_graph,
+        warnings,
         tx,
+        loaded_modules: FxDashMap::default(),
+        loaded_modules_notify: tokio::sync::Notify::new(),
+        load_waits: std::sync::Mutex::default(),
+        cancellation_token: std::sync::Mutex::default(),
         plugin_usage_vec,
+        plugin_hook_filters,
         options: Arc::clone(options),
       }
     })
   }
 
+  // `watch_file_dependents` is reset by `clear_watch_file_dependents` at build start instead. `module_meta`
+  // and `loaded_modules` are kept, because modules that are not transformed again in an incremental build
+  // keep the meta plugins attached and are already loaded.
   pub fn clear(&self) {
     self.watch_files.clear();
     self.modules.clear();
+    self.reset_linked_module_graph();
     self.file_emitter.clear();
   }
 
+  pub fn options(&self) -> &SharedNormalizedBundlerOptions {
+    &self.options
+  }
+
+  /// Calls the `output_options` hook of each plugin in the order they're registered.
+  pub fn output_options(&self, options: &mut OutputOptions) {
+    for plugin in &self.plugins {
+      plugin.call_output_options(options);
+    }
+  }
+
+  /// Drains the warnings plugins reported so far.
+  pub fn take_warnings(&self) -> Vec<BuildDiagnostic> {
+    std::mem::take(&mut *self.warnings.lock().expect("should not be poisoned"))
+  }
+
+  /// Makes the module graph queryable by plugins. Called once linking finished.
+  pub fn set_linked_module_graph(&self, graph: LinkedModuleGraph) {
+    *self.linked_module_graph.lock().expect("should not be poisoned") = Some(graph);
+  }
+
+  /// Records which chunk each included module ended up in. Called once chunks are generated.
+  pub fn set_module_chunk_filenames(&self, filenames: FxHashMap<ArcStr, ArcStr>) {
+    if let Some(graph) = self.linked_module_graph.lock().expect("should not be poisoned").as_mut() {
+      graph.module_chunk_filenames = Some(filenames);
+    }
+  }
+
+  pub fn set_cancellation_token(&self, token: Option<CancellationToken>) {
+    *self.cancellation_token.lock().expect("should not be poisoned") = token;
+  }
+
+  /// Whether the running build has been cancelled. Always `false` for builds that can't be cancelled.
+  pub fn is_cancelled(&self) -> bool {
+    self
+      .cancellation_token
+      .lock()
+      .expect("should not be poisoned")
+      .as_ref()
+      .is_some_and(CancellationToken::is_cancelled)
+  }
+
+  pub fn reset_linked_module_graph(&self) {
+    *self.linked_module_graph.lock().expect("should not be poisoned") = None;
+  }
+
+  /// Forgets the watch files registered by `module_ids`, or by every module if `module_ids` is `None`.
+  /// The modules register them again when they are transformed.
+  pub fn clear_watch_file_dependents(&self, module_ids: Option<&[ArcStr]>) {
+    match module_ids {
+      None => self.watch_file_dependents.clear(),
+      Some(module_ids) => self.watch_file_dependents.retain(|_, dependents| {
+        for module_id in module_ids {
+          dependents.remove(module_id);
+        }
+        !dependents.is_empty()
+      }),
+    }
+  }
+
+  /// Ids of the modules that should be treated as changed when `file` changes.
+  pub fn get_watch_file_dependents(&self, file: &str) -> Vec<ArcStr> {
+    self
+      .watch_file_dependents
+      .get(file)
+      .map(|dependents| dependents.iter().map(|id| id.key().clone()).collect())
+      .unwrap_or_default()
+  }
+
+  /// Whether any plugin registers the given hook, so work that only serves that hook could be skipped.
+  pub fn has_hook_usage(&self, usage: HookUsage) -> bool {
+    self.plugin_usage_vec.iter().any(|plugin_usage| plugin_usage.contains(usage))
+  }
+
+  /// Whether every plugin allows reusing the cached `load` and `transform` results of the module.
+  pub fn should_cache_module(&self, module_id: &str) -> bool {
+    self.plugins.iter().all(|plugin| plugin.call_should_cache_module(module_id))
+  }
+
+  /// Names of the plugins in the order they're registered.
+  pub fn plugin_names(&self) -> impl Iterator<Item = Cow<'static, str>> + '_ {
+    self.plugins.iter().map(|plugin| plugin.call_name())
+  }
+
+  pub(crate) fn plugin_indices(&self) -> impl Iterator<Item = PluginIdx> + '_ {
+    self.plugins.indices()
+  }
+
+  /// The meta of the module, created empty if no plugin attached any yet.
+  pub fn get_module_meta(&self, module_id: &str) -> ModuleMeta {
+    Arc::clone(&self.module_meta.entry(module_id.into()).or_default())
+  }
+
+  pub(crate) fn merge_module_meta(
+    &self,
+    module_id: &str,
+    meta: Option<serde_json::Map<String, serde_json::Value>>,
+  ) {
+    if let Some(meta) = meta {
+      let module_meta = self.get_module_meta(module_id);
+      for (key, value) in meta {
+        module_meta.insert(key, value);
+      }
+    }
+  }
+
   pub fn set_module_info(&self, module_id: &ModuleId, module_info: Arc<ModuleInfo>) {
     self.modules.insert(module_id.resource_id().into(), module_info);
   }
@@ -109,6 +268,11 @@ impl PluginDriver {
     module_id: &ModuleId,
     success: bool,
   ) -> anyhow::Result<()> {
+    let module_info = success
+      .then(|| self.modules.get(module_id.resource_id()).map(|info| Arc::clone(info.value())))
+      .flatten();
+    self.loaded_modules.insert(module_id.resource_id().clone(), module_info);
+    self.loaded_modules_notify.notify_waiters();
     if let Some(mark_module_loaded) = &self.options.mark_module_loaded {
       mark_module_loaded.call(module_id, success).await?;
     }
@@ -157,6 +321,7 @@ pub struct HookOrderIndicates {
   pub order_by_close_bundle_meta: Vec<PluginIdx>,
   pub order_by_watch_change_meta: Vec<PluginIdx>,
   pub order_by_close_watcher_meta: Vec<PluginIdx>,
+  pub order_by_hmr_accept_meta: Vec<PluginIdx>,
   pub order_by_transform_ast_meta: Vec<PluginIdx>,
 }
 
@@ -217,34 +382,28 @@ impl HookOrderIndicates {
       order_by_close_watcher_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_close_watcher_meta()
       }),
+      order_by_hmr_accept_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_hmr_accept_meta()
+      }),
       order_by_transform_ast_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_transform_ast_meta()
       }),
     }
   }
 
+  /// See [`PluginHookMeta`] for the order.
   fn sort_plugins_by_hook_meta(
     index_plugins: &IndexPluginable,
     get_hook_meta: impl Fn(&SharedPluginable) -> Option<PluginHookMeta>,
   ) -> Vec<PluginIdx> {
-    let mut pre_plugins = vec![];
-    let mut normal_plugins = vec![];
-    let mut post_plugins = vec![];
-
-    for (idx, plugin) in index_plugins.iter_enumerated() {
-      let meta = get_hook_meta(plugin);
-      if let Some(meta) = meta {
-        match meta.order {
-          Some(PluginOrder::Pre) => pre_plugins.push(idx),
-          Some(PluginOrder::Post) => post_plugins.push(idx),
-          None => normal_plugins.push(idx),
-        }
-      } else {
-        normal_plugins.push(idx);
-      }
-    }
-    pre_plugins.extend(normal_plugins);
-    pre_plugins.extend(post_plugins);
-    pre_plugins
+    let mut ordered = index_plugins
+      .iter_enumerated()
+      .map(|(idx, plugin)| {
+        let (phase, priority) = get_hook_meta(plugin).unwrap_or_default().sort_key();
+        (phase, priority, idx)
+      })
+      .collect::<Vec<_>>();
+    ordered.sort_unstable();
+    ordered.into_iter().map(|(_, _, idx)| idx).collect()
   }
 }
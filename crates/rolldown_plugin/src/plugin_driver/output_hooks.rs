@@ -17,10 +17,14 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::RenderStart) {
         continue;
       }
-      plugin
-        .call_render_start(ctx, &crate::HookRenderStartArgs { options: opts })
-        .instrument(debug_span!("render_start_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?;
+      Self::run_hook(
+        &plugin.call_name(),
+        "renderStart",
+        plugin
+          .call_render_start(ctx, &crate::HookRenderStartArgs { options: opts })
+          .instrument(debug_span!("render_start_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?;
     }
     Ok(())
   }
@@ -32,10 +36,14 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::Banner) {
         continue;
       }
-      if let Some(r) = plugin
-        .call_banner(ctx, &args)
-        .instrument(debug_span!("banner_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?
+      if let Some(r) = Self::run_hook(
+        &plugin.call_name(),
+        "banner",
+        plugin
+          .call_banner(ctx, &args)
+          .instrument(debug_span!("banner_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?
       {
         banner.push('\n');
         banner.push_str(r.as_str());
@@ -54,10 +62,14 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::Footer) {
         continue;
       }
-      if let Some(r) = plugin
-        .call_footer(ctx, &args)
-        .instrument(debug_span!("footer_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?
+      if let Some(r) = Self::run_hook(
+        &plugin.call_name(),
+        "footer",
+        plugin
+          .call_footer(ctx, &args)
+          .instrument(debug_span!("footer_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?
       {
         footer.push('\n');
         footer.push_str(r.as_str());
@@ -76,10 +88,14 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::Intro) {
         continue;
       }
-      if let Some(r) = plugin
-        .call_intro(ctx, &args)
-        .instrument(debug_span!("intro_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?
+      if let Some(r) = Self::run_hook(
+        &plugin.call_name(),
+        "intro",
+        plugin
+          .call_intro(ctx, &args)
+          .instrument(debug_span!("intro_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?
       {
         intro.push('\n');
         intro.push_str(r.as_str());
@@ -98,10 +114,14 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::Outro) {
         continue;
       }
-      if let Some(r) = plugin
-        .call_outro(ctx, &args)
-        .instrument(debug_span!("outro_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?
+      if let Some(r) = Self::run_hook(
+        &plugin.call_name(),
+        "outro",
+        plugin
+          .call_outro(ctx, &args)
+          .instrument(debug_span!("outro_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?
       {
         outro.push('\n');
         outro.push_str(r.as_str());
@@ -124,10 +144,14 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::RenderChunk) {
         continue;
       }
-      if let Some(r) = plugin
-        .call_render_chunk(ctx, &args)
-        .instrument(debug_span!("render_chunk_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?
+      if let Some(r) = Self::run_hook(
+        &plugin.call_name(),
+        "renderChunk",
+        plugin
+          .call_render_chunk(ctx, &args)
+          .instrument(debug_span!("render_chunk_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?
       {
         args.code = r.code;
         if let Some(map) = r.map {
@@ -149,13 +173,15 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::AugmentChunkHash) {
         continue;
       }
-      if let Some(plugin_hash) = plugin
-        .call_augment_chunk_hash(ctx, Arc::clone(&chunk))
-        .instrument(debug_span!(
+      if let Some(plugin_hash) = Self::run_hook(
+        &plugin.call_name(),
+        "augmentChunkHash",
+        plugin.call_augment_chunk_hash(ctx, Arc::clone(&chunk)).instrument(debug_span!(
           "augment_chunk_hash_hook",
           plugin_name = plugin.call_name().as_ref()
-        ))
-        .await?
+        )),
+      )
+      .await?
       {
         hash.get_or_insert_with(String::default).push_str(&plugin_hash);
       }
@@ -170,10 +196,14 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::RenderError) {
         continue;
       }
-      plugin
-        .call_render_error(ctx, args)
-        .instrument(debug_span!("render_error_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?;
+      Self::run_hook(
+        &plugin.call_name(),
+        "renderError",
+        plugin
+          .call_render_error(ctx, args)
+          .instrument(debug_span!("render_error_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?;
     }
     Ok(())
   }
@@ -192,10 +222,15 @@ impl PluginDriver {
         continue;
       }
       let mut args = crate::HookGenerateBundleArgs { is_write, bundle, options: opts };
-      plugin
-        .call_generate_bundle(ctx, &mut args)
-        .instrument(debug_span!("generate_bundle_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?;
+      Self::run_hook(
+        &plugin.call_name(),
+        "generateBundle",
+        plugin.call_generate_bundle(ctx, &mut args).instrument(debug_span!(
+          "generate_bundle_hook",
+          plugin_name = plugin.call_name().as_ref()
+        )),
+      )
+      .await?;
       ctx.file_emitter().add_additional_files(bundle, warnings);
     }
     Ok(())
@@ -211,10 +246,14 @@ impl PluginDriver {
     {
       let mut args = crate::HookWriteBundleArgs { bundle, options: opts };
 
-      plugin
-        .call_write_bundle(ctx, &mut args)
-        .instrument(debug_span!("write_bundle_hook", plugin_name = plugin.call_name().as_ref()))
-        .await?;
+      Self::run_hook(
+        &plugin.call_name(),
+        "writeBundle",
+        plugin
+          .call_write_bundle(ctx, &mut args)
+          .instrument(debug_span!("write_bundle_hook", plugin_name = plugin.call_name().as_ref())),
+      )
+      .await?;
       ctx.file_emitter().add_additional_files(bundle, warnings);
     }
     Ok(())
@@ -223,10 +262,14 @@ impl PluginDriver {
   pub async fn close_bundle(&self) -> HookNoopReturn {
     for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_close_bundle_meta)
     {
-      plugin
-        .call_close_bundle(ctx)
-        .instrument(debug_span!("close_bundle_hook", plugin_name = plugin.call_name().as_ref(),))
-        .await?;
+      Self::run_hook(
+        &plugin.call_name(),
+        "closeBundle",
+        plugin
+          .call_close_bundle(ctx)
+          .instrument(debug_span!("close_bundle_hook", plugin_name = plugin.call_name().as_ref(),)),
+      )
+      .await?;
     }
     Ok(())
   }
@@ -11,7 +11,12 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::WatchChange) {
         continue;
       }
-      plugin.call_watch_change(ctx, path, event).await?;
+      Self::run_hook(
+        &plugin.call_name(),
+        "watchChange",
+        plugin.call_watch_change(ctx, path, event),
+      )
+      .await?;
     }
     Ok(())
   }
@@ -23,7 +28,7 @@ impl PluginDriver {
       if !self.plugin_usage_vec[plugin_idx].contains(HookUsage::CloseWatcher) {
         continue;
       }
-      plugin.call_close_watcher(ctx).await?;
+      Self::run_hook(&plugin.call_name(), "closeWatcher", plugin.call_close_watcher(ctx)).await?;
     }
     Ok(())
   }
@@ -2,17 +2,19 @@ use std::{any::Any, borrow::Cow, fmt::Debug, sync::Arc};
 
 use super::plugin_context::PluginContext;
 use crate::{
-  HookAddonArgs, HookBuildEndArgs, HookBuildStartArgs, HookGenerateBundleArgs,
-  HookInjectionOutputReturn, HookLoadArgs, HookRenderChunkArgs, HookRenderStartArgs,
-  HookResolveIdArgs, HookTransformArgs, HookUsage, Plugin, PluginHookMeta,
-  SharedTransformPluginContext,
+  HookAddonArgs, HookBuildEndArgs, HookBuildStartArgs, HookFilter, HookGenerateBundleArgs,
+  HookHmrAcceptArgs, HookHmrAcceptReturn, HookInjectionOutputReturn, HookLoadArgs,
+  HookRenderChunkArgs, HookRenderStartArgs, HookResolveDynamicImportArgs, HookResolveIdArgs,
+  HookTransformArgs, HookUsage, Plugin, PluginHookMeta, SharedTransformPluginContext,
   types::{
     hook_render_error::HookRenderErrorArgs, hook_transform_ast_args::HookTransformAstArgs,
     hook_write_bundle_args::HookWriteBundleArgs,
   },
 };
 use anyhow::Ok;
-use rolldown_common::{ModuleInfo, NormalModule, RollupRenderedChunk, WatcherChangeKind};
+use rolldown_common::{
+  BundlerOptions, ModuleInfo, NormalModule, OutputOptions, RollupRenderedChunk, WatcherChangeKind,
+};
 
 pub use crate::plugin::HookAugmentChunkHashReturn;
 pub use crate::plugin::HookLoadReturn;
@@ -34,7 +36,9 @@ pub type SharedPluginable = Arc<dyn Pluginable>;
 pub trait Pluginable: Any + Debug + Send + Sync + 'static {
   fn call_name(&self) -> Cow<'static, str>;
 
-  // The `option` hook consider call at node side.
+  fn call_options(&self, _options: &mut BundlerOptions);
+
+  fn call_output_options(&self, _options: &mut OutputOptions);
 
   // --- Build hooks ---
 
@@ -54,13 +58,12 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_resolve_id_meta(&self) -> Option<PluginHookMeta>;
 
-  #[deprecated(
-    note = "This hook is only for rollup compatibility, please use `resolve_id` instead."
-  )]
+  fn call_resolve_id_filter(&self) -> Option<HookFilter>;
+
   async fn call_resolve_dynamic_import(
     &self,
     _ctx: &PluginContext,
-    _args: &HookResolveIdArgs,
+    _args: &HookResolveDynamicImportArgs,
   ) -> HookResolveIdReturn;
 
   fn call_resolve_dynamic_import_meta(&self) -> Option<PluginHookMeta>;
@@ -69,6 +72,8 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_load_meta(&self) -> Option<PluginHookMeta>;
 
+  fn call_load_filter(&self) -> Option<HookFilter>;
+
   async fn call_transform(
     &self,
     _ctx: SharedTransformPluginContext,
@@ -77,6 +82,10 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_transform_meta(&self) -> Option<PluginHookMeta>;
 
+  fn call_transform_filter(&self) -> Option<HookFilter>;
+
+  fn call_should_cache_module(&self, id: &str) -> bool;
+
   async fn call_transform_ast(
     &self,
     _ctx: &PluginContext,
@@ -85,6 +94,8 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_transform_ast_meta(&self) -> Option<PluginHookMeta>;
 
+  fn call_transform_ast_filter(&self) -> Option<HookFilter>;
+
   async fn call_module_parsed(
     &self,
     _ctx: &PluginContext,
@@ -209,6 +220,18 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  async fn call_hmr_accept(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookHmrAcceptArgs<'_>,
+  ) -> HookHmrAcceptReturn {
+    Ok(None)
+  }
+
+  fn call_hmr_accept_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   fn call_hook_usage(&self) -> HookUsage;
 }
 
@@ -218,6 +241,14 @@ impl<T: Plugin> Pluginable for T {
     Plugin::name(self)
   }
 
+  fn call_options(&self, options: &mut BundlerOptions) {
+    Plugin::options(self, options);
+  }
+
+  fn call_output_options(&self, options: &mut OutputOptions) {
+    Plugin::output_options(self, options);
+  }
+
   async fn call_build_start(
     &self,
     ctx: &PluginContext,
@@ -242,11 +273,14 @@ impl<T: Plugin> Pluginable for T {
     Plugin::resolve_id_meta(self)
   }
 
-  #[allow(deprecated)]
+  fn call_resolve_id_filter(&self) -> Option<HookFilter> {
+    Plugin::resolve_id_filter(self)
+  }
+
   async fn call_resolve_dynamic_import(
     &self,
     ctx: &PluginContext,
-    args: &HookResolveIdArgs,
+    args: &HookResolveDynamicImportArgs,
   ) -> HookResolveIdReturn {
     Plugin::resolve_dynamic_import(self, ctx, args).await
   }
@@ -263,6 +297,10 @@ impl<T: Plugin> Pluginable for T {
     Plugin::load_meta(self)
   }
 
+  fn call_load_filter(&self) -> Option<HookFilter> {
+    Plugin::load_filter(self)
+  }
+
   async fn call_transform(
     &self,
     ctx: SharedTransformPluginContext,
@@ -275,6 +313,14 @@ impl<T: Plugin> Pluginable for T {
     Plugin::transform_meta(self)
   }
 
+  fn call_transform_filter(&self) -> Option<HookFilter> {
+    Plugin::transform_filter(self)
+  }
+
+  fn call_should_cache_module(&self, id: &str) -> bool {
+    Plugin::should_cache_module(self, id)
+  }
+
   async fn call_module_parsed(
     &self,
     ctx: &PluginContext,
@@ -449,6 +495,18 @@ impl<T: Plugin> Pluginable for T {
     Plugin::close_watcher_meta(self)
   }
 
+  async fn call_hmr_accept(
+    &self,
+    ctx: &PluginContext,
+    args: &HookHmrAcceptArgs<'_>,
+  ) -> HookHmrAcceptReturn {
+    Plugin::hmr_accept(self, ctx, args).await
+  }
+
+  fn call_hmr_accept_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::hmr_accept_meta(self)
+  }
+
   async fn call_transform_ast(
     &self,
     ctx: &PluginContext,
@@ -461,6 +519,10 @@ impl<T: Plugin> Pluginable for T {
     Plugin::transform_ast_meta(self)
   }
 
+  fn call_transform_ast_filter(&self) -> Option<HookFilter> {
+    Plugin::transform_ast_filter(self)
+  }
+
   fn call_hook_usage(&self) -> HookUsage {
     Plugin::register_hook_usage(self)
   }
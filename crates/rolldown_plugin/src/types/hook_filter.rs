@@ -0,0 +1,74 @@
+use rolldown_common::ModuleType;
+use rolldown_utils::pattern_filter::{StringOrRegex, filter, filter_code};
+
+use crate::__inner::SharedPluginable;
+
+/// Patterns with the semantics of `createFilter` from `@rollup/pluginutils`: `exclude` wins over
+/// `include`, and a missing or empty `include` matches everything that isn't excluded.
+#[derive(Debug, Default, Clone)]
+pub struct GeneralHookFilter {
+  pub include: Option<Vec<StringOrRegex>>,
+  pub exclude: Option<Vec<StringOrRegex>>,
+}
+
+/// Conditions checked by the plugin driver before calling a hook, so a plugin that only handles a
+/// few modules doesn't pay for calling the hook on all the others. The hook is called only if every
+/// condition that is set matches. Conditions on values the hook doesn't receive are ignored, e.g.
+/// `code` for `load`.
+#[derive(Debug, Default, Clone)]
+pub struct HookFilter {
+  /// Matched against the module id, or the specifier for `resolve_id`. String patterns are globs
+  /// relative to `cwd`.
+  pub id: Option<GeneralHookFilter>,
+  pub module_type: Option<Vec<ModuleType>>,
+  /// String patterns match if the code contains them.
+  pub code: Option<GeneralHookFilter>,
+}
+
+impl HookFilter {
+  pub(crate) fn matches(
+    &self,
+    cwd: &str,
+    id: &str,
+    module_type: Option<&ModuleType>,
+    code: Option<&str>,
+  ) -> bool {
+    if let (Some(module_types), Some(module_type)) = (&self.module_type, module_type) {
+      if !module_types.contains(module_type) {
+        return false;
+      }
+    }
+    if let Some(id_filter) = &self.id {
+      if !filter(id_filter.exclude.as_deref(), id_filter.include.as_deref(), id, cwd).inner() {
+        return false;
+      }
+    }
+    if let (Some(code_filter), Some(code)) = (&self.code, code) {
+      if !filter_code(code_filter.exclude.as_deref(), code_filter.include.as_deref(), code).inner()
+      {
+        return false;
+      }
+    }
+    true
+  }
+}
+
+/// The filters of a plugin's hooks, queried once when the plugin driver is created.
+#[derive(Debug, Default)]
+pub struct PluginHookFilters {
+  pub resolve_id: Option<HookFilter>,
+  pub load: Option<HookFilter>,
+  pub transform: Option<HookFilter>,
+  pub transform_ast: Option<HookFilter>,
+}
+
+impl PluginHookFilters {
+  pub fn new(plugin: &SharedPluginable) -> Self {
+    Self {
+      resolve_id: plugin.call_resolve_id_filter(),
+      load: plugin.call_load_filter(),
+      transform: plugin.call_transform_filter(),
+      transform_ast: plugin.call_transform_ast_filter(),
+    }
+  }
+}
@@ -0,0 +1,18 @@
+#[derive(Debug)]
+pub struct HookHmrAcceptArgs<'a> {
+  /// The module that was edited.
+  pub changed_module_id: &'a str,
+  /// The module the update is propagating through, the changed module itself or one of its
+  /// importers.
+  pub module_id: &'a str,
+  /// Whether the module calls `import.meta.hot.accept()` without dependencies.
+  pub is_self_accepting: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookHmrAcceptOutput {
+  /// Makes the module an hmr boundary, as if it accepted its own updates.
+  SelfAccept,
+  /// Keeps the module from accepting the update, so it propagates to its importers.
+  Invalidate,
+}
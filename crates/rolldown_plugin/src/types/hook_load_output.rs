@@ -8,4 +8,6 @@ pub struct HookLoadOutput {
   pub map: Option<SourceMap>,
   pub side_effects: Option<HookSideEffects>,
   pub module_type: Option<ModuleType>,
+  /// Merged into the module's meta, see `PluginContext::set_module_meta`.
+  pub meta: Option<serde_json::Map<String, serde_json::Value>>,
 }
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use super::custom_field::CustomField;
+
+#[derive(Debug, Clone, Copy)]
+pub enum DynamicImportSpecifier<'a> {
+  /// `./foo.js` in `import('./foo.js')`
+  Static(&'a str),
+  /// The source text of a specifier that can't be determined statically, such as
+  /// `` `./locales/${lang}.json` `` in `` import(`./locales/${lang}.json`) ``.
+  Expression(&'a str),
+}
+
+#[derive(Debug)]
+pub struct HookResolveDynamicImportArgs<'a> {
+  pub importer: Option<&'a str>,
+  pub specifier: DynamicImportSpecifier<'a>,
+  pub custom: Arc<CustomField>,
+}
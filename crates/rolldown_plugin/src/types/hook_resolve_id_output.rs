@@ -7,4 +7,6 @@ pub struct HookResolveIdOutput {
   pub external: Option<ResolvedExternal>,
   pub normalize_external_id: Option<bool>,
   pub side_effects: Option<HookSideEffects>,
+  /// Merged into the module's meta, see `PluginContext::set_module_meta`.
+  pub meta: Option<serde_json::Map<String, serde_json::Value>>,
 }
@@ -0,0 +1,14 @@
+use arcstr::ArcStr;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The module graph after linking, which plugins can query from `renderStart` on.
+#[derive(Debug, Default)]
+pub struct LinkedModuleGraph {
+  /// Ids of all modules in the graph, in a stable order.
+  pub module_ids: Vec<ArcStr>,
+  /// Ids of the modules whose code ends up in the output.
+  pub included_module_ids: FxHashSet<ArcStr>,
+  /// Final filename of the chunk that contains each included module, with hashes filled in. `None`
+  /// until the chunks are rendered and their hashes are computed.
+  pub module_chunk_filenames: Option<FxHashMap<ArcStr, ArcStr>>,
+}
@@ -2,13 +2,16 @@ pub mod custom_field;
 pub mod hook_addon_args;
 pub mod hook_build_end_args;
 pub mod hook_build_start_args;
+pub mod hook_filter;
 pub mod hook_generate_bundle_args;
+pub mod hook_hmr_accept_args;
 pub mod hook_load_args;
 pub mod hook_load_output;
 pub mod hook_render_chunk_args;
 pub mod hook_render_chunk_output;
 pub mod hook_render_error;
 pub mod hook_render_start_args;
+pub mod hook_resolve_dynamic_import_args;
 pub mod hook_resolve_id_args;
 pub mod hook_resolve_id_output;
 pub mod hook_resolve_id_skipped;
@@ -16,6 +19,9 @@ pub mod hook_transform_args;
 pub mod hook_transform_ast_args;
 pub mod hook_transform_output;
 pub mod hook_write_bundle_args;
+pub mod linked_module_graph;
+pub mod plugin_context_load_options;
 pub mod plugin_context_resolve_options;
+pub mod plugin_diagnostic;
 pub mod plugin_hook_meta;
 pub mod plugin_idx;
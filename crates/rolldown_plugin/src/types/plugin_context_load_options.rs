@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use rolldown_common::{ModuleInfo, ModuleType, side_effects::HookSideEffects};
+
+#[derive(Debug, Default)]
+pub struct PluginContextLoadOptions {
+  /// Forces the module type, e.g. to load a `.txt` file as js.
+  pub module_type: Option<ModuleType>,
+  pub side_effects: Option<HookSideEffects>,
+  /// Merged into the module's meta, see `PluginContext::set_module_meta`.
+  pub meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Result of `PluginContext::load`.
+#[derive(Debug)]
+pub struct LoadedModule {
+  pub info: Arc<ModuleInfo>,
+  /// The module is, possibly transitively, waiting for the module that called `load`. Waiting for
+  /// it would never finish, so `info` is what's known about the module so far.
+  pub is_cycle: bool,
+}
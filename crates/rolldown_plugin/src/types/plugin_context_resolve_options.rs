@@ -6,13 +6,28 @@ use super::custom_field::CustomField;
 
 #[derive(Debug)]
 pub struct PluginContextResolveOptions {
+  /// Selects the conditions used by the builtin resolver, e.g. `require` for `ImportKind::Require`.
   pub import_kind: ImportKind,
+  /// Don't call the `resolveId` hook of the calling plugin again for the same specifier and importer.
   pub skip_self: bool,
+  /// Don't call the `resolveId` and `resolveDynamicImport` hooks of any plugin, so only `external`,
+  /// `alias` and the builtin resolver take part. Useful to analyze a module without running into
+  /// the hooks of other plugins, or back into the calling one.
+  pub skip_plugins: bool,
+  /// Passed to the `resolveId` hooks of other plugins as `args.custom`.
   pub custom: Arc<CustomField>,
+  /// Extra condition names matched by the builtin resolver, e.g. `types`.
+  pub conditions: Vec<String>,
 }
 
 impl Default for PluginContextResolveOptions {
   fn default() -> Self {
-    Self { import_kind: ImportKind::Import, skip_self: true, custom: Arc::default() }
+    Self {
+      import_kind: ImportKind::Import,
+      skip_self: true,
+      skip_plugins: false,
+      custom: Arc::default(),
+      conditions: vec![],
+    }
   }
 }
@@ -0,0 +1,48 @@
+use arcstr::ArcStr;
+
+/// A warning or an error to report through [`crate::PluginContext::warn`] or
+/// [`crate::PluginContext::error`]. It's rendered like the diagnostics of the bundler itself, with a
+/// code frame if it points to a span of a module.
+#[derive(Debug, Default)]
+pub struct PluginDiagnostic {
+  pub(crate) message: String,
+  pub(crate) id: Option<String>,
+  pub(crate) span: Option<(u32, u32)>,
+  pub(crate) source: Option<ArcStr>,
+  pub(crate) hint: Option<String>,
+}
+
+impl PluginDiagnostic {
+  pub fn new(message: impl Into<String>) -> Self {
+    Self { message: message.into(), ..Default::default() }
+  }
+
+  /// The id of the module the diagnostic is about.
+  #[must_use]
+  pub fn with_id(mut self, id: impl Into<String>) -> Self {
+    self.id = Some(id.into());
+    self
+  }
+
+  /// Points the diagnostic at `length` bytes starting at `offset` in the source of the module.
+  /// Only rendered as a code frame together with `with_id`.
+  #[must_use]
+  pub fn with_span(mut self, offset: u32, length: u32) -> Self {
+    self.span = Some((offset, length));
+    self
+  }
+
+  /// The source the span points into. By default, it's the code of the module, or the file on disk
+  /// if the module hasn't been loaded yet. Hooks that see an already transformed code should pass it.
+  #[must_use]
+  pub fn with_source(mut self, source: impl Into<ArcStr>) -> Self {
+    self.source = Some(source.into());
+    self
+  }
+
+  #[must_use]
+  pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+    self.hint = Some(hint.into());
+    self
+  }
+}
@@ -1,10 +1,26 @@
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PluginOrder {
   Pre,
   Post,
 }
 
-#[derive(Debug)]
+/// Plugins run a hook sorted by `(order, priority, registration index)`: all `Pre` hooks first, then
+/// the ones without an order, then all `Post` hooks. Within the same order, a lower `priority` runs
+/// earlier, and plugins with equal priorities keep the order in which they were registered.
+#[derive(Debug, Default)]
 pub struct PluginHookMeta {
   pub order: Option<PluginOrder>,
+  /// Defaults to `0`, so hooks that only set `order` keep running in registration order.
+  pub priority: i32,
+}
+
+impl PluginHookMeta {
+  pub(crate) fn sort_key(&self) -> (u8, i32) {
+    let phase = match self.order {
+      Some(PluginOrder::Pre) => 0,
+      None => 1,
+      Some(PluginOrder::Post) => 2,
+    };
+    (phase, self.priority)
+  }
 }
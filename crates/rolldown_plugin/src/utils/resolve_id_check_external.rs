@@ -26,6 +26,7 @@ pub async fn resolve_id_check_external(
   skipped_resolve_calls: Option<Vec<Arc<HookResolveIdSkipped>>>,
   custom: Arc<CustomField>,
   is_user_defined_entry: bool,
+  conditions: &[String],
   bundle_options: &SharedNormalizedBundlerOptions,
 ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
   // Check external with unresolved path
@@ -43,6 +44,7 @@ pub async fn resolve_id_check_external(
     skipped_resolve_calls,
     custom,
     is_user_defined_entry,
+    conditions,
   )
   .await?;
 
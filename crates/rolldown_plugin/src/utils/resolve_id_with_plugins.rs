@@ -1,10 +1,11 @@
 use crate::{
-  HookResolveIdArgs, PluginDriver,
+  DynamicImportSpecifier, HookResolveDynamicImportArgs, HookResolveIdArgs, PluginDriver,
   types::{custom_field::CustomField, hook_resolve_id_skipped::HookResolveIdSkipped},
 };
 use rolldown_common::{ImportKind, ModuleDefFormat, ResolvedId, is_existing_node_builtin_modules};
 use rolldown_resolver::{ResolveError, Resolver};
 use std::{path::Path, sync::Arc};
+use sugar_path::SugarPath;
 
 fn is_http_url(s: &str) -> bool {
   s.starts_with("http://") || s.starts_with("https://") || s.starts_with("//")
@@ -25,15 +26,14 @@ pub async fn resolve_id_with_plugins(
   skipped_resolve_calls: Option<Vec<Arc<HookResolveIdSkipped>>>,
   custom: Arc<CustomField>,
   is_user_defined_entry: bool,
+  conditions: &[String],
 ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
   if matches!(import_kind, ImportKind::DynamicImport) {
     if let Some(r) = plugin_driver
       .resolve_dynamic_import(
-        &HookResolveIdArgs {
-          importer: importer.map(std::convert::AsRef::as_ref),
-          specifier,
-          is_entry,
-          kind: import_kind,
+        &HookResolveDynamicImportArgs {
+          importer,
+          specifier: DynamicImportSpecifier::Static(specifier),
           custom: Arc::clone(&custom),
         },
         skipped_resolve_calls.as_ref(),
@@ -83,7 +83,28 @@ pub async fn resolve_id_with_plugins(
     }));
   }
 
-  Ok(resolve_id(resolver, specifier, importer, import_kind, is_user_defined_entry))
+  let resolve = |specifier: &str| {
+    resolve_id(resolver, specifier, importer, import_kind, is_user_defined_entry, conditions)
+  };
+  let Some((entry, aliased)) = plugin_driver
+    .options()
+    .alias
+    .iter()
+    .find_map(|entry| entry.rewrite(specifier).map(|aliased| (entry, aliased)))
+  else {
+    return Ok(resolve(specifier));
+  };
+  if !entry.force {
+    let resolved = resolve(specifier);
+    if resolved.is_ok() {
+      return Ok(resolved);
+    }
+  }
+  // Otherwise a relative replacement would point at a different file from each importer.
+  if aliased.starts_with("./") || aliased.starts_with("../") {
+    return Ok(resolve(&resolver.cwd().join(&*aliased).normalize().to_string_lossy()));
+  }
+  Ok(resolve(&aliased))
 }
 
 fn resolve_id(
@@ -92,9 +113,15 @@ fn resolve_id(
   importer: Option<&str>,
   import_kind: ImportKind,
   is_user_defined_entry: bool,
+  conditions: &[String],
 ) -> Result<ResolvedId, ResolveError> {
-  let resolved =
-    resolver.resolve(importer.map(Path::new), specifier, import_kind, is_user_defined_entry);
+  let resolved = resolver.resolve_with_conditions(
+    importer.map(Path::new),
+    specifier,
+    import_kind,
+    is_user_defined_entry,
+    conditions,
+  );
 
   match resolved {
     Ok(resolved) => Ok(ResolvedId::from(resolved)),
@@ -47,6 +47,7 @@ impl Plugin for AliasPlugin {
           skip_self: true,
           import_kind: args.kind,
           custom: Arc::clone(&args.custom),
+          ..Default::default()
         }),
       )
       .await??;
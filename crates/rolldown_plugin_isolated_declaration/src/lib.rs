@@ -1,25 +1,65 @@
-use std::{borrow::Cow, path::Path};
+use std::{
+  borrow::Cow,
+  path::{Path, PathBuf},
+};
 
 use arcstr::ArcStr;
-use itertools::Itertools as _;
 use oxc::{
-  allocator::IntoIn,
-  ast_visit::VisitMut,
-  codegen::Codegen,
+  ast::ast::Statement,
+  codegen::{Codegen, CodegenOptions},
   isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions},
+  span::Atom,
+};
+use rolldown_common::{ModuleType, Output, OutputAsset, ResolvedExternal, WatcherChangeKind};
+use rolldown_error::BuildDiagnostic;
+use rolldown_plugin::{
+  HookFilter, HookUsage, Plugin, PluginContext, PluginContextResolveOptions, PluginDiagnostic,
+  PluginHookMeta, PluginOrder,
 };
-use rolldown_common::{ModuleType, ResolvedExternal};
-use rolldown_error::{BuildDiagnostic, Severity};
-use rolldown_plugin::{HookUsage, Plugin, PluginHookMeta, PluginOrder};
-use rolldown_utils::stabilize_id::stabilize_id;
+use rolldown_utils::dashmap::{FxDashMap, FxDashSet};
+use rustc_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath;
-use type_import_visitor::TypeImportVisitor;
+use type_imports::{collect_import_sources, collect_type_import_sources, rewrite_import_sources};
 
-mod type_import_visitor;
+mod type_imports;
 
 #[derive(Debug, Default)]
 pub struct IsolatedDeclarationPlugin {
-  pub strip_internal: bool,
+  strip_internal: bool,
+  entries_only: bool,
+  /// Declarations of the transformed modules, keyed by module id. They are kept across builds,
+  /// because incremental builds only transform the changed modules again.
+  declarations: FxDashMap<ArcStr, Declaration>,
+  /// Ids of the type imports loaded in the current build. Barrel files re-export the same modules
+  /// over and over, each of them only needs to be loaded once.
+  loaded_type_imports: FxDashSet<ArcStr>,
+}
+
+#[derive(Debug)]
+struct Declaration {
+  file_name: ArcStr,
+  code: String,
+  is_entry: bool,
+  /// Whether the declaration has a default export, which `export *` doesn't re-export.
+  has_default_export: bool,
+  /// Ids of the modules the declaration imports or re-exports from. Only collected for `entries_only`.
+  referenced_ids: Vec<ArcStr>,
+}
+
+impl IsolatedDeclarationPlugin {
+  /// With `entries_only`, declarations are only emitted for entry modules and for the modules that
+  /// their declarations import or re-export from, transitively. Internal modules that don't show up
+  /// in the public types of an entry are skipped. Each entry chunk also gets a declaration index
+  /// named after it, e.g. `utils.d.ts` for `utils.js`, that re-exports the declaration of its entry
+  /// module, for the `types` of subpath exports.
+  pub fn new(strip_internal: bool, entries_only: bool) -> Self {
+    Self {
+      strip_internal,
+      entries_only,
+      declarations: FxDashMap::default(),
+      loaded_type_imports: FxDashSet::default(),
+    }
+  }
 }
 
 impl Plugin for IsolatedDeclarationPlugin {
@@ -27,60 +67,133 @@ impl Plugin for IsolatedDeclarationPlugin {
     Cow::Borrowed("builtin:isolated-declaration")
   }
 
+  async fn build_start(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    _args: &rolldown_plugin::HookBuildStartArgs<'_>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    // Every build starts with a new module graph.
+    self.loaded_type_imports.clear();
+    Ok(())
+  }
+
+  #[allow(clippy::too_many_lines)]
   async fn transform_ast(
     &self,
     ctx: &rolldown_plugin::PluginContext,
     mut args: rolldown_plugin::HookTransformAstArgs<'_>,
   ) -> rolldown_plugin::HookTransformAstReturn {
-    if matches!(args.module_type, ModuleType::Ts | ModuleType::Tsx) {
-      let type_import_specifiers = args.ast.program.with_mut(|fields| {
-        let mut visitor = TypeImportVisitor { imported: vec![].into_in(fields.allocator) };
-        visitor.visit_program(fields.program);
-        visitor.imported
+    // Declaration files reached through type imports are already declarations.
+    if !is_declaration_file(args.id) {
+      // Spans of the reported diagnostics point into the code the hook received.
+      let source = args.ast.source().clone();
+      let (type_import_specifiers, mut ret) = args.ast.program.with_mut(|fields| {
+        let mut type_import_specifiers = vec![];
+        collect_type_import_sources(&fields.program.body, &mut type_import_specifiers);
+        let ret = IsolatedDeclarations::new(
+          fields.allocator,
+          IsolatedDeclarationsOptions { strip_internal: self.strip_internal },
+        )
+        .build(fields.program);
+        (type_import_specifiers, ret)
       });
 
-      for specifier in type_import_specifiers {
-        let resolved_id = ctx.resolve(&specifier, Some(args.id), None).await??;
+      let file_name = declaration_file_name(args.stable_id);
+      let emitted_declaration = ctx.options().resolved_out_dir().join(file_name.as_str());
+
+      let mut resolved_ids = FxHashMap::default();
+      let mut referenced_project_dirs = None;
+      // Sources of the type imports that point to the declarations of a referenced project.
+      let mut referenced_sources = FxHashMap::default();
+      for (specifier, span) in type_import_specifiers {
+        let resolved_id = match ctx
+          .resolve(
+            &specifier,
+            Some(args.id),
+            Some(PluginContextResolveOptions {
+              conditions: vec!["types".to_string()],
+              ..Default::default()
+            }),
+          )
+          .await?
+        {
+          Ok(resolved_id) => resolved_id,
+          Err(err) => {
+            // Type-only imports are erased from the js output, so a failure here only affects the emitted declaration.
+            return Err(
+              BuildDiagnostic::unresolved_type_import(
+                args.id.to_string(),
+                specifier.to_string(),
+                err.to_string(),
+                source.clone(),
+                span,
+              )
+              .into(),
+            );
+          }
+        };
         if matches!(resolved_id.external, ResolvedExternal::Bool(false)) {
-          ctx.load(&resolved_id.id, None).await?;
+          // Referenced projects are built on their own, their declarations are imported instead
+          // of generated again.
+          let project_dirs = referenced_project_dirs
+            .get_or_insert_with(|| find_referenced_project_dirs(ctx, args.id));
+          if project_dirs.iter().any(|dir| Path::new(resolved_id.id.as_str()).starts_with(dir)) {
+            // Bare specifiers already resolve to the package's declarations.
+            if specifier.starts_with('.') {
+              referenced_sources.insert(
+                specifier,
+                referenced_declaration_source(&resolved_id.id, &emitted_declaration),
+              );
+            }
+            continue;
+          }
+          if self.loaded_type_imports.insert(resolved_id.id.clone()) {
+            ctx.load(&resolved_id.id, None).await?;
+          }
+          resolved_ids.insert(specifier, resolved_id.id);
         }
       }
-
-      let ret = args.ast.program.with_mut(|fields| {
-        IsolatedDeclarations::new(
-          fields.allocator,
-          IsolatedDeclarationsOptions { strip_internal: self.strip_internal },
-        )
-        .build(fields.program)
+      rewrite_import_sources(&mut ret.program.body, &|source| {
+        referenced_sources.get(source).map(|source| Atom::from(source.as_str()))
       });
 
-      if !ret.errors.is_empty() {
-        let errors = BuildDiagnostic::from_oxc_diagnostics(
-          ret.errors,
-          &ArcStr::from(ret.program.source_text),
-          &stabilize_id(args.id, ctx.cwd()),
-          &Severity::Error,
-        )
-        .iter()
-        .map(|error| error.to_diagnostic().with_kind(self.name().into_owned()).to_color_string())
-        .join("\n\n");
-        return Err(anyhow::anyhow!("\n{errors}"));
+      // Only the first error gets a code frame, fixing it usually resolves the ones that follow.
+      if let Some(mut error) = ret.errors.into_iter().next() {
+        let mut diagnostic =
+          PluginDiagnostic::new(error.message.to_string()).with_id(args.id).with_source(source);
+        if let Some(label) = error.labels.take().and_then(|labels| labels.into_iter().next()) {
+          diagnostic = diagnostic
+            .with_span(u32::try_from(label.offset()).unwrap(), u32::try_from(label.len()).unwrap());
+        }
+        if let Some(help) = error.help.take() {
+          diagnostic = diagnostic.with_hint(help.to_string());
+        }
+        return Err(ctx.error(diagnostic));
       }
 
-      let codegen_ret = Codegen::new().build(&ret.program);
-
-      let mut emit_dts_path = Path::new(args.stable_id).to_path_buf();
-      emit_dts_path.set_extension("d.ts");
-      ctx.emit_file(
-        rolldown_common::EmittedAsset {
-          name: None,
-          original_file_name: None,
-          // TODO make sure to the .d.ts file relative to the output entry file
-          file_name: Some(emit_dts_path.to_slash_lossy().into()),
-          source: codegen_ret.code.into(),
+      // Declarations follow `output.compact`, so they stay in the style of the bundled code.
+      let codegen_ret = Codegen::new()
+        .with_options(CodegenOptions { minify: ctx.options().compact, ..CodegenOptions::default() })
+        .build(&ret.program);
+
+      let referenced_ids = if self.entries_only {
+        let mut sources = vec![];
+        collect_import_sources(&ret.program.body, &mut sources);
+        // The declarations of referenced projects aren't emitted by this build.
+        sources.retain(|source| !referenced_sources.values().any(|s| s == source.as_str()));
+        resolve_referenced_ids(ctx, args.id, sources, &resolved_ids).await?
+      } else {
+        vec![]
+      };
+      self.declarations.insert(
+        args.id.into(),
+        Declaration {
+          file_name,
+          code: codegen_ret.code,
+          is_entry: args.is_user_defined_entry,
+          has_default_export: has_default_export(&ret.program.body),
+          referenced_ids,
         },
-        None,
-        None,
       );
     }
     Ok(args.ast)
@@ -88,10 +201,258 @@ impl Plugin for IsolatedDeclarationPlugin {
 
   // The rolldown strip types at the end of the build process, make sure to run this plugin before that.
   fn transform_ast_meta(&self) -> Option<PluginHookMeta> {
-    Some(PluginHookMeta { order: Some(PluginOrder::Post) })
+    Some(PluginHookMeta { order: Some(PluginOrder::Post), ..Default::default() })
+  }
+
+  fn transform_ast_filter(&self) -> Option<HookFilter> {
+    Some(HookFilter {
+      module_type: Some(vec![ModuleType::Ts, ModuleType::Tsx]),
+      ..Default::default()
+    })
+  }
+
+  async fn render_start(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    _args: &rolldown_plugin::HookRenderStartArgs<'_>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    // Modules that are no longer part of the graph may still have a cached declaration.
+    let module_ids = ctx.module_ids()?.collect::<FxHashSet<_>>();
+    let mut emitted_ids = if self.entries_only {
+      let mut reachable = FxHashSet::default();
+      let mut queue = self
+        .declarations
+        .iter()
+        .filter(|declaration| declaration.is_entry)
+        .map(|declaration| declaration.key().clone())
+        .collect::<Vec<_>>();
+      while let Some(id) = queue.pop() {
+        if !module_ids.contains(&id) || !reachable.insert(id.clone()) {
+          continue;
+        }
+        // Modules without a declaration, e.g. `.d.ts` files, don't need one emitted.
+        if let Some(declaration) = self.declarations.get(&id) {
+          queue.extend(declaration.referenced_ids.iter().cloned());
+        }
+      }
+      reachable.into_iter().collect::<Vec<_>>()
+    } else {
+      module_ids.into_iter().collect::<Vec<_>>()
+    };
+    emitted_ids.sort_unstable();
+
+    // Different modules could end up with the same declaration file, e.g. `foo.ts` and `foo.tsx`.
+    // The first one in id order wins, emitting the other one would only conflict with it.
+    let mut emitted_file_names = FxHashSet::default();
+    for id in emitted_ids {
+      if let Some(declaration) = self.declarations.get(&id) {
+        if !emitted_file_names.insert(declaration.file_name.clone()) {
+          continue;
+        }
+        ctx.emit_file(
+          rolldown_common::EmittedAsset {
+            name: None,
+            // Links the declaration to its module, e.g. for `output.manifest`.
+            original_file_name: Some(id.to_string()),
+            file_name: Some(declaration.file_name.clone()),
+            source: declaration.code.clone().into(),
+          },
+          None,
+          None,
+        );
+      }
+    }
+    Ok(())
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &mut rolldown_plugin::HookGenerateBundleArgs<'_>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    if !self.entries_only {
+      return Ok(());
+    }
+    let out_dir = ctx.options().resolved_out_dir();
+    let mut indexes = vec![];
+    for output in args.bundle.iter() {
+      let Output::Chunk(chunk) = output else { continue };
+      let Some(facade_module_id) = chunk.facade_module_id.as_ref().filter(|_| chunk.is_entry)
+      else {
+        continue;
+      };
+      let Some(declaration) = self.declarations.get(facade_module_id.resource_id()) else {
+        continue;
+      };
+      let index_file_name = declaration_index_file_name(&chunk.filename);
+      // The declaration of the entry module is the index already.
+      if index_file_name == declaration.file_name
+        || args.bundle.iter().any(|output| output.filename() == index_file_name.as_str())
+      {
+        continue;
+      }
+      let source = referenced_declaration_source(
+        out_dir.join(declaration.file_name.as_str()).to_slash_lossy().as_ref(),
+        &out_dir.join(index_file_name.as_str()),
+      );
+      let default_export = if declaration.has_default_export {
+        format!("export {{ default }} from \"{source}\";\n")
+      } else {
+        String::new()
+      };
+      let code = format!("export * from \"{source}\";\n{default_export}");
+      indexes.push(OutputAsset {
+        names: vec![],
+        original_file_names: vec![],
+        filename: index_file_name,
+        source: code.into(),
+      });
+    }
+    args.bundle.extend(indexes.into_iter().map(|index| Output::Asset(Box::new(index))));
+    Ok(())
+  }
+
+  async fn watch_change(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    path: &str,
+    _event: WatcherChangeKind,
+  ) -> rolldown_plugin::HookNoopReturn {
+    self.declarations.remove(path);
+    Ok(())
+  }
+
+  async fn close_watcher(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+  ) -> rolldown_plugin::HookNoopReturn {
+    self.declarations.clear();
+    Ok(())
   }
 
   fn register_hook_usage(&self) -> HookUsage {
-    HookUsage::TransformAst
+    HookUsage::BuildStart
+      | HookUsage::TransformAst
+      | HookUsage::RenderStart
+      | HookUsage::GenerateBundle
+      | HookUsage::WatchChange
+      | HookUsage::CloseWatcher
+  }
+}
+
+/// Ids of the modules that `sources`, imported by the declaration of `id`, resolve to.
+async fn resolve_referenced_ids(
+  ctx: &PluginContext,
+  id: &str,
+  sources: Vec<Atom<'_>>,
+  resolved_ids: &FxHashMap<Atom<'_>, ArcStr>,
+) -> anyhow::Result<Vec<ArcStr>> {
+  let mut referenced_ids = vec![];
+  for source in sources {
+    if let Some(id) = resolved_ids.get(&source) {
+      referenced_ids.push(id.clone());
+      continue;
+    }
+    // Value imports were resolved by the bundler already, a failure here means the module
+    // isn't part of the build, so there is no declaration to emit for it either.
+    if let Ok(resolved_id) = ctx.resolve(&source, Some(id), None).await? {
+      if matches!(resolved_id.external, ResolvedExternal::Bool(false)) {
+        referenced_ids.push(resolved_id.id);
+      }
+    }
+  }
+  Ok(referenced_ids)
+}
+
+/// Directories of the projects listed in `references` of the `tsconfig.json` closest to `id`.
+fn find_referenced_project_dirs(ctx: &PluginContext, id: &str) -> Vec<PathBuf> {
+  let Some(tsconfig_path) = Path::new(id)
+    .ancestors()
+    .skip(1)
+    .map(|dir| dir.join("tsconfig.json"))
+    .find(|tsconfig_path| tsconfig_path.is_file())
+  else {
+    return vec![];
+  };
+  // Without a readable tsconfig there are no references to follow.
+  let Ok(tsconfig) = ctx.resolver().resolve_tsconfig(&tsconfig_path) else {
+    return vec![];
+  };
+  tsconfig
+    .references
+    .iter()
+    .filter_map(|reference| Some(reference.tsconfig.as_ref()?.path.parent()?.to_path_buf()))
+    .collect()
+}
+
+/// Name of the declaration emitted for the module with `stable_id`, which is relative to the cwd.
+// TODO make sure to the .d.ts file relative to the output entry file
+fn declaration_file_name(stable_id: &str) -> ArcStr {
+  let mut emit_dts_path = Path::new(stable_id).to_path_buf();
+  emit_dts_path.set_extension("d.ts");
+  ArcStr::from(emit_dts_path.to_slash_lossy())
+}
+
+/// Name of the declaration index of the entry chunk with `chunk_file_name`, e.g. `utils.d.ts` for
+/// `utils.js` and `utils.d.mts` for `utils.mjs`.
+fn declaration_index_file_name(chunk_file_name: &str) -> ArcStr {
+  let path = Path::new(chunk_file_name);
+  let extension = match path.extension().and_then(|ext| ext.to_str()) {
+    Some("mjs") => "d.mts",
+    Some("cjs") => "d.cts",
+    _ => "d.ts",
+  };
+  ArcStr::from(path.with_extension(extension).to_slash_lossy())
+}
+
+/// Whether `body` has a default export.
+fn has_default_export(body: &[Statement<'_>]) -> bool {
+  body.iter().any(|stmt| match stmt {
+    Statement::ExportDefaultDeclaration(_) => true,
+    Statement::ExportNamedDeclaration(decl) => {
+      decl.specifiers.iter().any(|specifier| specifier.exported.name() == "default")
+    }
+    _ => false,
+  })
+}
+
+/// The source, relative to `emitted_declaration`, of the declaration that `tsc --build` writes
+/// for `id` in a referenced project. That is `id` itself for declaration files, and the `.d.ts`
+/// file next to it otherwise.
+fn referenced_declaration_source(id: &str, emitted_declaration: &Path) -> String {
+  let declaration =
+    if is_declaration_file(id) { PathBuf::from(id) } else { Path::new(id).with_extension("d.ts") };
+  let relative =
+    declaration.relative(emitted_declaration.parent().unwrap()).to_slash_lossy().into_owned();
+  let source = [(".d.ts", ""), (".d.mts", ".mjs"), (".d.cts", ".cjs")]
+    .iter()
+    .find_map(|(ext, replacement)| Some(format!("{}{replacement}", relative.strip_suffix(ext)?)))
+    .unwrap_or(relative);
+  if source.starts_with("../") { source } else { format!("./{source}") }
+}
+
+fn is_declaration_file(id: &str) -> bool {
+  [".d.ts", ".d.mts", ".d.cts"].iter().any(|ext| id.ends_with(ext))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use rolldown_utils::stabilize_id::stabilize_id;
+
+  use super::declaration_file_name;
+
+  #[test]
+  fn declarations_of_windows_modules_are_emitted_relative_to_cwd() {
+    let cwd = Path::new(r"C:\work\proj");
+    for id in [r"C:\work\proj\src\a.ts", r"\\?\C:\work\proj\src\a.ts", r"c:/Work/Proj/src/a.ts"] {
+      assert_eq!(declaration_file_name(&stabilize_id(id, cwd)).as_str(), "src/a.d.ts", "{id}");
+    }
+
+    let cwd = Path::new(r"\\server\share\proj");
+    for id in [r"\\server\share\proj\src\a.ts", r"\\?\UNC\server\share\proj\src\a.ts"] {
+      assert_eq!(declaration_file_name(&stabilize_id(id, cwd)).as_str(), "src/a.d.ts", "{id}");
+    }
   }
 }
@@ -0,0 +1,143 @@
+use oxc::{
+  ast::ast::{
+    ImportDeclarationSpecifier, ImportOrExportKind, Statement, TSModuleDeclaration,
+    TSModuleDeclarationBody,
+  },
+  span::{Atom, Span},
+};
+
+/// Collects the sources of type-only imports and re-exports, e.g. `./foo` in
+/// `import type { Foo } from './foo'`, `export { type Foo } from './foo'` or `export type * from './foo'`, with the span of the
+/// source string.
+///
+/// Import and export declarations only appear in statement lists, so this looks at the statements
+/// of the program and of namespaces instead of walking the whole AST, leaving `IsolatedDeclarations`
+/// as the only full traversal.
+pub fn collect_type_import_sources<'ast>(
+  statements: &[Statement<'ast>],
+  sources: &mut Vec<(Atom<'ast>, Span)>,
+) {
+  for stmt in statements {
+    match stmt {
+      Statement::ImportDeclaration(decl) => {
+        let is_type_import = match decl.import_kind {
+          ImportOrExportKind::Type => true,
+          ImportOrExportKind::Value => decl.specifiers.iter().flatten().any(|specifier| {
+            matches!(
+              specifier,
+              ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                if specifier.import_kind == ImportOrExportKind::Type
+            )
+          }),
+        };
+        if is_type_import {
+          sources.push((decl.source.value, decl.source.span));
+        }
+      }
+      Statement::ExportNamedDeclaration(decl) => {
+        let Some(source) = &decl.source else {
+          continue;
+        };
+        let is_type_export = match decl.export_kind {
+          ImportOrExportKind::Type => true,
+          ImportOrExportKind::Value => decl
+            .specifiers
+            .iter()
+            .any(|specifier| specifier.export_kind == ImportOrExportKind::Type),
+        };
+        if is_type_export {
+          sources.push((source.value, source.span));
+        }
+      }
+      // `export type * from './foo'`, typical for barrel files of types.
+      Statement::ExportAllDeclaration(decl) if decl.export_kind == ImportOrExportKind::Type => {
+        sources.push((decl.source.value, decl.source.span));
+      }
+      Statement::TSModuleDeclaration(decl) => {
+        if let Some(statements) = module_block_statements(decl) {
+          collect_type_import_sources(statements, sources);
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Collects the sources of all imports and re-exports, type-only or not. Used on the generated
+/// declarations, where the remaining imports are exactly the modules the public types refer to.
+pub fn collect_import_sources<'ast>(statements: &[Statement<'ast>], sources: &mut Vec<Atom<'ast>>) {
+  for stmt in statements {
+    match stmt {
+      Statement::ImportDeclaration(decl) => sources.push(decl.source.value),
+      Statement::ExportNamedDeclaration(decl) => {
+        if let Some(source) = &decl.source {
+          sources.push(source.value);
+        }
+      }
+      Statement::ExportAllDeclaration(decl) => sources.push(decl.source.value),
+      Statement::TSModuleDeclaration(decl) => {
+        if let Some(statements) = module_block_statements(decl) {
+          collect_import_sources(statements, sources);
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Replaces the sources of the imports and re-exports that `rewrite` returns a new source for.
+pub fn rewrite_import_sources<'ast>(
+  statements: &mut [Statement<'ast>],
+  rewrite: &impl Fn(&str) -> Option<Atom<'ast>>,
+) {
+  for stmt in statements {
+    let source = match stmt {
+      Statement::ImportDeclaration(decl) => &mut decl.source,
+      Statement::ExportNamedDeclaration(decl) => {
+        let Some(source) = &mut decl.source else {
+          continue;
+        };
+        source
+      }
+      Statement::ExportAllDeclaration(decl) => &mut decl.source,
+      Statement::TSModuleDeclaration(decl) => {
+        if let Some(statements) = module_block_statements_mut(decl) {
+          rewrite_import_sources(statements, rewrite);
+        }
+        continue;
+      }
+      _ => continue,
+    };
+    if let Some(value) = rewrite(&source.value) {
+      source.value = value;
+      source.raw = None;
+    }
+  }
+}
+
+fn module_block_statements<'a, 'ast>(
+  decl: &'a TSModuleDeclaration<'ast>,
+) -> Option<&'a [Statement<'ast>]> {
+  let mut body = decl.body.as_ref();
+  // `namespace a.b {}` nests a declaration per segment.
+  while let Some(TSModuleDeclarationBody::TSModuleDeclaration(inner)) = body {
+    body = inner.body.as_ref();
+  }
+  match body {
+    Some(TSModuleDeclarationBody::TSModuleBlock(block)) => Some(&block.body),
+    _ => None,
+  }
+}
+
+fn module_block_statements_mut<'a, 'ast>(
+  decl: &'a mut TSModuleDeclaration<'ast>,
+) -> Option<&'a mut [Statement<'ast>]> {
+  let mut body = decl.body.as_mut();
+  while let Some(TSModuleDeclarationBody::TSModuleDeclaration(inner)) = body {
+    body = inner.body.as_mut();
+  }
+  match body {
+    Some(TSModuleDeclarationBody::TSModuleBlock(block)) => Some(&mut block.body),
+    _ => None,
+  }
+}
@@ -0,0 +1,2 @@
+export * from './index';
+export type * as Types from './types';
@@ -0,0 +1,2 @@
+export type * from './a';
+export type { B } from './b';
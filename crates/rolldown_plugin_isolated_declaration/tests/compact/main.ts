@@ -0,0 +1,8 @@
+export interface Options {
+  name: string;
+  retries?: number;
+}
+
+export function run(options: Options): Promise<void> {
+  return Promise.resolve();
+}
@@ -0,0 +1,6 @@
+import type { Color } from './color';
+
+export interface Button {
+  label: string;
+  color: Color;
+}
@@ -0,0 +1 @@
+export type Color = 'red' | 'blue';
@@ -0,0 +1,5 @@
+import { clamp } from './math';
+
+export function formatLabel(label: string): string {
+  return label.slice(0, clamp(label.length));
+}
@@ -0,0 +1,3 @@
+export function clamp(length: number): number {
+  return Math.min(length, 10);
+}
@@ -0,0 +1,9 @@
+import type { Button } from './button';
+import { formatLabel } from './internal/format';
+
+export { Button };
+export * from './theme';
+
+export function render(button: Button): string {
+  return formatLabel(button.label);
+}
@@ -0,0 +1,11 @@
+import { clone } from './utils/clone';
+
+export interface Store<T> {
+  get(): T;
+}
+
+export function createStore<T>(value: T): Store<T> {
+  return { get: () => clone(value) };
+}
+
+export default createStore;
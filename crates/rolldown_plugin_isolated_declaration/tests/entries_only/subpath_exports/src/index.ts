@@ -0,0 +1,3 @@
+export { createStore } from './core';
+export type { Store } from './core';
+export * from './utils';
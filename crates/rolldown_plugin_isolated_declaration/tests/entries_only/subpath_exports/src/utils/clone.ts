@@ -0,0 +1,3 @@
+export function clone<T>(value: T): T {
+  return structuredClone(value);
+}
@@ -0,0 +1 @@
+export { clone } from './clone';
@@ -6,7 +6,10 @@ use testing_macros::fixture;
 #[allow(clippy::needless_pass_by_value)]
 #[fixture("./tests/**/_config.json")]
 fn fixture_with_config(config_path: PathBuf) {
-  Fixture::new(config_path.parent().unwrap()).run_integration_test_with_plugins(vec![Arc::new(
-    IsolatedDeclarationPlugin { strip_internal: true },
+  let fixture_path = config_path.parent().unwrap();
+  // Fixtures under `entries_only/` run the plugin in that mode.
+  let entries_only = fixture_path.components().any(|c| c.as_os_str() == "entries_only");
+  Fixture::new(fixture_path).run_integration_test_with_plugins(vec![Arc::new(
+    IsolatedDeclarationPlugin::new(true, entries_only),
   )]);
 }
@@ -0,0 +1,6 @@
+import type { ButtonProps } from '../../ui/src/button';
+import type { Theme } from './theme';
+
+export function renderButton(props: ButtonProps, theme: Theme): string {
+  return `${props.label}:${theme.primary}`;
+}
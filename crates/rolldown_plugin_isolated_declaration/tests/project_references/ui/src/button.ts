@@ -0,0 +1,4 @@
+export interface ButtonProps {
+  label: string;
+  disabled?: boolean;
+}
@@ -0,0 +1,3 @@
+import type { Options } from 'pkg';
+
+export type { Options };
@@ -0,0 +1,5 @@
+import type { Foo } from './missing'
+
+export function foo(): Foo {
+  return {} as Foo
+}
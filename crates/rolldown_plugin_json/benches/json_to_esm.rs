@@ -0,0 +1,24 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use rolldown_plugin_json::__inner::json_to_esm;
+
+fn bench_json_to_esm(c: &mut Criterion) {
+  let mut group = c.benchmark_group("json_to_esm");
+  // A large object with small values, each key turns into a named export.
+  let many_keys = (0..50_000)
+    .map(|i| (format!("key{i}"), serde_json::json!({ "id": i, "name": format!("item {i}") })))
+    .collect::<serde_json::Map<_, _>>();
+  let many_keys = serde_json::Value::Object(many_keys);
+  let many_keys_source = serde_json::to_string_pretty(&many_keys).unwrap();
+
+  group.bench_function("named_exports_many_keys", |b| {
+    b.iter(|| black_box(json_to_esm(&many_keys, true, many_keys_source.len())));
+  });
+  group.bench_function("default_export_many_keys", |b| {
+    b.iter(|| black_box(json_to_esm(&many_keys, false, many_keys_source.len())));
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, bench_json_to_esm);
+criterion_main!(benches);
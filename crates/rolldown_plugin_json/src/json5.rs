@@ -0,0 +1,119 @@
+use anyhow::Context;
+use oxc::{
+  allocator::Allocator,
+  ast::ast::{ArrayExpressionElement, Expression, ObjectPropertyKind, PropertyKey, PropertyKind},
+  parser::Parser,
+  span::SourceType,
+  syntax::operator::UnaryOperator,
+};
+use serde_json::{Map, Value};
+
+/// JSON5 is a subset of the ECMAScript expression syntax, so the source is parsed as an expression
+/// and converted to a JSON value.
+pub fn parse_json5(source: &str) -> anyhow::Result<Value> {
+  let allocator = Allocator::default();
+  let expr = Parser::new(&allocator, source, SourceType::default()).parse_expression().map_err(
+    |errors| {
+      let messages = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+      anyhow::anyhow!("Invalid JSON5: {}", messages.join("\n"))
+    },
+  )?;
+  expr_to_value(&expr)
+}
+
+fn expr_to_value(expr: &Expression) -> anyhow::Result<Value> {
+  Ok(match expr {
+    Expression::NullLiteral(_) => Value::Null,
+    Expression::BooleanLiteral(lit) => Value::Bool(lit.value),
+    Expression::StringLiteral(lit) => Value::String(lit.value.to_string()),
+    Expression::NumericLiteral(lit) => number_to_value(lit.value)?,
+    Expression::UnaryExpression(unary) => match (&unary.operator, &unary.argument) {
+      (UnaryOperator::UnaryNegation, Expression::NumericLiteral(lit)) => {
+        number_to_value(-lit.value)?
+      }
+      (UnaryOperator::UnaryPlus, Expression::NumericLiteral(lit)) => number_to_value(lit.value)?,
+      _ => anyhow::bail!("Invalid JSON5: unexpected unary expression"),
+    },
+    Expression::ArrayExpression(array) => Value::Array(
+      array
+        .elements
+        .iter()
+        .map(|element| match element {
+          ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_) => {
+            anyhow::bail!("Invalid JSON5: array elements should be values")
+          }
+          _ => expr_to_value(element.to_expression()),
+        })
+        .collect::<anyhow::Result<_>>()?,
+    ),
+    Expression::ObjectExpression(object) => {
+      let mut map = Map::with_capacity(object.properties.len());
+      for property in &object.properties {
+        let ObjectPropertyKind::ObjectProperty(property) = property else {
+          anyhow::bail!("Invalid JSON5: spread properties are not allowed");
+        };
+        if property.kind != PropertyKind::Init
+          || property.method
+          || property.shorthand
+          || property.computed
+        {
+          anyhow::bail!("Invalid JSON5: object members should be `key: value` pairs");
+        }
+        let key = match &property.key {
+          PropertyKey::StaticIdentifier(ident) => ident.name.to_string(),
+          PropertyKey::StringLiteral(lit) => lit.value.to_string(),
+          _ => anyhow::bail!("Invalid JSON5: object keys should be identifiers or strings"),
+        };
+        map.insert(key, expr_to_value(&property.value)?);
+      }
+      Value::Object(map)
+    }
+    Expression::Identifier(ident) if matches!(ident.name.as_str(), "Infinity" | "NaN") => {
+      anyhow::bail!("Invalid JSON5: `{}` can't be represented in JSON", ident.name)
+    }
+    _ => anyhow::bail!("Invalid JSON5: unexpected expression"),
+  })
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn number_to_value(value: f64) -> anyhow::Result<Value> {
+  // Keep integers as integers, so `1` isn't printed as `1.0`.
+  if value.fract() == 0.0 && value.abs() < 9_007_199_254_740_992.0 {
+    return Ok(Value::from(value as i64));
+  }
+  serde_json::Number::from_f64(value)
+    .map(Value::Number)
+    .with_context(|| format!("Invalid JSON5: `{value}` can't be represented in JSON"))
+}
+
+#[cfg(test)]
+mod test {
+  use super::parse_json5;
+
+  #[test]
+  fn json5_syntax() {
+    let value = parse_json5(
+      "// comment\n{ unquoted: 'single', \"quoted\": [1, -2.5, +3, 0x10,], /* trailing */ nested: { ok: true, none: null }, }",
+    )
+    .unwrap();
+    assert_eq!(
+      value,
+      serde_json::json!({
+        "unquoted": "single",
+        "quoted": [1, -2.5, 3, 16],
+        "nested": { "ok": true, "none": null }
+      })
+    );
+    assert_eq!(
+      serde_json::to_string(&value).unwrap(),
+      r#"{"unquoted":"single","quoted":[1,-2.5,3,16],"nested":{"ok":true,"none":null}}"#
+    );
+  }
+
+  #[test]
+  fn json5_rejects_non_json_values() {
+    assert!(parse_json5("{ a: b }").is_err());
+    assert!(parse_json5("[Infinity]").is_err());
+    assert!(parse_json5("{ ...a }").is_err());
+  }
+}
@@ -1,5 +1,11 @@
+mod json5;
 mod utils;
 
+#[doc(hidden)]
+pub mod __inner {
+  pub use super::utils::json_to_esm;
+}
+
 use std::borrow::Cow;
 
 use rolldown_common::ModuleType;
@@ -8,11 +14,16 @@ use rolldown_sourcemap::SourceMap;
 use rolldown_utils::concat_string;
 use serde_json::Value;
 
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Default)]
 pub struct JsonPlugin {
   pub minify: bool,
   pub named_exports: bool,
   pub stringify: JsonPluginStringify,
+  /// Also load `.json5` files, which allow comments, trailing commas and unquoted keys.
+  pub json5: bool,
+  /// Also load `.jsonl` and `.ndjson` files, exporting one array item per line.
+  pub json_lines: bool,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -23,6 +34,38 @@ pub enum JsonPluginStringify {
   False,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonFormat {
+  Json,
+  Json5,
+  JsonLines,
+}
+
+impl JsonPlugin {
+  fn json_format(&self, id: &str, module_type: &ModuleType) -> Option<JsonFormat> {
+    if utils::is_special_query(id) {
+      return None;
+    }
+    if *module_type == ModuleType::Json && utils::is_json_ext(id) {
+      return Some(JsonFormat::Json);
+    }
+    // Unknown extensions are loaded as `js`, so these formats don't need a `moduleTypes` entry.
+    if !matches!(module_type, ModuleType::Json | ModuleType::Js) {
+      return None;
+    }
+    if self.json5 && utils::is_json5_ext(id) {
+      Some(JsonFormat::Json5)
+    } else if self.json_lines && utils::is_json_lines_ext(id) {
+      Some(JsonFormat::JsonLines)
+    } else if *module_type == ModuleType::Json && !utils::is_commonjs_query(id) {
+      // Other extensions are JSON modules through `with { type: 'json' }`.
+      Some(JsonFormat::Json)
+    } else {
+      None
+    }
+  }
+}
+
 impl Plugin for JsonPlugin {
   fn name(&self) -> Cow<'static, str> {
     Cow::Borrowed("builtin:json")
@@ -33,14 +76,24 @@ impl Plugin for JsonPlugin {
     _ctx: rolldown_plugin::SharedTransformPluginContext,
     args: &rolldown_plugin::HookTransformArgs<'_>,
   ) -> rolldown_plugin::HookTransformReturn {
-    if *args.module_type != ModuleType::Json
-      || !utils::is_json_ext(args.id)
-      || utils::is_special_query(args.id)
-    {
+    let Some(format) = self.json_format(args.id, args.module_type) else {
       return Ok(None);
-    }
+    };
 
     let code = utils::strip_bom(args.code);
+    // Other formats are normalized to JSON, so they share the output logic below.
+    let normalized;
+    let code = match format {
+      JsonFormat::Json => code,
+      JsonFormat::Json5 => {
+        normalized = serde_json::to_string(&json5::parse_json5(code)?)?;
+        normalized.as_str()
+      }
+      JsonFormat::JsonLines => {
+        normalized = serde_json::to_string(&utils::parse_json_lines(code)?)?;
+        normalized.as_str()
+      }
+    };
 
     let is_name_exports = self.named_exports && code.trim_start().starts_with('{');
     let is_stringify = self.stringify != JsonPluginStringify::False
@@ -67,7 +120,7 @@ impl Plugin for JsonPlugin {
     }
 
     let value = serde_json::from_str(code)?;
-    let code = utils::json_to_esm(&value, self.named_exports);
+    let code = utils::json_to_esm(&value, self.named_exports, code.len());
     Ok(Some(HookTransformOutput {
       code: Some(code),
       map: Some(SourceMap::default()),
@@ -1,5 +1,6 @@
 use std::fmt::Write as _;
 
+use anyhow::Context;
 use rolldown_utils::concat_string;
 use serde_json::Value;
 
@@ -8,15 +9,33 @@ use serde_json::Value;
 pub const THRESHOLD_SIZE: usize = 10 * 1000;
 
 /// /\.json(?:$|\?)(?!commonjs-(?:proxy|external))/
+pub fn is_json_ext(id: &str) -> bool {
+  has_ext(id, ".json")
+}
+
+pub fn is_json5_ext(id: &str) -> bool {
+  has_ext(id, ".json5")
+}
+
+pub fn is_json_lines_ext(id: &str) -> bool {
+  has_ext(id, ".jsonl") || has_ext(id, ".ndjson")
+}
+
+/// /\?commonjs-(?:proxy|external)$/
+pub fn is_commonjs_query(id: &str) -> bool {
+  id.ends_with("?commonjs-proxy") || id.ends_with("?commonjs-external")
+}
+
 #[allow(clippy::case_sensitive_file_extension_comparisons)]
-pub fn is_json_ext(ext: &str) -> bool {
-  if ext.ends_with(".json") {
+fn has_ext(id: &str, ext: &str) -> bool {
+  if id.ends_with(ext) {
     return true;
   }
-  let Some(i) = memchr::memmem::rfind(ext.as_bytes(), b".json?") else {
+  let ext_with_query = concat_string!(ext, "?");
+  let Some(i) = memchr::memmem::rfind(id.as_bytes(), ext_with_query.as_bytes()) else {
     return false;
   };
-  let postfix = &ext[i + 6..];
+  let postfix = &id[i + ext_with_query.len()..];
   postfix != "commonjs-proxy" && postfix != "commonjs-external"
 }
 
@@ -49,6 +68,19 @@ pub fn is_special_query(ext: &str) -> bool {
   false
 }
 
+/// Parses JSON Lines (also known as NDJSON) into an array with one item per non-empty line.
+pub fn parse_json_lines(code: &str) -> anyhow::Result<Value> {
+  code
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(i, line)| {
+      serde_json::from_str(line).with_context(|| format!("Invalid JSON Lines: line {}", i + 1))
+    })
+    .collect::<anyhow::Result<_>>()
+    .map(Value::Array)
+}
+
 #[inline]
 pub fn strip_bom(code: &str) -> &str {
   code.strip_prefix("\u{FEFF}").unwrap_or(code)
@@ -65,7 +97,10 @@ fn serialize_value(value: &Value) -> Result<String, serde_json::Error> {
   }
 }
 
-pub fn json_to_esm(data: &Value, named_exports: bool) -> String {
+/// `source_len` is the length of the JSON text `data` was parsed from. The values are serialized
+/// again into the output, so it's used to size the buffers upfront instead of growing them once per
+/// key for large objects.
+pub fn json_to_esm(data: &Value, named_exports: bool, source_len: usize) -> String {
   if !named_exports || !data.is_object() {
     return concat_string!("export default ", data.to_string(), ";\n");
   }
@@ -75,8 +110,17 @@ pub fn json_to_esm(data: &Value, named_exports: bool) -> String {
     return "export default {};\n".to_string();
   }
 
-  let mut named_export_code = String::new();
-  let mut default_object_code = String::new();
+  let keys_len = data.keys().map(String::len).sum::<usize>();
+  let default_object_capacity = keys_len + data.len() * "  ,\n".len();
+  // `export const {key} = {value};\n` for every key, followed by the default export.
+  let mut named_export_code = String::with_capacity(
+    source_len
+      + keys_len
+      + data.len() * "export const  = ;\n".len()
+      + "export default {\n\n};".len()
+      + default_object_capacity,
+  );
+  let mut default_object_code = String::with_capacity(default_object_capacity);
   for (key, value) in data {
     let value = serialize_value(value).expect("Invalid JSON value");
     if rolldown_utils::ecmascript::is_validate_assignee_identifier_name(key) {
@@ -91,12 +135,18 @@ pub fn json_to_esm(data: &Value, named_exports: bool) -> String {
   // Remove the trailing ",\n"
   default_object_code.truncate(default_object_code.len() - 2);
 
-  concat_string!(named_export_code, "export default {\n", default_object_code, "\n};")
+  // Reuses the capacity reserved for the default export instead of allocating the output again.
+  named_export_code.push_str("export default {\n");
+  named_export_code.push_str(&default_object_code);
+  named_export_code.push_str("\n};");
+  named_export_code
 }
 
 #[cfg(test)]
 mod test {
-  use crate::utils::{is_json_ext, is_special_query, json_to_esm};
+  use crate::utils::{
+    is_json_ext, is_json_lines_ext, is_json5_ext, is_special_query, json_to_esm, parse_json_lines,
+  };
 
   #[test]
   fn json_ext() {
@@ -107,6 +157,20 @@ mod test {
 
     assert!(!is_json_ext("test.json?commonjs-proxy"));
     assert!(!is_json_ext("test.json?commonjs-external"));
+
+    assert!(!is_json_ext("test.json5"));
+    assert!(is_json5_ext("test.json5?test=test"));
+    assert!(is_json_lines_ext("test.jsonl"));
+    assert!(is_json_lines_ext("test.ndjson"));
+  }
+
+  #[test]
+  fn json_lines() {
+    let value = parse_json_lines("{\"a\":1}\r\n\n[2]\n").unwrap();
+    assert_eq!(value, serde_json::json!([{"a": 1}, [2]]));
+
+    let err = parse_json_lines("{}\n{\n").unwrap_err();
+    assert_eq!(err.to_string(), "Invalid JSON Lines: line 2");
   }
 
   #[test]
@@ -124,14 +188,14 @@ mod test {
     let data = serde_json::json!({"name": "name"});
     assert_eq!(
       "export const name = \"name\";\nexport default {\n  name\n};",
-      json_to_esm(&data, true)
+      json_to_esm(&data, true, data.to_string().len())
     );
   }
 
   #[test]
   fn to_esm_named_exports_literal() {
     let data = serde_json::json!(1);
-    assert_eq!("export default 1;\n", json_to_esm(&data, true));
+    assert_eq!("export default 1;\n", json_to_esm(&data, true, data.to_string().len()));
   }
 
   #[test]
@@ -139,16 +203,38 @@ mod test {
     let data = serde_json::json!({"true": true, "\\\"\n": 1234});
     assert_eq!(
       "export default {\n  \"true\": true,\n  \"\\\\\\\"\\n\": 1234\n};",
-      json_to_esm(&data, true)
+      json_to_esm(&data, true, data.to_string().len())
     );
   }
 
+  #[test]
+  fn to_esm_named_exports_large_object() {
+    let data = (0..1000)
+      .map(|i| (format!("key{i}"), serde_json::json!({"value": i})))
+      .collect::<serde_json::Map<_, _>>();
+    let named_exports =
+      (0..1000).map(|i| format!("export const key{i} = {{\"value\":{i}}};")).collect::<Vec<_>>();
+    let default_object = (0..1000).map(|i| format!("  key{i}")).collect::<Vec<_>>();
+    let data = serde_json::Value::Object(data);
+    // The source length is only a size hint and doesn't change the output.
+    for source_len in [0, data.to_string().len(), usize::from(u16::MAX)] {
+      assert_eq!(
+        format!(
+          "{}\nexport default {{\n{}\n}};",
+          named_exports.join("\n"),
+          default_object.join(",\n")
+        ),
+        json_to_esm(&data, true, source_len)
+      );
+    }
+  }
+
   #[test]
   fn to_esm_named_exports_multiple_fields() {
     let data = serde_json::json!({"foo": "foo", "bar": "bar"});
     assert_eq!(
       "export const foo = \"foo\";\nexport const bar = \"bar\";\nexport default {\n  foo,\n  bar\n};",
-      json_to_esm(&data, true)
+      json_to_esm(&data, true, data.to_string().len())
     );
   }
 }
@@ -0,0 +1,3 @@
+import config, { name, plugins } from './config.json5'
+
+console.log(config, name, plugins)
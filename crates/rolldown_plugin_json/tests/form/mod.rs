@@ -0,0 +1,3 @@
+mod json5_named_exports;
+mod ndjson;
+mod with_type_json;
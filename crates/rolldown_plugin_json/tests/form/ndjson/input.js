@@ -0,0 +1,3 @@
+import events from './events.ndjson'
+
+console.log(events.length, events[0].type)
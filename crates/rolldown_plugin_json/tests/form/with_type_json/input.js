@@ -0,0 +1,3 @@
+import settings from './settings.data' with { type: 'json' }
+
+console.log(settings)
@@ -269,7 +269,10 @@ impl Plugin for ModuleFederationPlugin {
 
   // Make sure the plugin resolve_id hook called at first, avoid vite resolver resolved the shared module.
   fn resolve_id_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
-    Some(rolldown_plugin::PluginHookMeta { order: Some(rolldown_plugin::PluginOrder::Pre) })
+    Some(rolldown_plugin::PluginHookMeta {
+      order: Some(rolldown_plugin::PluginOrder::Pre),
+      ..Default::default()
+    })
   }
 
   async fn resolve_id(
@@ -26,6 +26,7 @@ impl Plugin for OxcRuntimePlugin {
             skip_self: true,
             import_kind: args.kind,
             custom: Arc::clone(&args.custom),
+            ..Default::default()
           }),
         )
         .await??;
@@ -35,6 +36,7 @@ impl Plugin for OxcRuntimePlugin {
         external: Some(resolved_id.external),
         side_effects: resolved_id.side_effects,
         normalize_external_id: resolved_id.normalize_external_id,
+        ..Default::default()
       }));
     }
 
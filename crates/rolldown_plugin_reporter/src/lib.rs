@@ -260,8 +260,7 @@ impl Plugin for ReporterPlugin {
       let map_pad = utils::display_size(biggest_map_size).len();
       let compress_pad = utils::display_size(biggest_compress_size).len();
 
-      let out_dir =
-        args.options.cwd.join(&args.options.out_dir).normalize().relative(&args.options.cwd);
+      let out_dir = args.options.resolved_out_dir().relative(&args.options.cwd);
       let out_dir = out_dir.to_slash_lossy();
 
       for group in utils::GROUPS {
@@ -5,8 +5,8 @@ use std::borrow::Cow;
 use std::path::Path;
 
 use arcstr::ArcStr;
-use itertools::Itertools;
 use oxc::codegen::{Codegen, CodegenOptions, CodegenReturn};
+use oxc::diagnostics::OxcDiagnostic;
 use oxc::parser::Parser;
 use oxc::semantic::SemanticBuilder;
 use oxc::transformer::Transformer;
@@ -57,16 +57,7 @@ impl Plugin for TransformPlugin {
     let allocator = oxc::allocator::Allocator::default();
     let ret = Parser::new(&allocator, args.code, source_type).parse();
     if ret.panicked || !ret.errors.is_empty() {
-      let errors = BuildDiagnostic::from_oxc_diagnostics(
-        ret.errors,
-        &ArcStr::from(args.code.as_str()),
-        &stabilize_id(args.id, ctx.inner.cwd()),
-        &Severity::Error,
-      )
-      .iter()
-      .map(|error| error.to_diagnostic().with_kind(self.name().into_owned()).to_color_string())
-      .join("\n\n");
-      Err(anyhow::anyhow!("\n{errors}"))?;
+      Err(first_error(ret.errors, args.code, &stabilize_id(args.id, ctx.inner.cwd())))?;
     }
 
     let mut program = ret.program;
@@ -75,16 +66,11 @@ impl Plugin for TransformPlugin {
 
     let transformer_return = transformer.build_with_scoping(scoping, &mut program);
     if !transformer_return.errors.is_empty() {
-      let errors = BuildDiagnostic::from_oxc_diagnostics(
+      Err(first_error(
         transformer_return.errors,
-        &ArcStr::from(args.code.as_str()),
+        args.code,
         &stabilize_id(args.id, ctx.inner.cwd()),
-        &Severity::Error,
-      )
-      .iter()
-      .map(|error| error.to_diagnostic().with_kind(self.name().into_owned()).to_color_string())
-      .join("\n\n");
-      Err(anyhow::anyhow!("\n{errors}"))?;
+      ))?;
     }
 
     let ret = Codegen::new()
@@ -116,3 +102,12 @@ impl Plugin for TransformPlugin {
     HookUsage::Transform
   }
 }
+
+/// Only the first error is reported, fixing it usually resolves the ones that follow. The plugin
+/// driver attaches the plugin and the hook to it.
+fn first_error(errors: Vec<OxcDiagnostic>, code: &str, stable_id: &str) -> anyhow::Error {
+  BuildDiagnostic::from_oxc_diagnostics(errors, &ArcStr::from(code), stable_id, &Severity::Error)
+    .into_iter()
+    .next()
+    .map_or_else(|| anyhow::anyhow!("Failed to parse {stable_id}"), Into::into)
+}
@@ -106,7 +106,7 @@ impl TransformPlugin {
         // Tsconfig could be out of root, make sure it is watched
         let tsconfig_path = tsconfig.path.to_string_lossy();
         if !tsconfig_path.starts_with(cwd) {
-          ctx.inner.add_watch_file(&tsconfig_path);
+          ctx.add_watch_file_dependency(&tsconfig_path);
         }
 
         let compiler_options = &tsconfig.compiler_options;
@@ -0,0 +1,81 @@
+use arcstr::ArcStr;
+use oxc::{
+  ast::{
+    AstBuilder,
+    ast::{Argument, Expression, NewExpression},
+  },
+  ast_visit::{Visit, VisitMut, walk, walk_mut},
+  span::SPAN,
+};
+use rolldown_ecmascript_utils::ExpressionExt as _;
+use rolldown_utils::concat_string;
+
+/// The specifier of the worker in `new Worker(new URL('<specifier>', import.meta.url))` or
+/// `new SharedWorker(...)`.
+fn worker_url_specifier<'a>(expr: &NewExpression<'a>) -> Option<&'a str> {
+  let Expression::Identifier(callee) = &expr.callee else { return None };
+  if !matches!(callee.name.as_str(), "Worker" | "SharedWorker") {
+    return None;
+  }
+  let Some(Argument::NewExpression(url)) = expr.arguments.first() else { return None };
+  let Expression::Identifier(url_callee) = &url.callee else { return None };
+  if url_callee.name != "URL" || url.arguments.len() != 2 {
+    return None;
+  }
+  let (Argument::StringLiteral(specifier), Some(base)) =
+    (&url.arguments[0], url.arguments[1].as_expression())
+  else {
+    return None;
+  };
+  // Data and remote URLs aren't bundled.
+  let is_relative = specifier.value.starts_with("./") || specifier.value.starts_with("../");
+  (is_relative && base.is_import_meta_url()).then_some(specifier.value.as_str())
+}
+
+/// Collects the specifiers of the workers, in the order [`WorkerUrlRewrite`] visits them.
+pub struct WorkerUrlCollect {
+  pub specifiers: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for WorkerUrlCollect {
+  fn visit_new_expression(&mut self, it: &NewExpression<'ast>) {
+    if let Some(specifier) = worker_url_specifier(it) {
+      self.specifiers.push(specifier.to_string());
+    }
+    walk::walk_new_expression(self, it);
+  }
+}
+
+/// Replaces the URL of each worker with `import.meta.ROLLUP_FILE_URL_<reference id>`, which
+/// becomes the URL of the emitted worker chunk.
+pub struct WorkerUrlRewrite<'ast> {
+  pub ast_builder: AstBuilder<'ast>,
+  /// The reference id of each collected worker, or `None` if it isn't bundled.
+  pub reference_ids: Vec<Option<ArcStr>>,
+  pub current: usize,
+}
+
+impl<'ast> VisitMut<'ast> for WorkerUrlRewrite<'ast> {
+  fn visit_new_expression(&mut self, it: &mut NewExpression<'ast>) {
+    if worker_url_specifier(it).is_some() {
+      let index = self.current;
+      self.current += 1;
+      if let Some(Some(reference_id)) = self.reference_ids.get(index) {
+        let import_meta = self.ast_builder.expression_meta_property(
+          SPAN,
+          self.ast_builder.identifier_name(SPAN, "import"),
+          self.ast_builder.identifier_name(SPAN, "meta"),
+        );
+        let property = self.ast_builder.atom(&concat_string!("ROLLUP_FILE_URL_", reference_id));
+        it.arguments[0] =
+          Argument::StaticMemberExpression(self.ast_builder.alloc_static_member_expression(
+            SPAN,
+            import_meta,
+            self.ast_builder.identifier_name(SPAN, property),
+            false,
+          ));
+      }
+    }
+    walk_mut::walk_new_expression(self, it);
+  }
+}
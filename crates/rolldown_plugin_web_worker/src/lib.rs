@@ -0,0 +1,152 @@
+mod ast_visit;
+
+use std::borrow::Cow;
+
+use arcstr::ArcStr;
+use oxc::{
+  ast::AstBuilder,
+  ast_visit::{Visit, VisitMut},
+};
+use rolldown_common::{EmittedChunk, OutputFormat};
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookNoopReturn, HookTransformAstArgs,
+  HookTransformAstReturn, HookUsage, Plugin, PluginContext,
+};
+use rolldown_utils::{concat_string, dashmap::FxDashMap, futures::block_on_spawn_all};
+
+use ast_visit::{WorkerUrlCollect, WorkerUrlRewrite};
+
+/// Bundles the Web Workers of a module as entry chunks of their own.
+///
+/// - `new Worker(new URL('./worker.js', import.meta.url))`, and the same for `SharedWorker`, get the
+///   URL of the emitted worker chunk.
+/// - `import MyWorker from './worker.js?worker'` gives a function which starts the worker, and
+///   `?sharedworker` one which starts a `SharedWorker`.
+#[derive(Debug, Default)]
+pub struct WebWorkerPlugin {
+  /// Reference ids of the emitted worker chunks by worker id, so each worker is bundled once.
+  worker_chunks: FxDashMap<String, ArcStr>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum WorkerKind {
+  Worker,
+  SharedWorker,
+}
+
+impl WorkerKind {
+  /// The kind of worker a `?worker` or `?sharedworker` query asks for, and the id without it.
+  fn from_query(id: &str) -> Option<(&str, Self)> {
+    let (path, query) = id.split_once('?')?;
+    query.split('&').find_map(|param| match param {
+      "worker" => Some((path, Self::Worker)),
+      "sharedworker" => Some((path, Self::SharedWorker)),
+      _ => None,
+    })
+  }
+
+  fn constructor(self) -> &'static str {
+    match self {
+      Self::Worker => "Worker",
+      Self::SharedWorker => "SharedWorker",
+    }
+  }
+}
+
+impl WebWorkerPlugin {
+  async fn emit_worker_chunk(
+    &self,
+    ctx: &PluginContext,
+    id: &str,
+    importer: &str,
+  ) -> anyhow::Result<ArcStr> {
+    if let Some(reference_id) = self.worker_chunks.get(id) {
+      return Ok(reference_id.clone());
+    }
+    let reference_id = ctx
+      .emit_chunk(EmittedChunk {
+        id: id.to_string(),
+        importer: Some(importer.to_string()),
+        ..Default::default()
+      })
+      .await?;
+    self.worker_chunks.insert(id.to_string(), reference_id.clone());
+    Ok(reference_id)
+  }
+}
+
+impl Plugin for WebWorkerPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("builtin:web-worker")
+  }
+
+  async fn build_start(
+    &self,
+    _ctx: &PluginContext,
+    _args: &rolldown_plugin::HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    self.worker_chunks.clear();
+    Ok(())
+  }
+
+  async fn load(&self, ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    let Some((worker_id, kind)) = WorkerKind::from_query(args.id) else {
+      return Ok(None);
+    };
+    let reference_id = self.emit_worker_chunk(ctx, worker_id, args.id).await?;
+    // The worker chunk is an ES module only when the output is.
+    let options = if matches!(ctx.options().format, OutputFormat::Esm) {
+      r#"{ type: "module", ...options }"#
+    } else {
+      "options"
+    };
+    let code = concat_string!(
+      "export default function WorkerWrapper(options) {\n  return new ",
+      kind.constructor(),
+      "(import.meta.ROLLUP_FILE_URL_",
+      reference_id,
+      ", ",
+      options,
+      ");\n}\n"
+    );
+    Ok(Some(HookLoadOutput { code: code.into(), ..Default::default() }))
+  }
+
+  async fn transform_ast(
+    &self,
+    ctx: &PluginContext,
+    mut args: HookTransformAstArgs<'_>,
+  ) -> HookTransformAstReturn {
+    let mut specifiers = vec![];
+    args.ast.program.with_mut(|fields| {
+      let mut visitor = WorkerUrlCollect { specifiers: vec![] };
+      visitor.visit_program(fields.program);
+      specifiers = visitor.specifiers;
+    });
+    if specifiers.is_empty() {
+      return Ok(args.ast);
+    }
+
+    // Workers which can't be resolved are left for the browser to load as they are.
+    let iter = specifiers.into_iter().map(async |specifier| {
+      let resolved = ctx.resolve(&specifier, Some(args.id), None).await.ok()?.ok()?;
+      if resolved.external.is_external() {
+        return None;
+      }
+      Some(self.emit_worker_chunk(ctx, &resolved.id, args.id).await)
+    });
+    let reference_ids = block_on_spawn_all(iter).await.into_iter().map(Option::transpose);
+    let reference_ids = reference_ids.collect::<anyhow::Result<Vec<_>>>()?;
+
+    args.ast.program.with_mut(|fields| {
+      let ast_builder = AstBuilder::new(fields.allocator);
+      let mut visitor = WorkerUrlRewrite { ast_builder, reference_ids, current: 0 };
+      visitor.visit_program(fields.program);
+    });
+    Ok(args.ast)
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::Load | HookUsage::TransformAst
+  }
+}
@@ -0,0 +1,9 @@
+export function startWorkers() {
+  const worker = new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })
+  // The same worker is bundled once.
+  const again = new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })
+  const shared = new SharedWorker(new URL('./shared-worker.js', import.meta.url), { type: 'module' })
+  // Remote workers are left as they are.
+  const remote = new Worker(new URL('https://example.com/worker.js', import.meta.url))
+  return [worker, again, shared, remote]
+}
@@ -0,0 +1,6 @@
+import MyWorker from './worker.js?worker'
+import MySharedWorker from './worker.js?sharedworker'
+
+export function startWorkers() {
+  return [new MyWorker({ name: 'counter' }), new MySharedWorker()]
+}
@@ -2,9 +2,9 @@ use arcstr::ArcStr;
 use dashmap::DashMap;
 use itertools::Itertools;
 use rolldown_common::{
-  ImportKind, ModuleDefFormat, PackageJson, Platform, ResolveOptions, ResolvedId,
+  ImportKind, ModuleDefFormat, PackageJson, Platform, ResolveOptions, ResolvedId, SharedFileSystem,
 };
-use rolldown_fs::{FileSystem, OsFileSystem};
+use rolldown_fs::FileSystem;
 use rolldown_utils::{dashmap::FxDashMap, indexmap::FxIndexMap};
 use std::{
   path::{Path, PathBuf},
@@ -18,9 +18,11 @@ use oxc_resolver::{
   TsconfigOptions,
 };
 
+type ConditionedResolverKey = (ImportKind, /* extra conditions */ Vec<String>);
+
 #[derive(Debug)]
 #[allow(dead_code, clippy::struct_field_names)]
-pub struct Resolver<T: FileSystem + Default = OsFileSystem> {
+pub struct Resolver<T: FileSystem + Default = SharedFileSystem> {
   cwd: PathBuf,
   default_resolver: ResolverGeneric<FsCache<T>>,
   // Resolver for `import '...'` and `import(...)`
@@ -32,6 +34,8 @@ pub struct Resolver<T: FileSystem + Default = OsFileSystem> {
   // Resolver for `new URL(..., import.meta.url)`
   new_url_resolver: ResolverGeneric<FsCache<T>>,
   package_json_cache: FxDashMap<PathBuf, Arc<PackageJson>>,
+  // Resolvers created for `resolve_with_conditions`, keyed by the import kind and the extra conditions
+  conditioned_resolvers: FxDashMap<ConditionedResolverKey, Arc<ResolverGeneric<FsCache<T>>>>,
 }
 
 impl<F: FileSystem + Default> Resolver<F> {
@@ -62,7 +66,8 @@ impl<F: FileSystem + Default> Resolver<F> {
         vec!["main".to_string(), "module".to_string()]
       }
       Platform::Browser => vec!["browser".to_string(), "module".to_string(), "main".to_string()],
-      Platform::Neutral => vec![],
+      // `neutral` doesn't assume any environment, so only the standard `main` field is respected.
+      Platform::Neutral => vec!["main".to_string()],
     });
 
     let alias_fields = raw_resolve.alias_fields.clone().unwrap_or_else(|| match platform {
@@ -159,6 +164,7 @@ impl<F: FileSystem + Default> Resolver<F> {
       css_resolver,
       new_url_resolver,
       package_json_cache: DashMap::default(),
+      conditioned_resolvers: DashMap::default(),
     }
   }
 
@@ -192,6 +198,19 @@ impl<F: FileSystem + Default> Resolver<F> {
     specifier: &str,
     import_kind: ImportKind,
     is_user_defined_entry: bool,
+  ) -> Result<ResolveReturn, ResolveError> {
+    self.resolve_with_conditions(importer, specifier, import_kind, is_user_defined_entry, &[])
+  }
+
+  /// Same as [`Resolver::resolve`], but also matches `extra_conditions` in package.json `exports`
+  /// and `imports`, e.g. `types` for resolving declaration files.
+  pub fn resolve_with_conditions(
+    &self,
+    importer: Option<&Path>,
+    specifier: &str,
+    import_kind: ImportKind,
+    is_user_defined_entry: bool,
+    extra_conditions: &[String],
   ) -> Result<ResolveReturn, ResolveError> {
     let selected_resolver = match import_kind {
       ImportKind::Import | ImportKind::DynamicImport | ImportKind::HotAccept => {
@@ -202,6 +221,15 @@ impl<F: FileSystem + Default> Resolver<F> {
       ImportKind::AtImport | ImportKind::UrlImport => &self.css_resolver,
     };
 
+    let resolver_with_extra_conditions;
+    let selected_resolver = if extra_conditions.is_empty() {
+      selected_resolver
+    } else {
+      resolver_with_extra_conditions =
+        self.resolver_with_conditions(selected_resolver, import_kind, extra_conditions);
+      resolver_with_extra_conditions.as_ref()
+    };
+
     let importer_dir = importer.and_then(|importer| importer.parent()).and_then(|inner| {
       if inner.components().next().is_none() {
         // Empty path `Path::new("")`
@@ -245,6 +273,27 @@ impl<F: FileSystem + Default> Resolver<F> {
     })
   }
 
+  /// Returns the resolver matching `extra_conditions` on top of the conditions of `base`, creating
+  /// it on the first use. It shares the cache of `base`, so only the options are rebuilt.
+  fn resolver_with_conditions(
+    &self,
+    base: &ResolverGeneric<FsCache<F>>,
+    import_kind: ImportKind,
+    extra_conditions: &[String],
+  ) -> Arc<ResolverGeneric<FsCache<F>>> {
+    let key = (import_kind, extra_conditions.to_vec());
+    if let Some(resolver) = self.conditioned_resolvers.get(&key) {
+      return Arc::clone(resolver.value());
+    }
+    let options = base.options();
+    let resolver = Arc::new(base.clone_with_options(OxcResolverOptions {
+      condition_names:
+        extra_conditions.iter().chain(&options.condition_names).unique().cloned().collect(),
+      ..options.clone()
+    }));
+    Arc::clone(self.conditioned_resolvers.entry(key).or_insert(resolver).value())
+  }
+
   fn cached_package_json(&self, oxc_pkg_json: &OxcPackageJson) -> Arc<PackageJson> {
     match self.package_json_cache.get(&oxc_pkg_json.realpath) {
       Some(v) => Arc::clone(v.value()),
@@ -1,15 +1,19 @@
 mod source;
 mod source_joiner;
+mod visualizer;
 
 use oxc_sourcemap::Token;
 use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
 use rustc_hash::FxHashMap;
 
 pub use oxc_sourcemap::SourceMapBuilder;
-pub use oxc_sourcemap::{JSONSourceMap, SourceMap, SourcemapVisualizer};
+pub use oxc_sourcemap::{JSONSourceMap, SourceMap};
 pub use source_joiner::SourceJoiner;
 
-pub use crate::source::{Source, SourceMapSource};
+pub use crate::{
+  source::{Source, SourceMapSource},
+  visualizer::SourcemapVisualizer,
+};
 
 #[allow(clippy::from_iter_instead_of_collect, clippy::cast_possible_truncation)]
 pub fn collapse_sourcemaps(mut sourcemap_chain: Vec<&SourceMap>) -> SourceMap {
@@ -75,14 +79,13 @@ pub fn collapse_sourcemaps(mut sourcemap_chain: Vec<&SourceMap>) -> SourceMap {
 
 #[test]
 fn test_collapse_sourcemaps() {
-  use crate::{SourceJoiner, SourceMapSource, collapse_sourcemaps};
+  use crate::{SourceJoiner, SourceMapSource, SourcemapVisualizer, collapse_sourcemaps};
   use oxc::{
     allocator::Allocator,
     codegen::{Codegen, CodegenOptions, CodegenReturn},
     parser::Parser,
     span::SourceType,
   };
-  use oxc_sourcemap::SourcemapVisualizer;
 
   let allocator = Allocator::default();
 
@@ -73,6 +73,7 @@ impl<'source> SourceJoiner<'source> {
 
 #[test]
 fn test_concat_sourcemaps() {
+  use crate::SourcemapVisualizer;
   use crate::{SourceJoiner, SourceMapSource};
   use oxc::{
     allocator::Allocator,
@@ -80,7 +81,6 @@ fn test_concat_sourcemaps() {
     parser::Parser,
     span::SourceType,
   };
-  use oxc_sourcemap::SourcemapVisualizer;
 
   let mut source_joiner = SourceJoiner::default();
   source_joiner.append_source("\nconsole.log()".to_string());
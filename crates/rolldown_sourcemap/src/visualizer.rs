@@ -0,0 +1,263 @@
+use std::{borrow::Cow, fmt::Write as _};
+
+use oxc_sourcemap::{SourceMap, Token};
+
+/// Prints the mappings of a sourcemap as pairs of original and generated code, for snapshots.
+///
+/// Mappings with a name are followed by it, as `(name: foo)`, so snapshots show when a name gets
+/// dropped. Sourcemaps without `sourcesContent` only list their sources.
+pub struct SourcemapVisualizer<'a> {
+  output: &'a str,
+  sourcemap: &'a SourceMap,
+}
+
+impl<'a> SourcemapVisualizer<'a> {
+  pub fn new(output: &'a str, sourcemap: &'a SourceMap) -> Self {
+    Self { output, sourcemap }
+  }
+
+  /// Like [`Self::into_visualizer_text`], but fails with the list of mappings that are corrupted,
+  /// instead of printing them as they are.
+  pub fn into_strict_visualizer_text(self) -> Result<String, String> {
+    let issues = self.validate();
+    if issues.is_empty() { Ok(self.into_visualizer_text()) } else { Err(issues.join("\n")) }
+  }
+
+  #[allow(clippy::cast_possible_truncation)]
+  pub fn into_visualizer_text(self) -> String {
+    let mut s = String::new();
+    let source_contents_lines_map = self
+      .sourcemap
+      .get_source_contents()
+      .map(|content| content.map(generate_line_utf16_tables))
+      .collect::<Vec<_>>();
+    if source_contents_lines_map.iter().all(Option::is_none) {
+      s.push_str("[no source contents]\n");
+      for source in self.sourcemap.get_sources() {
+        writeln!(s, "- {source}").unwrap();
+      }
+      return s;
+    }
+
+    let output_lines = generate_line_utf16_tables(self.output);
+
+    let tokens = self.sourcemap.get_tokens().collect::<Vec<_>>();
+
+    let mut last_source: Option<&str> = None;
+    for (i, t) in tokens.iter().enumerate() {
+      let Some(source_id) = t.get_source_id() else {
+        continue;
+      };
+      let Some(source) = self.sourcemap.get_source(source_id) else { continue };
+      let Some(Some(source_lines)) = source_contents_lines_map.get(source_id as usize) else {
+        continue;
+      };
+
+      if last_source != Some(source) {
+        s.push_str("- ");
+        s.push_str(source);
+        s.push('\n');
+        last_source = Some(source);
+      }
+
+      let (src_line, src_col) = (t.get_src_line(), t.get_src_col());
+      let (dst_line, dst_col) = (t.get_dst_line(), t.get_dst_col());
+
+      let dst_invalid = dst_line as usize >= output_lines.len()
+        || (dst_col as usize) >= output_lines[dst_line as usize].len();
+      let src_invalid = src_line as usize >= source_lines.len()
+        || (src_col as usize) >= source_lines[src_line as usize].len();
+      if dst_invalid || src_invalid {
+        writeln!(
+          s,
+          "({src_line}:{src_col}){} --> ({dst_line}:{dst_col}){}",
+          if src_invalid { " [invalid]" } else { "" },
+          if dst_invalid { " [invalid]" } else { "" },
+        )
+        .unwrap();
+        continue;
+      }
+
+      // The next generated column on the line, or its end.
+      let dst_end_col = match tokens.get(i + 1) {
+        Some(t2) if t2.get_dst_line() == dst_line => t2.get_dst_col(),
+        _ => output_lines[dst_line as usize].len() as u32,
+      };
+
+      // The next original column on the line, skipping the duplicated and backward ones, or its end.
+      let src_end_col = 'result: {
+        for t2 in &tokens[i + 1..] {
+          if t2.get_source_id() == t.get_source_id() && t2.get_src_line() == src_line {
+            if t2.get_src_col() <= src_col {
+              continue;
+            }
+            break 'result t2.get_src_col();
+          }
+          break;
+        }
+        source_lines[src_line as usize].len() as u32
+      };
+
+      write!(
+        s,
+        "({src_line}:{src_col}) {:?} --> ({dst_line}:{dst_col}) {:?}",
+        str_slice_by_token(source_lines, src_line, src_col, src_end_col),
+        str_slice_by_token(&output_lines, dst_line, dst_col, dst_end_col)
+      )
+      .unwrap();
+      if let Some(name) = t.get_name_id().and_then(|name_id| self.sourcemap.get_name(name_id)) {
+        write!(s, " (name: {name})").unwrap();
+      }
+      s.push('\n');
+    }
+
+    s
+  }
+
+  /// Describes the mappings that point at sources or names that don't exist, go back on their
+  /// generated line, or point past the end of their source.
+  fn validate(&self) -> Vec<String> {
+    let sources_len = self.sourcemap.get_sources().count();
+    let names_len = self.sourcemap.get_names().count();
+    let source_lines = self
+      .sourcemap
+      .get_source_contents()
+      .map(|content| content.map(generate_line_utf16_tables))
+      .collect::<Vec<_>>();
+
+    let mut issues = vec![];
+    let mut previous: Option<&Token> = None;
+    for (i, t) in self.sourcemap.get_tokens().enumerate() {
+      let position = format!(
+        "mapping #{i} ({}:{}) --> ({}:{})",
+        t.get_src_line(),
+        t.get_src_col(),
+        t.get_dst_line(),
+        t.get_dst_col()
+      );
+      if let Some(previous) = previous {
+        let (line, col) = (t.get_dst_line(), t.get_dst_col());
+        if line < previous.get_dst_line()
+          || (line == previous.get_dst_line() && col < previous.get_dst_col())
+        {
+          issues.push(format!(
+            "{position}: generated position goes back from ({}:{})",
+            previous.get_dst_line(),
+            previous.get_dst_col()
+          ));
+        }
+      }
+      previous = Some(t);
+
+      if let Some(name_id) = t.get_name_id() {
+        if name_id as usize >= names_len {
+          issues.push(format!("{position}: name index {name_id} is out of {names_len} names"));
+        }
+      }
+      let Some(source_id) = t.get_source_id() else { continue };
+      if source_id as usize >= sources_len {
+        issues
+          .push(format!("{position}: source index {source_id} is out of {sources_len} sources"));
+        continue;
+      }
+      let Some(Some(lines)) = source_lines.get(source_id as usize) else { continue };
+      // A mapping may point at the end of a line, but not past it.
+      let past_end = lines
+        .get(t.get_src_line() as usize)
+        .is_none_or(|line| t.get_src_col() as usize > line.len());
+      if past_end {
+        issues.push(format!(
+          "{position}: points past the end of {}",
+          self.sourcemap.get_source(source_id).unwrap_or_default()
+        ));
+      }
+    }
+    issues
+  }
+}
+
+fn generate_line_utf16_tables(content: &str) -> Vec<Vec<u16>> {
+  let mut tables = vec![];
+  let mut line_byte_offset = 0;
+  for (i, ch) in content.char_indices() {
+    match ch {
+      '\r' | '\n' | '\u{2028}' | '\u{2029}' => {
+        // Handle Windows-specific "\r\n" newlines
+        if ch == '\r' && content[i + 1..].starts_with('\n') {
+          continue;
+        }
+        tables.push(content[line_byte_offset..i + ch.len_utf8()].encode_utf16().collect());
+        line_byte_offset = i + ch.len_utf8();
+      }
+      _ => {}
+    }
+  }
+  tables.push(content[line_byte_offset..].encode_utf16().collect());
+  tables
+}
+
+fn str_slice_by_token(buff: &[Vec<u16>], line: u32, start: u32, end: u32) -> Cow<'_, str> {
+  let s = &buff[line as usize];
+  let (start, end) = (start as usize, end as usize);
+  String::from_utf16(&s[start.min(end).min(s.len())..start.max(end).min(s.len())])
+    .unwrap()
+    .replace('\r', "")
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+  use oxc_sourcemap::{SourceMap, Token};
+
+  use super::SourcemapVisualizer;
+
+  fn sourcemap(tokens: Vec<Token>) -> SourceMap {
+    SourceMap::new(
+      None,
+      vec!["foo".into()],
+      None,
+      vec!["foo.js".into()],
+      vec![Some("const foo = 1;\n".into())],
+      tokens,
+      None,
+    )
+  }
+
+  #[test]
+  fn prints_the_names_of_mappings() {
+    let map = sourcemap(vec![
+      Token::new(0, 0, 0, 0, Some(0), None),
+      Token::new(0, 4, 0, 6, Some(0), Some(0)),
+      Token::new(0, 7, 0, 9, Some(0), None),
+    ]);
+
+    assert_eq!(
+      SourcemapVisualizer::new("let foo = 1;\n", &map).into_strict_visualizer_text().unwrap(),
+      r#"- foo.js
+(0:0) "const " --> (0:0) "let "
+(0:6) "foo" --> (0:4) "foo" (name: foo)
+(0:9) " = 1;\n" --> (0:7) " = 1;\n"
+"#
+    );
+  }
+
+  #[test]
+  fn strict_mode_lists_corrupted_mappings() {
+    let map = sourcemap(vec![
+      Token::new(0, 4, 0, 6, Some(0), Some(3)),
+      Token::new(0, 2, 0, 0, Some(0), None),
+      Token::new(0, 6, 0, 0, Some(2), None),
+      Token::new(0, 8, 0, 40, Some(0), None),
+      Token::new(1, 0, 5, 0, Some(0), None),
+    ]);
+
+    assert_eq!(
+      SourcemapVisualizer::new("let foo = 1;\n", &map).into_strict_visualizer_text().unwrap_err(),
+      "mapping #0 (0:6) --> (0:4): name index 3 is out of 1 names
+mapping #1 (0:0) --> (0:2): generated position goes back from (0:4)
+mapping #2 (0:0) --> (0:6): source index 2 is out of 1 sources
+mapping #3 (0:40) --> (0:8): points past the end of foo.js
+mapping #4 (5:0) --> (1:0): points past the end of foo.js"
+    );
+  }
+}
@@ -13,6 +13,7 @@ pub fn multi_duplicated_symbol() -> BundlerOptions {
         .to_str()
         .unwrap()
         .to_string(),
+      ..Default::default()
     }]),
     cwd: Some(root_dir().join("tmp/bench/rolldown-benchcases/packages/multi-duplicated-symbols")),
 
@@ -7,6 +7,7 @@ pub fn rome_ts() -> BundlerOptions {
     input: Some(vec![InputItem {
       name: Some("rome-ts".to_string()),
       import: root_dir().join("tmp/bench/rome/src/entry.ts").to_str().unwrap().to_string(),
+      ..Default::default()
     }]),
     cwd: Some(root_dir().join("tmp/bench/rome")),
 
@@ -7,6 +7,7 @@ pub fn threejs() -> BundlerOptions {
     input: Some(vec![InputItem {
       name: Some("threejs".to_string()),
       import: root_dir().join("tmp/bench/three/entry.js").to_str().unwrap().to_string(),
+      ..Default::default()
     }]),
     cwd: root_dir().join("tmp/bench/three").into(),
     ..Default::default()
@@ -18,6 +19,7 @@ pub fn threejs10x() -> BundlerOptions {
     input: Some(vec![InputItem {
       name: Some("threejs".to_string()),
       import: root_dir().join("tmp/bench/three10x/entry.js").to_str().unwrap().to_string(),
+      ..Default::default()
     }]),
     cwd: root_dir().join("tmp/bench/three10x").into(),
     ..Default::default()
@@ -5,10 +5,15 @@
 class TestDevRuntime extends DevRuntime {
   /**
    * @override
-   * @param {string} _moduleId
+   * @param {string} moduleId
    */
-  createModuleHotContext(_moduleId) {
-    return { accept() {} };
+  createModuleHotContext(moduleId) {
+    return {
+      accept() {},
+      data: this.getHotData(moduleId),
+      /** @param {(data: Record<string, any>) => void} cb */
+      dispose: (cb) => this.addDisposeHandler(moduleId, cb),
+    };
   }
   /**
    * @override
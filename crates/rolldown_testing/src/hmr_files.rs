@@ -5,15 +5,43 @@ use std::{
 };
 
 use regex::Regex;
+use rolldown_common::HmrChange;
+
+/// Edit files are named `<name>.hmr-<step>.<ext>`. Files named `<name>.hmr-mem-<step>.<ext>` are
+/// passed to the bundler as in-memory patches and never written to disk. Files named
+/// `<name>.hmr-delete-<step>.<ext>` delete `<name>.<ext>` and their content is ignored. Files named
+/// `<name>.hmr-invalidate-<step>.<ext>` make `<name>.<ext>` call `import.meta.hot.invalidate()`
+/// at that step, and their content is ignored as well.
+static HMR_EDIT_FILENAME_RE: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"\.hmr-(mem-|delete-|invalidate-)?(\d+)(\..+)$")
+    .expect("invalid hmr edit filename regex")
+});
+
+fn extract_hmr_edit_kind_from_hmr_edit_filename(hmr_filename: &Path) -> Option<&str> {
+  HMR_EDIT_FILENAME_RE
+    .captures(hmr_filename.to_str().unwrap())
+    .expect("invalid hmr filename")
+    .get(1)
+    .map(|kind| kind.as_str())
+}
+
+fn is_in_memory_hmr_edit_file(hmr_filename: &Path) -> bool {
+  extract_hmr_edit_kind_from_hmr_edit_filename(hmr_filename) == Some("mem-")
+}
+
+fn is_deletion_hmr_edit_file(hmr_filename: &Path) -> bool {
+  extract_hmr_edit_kind_from_hmr_edit_filename(hmr_filename) == Some("delete-")
+}
 
-static HMR_EDIT_FILENAME_RE: LazyLock<Regex> =
-  LazyLock::new(|| Regex::new(r"\.hmr-(\d+)(\..+)$").expect("invalid hmr edit filename regex"));
+fn is_invalidation_hmr_edit_file(hmr_filename: &Path) -> bool {
+  extract_hmr_edit_kind_from_hmr_edit_filename(hmr_filename) == Some("invalidate-")
+}
 
 fn extract_hmr_step_from_hmr_edit_filename(hmr_filename: &Path) -> usize {
   HMR_EDIT_FILENAME_RE
     .captures(hmr_filename.to_str().unwrap())
     .expect("invalid hmr filename")
-    .get(1)
+    .get(2)
     .unwrap()
     .as_str()
     .parse::<usize>()
@@ -25,7 +53,7 @@ fn get_filename_without_hmr_step(hmr_filename: &Path) -> PathBuf {
   let captures = HMR_EDIT_FILENAME_RE.captures(hmr_filename).expect("invalid hmr edit filename");
 
   let name = &hmr_filename[0..captures.get(0).unwrap().start()];
-  let ext = &hmr_filename[captures.get(2).unwrap().start()..];
+  let ext = &hmr_filename[captures.get(3).unwrap().start()..];
   let filename = name.to_owned() + ext;
   PathBuf::from(filename)
 }
@@ -91,18 +119,43 @@ fn get_hmr_edit_file_dest_path(
   hmr_temp_dir_path.join(relative)
 }
 
-pub fn get_changed_files_from_hmr_edit_files(
+pub fn get_changes_from_hmr_edit_files(
   test_folder_path: &Path,
   hmr_temp_dir_path: &Path,
   patch: &[PathBuf],
-) -> Vec<String> {
+) -> Vec<HmrChange> {
   patch
     .iter()
+    .filter(|src_path| !is_invalidation_hmr_edit_file(src_path))
     .map(|src_path| {
-      get_hmr_edit_file_dest_path(test_folder_path, hmr_temp_dir_path, src_path)
+      let id = get_hmr_edit_file_dest_path(test_folder_path, hmr_temp_dir_path, src_path)
         .to_str()
         .unwrap()
-        .to_owned()
+        .to_owned();
+      if is_in_memory_hmr_edit_file(src_path) {
+        HmrChange::Patched { id, code: fs::read_to_string(src_path).unwrap() }
+      } else {
+        HmrChange::Path(id)
+      }
+    })
+    .collect()
+}
+
+/// The path and the stable id of each module invalidating itself at this step, as
+/// `Bundler::hmr_invalidate` expects them.
+pub fn get_invalidations_from_hmr_edit_files(
+  test_folder_path: &Path,
+  hmr_temp_dir_path: &Path,
+  patch: &[PathBuf],
+) -> Vec<(String, String)> {
+  patch
+    .iter()
+    .filter(|src_path| is_invalidation_hmr_edit_file(src_path))
+    .map(|src_path| {
+      let dest_path = get_hmr_edit_file_dest_path(test_folder_path, hmr_temp_dir_path, src_path);
+      let stable_id =
+        dest_path.strip_prefix(hmr_temp_dir_path).unwrap().to_str().unwrap().replace('\\', "/");
+      (dest_path.to_str().unwrap().to_owned(), stable_id)
     })
     .collect()
 }
@@ -112,9 +165,16 @@ pub fn apply_hmr_edit_files_to_hmr_temp_dir(
   hmr_temp_dir_path: &Path,
   patch: &[PathBuf],
 ) {
-  for src_path in patch {
+  for src_path in patch.iter().filter(|src_path| {
+    !is_in_memory_hmr_edit_file(src_path) && !is_invalidation_hmr_edit_file(src_path)
+  }) {
     let dest_path = get_hmr_edit_file_dest_path(test_folder_path, hmr_temp_dir_path, src_path);
 
+    if is_deletion_hmr_edit_file(src_path) {
+      fs::remove_file(&dest_path).unwrap();
+      continue;
+    }
+
     if let Some(parent) = dest_path.parent() {
       fs::create_dir_all(parent).unwrap();
     }
@@ -127,9 +187,18 @@ pub fn apply_hmr_edit_files_to_hmr_temp_dir(
 fn test_extract_hmr_step_from_hmr_edit_filename() {
   assert_eq!(extract_hmr_step_from_hmr_edit_filename(Path::new("foo.hmr-1.js")), 1);
   assert_eq!(extract_hmr_step_from_hmr_edit_filename(Path::new("foo.hmr-1.d.ts")), 1);
+  assert_eq!(extract_hmr_step_from_hmr_edit_filename(Path::new("foo.hmr-mem-2.js")), 2);
+  assert_eq!(extract_hmr_step_from_hmr_edit_filename(Path::new("foo.hmr-delete-3.js")), 3);
+  assert_eq!(extract_hmr_step_from_hmr_edit_filename(Path::new("foo.hmr-invalidate-4.js")), 4);
 }
 
 #[test]
 fn test_get_filename_without_hmr_step() {
   assert_eq!(get_filename_without_hmr_step(Path::new("foo.hmr-1.js")), Path::new("foo.js"));
+  assert_eq!(get_filename_without_hmr_step(Path::new("foo.hmr-mem-1.js")), Path::new("foo.js"));
+  assert_eq!(get_filename_without_hmr_step(Path::new("foo.hmr-delete-1.js")), Path::new("foo.js"));
+  assert_eq!(
+    get_filename_without_hmr_step(Path::new("foo.hmr-invalidate-1.js")),
+    Path::new("foo.js")
+  );
 }
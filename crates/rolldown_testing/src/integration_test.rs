@@ -2,6 +2,7 @@ use core::str;
 use std::fmt::Write as _;
 use std::{
   borrow::Cow,
+  collections::BTreeMap,
   ffi::OsStr,
   fs,
   io::{Read, Write},
@@ -11,11 +12,11 @@ use std::{
 
 use anyhow::Context;
 use rolldown::{
-  BundleOutput, Bundler, BundlerOptions, IsExternal, OutputFormat, Platform, SourceMapType,
-  plugin::__inner::SharedPluginable,
+  BundleOutput, Bundler, BundlerOptions, IsExternal, LogColor, OutputFormat, Platform,
+  SourceMapType, plugin::__inner::SharedPluginable,
 };
-use rolldown_common::{HmrOutput, Output};
-use rolldown_error::{BuildDiagnostic, BuildResult, DiagnosticOptions};
+use rolldown_common::{HmrImplement, HmrImplementPerFormat, HmrOutput, Output};
+use rolldown_error::{BuildDiagnostic, BuildResult, ColorMode};
 use rolldown_sourcemap::SourcemapVisualizer;
 use rolldown_testing_config::TestMeta;
 use serde_json::{Map, Value};
@@ -24,9 +25,13 @@ use sugar_path::SugarPath;
 use crate::{
   hmr_files::{
     apply_hmr_edit_files_to_hmr_temp_dir, collect_hmr_edit_files,
-    copy_non_hmr_edit_files_to_hmr_temp_dir, get_changed_files_from_hmr_edit_files,
+    copy_non_hmr_edit_files_to_hmr_temp_dir, get_changes_from_hmr_edit_files,
+    get_invalidations_from_hmr_edit_files,
+  },
+  utils::{
+    RUNTIME_MODULE_OUTPUT_RE, assert_colorless, normalize_line_endings, render_bytes_for_snapshot,
+    snapshot_diagnostic_options,
   },
-  utils::RUNTIME_MODULE_OUTPUT_RE,
 };
 
 #[derive(Default)]
@@ -39,8 +44,26 @@ pub struct NamedBundlerOptions {
   pub options: BundlerOptions,
 }
 
+/// Returns the names and the filenames of the entry chunks, which could be renamed by
+/// `entry_filenames` or `InputItem#file_name`.
+fn entry_chunk_filenames(assets: &[Output]) -> Vec<(String, String)> {
+  assets
+    .iter()
+    .filter_map(|asset| match asset {
+      Output::Chunk(chunk) if chunk.is_entry => {
+        Some((chunk.name.to_string(), chunk.filename.to_string()))
+      }
+      _ => None,
+    })
+    .collect()
+}
+
 fn default_test_input_item() -> rolldown::InputItem {
-  rolldown::InputItem { name: Some("main".to_string()), import: "./main.js".to_string() }
+  rolldown::InputItem {
+    name: Some("main".to_string()),
+    import: "./main.js".to_string(),
+    ..Default::default()
+  }
 }
 
 impl IntegrationTest {
@@ -54,9 +77,10 @@ impl IntegrationTest {
     let mut bundler = Bundler::new(options);
 
     if self.test_meta.write_to_disk {
-      if bundler.options().out_dir.as_path().is_dir() {
-        std::fs::remove_dir_all(&bundler.options().out_dir)
-          .context(bundler.options().out_dir.clone())
+      let abs_output_dir = bundler.options().resolved_out_dir();
+      if abs_output_dir.is_dir() {
+        std::fs::remove_dir_all(&abs_output_dir)
+          .context(abs_output_dir.to_string_lossy().to_string())
           .expect("Failed to clean the output directory");
       }
       bundler.write().await
@@ -82,7 +106,7 @@ impl IntegrationTest {
     let cwd = bundler.options().cwd.clone();
 
     let bundle_output = if self.test_meta.write_to_disk {
-      let abs_output_dir = cwd.join(&bundler.options().out_dir);
+      let abs_output_dir = bundler.options().resolved_out_dir();
       if abs_output_dir.is_dir() {
         std::fs::remove_dir_all(&abs_output_dir)
           .context(format!("{abs_output_dir:?}"))
@@ -100,6 +124,8 @@ impl IntegrationTest {
           "Expected the bundling to be failed with diagnosable errors, but got success"
         );
 
+        assert_sourcemap_references(bundler.options().sourcemap, &bundle_output.assets);
+        let entry_filenames = entry_chunk_filenames(&bundle_output.assets);
         self.snapshot_bundle_output(bundle_output, vec![], &cwd);
 
         if !self.test_meta.expect_executed
@@ -108,7 +134,7 @@ impl IntegrationTest {
         {
           // do nothing
         } else {
-          Self::execute_output_assets(&bundler, "", vec![]);
+          self.execute_output_assets(&bundler, "", &entry_filenames, vec![]);
         }
       }
       Err(errs) => {
@@ -146,20 +172,15 @@ impl IntegrationTest {
         named_options.options.cwd = Some(hmr_temp_dir_path.clone());
       }
 
-      let output_dir = format!(
-        "{}/{}",
-        named_options.options.cwd.as_ref().map_or(".", |cwd| cwd.to_str().unwrap()),
-        named_options.options.dir.as_ref().map_or("dist", |v| v)
-      );
-
       let mut bundler = Bundler::with_plugins(named_options.options, plugins.clone());
+      let output_dir = bundler.options().resolved_out_dir();
 
       let debug_title = named_options.name.clone().unwrap_or_else(String::new);
 
       let cwd = bundler.options().cwd.clone();
 
       let bundle_output = if self.test_meta.write_to_disk {
-        let abs_output_dir = cwd.join(&bundler.options().out_dir);
+        let abs_output_dir = bundler.options().resolved_out_dir();
         if abs_output_dir.is_dir() {
           std::fs::remove_dir_all(&abs_output_dir)
             .context(format!("{abs_output_dir:?}"))
@@ -186,6 +207,7 @@ impl IntegrationTest {
             "Expected the bundling to be failed with diagnosable errors, but got success"
           );
 
+          let entry_filenames = entry_chunk_filenames(&bundle_output.assets);
           let snapshot_content = self.render_bundle_output_to_string(bundle_output, vec![], &cwd);
           snapshot_outputs.push(snapshot_content);
 
@@ -196,42 +218,61 @@ impl IntegrationTest {
               &hmr_temp_dir_path,
               hmr_edit_files,
             );
-            let changed_files = get_changed_files_from_hmr_edit_files(
+            let changes =
+              get_changes_from_hmr_edit_files(test_folder_path, &hmr_temp_dir_path, hmr_edit_files);
+            let invalidations = get_invalidations_from_hmr_edit_files(
               test_folder_path,
               &hmr_temp_dir_path,
               hmr_edit_files,
             );
-            let hmr_output = bundler.generate_hmr_patch(changed_files).await;
-            match hmr_output {
-              Ok(output) => {
-                let snapshot_content =
-                  Self::render_hmr_output_to_string(step, &output, vec![], &cwd);
-                snapshot_outputs.push(snapshot_content);
-
-                if execute_output {
-                  assert!(
-                    !output.full_reload,
-                    "execute_output should be false when full reload happens"
+            let mut hmr_outputs = vec![];
+            if !changes.is_empty() {
+              hmr_outputs.push(bundler.generate_hmr_patch(changes).await);
+            }
+            // Like the runtime does, a module invalidating itself first names itself as the
+            // first invalidated module.
+            for (file, stable_id) in invalidations {
+              hmr_outputs.push(bundler.hmr_invalidate(file, Some(stable_id)).await);
+            }
+            for hmr_output in hmr_outputs {
+              match hmr_output {
+                Ok(output) => {
+                  let snapshot_content =
+                    self.render_hmr_output_to_string(step, &output, vec![], &cwd);
+                  snapshot_outputs.push(snapshot_content);
+
+                  if execute_output {
+                    assert!(
+                      !output.full_reload,
+                      "execute_output should be false when full reload happens"
+                    );
+                    // Parts of split patches have to run in order, before their manifest.
+                    for patch in output.patches {
+                      fs::write(output_dir.join(&patch.filename), patch.code).unwrap();
+                      if let (Some(map), Some(map_filename)) =
+                        (&patch.map, &patch.sourcemap_filename)
+                      {
+                        fs::write(output_dir.join(map_filename), map.to_json_string()).unwrap();
+                      }
+                      patch_chunks.push(format!("./{}", patch.filename));
+                    }
+                  }
+                }
+                Err(errs) => {
+                  let snapshot_content = self.render_hmr_output_to_string(
+                    step,
+                    &HmrOutput::default(),
+                    errs.into_vec(),
+                    &cwd,
                   );
-                  let output_path = format!("{}/{}", &output_dir, &output.filename);
-                  fs::write(&output_path, output.code).unwrap();
-                  patch_chunks.push(format!("./{}", output.filename));
+                  snapshot_outputs.push(snapshot_content);
                 }
               }
-              Err(errs) => {
-                let snapshot_content = Self::render_hmr_output_to_string(
-                  step,
-                  &HmrOutput::default(),
-                  errs.into_vec(),
-                  &cwd,
-                );
-                snapshot_outputs.push(snapshot_content);
-              }
             }
           }
 
           if execute_output {
-            Self::execute_output_assets(&bundler, &debug_title, patch_chunks);
+            self.execute_output_assets(&bundler, &debug_title, &entry_filenames, patch_chunks);
           } else {
             // do nothing
           }
@@ -255,7 +296,92 @@ impl IntegrationTest {
     settings.remove_input_file();
     settings.set_omit_expression(true);
     settings.bind(|| {
-      insta::assert_snapshot!("artifacts", snapshot_outputs.concat());
+      let content = snapshot_outputs.concat();
+      assert_colorless(&content);
+      insta::assert_snapshot!("artifacts", content);
+    });
+  }
+
+  /// Bundles `base_options` and `variant_options`, runs both outputs under Node and asserts they
+  /// print the same, so optimizations can change the emitted code but not what it does.
+  ///
+  /// The outputs are written to `base` and `variant` in the output directory, and the snapshot
+  /// records both of them and whether their code differs.
+  pub async fn run_diff(&self, base_options: BundlerOptions, variant_options: BundlerOptions) {
+    assert!(
+      self.test_meta.write_to_disk && !self.test_meta.expect_error,
+      "`run_diff` executes the outputs, which have to be written to disk without errors"
+    );
+
+    let mut snapshot_outputs = vec![];
+    let mut runs = vec![];
+    // Boxed, so the options waiting for their turn don't bloat the future.
+    for (title, options) in
+      [("base", Box::new(base_options)), ("variant", Box::new(variant_options))]
+    {
+      let mut options = *options;
+      self.apply_test_defaults(&mut options);
+      options.dir = Some(format!("{}/{title}", options.dir.as_deref().unwrap_or("dist")));
+
+      let mut bundler = Bundler::new(options);
+      let cwd = bundler.options().cwd.clone();
+      let abs_output_dir = bundler.options().resolved_out_dir();
+      if abs_output_dir.is_dir() {
+        std::fs::remove_dir_all(&abs_output_dir)
+          .context(abs_output_dir.to_string_lossy().to_string())
+          .expect("Failed to clean the output directory");
+      }
+      let bundle_output = bundler
+        .write()
+        .await
+        .unwrap_or_else(|errs| panic!("Failed to bundle the {title} output: {errs:#?}"));
+
+      let code = bundle_output
+        .assets
+        .iter()
+        .filter_map(|asset| match asset {
+          Output::Chunk(chunk) => Some((chunk.filename.to_string(), chunk.code.clone())),
+          Output::Asset(_) => None,
+        })
+        .collect::<Vec<_>>();
+      if title != "base" {
+        snapshot_outputs.push(format!("\n---\n\nVariant: {title}\n\n"));
+      }
+      let entry_filenames = entry_chunk_filenames(&bundle_output.assets);
+      snapshot_outputs.push(self.render_bundle_output_to_string(bundle_output, vec![], &cwd));
+
+      let output = self.execute_output_assets(&bundler, title, &entry_filenames, vec![]);
+      runs.push((
+        code,
+        String::from_utf8(output.stdout).expect("stdout should be valid utf8"),
+        cwd,
+      ));
+    }
+
+    let [(base_code, base_stdout, cwd), (variant_code, variant_stdout, _)] =
+      <[_; 2]>::try_from(runs).unwrap();
+    assert_eq!(
+      base_stdout, variant_stdout,
+      "The base and variant outputs print differently, so the variant changes what the code does"
+    );
+
+    snapshot_outputs.push("\n---\n\n# Runtime Diff\n\n".to_string());
+    snapshot_outputs.push(if base_code == variant_code {
+      "The emitted code is the same.\n".to_string()
+    } else {
+      "The emitted code differs, but both outputs print the same.\n".to_string()
+    });
+    snapshot_outputs.push(format!("\n```\n{base_stdout}```"));
+
+    let mut settings = insta::Settings::clone_current();
+    settings.set_snapshot_path(cwd);
+    settings.set_prepend_module_to_snapshot(false);
+    settings.remove_input_file();
+    settings.set_omit_expression(true);
+    settings.bind(|| {
+      let content = snapshot_outputs.concat();
+      assert_colorless(&content);
+      insta::assert_snapshot!("artifacts", content);
     });
   }
 
@@ -268,6 +394,11 @@ impl IntegrationTest {
       options.input = Some(vec![default_test_input_item()]);
     }
 
+    // Diagnostics embedded in plugin errors and logs would otherwise depend on the terminal.
+    if options.log_color.is_none() {
+      options.log_color = Some(LogColor::Never);
+    }
+
     // if options.cwd.is_none() {
     //   options.cwd = Some(fixture_path.to_path_buf());
     // }
@@ -293,8 +424,8 @@ impl IntegrationTest {
     if self.test_meta.visualize_sourcemap {
       if options.sourcemap.is_none() {
         options.sourcemap = Some(SourceMapType::File);
-      } else if !matches!(options.sourcemap, Some(SourceMapType::File)) {
-        panic!("`visualizeSourcemap` is only supported with `sourcemap: 'file'`")
+      } else if !matches!(options.sourcemap, Some(SourceMapType::File | SourceMapType::Hidden)) {
+        panic!("`visualizeSourcemap` is only supported with `sourcemap: 'file'` or `'hidden'`")
       }
     }
     if options.sourcemap.is_none() && self.test_meta.visualize_sourcemap {
@@ -303,8 +434,16 @@ impl IntegrationTest {
 
     if let Some(experimental) = &mut options.experimental {
       if let Some(hmr) = &mut experimental.hmr {
-        if hmr.implement.is_none() {
-          hmr.implement = Some(include_str!("./hmr-runtime.js").to_owned());
+        // The default test runtime targets esm output, fixtures building other formats provide
+        // their own one.
+        match hmr
+          .implement
+          .get_or_insert_with(|| HmrImplement::PerFormat(HmrImplementPerFormat::default()))
+        {
+          HmrImplement::PerFormat(per_format) => {
+            per_format.esm.get_or_insert_with(|| include_str!("./hmr-runtime.js").to_owned());
+          }
+          HmrImplement::All(_) => {}
         }
       }
     }
@@ -325,14 +464,14 @@ impl IntegrationTest {
       errors.sort_by_key(|e| e.kind().to_string());
       let diagnostics = errors
         .into_iter()
-        .map(|e| (e.kind(), e.to_diagnostic_with(&DiagnosticOptions { cwd: cwd.to_path_buf() })));
+        .map(|e| (e.kind(), e.to_diagnostic_with(&snapshot_diagnostic_options(cwd))));
 
       let mut rendered_diagnostics = diagnostics
         .map(|(code, diagnostic)| {
           [
             Cow::Owned(format!("## {code}\n")),
             "```text".into(),
-            Cow::Owned(diagnostic.to_string()),
+            Cow::Owned(diagnostic.to_string_with_color(ColorMode::Never)),
             "```".into(),
           ]
           .join("\n")
@@ -352,13 +491,13 @@ impl IntegrationTest {
       snapshot.push_str("# warnings\n\n");
       let diagnostics = warnings
         .into_iter()
-        .map(|e| (e.kind(), e.to_diagnostic_with(&DiagnosticOptions { cwd: cwd.to_path_buf() })));
+        .map(|e| (e.kind(), e.to_diagnostic_with(&snapshot_diagnostic_options(cwd))));
       let mut rendered_diagnostics = diagnostics
         .map(|(code, diagnostic)| {
           [
             Cow::Owned(format!("## {code}\n")),
             "```text".into(),
-            Cow::Owned(diagnostic.to_string()),
+            Cow::Owned(diagnostic.to_string_with_color(ColorMode::Never)),
             "```".into(),
           ]
           .join("\n")
@@ -375,7 +514,7 @@ impl IntegrationTest {
 
     let mut assets = bundle_output.assets;
 
-    let assets_section = if !assets.is_empty() {
+    let mut assets_section = if !assets.is_empty() {
       let mut snapshot = String::new();
       snapshot.push_str("# Assets\n\n");
       assets.sort_by_key(|c| c.filename().to_string());
@@ -394,10 +533,16 @@ impl IntegrationTest {
           match asset {
             Output::Chunk(output_chunk) => {
               let content = &output_chunk.code;
-              let content = if self.test_meta.hidden_runtime_module {
-                RUNTIME_MODULE_OUTPUT_RE.replace_all(content, "")
+              let content =
+                if self.test_meta.hidden_runtime_module || self.test_meta.isolate_runtime_module {
+                  RUNTIME_MODULE_OUTPUT_RE.replace_all(content, "")
+                } else {
+                  Cow::Borrowed(content.as_str())
+                };
+              let content = if self.test_meta.normalize_line_endings {
+                normalize_line_endings(content)
               } else {
-                Cow::Borrowed(content.as_str())
+                content
               };
 
               Some(vec![
@@ -416,17 +561,20 @@ impl IntegrationTest {
                 rolldown_common::StrOrBytes::Str(content) => Some(vec![
                   Cow::Owned(format!("## {}\n", asset.filename())),
                   Cow::Owned(format!("```{file_ext}")),
-                  Cow::Borrowed(content),
+                  if self.test_meta.normalize_line_endings {
+                    normalize_line_endings(Cow::Borrowed(content))
+                  } else {
+                    Cow::Borrowed(content)
+                  },
                   "```".into(),
                 ]),
+                // Bytes are rendered as they are, normalizing them could hide real differences in binary assets.
                 rolldown_common::StrOrBytes::Bytes(bytes) => {
                   let mut ret = vec![Cow::Owned(format!("## {}\n", asset.filename()))];
                   if self.test_meta.snapshot_bytes {
-                    ret.extend([
-                      Cow::Owned(format!("```{file_ext}")),
-                      String::from_utf8_lossy(bytes),
-                      "```".into(),
-                    ]);
+                    let (lang, rendered) = render_bytes_for_snapshot(bytes);
+                    let lang = lang.unwrap_or(file_ext);
+                    ret.extend([Cow::Owned(format!("```{lang}")), rendered, "```".into()]);
                   }
                   Some(ret)
                 }
@@ -443,19 +591,57 @@ impl IntegrationTest {
       String::new()
     };
 
-    let output_stats_section = if self.test_meta.snapshot_output_stats {
+    // Only appended when enabled, so snapshots without this section are left untouched.
+    if self.test_meta.isolate_runtime_module {
+      let mut snapshot = String::new();
+      snapshot.push_str("# Runtime\n\n");
+      let runtime_modules = assets
+        .iter()
+        .filter_map(|asset| match asset {
+          Output::Chunk(chunk) => {
+            let runtime_module = RUNTIME_MODULE_OUTPUT_RE.find(&chunk.code)?.as_str();
+            Some(
+              [
+                Cow::Owned(format!("## {}\n", chunk.filename)),
+                "```js".into(),
+                normalize_line_endings(Cow::Borrowed(runtime_module)),
+                "```".into(),
+              ]
+              .join("\n"),
+            )
+          }
+          Output::Asset(_) => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+      snapshot.push_str(&runtime_modules);
+      assets_section.push('\n');
+      assets_section.push_str(&snapshot);
+    }
+
+    let mut output_stats_section = if self.test_meta.snapshot_output_stats {
       let mut snapshot = String::new();
       snapshot.push_str("## Output Stats\n\n");
       let stats = assets
         .iter()
         .flat_map(|asset| match asset {
           Output::Chunk(chunk) => {
+            let imported_bindings = chunk
+              .imported_bindings
+              .iter()
+              .map(|(importee, bindings)| {
+                (importee.as_str(), bindings.iter().map(ToString::to_string).collect::<Vec<_>>())
+              })
+              .collect::<BTreeMap<_, _>>();
             vec![Cow::Owned(format!(
-              "- {}, is_entry {}, is_dynamic_entry {}, exports {:?}",
+              "- {}, is_entry {}, is_dynamic_entry {}, exports {:?}, imports {:?}, dynamic_imports {:?}, imported_bindings {:?}",
               chunk.filename.as_str(),
               chunk.is_entry,
               chunk.is_dynamic_entry,
-              chunk.exports.iter().map(ToString::to_string).collect::<Vec<_>>()
+              chunk.exports.iter().map(ToString::to_string).collect::<Vec<_>>(),
+              chunk.imports.iter().map(ToString::to_string).collect::<Vec<_>>(),
+              chunk.dynamic_imports.iter().map(ToString::to_string).collect::<Vec<_>>(),
+              imported_bindings
             ))]
           }
           Output::Asset(_) => vec![],
@@ -468,6 +654,86 @@ impl IntegrationTest {
       String::new()
     };
 
+    // Only appended when enabled, so snapshots without this section are left untouched.
+    if self.test_meta.snapshot_module_graph || self.test_meta.snapshot_module_sizes {
+      let mut snapshot = String::new();
+      snapshot.push_str("# Chunk Layout\n\n");
+      let layout = assets
+        .iter()
+        .filter_map(|asset| match asset {
+          Output::Chunk(chunk) => {
+            // Sort after stabilizing, so the order doesn't depend on absolute paths or module processing order.
+            let mut module_ids =
+              chunk.module_ids.iter().map(|id| id.stabilize(cwd)).collect::<Vec<_>>();
+            module_ids.sort_unstable();
+            let mut rendered = format!("## {}\n", chunk.filename.as_str());
+            for module_id in module_ids {
+              write!(rendered, "\n- {module_id}").unwrap();
+              if !self.test_meta.snapshot_module_sizes {
+                continue;
+              }
+              let module = chunk
+                .modules
+                .keys
+                .iter()
+                .position(|id| id.stabilize(cwd) == module_id)
+                .map(|index| &chunk.modules.values[index]);
+              if let Some(module) = module {
+                write!(
+                  rendered,
+                  ", original {}, rendered {}",
+                  size_bucket(module.original_length),
+                  size_bucket(module.rendered_length)
+                )
+                .unwrap();
+                if let Some(minified_length) = module.minified_length {
+                  write!(rendered, ", minified {}", size_bucket(minified_length)).unwrap();
+                }
+              }
+            }
+            Some(rendered)
+          }
+          Output::Asset(_) => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+      snapshot.push_str(&layout);
+      output_stats_section.push('\n');
+      output_stats_section.push_str(&snapshot);
+    }
+
+    if self.test_meta.snapshot_interop {
+      let mut snapshot = String::new();
+      snapshot.push_str("# Interop\n\n");
+      let interop = assets
+        .iter()
+        .filter_map(|asset| match asset {
+          Output::Chunk(chunk) => {
+            let mut rendered = format!("## {}\n", chunk.filename.as_str());
+            if chunk.interop.is_empty() {
+              rendered.push_str("\n- (none)");
+            }
+            for interop in &chunk.interop {
+              write!(
+                rendered,
+                "\n- {}: {} imports {}",
+                interop.helper,
+                interop.importer.stabilize(cwd),
+                interop.importee.stabilize(cwd)
+              )
+              .unwrap();
+            }
+            Some(rendered)
+          }
+          Output::Asset(_) => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+      snapshot.push_str(&interop);
+      output_stats_section.push('\n');
+      output_stats_section.push_str(&snapshot);
+    }
+
     let visualize_sourcemap_section = if self.test_meta.visualize_sourcemap {
       let mut snapshot = String::new();
       snapshot.push_str("# Sourcemap Visualizer\n\n");
@@ -476,7 +742,11 @@ impl IntegrationTest {
         .iter()
         .filter_map(|asset| match asset {
           Output::Chunk(chunk) => chunk.map.as_ref().map(|sourcemap| {
-            SourcemapVisualizer::new(&chunk.code, sourcemap).into_visualizer_text()
+            SourcemapVisualizer::new(&chunk.code, sourcemap)
+              .into_strict_visualizer_text()
+              .unwrap_or_else(|issues| {
+                panic!("Sourcemap of {} is corrupted:\n{issues}", chunk.filename)
+              })
           }),
           Output::Asset(_) => None,
         })
@@ -500,8 +770,10 @@ impl IntegrationTest {
     .to_owned()
   }
 
+  #[expect(clippy::too_many_lines)]
   #[expect(clippy::if_not_else)]
   fn render_hmr_output_to_string(
+    &self,
     step: usize,
     hmr_output: &HmrOutput,
     errs: Vec<BuildDiagnostic>,
@@ -514,14 +786,14 @@ impl IntegrationTest {
       errors.sort_by_key(|e| e.kind().to_string());
       let diagnostics = errors
         .into_iter()
-        .map(|e| (e.kind(), e.to_diagnostic_with(&DiagnosticOptions { cwd: cwd.to_path_buf() })));
+        .map(|e| (e.kind(), e.to_diagnostic_with(&snapshot_diagnostic_options(cwd))));
 
       let mut rendered_diagnostics = diagnostics
         .map(|(code, diagnostic)| {
           [
             Cow::Owned(format!("### {code}\n")),
             "```text".into(),
-            Cow::Owned(diagnostic.to_string()),
+            Cow::Owned(diagnostic.to_string_with_color(ColorMode::Never)),
             "```".into(),
           ]
           .join("\n")
@@ -535,21 +807,70 @@ impl IntegrationTest {
       String::default()
     };
 
-    let code_section = if hmr_output.code.is_empty() {
+    let warnings_section = (!hmr_output.warnings.is_empty()).then(|| {
+      let mut snapshot = String::new();
+      snapshot.push_str("## Warnings\n\n");
+      let mut rendered_diagnostics = hmr_output
+        .warnings
+        .iter()
+        .map(|warning| {
+          let diagnostic = warning.to_diagnostic_with(&snapshot_diagnostic_options(cwd));
+          format!(
+            "### {}\n\n```text\n{}\n```",
+            warning.kind(),
+            diagnostic.to_string_with_color(ColorMode::Never)
+          )
+        })
+        .collect::<Vec<_>>();
+      rendered_diagnostics.sort();
+      snapshot.push_str(&rendered_diagnostics.join("\n"));
+      snapshot
+    });
+
+    // Patch filenames are timestamps, which would make the `sourceMappingURL` comments unstable.
+    let patches = hmr_output
+      .patches
+      .iter()
+      .enumerate()
+      .map(|(index, patch)| {
+        let filename = if hmr_output.patches.len() == 1 {
+          format!("hmr-patch-{step}.js")
+        } else {
+          format!("hmr-patch-{step}-{index}.js")
+        };
+        let code = patch.code.replace(&patch.filename, &filename);
+        (filename, code, patch)
+      })
+      .filter(|(_, code, _)| !code.is_empty())
+      .collect::<Vec<_>>();
+
+    let code_section = if patches.is_empty() {
       String::new()
     } else {
       let mut snapshot = String::new();
       write!(snapshot, "## Code\n\n").unwrap();
-      let file_ext = hmr_output.filename.as_path().extension().and_then(OsStr::to_str).map_or(
-        "unknown",
-        |ext| match ext {
-          "mjs" | "cjs" => "js",
-          _ => ext,
-        },
-      );
-      writeln!(snapshot, "```{file_ext}").unwrap();
-      snapshot.push_str(&hmr_output.code);
-      snapshot.push_str("\n```");
+      let rendered_patches = patches
+        .iter()
+        .map(|(filename, code, patch)| {
+          let mut rendered = String::new();
+          // Headings are only needed to tell the parts of a split patch apart.
+          if patches.len() > 1 {
+            write!(rendered, "### {filename}\n\n").unwrap();
+          }
+          let file_ext =
+            patch.filename.as_path().extension().and_then(OsStr::to_str).map_or("unknown", |ext| {
+              match ext {
+                "mjs" | "cjs" => "js",
+                _ => ext,
+              }
+            });
+          writeln!(rendered, "```{file_ext}").unwrap();
+          rendered.push_str(code);
+          rendered.push_str("\n```");
+          rendered
+        })
+        .collect::<Vec<_>>();
+      snapshot.push_str(&rendered_patches.join("\n"));
       snapshot
     };
 
@@ -563,11 +884,34 @@ impl IntegrationTest {
         hmr_output.first_invalidated_by.as_deref().unwrap_or("None")
       )
       .unwrap();
+      writeln!(
+        snapshot,
+        "- all_invalidated_by: {}",
+        if hmr_output.all_invalidated_by.is_empty() {
+          "None".to_string()
+        } else {
+          hmr_output.all_invalidated_by.join(", ")
+        }
+      )
+      .unwrap();
       writeln!(snapshot, "- is_self_accepting: {}", hmr_output.is_self_accepting).unwrap();
+      writeln!(
+        snapshot,
+        "- modules_to_dispose: {}",
+        if hmr_output.modules_to_dispose.is_empty() {
+          "None".to_string()
+        } else {
+          hmr_output.modules_to_dispose.join(", ")
+        }
+      )
+      .unwrap();
       writeln!(
         snapshot,
         "- full_reload_reason: {}",
-        hmr_output.full_reload_reason.as_deref().unwrap_or("None")
+        hmr_output
+          .full_reload_reason
+          .as_ref()
+          .map_or_else(|| "None".to_string(), ToString::to_string)
       )
       .unwrap();
       write!(snapshot, "### Hmr Boundaries\n\n").unwrap();
@@ -576,9 +920,10 @@ impl IntegrationTest {
         .iter()
         .map(|boundary| {
           format!(
-            "- boundary: {}, accepted_via: {}",
+            "- boundary: {}, accepted_via: {}{}",
             boundary.boundary.as_str(),
-            boundary.accepted_via.as_str()
+            boundary.accepted_via.as_str(),
+            if boundary.unevaluated_ok { ", unevaluated_ok: true" } else { "" }
           )
         })
         .collect::<Vec<_>>();
@@ -586,8 +931,35 @@ impl IntegrationTest {
       snapshot
     };
 
+    let visualize_sourcemap_section = if self.test_meta.visualize_sourcemap
+      && patches.iter().any(|(_, _, patch)| patch.map.is_some())
+    {
+      let mut snapshot = String::new();
+      snapshot.push_str("## Sourcemap Visualizer\n\n");
+      snapshot.push_str("```\n");
+      for (_, code, patch) in &patches {
+        if let Some(map) = &patch.map {
+          let visualizer_text =
+            SourcemapVisualizer::new(code, map).into_strict_visualizer_text().unwrap_or_else(
+              |issues| panic!("Sourcemap of HMR step {step} is corrupted:\n{issues}"),
+            );
+          snapshot.push_str(&visualizer_text);
+        }
+      }
+      snapshot.push_str("```");
+      snapshot
+    } else {
+      String::new()
+    };
+
     "\n".to_owned()
-      + [format!("# HMR Step {step}"), errors_section, code_section, meta_section].join("\n").trim()
+      + [format!("# HMR Step {step}"), errors_section]
+        .into_iter()
+        .chain(warnings_section)
+        .chain([code_section, meta_section, visualize_sourcemap_section])
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
   }
 
   fn snapshot_bundle_output(
@@ -603,14 +975,23 @@ impl IntegrationTest {
     settings.set_prepend_module_to_snapshot(false);
     settings.remove_input_file();
     settings.set_omit_expression(true);
+    assert_colorless(&content);
     settings.bind(|| {
       insta::assert_snapshot!("artifacts", content);
     });
   }
 
-  fn execute_output_assets(bundler: &Bundler, test_title: &str, patch_chunks: Vec<String>) {
+  /// Runs the output under Node and returns what it printed, failing the test on an unexpected
+  /// exit code.
+  fn execute_output_assets(
+    &self,
+    bundler: &Bundler,
+    test_title: &str,
+    entry_filenames: &[(String, String)],
+    patch_chunks: Vec<String>,
+  ) -> std::process::Output {
     let cwd = bundler.options().cwd.clone();
-    let dist_folder = cwd.join(&bundler.options().out_dir);
+    let dist_folder = bundler.options().resolved_out_dir();
 
     let is_expect_executed_under_esm = matches!(bundler.options().format, OutputFormat::Esm)
       || (!matches!(bundler.options().format, OutputFormat::Cjs)
@@ -660,11 +1041,13 @@ impl IntegrationTest {
         .input
         .iter()
         .map(|item| {
-          let name = item.name.clone().expect("inputs must have `name` in `_config.json`");
-          let ext = "js";
-          format!("{name}.{ext}",)
+          let name = item.name.as_deref().expect("inputs must have `name` in `_config.json`");
+          let (_, filename) = entry_filenames
+            .iter()
+            .find(|(chunk_name, _)| chunk_name == name)
+            .unwrap_or_else(|| panic!("no entry chunk is named `{name}`"));
+          dist_folder.join(filename)
         })
-        .map(|name| dist_folder.join(name))
         .collect::<Vec<_>>();
 
       compiled_entries.iter().for_each(|entry| {
@@ -682,17 +1065,76 @@ impl IntegrationTest {
 
     let output = node_command.output().unwrap();
 
+    let failure = match output.status.code() {
+      Some(code) if code == self.test_meta.expected_exit_code => None,
+      Some(code) => {
+        Some(format!("exited with code {code}, expected {}", self.test_meta.expected_exit_code))
+      }
+      None => Some(Self::describe_signal(output.status)),
+    };
+
     #[allow(clippy::print_stdout)]
-    if !output.status.success() {
+    if let Some(failure) = failure {
       let stdout_utf8 = std::str::from_utf8(&output.stdout).unwrap();
       let stderr_utf8 = std::str::from_utf8(&output.stderr).unwrap();
 
       println!(
-        "⬇️⬇️ Failed to execute command {test_title} ⬇️⬇️\n{node_command:?}\n⬆️⬆️ end  ⬆️⬆️"
+        "⬇️⬇️ Failed to execute command {test_title} ({failure}) ⬇️⬇️\n{node_command:?}\n⬆️⬆️ end  ⬆️⬆️"
       );
       panic!(
         "⬇️⬇️ stderr {test_title} ⬇️⬇️\n{stderr_utf8}\n⬇️⬇️ stdout ⬇️⬇️\n{stdout_utf8}\n⬆️⬆️ end  ⬆️⬆️",
       );
     }
+    output
+  }
+
+  /// Processes without an exit code were terminated by a signal, which only happens on Unix.
+  #[cfg(unix)]
+  fn describe_signal(status: std::process::ExitStatus) -> String {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal().map_or_else(
+      || "terminated without an exit code".to_string(),
+      |signal| format!("terminated by signal {signal}"),
+    )
+  }
+
+  #[cfg(not(unix))]
+  fn describe_signal(_status: std::process::ExitStatus) -> String {
+    "terminated without an exit code".to_string()
+  }
+}
+
+/// Checks that each chunk with a sourcemap links to it as `sourcemap` says. `File` and `Hidden` both
+/// emit the map as an asset, but only `File` points at it with a `sourceMappingURL` comment.
+fn assert_sourcemap_references(sourcemap: Option<SourceMapType>, assets: &[Output]) {
+  let Some(sourcemap) = sourcemap else { return };
+  for asset in assets {
+    let Output::Chunk(chunk) = asset else { continue };
+    if chunk.map.is_none() {
+      continue;
+    }
+    let has_comment = chunk.code.contains("# sourceMappingURL=");
+    let map_filename = format!("{}.map", chunk.filename);
+    let has_map_asset = assets.iter().any(|asset| asset.filename() == map_filename);
+    match sourcemap {
+      SourceMapType::File => {
+        assert!(has_map_asset, "{} should emit {map_filename}", chunk.filename);
+        assert!(has_comment, "{} should link to its sourcemap", chunk.filename);
+      }
+      SourceMapType::Hidden => {
+        assert!(has_map_asset, "{} should emit {map_filename}", chunk.filename);
+        assert!(!has_comment, "{} shouldn't link to its hidden sourcemap", chunk.filename);
+      }
+      SourceMapType::Inline => {
+        assert!(!has_map_asset, "{} shouldn't emit {map_filename}", chunk.filename);
+        assert!(has_comment, "{} should inline its sourcemap", chunk.filename);
+      }
+    }
   }
 }
+
+/// `length` rounded up to a power of two, as `<= 64 B`, so module sizes in snapshots only change
+/// when a module roughly doubles or halves.
+fn size_bucket(length: u32) -> String {
+  format!("<= {} B", length.max(1).next_power_of_two())
+}
@@ -2,5 +2,6 @@ pub mod bundler_options_presets;
 pub mod fixture;
 mod hmr_files;
 pub mod integration_test;
+pub mod memory_file_system;
 pub mod test_config;
 pub mod utils;
@@ -0,0 +1,108 @@
+use std::{
+  io,
+  path::{Path, PathBuf},
+  sync::Mutex,
+};
+
+use oxc_resolver::{FileMetadata, FileSystem as OxcResolverFileSystem};
+use rolldown_fs::FileSystem;
+use rustc_hash::FxHashMap;
+
+/// A file system that only exists in memory, to bundle a project given as a map of files and
+/// inspect what `Bundler::write` wrote without touching the disk.
+///
+/// Only files are stored, a directory exists as long as a file is inside it.
+#[derive(Debug, Default)]
+pub struct InMemoryFileSystem {
+  files: Mutex<FxHashMap<PathBuf, Vec<u8>>>,
+}
+
+impl InMemoryFileSystem {
+  pub fn new<P: Into<PathBuf>, C: Into<Vec<u8>>>(files: impl IntoIterator<Item = (P, C)>) -> Self {
+    let files = files.into_iter().map(|(path, content)| (path.into(), content.into())).collect();
+    Self { files: Mutex::new(files) }
+  }
+
+  /// The content of the file as utf8, if there is such a file.
+  pub fn file(&self, path: impl AsRef<Path>) -> Option<String> {
+    let files = self.files.lock().unwrap();
+    files.get(path.as_ref()).map(|content| String::from_utf8_lossy(content).into_owned())
+  }
+
+  /// Paths of the files inside `dir`, sorted.
+  pub fn files_in(&self, dir: impl AsRef<Path>) -> Vec<PathBuf> {
+    let files = self.files.lock().unwrap();
+    let mut paths =
+      files.keys().filter(|path| path.starts_with(dir.as_ref())).cloned().collect::<Vec<_>>();
+    paths.sort_unstable();
+    paths
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    self.files.lock().unwrap().keys().any(|file| file != path && file.starts_with(path))
+  }
+}
+
+fn not_found(path: &Path) -> io::Error {
+  io::Error::new(io::ErrorKind::NotFound, format!("{} doesn't exist", path.display()))
+}
+
+impl FileSystem for InMemoryFileSystem {
+  fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+    self.files.lock().unwrap().retain(|file, _| !file.starts_with(path));
+    Ok(())
+  }
+
+  fn create_dir_all(&self, _path: &Path) -> io::Result<()> {
+    Ok(())
+  }
+
+  fn write(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+    self.files.lock().unwrap().insert(path.to_path_buf(), content.to_vec());
+    Ok(())
+  }
+
+  fn exists(&self, path: &Path) -> bool {
+    self.files.lock().unwrap().contains_key(path) || self.is_dir(path)
+  }
+
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    self.files.lock().unwrap().get(path).cloned().ok_or_else(|| not_found(path))
+  }
+
+  fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+    let mut files = self.files.lock().unwrap();
+    let content = files.remove(from).ok_or_else(|| not_found(from))?;
+    files.insert(to.to_path_buf(), content);
+    Ok(())
+  }
+
+  fn remove_file(&self, path: &Path) -> io::Result<()> {
+    self.files.lock().unwrap().remove(path).map(|_| ()).ok_or_else(|| not_found(path))
+  }
+}
+
+impl OxcResolverFileSystem for InMemoryFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    String::from_utf8(self.read(path)?)
+      .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    if self.files.lock().unwrap().contains_key(path) {
+      Ok(FileMetadata::new(true, false, false))
+    } else if self.is_dir(path) {
+      Ok(FileMetadata::new(false, true, false))
+    } else {
+      Err(not_found(path))
+    }
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.metadata(path)
+  }
+
+  fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+    Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{} isn't a symlink", path.display())))
+  }
+}
@@ -27,9 +27,14 @@ static COMPILED_SCHEMA: LazyLock<Validator> = LazyLock::new(|| {
 
 #[allow(clippy::unnecessary_debug_formatting)]
 pub fn read_test_config(config_path: &std::path::Path) -> TestConfig {
-  let mut config_str = fs::read_to_string(config_path)
+  let config_str = fs::read_to_string(config_path)
     .unwrap_or_else(|e| panic!("Failed to read config file in {config_path:?}. Got {e:?}"));
+  parse_test_config(config_str, config_path)
+}
 
+/// `config_path` is only used to name the config in the errors.
+#[allow(clippy::unnecessary_debug_formatting)]
+fn parse_test_config(mut config_str: String, config_path: &std::path::Path) -> TestConfig {
   json_strip_comments::strip(&mut config_str)
     .unwrap_or_else(|e| panic!("Failed to strip comments of {config_path:?}. Got {e:?}"));
 
@@ -41,7 +46,30 @@ pub fn read_test_config(config_path: &std::path::Path) -> TestConfig {
   for error in errors {
     writeln!(msg, "Validation error: {} in {}", error, error.instance_path).unwrap();
   }
-  assert!(msg.is_empty(), "Failed to validate test config {config_path:?}. Got {msg}");
+  if !msg.is_empty() {
+    // Errors under an `anyOf` don't name the offending key, while serde's do.
+    if let Err(e) = serde_json::from_value::<TestConfig>(config_json) {
+      writeln!(msg, "Parse error: {e}").unwrap();
+    }
+    panic!("Failed to validate test config {config_path:?}. Got {msg}");
+  }
 
   serde_json::from_value(config_json).expect("Failed to parse test config file")
 }
+
+#[cfg(test)]
+fn parse_test_config_str(config: &str) -> TestConfig {
+  parse_test_config(config.to_string(), std::path::Path::new("_config.json"))
+}
+
+#[test]
+#[should_panic(expected = "'expectErrro' was unexpected")]
+fn unknown_meta_keys_are_rejected() {
+  parse_test_config_str(r#"{ "expectErrro": true }"#);
+}
+
+#[test]
+#[should_panic(expected = "unknown field `strictExecutionOrdr`")]
+fn unknown_nested_keys_are_named() {
+  parse_test_config_str(r#"{ "config": { "experimental": { "strictExecutionOrdr": true } } }"#);
+}
@@ -1,9 +1,15 @@
-use std::{borrow::Cow, path::Path, sync::LazyLock};
+use std::{
+  borrow::Cow,
+  fmt::Write as _,
+  path::{Path, PathBuf},
+  sync::LazyLock,
+};
 
 use regex::Regex;
 use rolldown::BundleOutput;
 use rolldown_common::{BundlerOptions, Output};
-use rolldown_error::DiagnosticOptions;
+use rolldown_error::{ColorMode, DiagnosticOptions};
+use sha2::{Digest, Sha256};
 
 pub fn assert_bundled(options: BundlerOptions) {
   let result = tokio::runtime::Builder::new_multi_thread()
@@ -45,13 +51,13 @@ pub fn stringify_bundle_output(output: BundleOutput, cwd: &Path) -> String {
     ret.push_str("# warnings\n\n");
     let diagnostics = warnings
       .into_iter()
-      .map(|e| (e.kind(), e.to_diagnostic_with(&DiagnosticOptions { cwd: cwd.to_path_buf() })));
+      .map(|e| (e.kind(), e.to_diagnostic_with(&snapshot_diagnostic_options(cwd))));
     let rendered = diagnostics
       .flat_map(|(code, diagnostic)| {
         [
           Cow::Owned(format!("## {code}\n")),
           "```text".into(),
-          Cow::Owned(diagnostic.to_string()),
+          Cow::Owned(diagnostic.to_string_with_color(ColorMode::Never)),
           "```".into(),
         ]
       })
@@ -83,6 +89,11 @@ pub fn stringify_bundle_output(output: BundleOutput, cwd: &Path) -> String {
   ret
 }
 
+/// Turn `\r\n` into `\n`, so fixtures authored on Windows render the same snapshot on every platform.
+pub(crate) fn normalize_line_endings(content: Cow<'_, str>) -> Cow<'_, str> {
+  if content.contains("\r\n") { Cow::Owned(content.replace("\r\n", "\n")) } else { content }
+}
+
 pub(crate) static RUNTIME_MODULE_OUTPUT_RE: LazyLock<Regex> = LazyLock::new(|| {
   Regex::new(r"(//#region rolldown:runtime[\s\S]*?//#endregion)")
     .expect("invalid runtime module output regex")
@@ -103,3 +114,85 @@ macro_rules! abs_file_dir {
     std::path::Path::new(env!("WORKSPACE_DIR")).join(file!()).parent().unwrap().to_path_buf()
   };
 }
+
+/// A directory under `std::env::temp_dir()` for tests that have to change files on disk. The process
+/// id is part of its name, so concurrent test runs don't share it, and it's removed on drop, even if
+/// the test panics.
+pub struct TempDir {
+  path: PathBuf,
+}
+
+impl TempDir {
+  /// Creates an empty `rolldown-<name>-<pid>` directory, removing the leftovers of an earlier run.
+  pub fn new(name: &str) -> Self {
+    let path = std::env::temp_dir().join(format!("rolldown-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&path);
+    std::fs::create_dir_all(&path).expect("should create the temp dir");
+    Self { path }
+  }
+
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+}
+
+impl Drop for TempDir {
+  fn drop(&mut self) {
+    let _ = std::fs::remove_dir_all(&self.path);
+  }
+}
+
+/// Diagnostics in snapshots are colorless, whatever the environment the tests run in. The code
+/// frames are pinned too, so changing their defaults doesn't touch every snapshot.
+pub fn snapshot_diagnostic_options(cwd: &Path) -> DiagnosticOptions {
+  DiagnosticOptions {
+    cwd: cwd.to_path_buf(),
+    color: ColorMode::Never,
+    context_lines: 1,
+    max_line_width: Some(100),
+  }
+}
+
+pub fn assert_colorless(snapshot: &str) {
+  assert!(!snapshot.contains("\x1b["), "Snapshots should not contain ANSI escapes:\n{snapshot}");
+}
+
+/// Binary contents up to this length are snapshot as a hex dump, longer ones as their digest.
+const HEX_DUMP_MAX_LEN: usize = 512;
+
+/// Renders bytes for `snapshotBytes`. Valid utf-8 is rendered as text, other contents as a hex dump
+/// in the format of `hexdump -C`, or as their sha256 digest and length if they're too long to
+/// review. Also returns the language of the code block, if it isn't the one of the file.
+pub fn render_bytes_for_snapshot(bytes: &[u8]) -> (Option<&'static str>, Cow<'_, str>) {
+  if let Ok(text) = std::str::from_utf8(bytes) {
+    return (None, Cow::Borrowed(text));
+  }
+  if bytes.len() > HEX_DUMP_MAX_LEN {
+    let digest = Sha256::digest(bytes);
+    let digest = digest.iter().fold(String::new(), |mut hex, byte| {
+      write!(hex, "{byte:02x}").unwrap();
+      hex
+    });
+    return (Some("text"), Cow::Owned(format!("sha256: {digest}\nlength: {}", bytes.len())));
+  }
+  let mut dump = String::new();
+  for (line_idx, line) in bytes.chunks(16).enumerate() {
+    write!(dump, "{:08x} ", line_idx * 16).unwrap();
+    for (idx, byte) in line.iter().enumerate() {
+      // An extra space separates the two halves of the line.
+      if idx == 8 {
+        dump.push(' ');
+      }
+      write!(dump, " {byte:02x}").unwrap();
+    }
+    let missing = 16 - line.len();
+    dump.push_str(&" ".repeat(missing * 3 + usize::from(line.len() <= 8)));
+    let ascii = line
+      .iter()
+      .map(|&byte| if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' })
+      .collect::<String>();
+    writeln!(dump, "  |{ascii}|").unwrap();
+  }
+  write!(dump, "{:08x}", bytes.len()).unwrap();
+  (Some("text"), Cow::Owned(dump))
+}
@@ -1,6 +1,8 @@
 use std::fmt::Display;
 
-use rolldown_common::{BundlerOptions, OutputExports, OutputFormat, PreserveEntrySignatures};
+use rolldown_common::{
+  BundlerOptions, OutputExports, OutputFormat, Platform, PreserveEntrySignatures,
+};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -15,6 +17,11 @@ pub struct ConfigVariant {
   pub entry_filenames: Option<String>,
   pub inline_dynamic_imports: Option<bool>,
   pub preserve_entry_signatures: Option<PreserveEntrySignatures>,
+  pub platform: Option<Platform>,
+  pub hoist_transitive_imports: Option<bool>,
+  /// Replaces `resolve.conditionNames`, so e.g. an esm and a cjs variant can resolve a dependency to
+  /// its `import` and `require` entry points respectively.
+  pub condition_names: Option<Vec<String>>,
 }
 
 impl ConfigVariant {
@@ -45,6 +52,15 @@ impl ConfigVariant {
     if let Some(preserve_entry_signatures) = &self.preserve_entry_signatures {
       config.preserve_entry_signatures = Some(*preserve_entry_signatures);
     }
+    if let Some(platform) = &self.platform {
+      config.platform = Some(*platform);
+    }
+    if let Some(hoist_transitive_imports) = &self.hoist_transitive_imports {
+      config.hoist_transitive_imports = Some(*hoist_transitive_imports);
+    }
+    if let Some(condition_names) = &self.condition_names {
+      config.resolve.get_or_insert_default().condition_names = Some(condition_names.clone());
+    }
     config
   }
 }
@@ -73,6 +89,15 @@ impl Display for ConfigVariant {
     if let Some(preserve_entry_signatures) = &self.preserve_entry_signatures {
       fields.push(format!("preserve_entry_signatures: {preserve_entry_signatures:?}"));
     }
+    if let Some(platform) = &self.platform {
+      fields.push(format!("platform: {platform:?}"));
+    }
+    if let Some(hoist_transitive_imports) = &self.hoist_transitive_imports {
+      fields.push(format!("hoist_transitive_imports: {hoist_transitive_imports:?}"));
+    }
+    if let Some(condition_names) = &self.condition_names {
+      fields.push(format!("condition_names: {condition_names:?}"));
+    }
     fields.sort();
     if fields.is_empty() { write!(f, "()") } else { write!(f, "({})", fields.join(", ")) }
   }
@@ -108,20 +133,43 @@ pub struct TestMeta {
   /// If `true`, the fixture output stats will be snapshot.
   pub snapshot_output_stats: bool,
   #[serde(default)]
+  /// If `true`, the modules contained in each chunk will be snapshot.
+  pub snapshot_module_graph: bool,
+  #[serde(default)]
+  /// If `true`, the modules of each chunk will be snapshot with their sizes, rounded up to a power
+  /// of two so small changes to the output don't churn the snapshot.
+  pub snapshot_module_sizes: bool,
+  #[serde(default)]
+  /// If `true`, the interop helpers each chunk uses will be snapshot, along with the imports that
+  /// need them.
+  pub snapshot_interop: bool,
+  #[serde(default)]
   /// If `true`, the sourcemap visualizer will be snapshot.
   pub visualize_sourcemap: bool,
   #[serde(default)]
-  /// If `true`, bytes source will be snapshot.
+  /// If `true`, bytes source will be snapshot. Binary contents are snapshot as a hex dump, or as
+  /// their sha256 digest and length if they're longer than 512 bytes.
   pub snapshot_bytes: bool,
   #[serde(default = "true_by_default")]
   /// Default is `true`. If `false`, the runtime module will not be hidden.
   pub hidden_runtime_module: bool,
+  #[serde(default)]
+  /// If `true`, the runtime module is moved out of the chunks into a separate `# Runtime` section, so
+  /// changes to runtime helpers are reviewed apart from user code. Takes precedence over `hiddenRuntimeModule`.
+  pub isolate_runtime_module: bool,
   /// If `true`, the `[hash]` pattern will be inserted in the `xxxxFilenames`.
   #[serde(default)]
   pub hash_in_filename: bool,
   /// If `true`, the bundle will be called with `write()` instead of `generate()`.
   #[serde(default = "true_by_default")]
   pub write_to_disk: bool,
+  /// Default is `true`. If `true`, `\r\n` in the rendered chunks and string assets will be normalized to `\n`.
+  #[serde(default = "true_by_default")]
+  pub normalize_line_endings: bool,
+  /// Exit code the executed artifacts or `_test.mjs` are expected to end with. Default is `0`.
+  /// Any other exit code, or being terminated by a signal, fails the test.
+  #[serde(default)]
+  pub expected_exit_code: i32,
 }
 
 impl Default for TestMeta {
@@ -5,3 +5,7 @@ pub fn to_url_safe_base64(input: impl AsRef<[u8]>) -> String {
 pub fn to_standard_base64(input: impl AsRef<[u8]>) -> String {
   base64_simd::STANDARD.encode_to_string(input)
 }
+
+pub fn from_standard_base64(input: impl AsRef<[u8]>) -> Option<Vec<u8>> {
+  base64_simd::STANDARD.decode_to_vec(input).ok()
+}
@@ -3,14 +3,107 @@ use std::path::Path;
 use rolldown_std_utils::PathExt as _;
 use sugar_path::SugarPath as _;
 
+/// Turns a module id into the form used in diagnostics, snapshots and emitted file names.
+///
+/// Absolute paths become relative to `cwd`, with `/` as separator. Virtual ids get their `\0`
+/// escaped and other ids, such as bare specifiers and URLs, are kept as they are.
 pub fn stabilize_id(module_id: &str, cwd: &Path) -> String {
-  if module_id.as_path().is_absolute() {
-    module_id.relative(cwd).as_path().expect_to_slash()
-  } else if module_id.starts_with('\0') {
+  if module_id.starts_with('\0') {
     // handle virtual modules
     module_id.replace('\0', "\\0")
   } else {
-    module_id.to_string()
+    stabilize_absolute_path(module_id, cwd).unwrap_or_else(|| module_id.to_string())
+  }
+}
+
+/// `path` relative to `cwd` with `/` as separator, or `None` if `path` isn't absolute.
+///
+/// Windows paths are recognized on every platform. The `\\?\` long-path prefix is dropped and they
+/// are compared with `cwd` case-insensitively. The ones on another drive or share than `cwd` are
+/// kept absolute, as `C:/...` or `//server/share/...`.
+pub fn stabilize_absolute_path(path: &str, cwd: &Path) -> Option<String> {
+  if let Some(windows_path) = WindowsPath::parse(path) {
+    return Some(match cwd.to_str().and_then(WindowsPath::parse) {
+      Some(cwd) if cwd.root.eq_ignore_ascii_case(&windows_path.root) => windows_path.relative(&cwd),
+      _ => windows_path.to_slash(),
+    });
+  }
+  path.as_path().is_absolute().then(|| path.relative(cwd).as_path().expect_to_slash())
+}
+
+/// An absolute Windows path. It's parsed by hand, as `std::path` only knows about the paths of the
+/// platform it's built for.
+struct WindowsPath<'a> {
+  /// `C:` for a drive, `//server/share` for a UNC share.
+  root: String,
+  /// Normalized components after the root, without `.` and `..`.
+  components: Vec<&'a str>,
+}
+
+impl<'a> WindowsPath<'a> {
+  fn parse(path: &'a str) -> Option<Self> {
+    let (root, rest) = if let Some(verbatim) = path.strip_prefix(r"\\?\") {
+      match verbatim.get(..4) {
+        Some(unc) if unc.eq_ignore_ascii_case(r"UNC\") => Self::parse_unc(&verbatim[4..])?,
+        _ => Self::parse_drive(verbatim)?,
+      }
+    } else if let Some(unc) = path.strip_prefix(r"\\") {
+      Self::parse_unc(unc)?
+    } else {
+      Self::parse_drive(path)?
+    };
+
+    let mut components = vec![];
+    for component in rest.split(['\\', '/']) {
+      match component {
+        "" | "." => {}
+        ".." => {
+          components.pop();
+        }
+        component => components.push(component),
+      }
+    }
+    Some(Self { root, components })
+  }
+
+  /// Splits `C:\rest` or `C:/rest` into `C:` and `rest`.
+  fn parse_drive(path: &str) -> Option<(String, &str)> {
+    match path.as_bytes() {
+      [letter, b':', b'\\' | b'/', ..] if letter.is_ascii_alphabetic() => {
+        Some((path[..2].to_string(), &path[3..]))
+      }
+      _ => None,
+    }
+  }
+
+  /// Splits `server\share\rest` into `//server/share` and `rest`.
+  fn parse_unc(path: &str) -> Option<(String, &str)> {
+    let mut parts = path.splitn(3, ['\\', '/']);
+    let server = parts.next().filter(|server| !server.is_empty())?;
+    let share = parts.next().filter(|share| !share.is_empty())?;
+    Some((format!("//{server}/{share}"), parts.next().unwrap_or_default()))
+  }
+
+  /// Relative path from `base`, which has the same root, to this one.
+  fn relative(&self, base: &WindowsPath) -> String {
+    let common = self
+      .components
+      .iter()
+      .zip(&base.components)
+      .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+      .count();
+    let mut parts = vec![".."; base.components.len() - common];
+    parts.extend(&self.components[common..]);
+    parts.join("/")
+  }
+
+  fn to_slash(&self) -> String {
+    let mut path = self.root.clone();
+    for component in &self.components {
+      path.push('/');
+      path.push_str(component);
+    }
+    path
   }
 }
 
@@ -34,3 +127,42 @@ fn test_stabilize_id() {
   // virtual module
   assert_eq!(stabilize_id("\0foo", &cwd), "\\0foo");
 }
+
+#[test]
+fn test_stabilize_windows_id() {
+  let cwd = Path::new(r"C:\work\proj");
+  assert_eq!(stabilize_id(r"C:\work\proj\src\a.ts", cwd), "src/a.ts");
+  assert_eq!(stabilize_id(r"C:/work/proj/src/a.ts", cwd), "src/a.ts");
+  assert_eq!(stabilize_id(r"C:\work\shared\b.ts", cwd), "../shared/b.ts");
+  // Paths on Windows are case-insensitive.
+  assert_eq!(stabilize_id(r"c:\Work\PROJ\src\a.ts", cwd), "src/a.ts");
+  // On another drive, there is no relative path.
+  assert_eq!(stabilize_id(r"D:\lib\b.ts", cwd), "D:/lib/b.ts");
+
+  // virtual modules and URLs
+  assert_eq!(stabilize_id("\0C:\\work\\proj\\a.ts", cwd), "\\0C:\\work\\proj\\a.ts");
+  assert_eq!(stabilize_id("file:///C:/work/proj/a.ts", cwd), "file:///C:/work/proj/a.ts");
+}
+
+#[test]
+fn test_stabilize_windows_long_path() {
+  let cwd = Path::new(r"C:\work\proj");
+  assert_eq!(stabilize_id(r"\\?\C:\work\proj\src\a.ts", cwd), "src/a.ts");
+  // `canonicalize` gives the cwd the prefix too.
+  assert_eq!(stabilize_id(r"C:\work\proj\src\a.ts", Path::new(r"\\?\C:\work\proj")), "src/a.ts");
+  // Without a Windows cwd, the prefix is still dropped.
+  assert_eq!(
+    stabilize_id(r"\\?\C:\work\proj\src\a.ts", Path::new("/home")),
+    "C:/work/proj/src/a.ts"
+  );
+}
+
+#[test]
+fn test_stabilize_unc_path() {
+  let cwd = Path::new(r"\\server\share\proj");
+  assert_eq!(stabilize_id(r"\\server\share\proj\src\a.ts", cwd), "src/a.ts");
+  assert_eq!(stabilize_id(r"\\?\UNC\server\share\proj\src\a.ts", cwd), "src/a.ts");
+  assert_eq!(stabilize_id(r"\\SERVER\Share\lib\b.ts", cwd), "../lib/b.ts");
+  assert_eq!(stabilize_id(r"\\other\share\b.ts", cwd), "//other/share/b.ts");
+  assert_eq!(stabilize_id(r"C:\work\a.ts", cwd), "C:/work/a.ts");
+}
@@ -6,4 +6,8 @@ export type Module = {
   is_external: boolean;
   imports: Array<ModuleImport> | null;
   importers: Array<string> | null;
+  /**
+   * Custom data attached to the module by plugins.
+   */
+  meta: Record<string, unknown>;
 };
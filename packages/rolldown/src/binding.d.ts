@@ -1143,6 +1143,8 @@ export declare class BindingNormalizedOptions {
   get globals(): Record<string, string> | undefined
   get hashCharacters(): 'base64' | 'base36' | 'hex'
   get sourcemapDebugIds(): boolean
+  get sourcemapIncludeSources(): boolean
+  get compact(): boolean
   get minify(): false | BindingMinifyOptions
   get polyfillRequire(): boolean
   get legalComments(): 'none' | 'inline'
@@ -1170,9 +1172,12 @@ export declare class BindingOutputChunk {
   get modules(): BindingModules
   get imports(): Array<string>
   get dynamicImports(): Array<string>
+  get importedBindings(): Record<string, Array<string>>
+  get importedCss(): Array<string>
   get code(): string
   get map(): string | null
   get sourcemapFileName(): string | null
+  get debugId(): string | null
   get preliminaryFileName(): string
   get name(): string
 }
@@ -1214,10 +1219,14 @@ export declare class BindingRenderedChunkMeta {
 export declare class BindingRenderedModule {
   get code(): string | null
   get renderedExports(): Array<string>
+  get originalLength(): number
+  get renderedLength(): number
+  get minifiedLength(): number | null
 }
 
 export declare class BindingTransformPluginContext {
   getCombinedSourcemap(): string
+  addWatchFile(file: string): void
   inner(): BindingPluginContext
 }
 
@@ -1330,6 +1339,7 @@ export type BindingBuiltinPluginName =  'builtin:alias'|
 'builtin:vite-resolve'|
 'builtin:wasm-fallback'|
 'builtin:wasm-helper'|
+'builtin:web-worker'|
 'builtin:web-worker-post';
 
 export interface BindingBundlerOptions {
@@ -1350,6 +1360,12 @@ export interface BindingChecksOptions {
   commonJsVariableInEsm?: boolean
   importIsUndefined?: boolean
   configurationFieldConflict?: boolean
+  unsupportedImportAttributes?: boolean
+  shimmedExport?: boolean
+  pluginWarning?: boolean
+  hmrExportChange?: boolean
+  duplicatePackage?: boolean
+  pluginOrder?: boolean
 }
 
 export interface BindingDebugOptions {
@@ -1386,7 +1402,9 @@ export interface BindingEmittedChunk {
 export interface BindingExperimentalHmrOptions {
   host?: string
   port?: number
-  implement?: string
+  implement?: string | BindingHmrImplementPerFormat
+  exportChange?: 'full-reload' | 'warn'
+  maxPatchSize?: number
 }
 
 export interface BindingExperimentalOptions {
@@ -1396,6 +1414,7 @@ export interface BindingExperimentalOptions {
   resolveNewUrlToAsset?: boolean
   hmr?: BindingExperimentalHmrOptions
   attachDebugInfo?: BindingAttachDebugInfo
+  deterministic?: boolean
 }
 
 export interface BindingFilterToken {
@@ -1403,21 +1422,61 @@ export interface BindingFilterToken {
   payload?: BindingStringOrRegex | number | boolean
 }
 
+export interface BindingFullReloadReason {
+  kind: 'no-accepting-boundary' | 'circular-accept-chain' | 'new-module-added' | 'html-or-config-change' | 'exports-changed' | 'module-deleted' | 'unknown'
+  module?: string
+}
+
+export interface BindingGeneratedCodeOptions {
+  preset?: 'es5' | 'es2015'
+  arrowFunctions?: boolean
+  constBindings?: boolean
+  objectShorthand?: boolean
+  symbols?: boolean
+}
+
 export interface BindingHmrBoundaryOutput {
   boundary: string
   acceptedVia: string
+  /**
+   * `acceptedVia` is dynamically imported by `boundary`. If it wasn't evaluated yet, the patch
+   * leaves it alone and only its cached copy needs to be dropped.
+   */
+  unevaluatedOk: boolean
+}
+
+export interface BindingHmrImplementPerFormat {
+  esm?: string
+  cjs?: string
+  iife?: string
+  umd?: string
 }
 
 export interface BindingHmrOutputPatch {
+  /** Code of the patch if it wasn't split, empty otherwise. */
   code: string
   filename: string
   sourcemap?: string
   sourcemapFilename?: string
+  /** Scripts to run in order, more than one when the patch exceeds `maxPatchSize`. */
+  patches: Array<BindingHmrPatch>
   hmrBoundaries: Array<BindingHmrBoundaryOutput>
   fullReload: boolean
   firstInvalidatedBy?: string
+  allInvalidatedBy: Array<string>
   isSelfAccepting: boolean
+  /** Modules re-executed by the patch, in the order their `dispose` handlers are called. */
+  modulesToDispose: Array<string>
+  /** The full reload reason rendered as a message. */
   fullReloadReason?: string
+  fullReloadReasonDetail?: BindingFullReloadReason
+}
+
+export interface BindingHmrPatch {
+  code: string
+  filename: string
+  sourcemap?: string
+  sourcemapFilename?: string
 }
 
 export interface BindingHookFilter {
@@ -1508,6 +1567,8 @@ export interface BindingInjectImportNamespace {
 export interface BindingInputItem {
   name?: string
   import: string
+  fileName?: string
+  banner?: string
 }
 
 export interface BindingInputOptions {
@@ -1535,6 +1596,7 @@ export interface BindingInputOptions {
   deferSyncScanData?: undefined | (() => BindingDeferSyncScanData[])
   makeAbsoluteExternalsRelative?: BindingMakeAbsoluteExternalsRelative
   debug?: BindingDebugOptions
+  debugPluginOrder?: boolean
   invalidateJsSideCache?: () => void
   markModuleLoaded?: (id: string, success: boolean) => void
   preserveEntrySignatures?: BindingPreserveEntrySignatures
@@ -1542,12 +1604,19 @@ export interface BindingInputOptions {
 
 export interface BindingIsolatedDeclarationPluginConfig {
   stripInternal?: boolean
+  /**
+   * Only emit declarations for entry modules and the modules their declarations import or
+   * re-export from.
+   */
+  entriesOnly?: boolean
 }
 
 export interface BindingJsonPluginConfig {
   minify?: boolean
   namedExports?: boolean
   stringify?: BindingJsonPluginStringify
+  json5?: boolean
+  jsonLines?: boolean
 }
 
 export type BindingJsonPluginStringify =
@@ -1661,6 +1730,7 @@ export interface BindingOutputOptions {
   cssChunkFileNames?: string | ((chunk: PreRenderedChunk) => string)
   sanitizeFileName?: boolean | ((name: string) => string)
   banner?: (chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>
+  compact?: boolean
   dir?: string
   file?: string
   esModule?: boolean | 'if-default-prop'
@@ -1669,16 +1739,22 @@ export interface BindingOutputOptions {
   externalLiveBindings?: boolean
   footer?: (chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>
   format?: 'es' | 'cjs' | 'iife' | 'umd'
+  generatedCode?: BindingGeneratedCodeOptions
   globals?: Record<string, string> | ((name: string) => string)
   hashCharacters?: 'base64' | 'base36' | 'hex'
+  hoistTransitiveImports?: boolean
   inlineDynamicImports?: boolean
   intro?: (chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>
   outro?: (chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>
+  paths?: Record<string, string> | ((id: string) => string)
   plugins: (BindingBuiltinPlugin | BindingPluginOptions | undefined)[]
   sourcemap?: 'file' | 'inline' | 'hidden'
   sourcemapIgnoreList?: (source: string, sourcemapPath: string) => boolean
   sourcemapDebugIds?: boolean
+  sourcemapIncludeSources?: boolean
+  sourcemapUrl?: string | ((mapFileName: string) => string)
   sourcemapPathTransform?: (source: string, sourcemapPath: string) => string
+  manifest?: string
   minify?: boolean | 'dce-only' | BindingMinifyOptions
   advancedChunks?: BindingAdvancedChunksOptions
   legalComments?: 'none' | 'inline'
@@ -1714,6 +1790,7 @@ export interface BindingPluginContextResolveOptions {
 
 export interface BindingPluginHookMeta {
   order?: BindingPluginOrder
+  priority?: number
 }
 
 export interface BindingPluginOptions {
@@ -133,6 +133,10 @@ export function assetPlugin(
   return new BuiltinPlugin('builtin:asset', config);
 }
 
+export function webWorkerPlugin(): BuiltinPlugin {
+  return new BuiltinPlugin('builtin:web-worker');
+}
+
 export function webWorkerPostPlugin(): BuiltinPlugin {
   return new BuiltinPlugin('builtin:web-worker-post');
 }
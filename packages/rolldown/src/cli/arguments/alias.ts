@@ -79,6 +79,10 @@ export const alias: Partial<Record<keyof CliOptions, OptionConfig>> = {
     default: 'strict',
     reverse: true,
   },
+  sourcemapIncludeSources: {
+    default: true,
+    reverse: true,
+  },
   moduleTypes: {
     hint: 'types',
   },
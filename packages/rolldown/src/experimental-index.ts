@@ -29,6 +29,7 @@ export {
   viteResolvePlugin,
   wasmFallbackPlugin,
   wasmHelperPlugin,
+  webWorkerPlugin,
   webWorkerPostPlugin,
 } from './builtin-plugin/constructors';
 
@@ -22,6 +22,7 @@ import type {
 } from './log/logging';
 import type {
   ExternalOption,
+  InputItemOption,
   InputOption,
   InputOptions,
   ModuleTypes,
@@ -39,6 +40,7 @@ import type {
   MinifyOptions,
   ModuleFormat,
   OutputOptions,
+  PathsFunction,
   PreRenderedAsset,
 } from './options/output-options';
 import type { WatchOptions } from './options/watch-options';
@@ -120,6 +122,7 @@ export type {
   HookFilter,
   HookFilterExtension,
   ImportKind,
+  InputItemOption,
   InputOption,
   InputOptions,
   InternalModuleFormat,
@@ -146,6 +149,7 @@ export type {
   ParallelPluginHooks,
   PartialNull,
   PartialResolvedId,
+  PathsFunction,
   Plugin,
   PluginContext,
   PluginContextMeta,
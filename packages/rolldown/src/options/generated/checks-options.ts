@@ -67,4 +67,40 @@ export interface ChecksOptions {
    * @default true
    */
   configurationFieldConflict?: boolean;
+
+  /**
+   * Whether to emit warning when import attributes of an external module can't be kept in the output format
+   * @default true
+   */
+  unsupportedImportAttributes?: boolean;
+
+  /**
+   * Whether to emit warning when a missing export is shimmed by `shimMissingExports`
+   * @default true
+   */
+  shimmedExport?: boolean;
+
+  /**
+   * Whether to emit warnings reported by plugins through `PluginContext::warn`
+   * @default true
+   */
+  pluginWarning?: boolean;
+
+  /**
+   * Whether to emit warning when a hot update changes the exports of a module accepting it, with `experimental.hmr.exportChange: 'warn'`
+   * @default true
+   */
+  hmrExportChange?: boolean;
+
+  /**
+   * Whether to emit warning when a package is bundled in more than one version
+   * @default true
+   */
+  duplicatePackage?: boolean;
+
+  /**
+   * Whether to emit the order plugin hooks run in, with `debugPluginOrder: true`
+   * @default true
+   */
+  pluginOrder?: boolean;
 }
@@ -11,7 +11,23 @@ import type { TreeshakingOptions } from '../types/module-side-effects';
 import type { NullValue, StringOrRegExp } from '../types/utils';
 import type { ChecksOptions } from './generated/checks-options';
 
-export type InputOption = string | string[] | Record<string, string>;
+export interface InputItemOption {
+  import: string;
+  /**
+   * Emits the chunk of this entry under the given path relative to the output directory,
+   * instead of the one rendered from `output.entryFileNames`. No hash is inserted.
+   */
+  fileName?: string;
+  /**
+   * Replaces `output.banner` for the chunk of this entry, e.g. to start an executable with a shebang.
+   */
+  banner?: string;
+}
+
+export type InputOption =
+  | string
+  | string[]
+  | Record<string, string | InputItemOption>;
 
 // Omit those key that are part of rolldown option
 type OxcTransformOption = Omit<
@@ -56,7 +72,16 @@ export interface WatcherOptions {
     pollInterval?: number;
     compareContents?: boolean;
   };
+  /**
+   * Only watch the files matching these patterns. A match also watches files under `node_modules`
+   * or the output directory, which are excluded by default.
+   */
   include?: StringOrRegExp | StringOrRegExp[];
+  /**
+   * Don't watch the files matching these patterns, in addition to the ones under `node_modules` and
+   * the output directory. Editing an excluded file doesn't trigger a rebuild, even if it is part of
+   * the bundle, so the output can be out of date until another change triggers one.
+   */
   exclude?: StringOrRegExp | StringOrRegExp[];
 }
 
@@ -65,7 +90,28 @@ type MakeAbsoluteExternalsRelative = boolean | 'ifRelativeSource';
 export type HmrOptions = boolean | {
   host?: string;
   port?: number;
-  implement?: string;
+  /**
+   * Source of a custom hmr runtime. Pass an object to use a different runtime per output format,
+   * building a format without one is an error.
+   */
+  implement?: string | {
+    esm?: string;
+    cjs?: string;
+    iife?: string;
+    umd?: string;
+  };
+  /**
+   * What to do when an edit adds or removes exports of a module accepting its own updates.
+   * Its importers keep the bindings they imported before, so it triggers a full reload by default.
+   *
+   * @default 'full-reload'
+   */
+  exportChange?: 'full-reload' | 'warn';
+  /**
+   * Maximum size in bytes of a single hmr patch.
+   * Larger patches are split into several scripts that must be executed in order.
+   */
+  maxPatchSize?: number;
 };
 
 export type AttachDebugOptions = 'none' | 'simple' | 'full';
@@ -91,6 +137,14 @@ export interface InputOptions {
      */
     alias?: Record<string, string[] | string>;
     aliasFields?: string[][];
+    /**
+     * Extra conditions to match against the `exports` and `imports` fields of `package.json`, e.g. `['development']`.
+     *
+     * They are added on top of the built-in conditions:
+     * - `default`, which is always active.
+     * - `import` for `import` statements and `import()`, `require` for `require()` calls.
+     * - `node` when `platform` is `'node'`, `browser` when `platform` is `'browser'`. `platform` itself defaults to `'node'` for the `cjs` format and `'browser'` otherwise.
+     */
     conditionNames?: string[];
     /**
      * Map of extensions to alternative extensions.
@@ -111,6 +165,11 @@ export interface InputOptions {
   /**
    * Expected platform where the code run.
    *
+   * `'neutral'` makes no assumption about the environment, which suits isomorphic libraries:
+   * - Node.js builtins aren't externalized automatically. `node:` imports are left external with an `UNRESOLVED_IMPORT` warning, add them to `external` to silence it.
+   * - No `define`s like `process.env.NODE_ENV` are applied.
+   * - Packages are resolved with the `main` field and the `default`/`module`/`import`/`require` conditions only.
+   *
    * @default
    * - 'node' if the format is 'cjs'
    * - 'browser' for other formats
@@ -139,6 +198,14 @@ export interface InputOptions {
     resolveNewUrlToAsset?: boolean;
     hmr?: HmrOptions;
     attachDebugInfo?: AttachDebugOptions;
+    /**
+     * Order modules, chunks, emitted assets and hash inputs by their stable ids instead of the order
+     * they were discovered in, so the same input produces byte-identical outputs across runs and
+     * machines, at a small performance cost.
+     *
+     * @default false
+     */
+    deterministic?: boolean;
   };
   /**
    * Replace global variables or [property accessors](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Property_accessors) with the provided values.
@@ -224,12 +291,24 @@ export interface InputOptions {
   transform?: OxcTransformOption;
   watch?: WatcherOptions | false;
   dropLabels?: string[];
+  /**
+   * Keep the `name` property of functions and classes, even if they are renamed while bundling or mangled by `minify`.
+   * Similar to esbuild's `--keep-names`.
+   *
+   * @default false
+   */
   keepNames?: boolean;
   checks?: ChecksOptions;
   makeAbsoluteExternalsRelative?: MakeAbsoluteExternalsRelative;
   debug?: {
     sessionId?: string;
   };
+  /**
+   * Log the order in which plugins run for each hook as an info log with the `PLUGIN_ORDER` code.
+   *
+   * @default false
+   */
+  debugPluginOrder?: boolean;
   preserveEntrySignatures?:
     | false
     | 'strict'
@@ -6,6 +6,7 @@ import type {
 import type {
   SourcemapIgnoreListOption,
   SourcemapPathTransformOption,
+  SourcemapUrlOption,
 } from '../types/misc';
 import { bindingifySourcemapIgnoreList } from '../utils/bindingify-output-options';
 import type {
@@ -41,9 +42,12 @@ export interface NormalizedOutputOptions {
   globals: Record<string, string> | GlobalsFunction;
   hashCharacters: 'base64' | 'base36' | 'hex';
   sourcemapDebugIds: boolean;
+  sourcemapIncludeSources: boolean;
+  sourcemapUrl: string | SourcemapUrlOption | undefined;
   sourcemapIgnoreList: SourcemapIgnoreListOption;
   sourcemapPathTransform: SourcemapPathTransformOption | undefined;
   minify: false | BindingMinifyOptions;
+  compact: boolean;
   legalComments: 'none' | 'inline';
   polyfillRequire: boolean;
   plugins: RolldownPlugin[];
@@ -154,6 +158,14 @@ export class NormalizedOutputOptionsImpl implements NormalizedOutputOptions {
     return this.inner.sourcemapDebugIds;
   }
 
+  get sourcemapIncludeSources(): boolean {
+    return this.inner.sourcemapIncludeSources;
+  }
+
+  get sourcemapUrl(): string | SourcemapUrlOption | undefined {
+    return this.outputOptions.sourcemapUrl;
+  }
+
   get sourcemapIgnoreList(): SourcemapIgnoreListOption {
     return bindingifySourcemapIgnoreList(
       this.outputOptions.sourcemapIgnoreList,
@@ -168,6 +180,10 @@ export class NormalizedOutputOptionsImpl implements NormalizedOutputOptions {
     return this.inner.minify;
   }
 
+  get compact(): boolean {
+    return this.inner.compact;
+  }
+
   get legalComments(): 'none' | 'inline' {
     return this.inner.legalComments;
   }
@@ -3,6 +3,7 @@ import type { RolldownOutputPluginOption } from '../plugin';
 import type {
   SourcemapIgnoreListOption,
   SourcemapPathTransformOption,
+  SourcemapUrlOption,
 } from '../types/misc';
 import type { RenderedChunk } from '../types/rolldown-output';
 import type { StringOrRegExp } from '../types/utils';
@@ -31,6 +32,8 @@ export type AssetFileNamesFunction = (chunkInfo: PreRenderedAsset) => string;
 
 export type GlobalsFunction = (name: string) => string;
 
+export type PathsFunction = (id: string) => string;
+
 export type MinifyOptions = BindingMinifyOptions;
 
 export interface OutputOptions {
@@ -50,6 +53,22 @@ export interface OutputOptions {
   format?: ModuleFormat;
   sourcemap?: boolean | 'inline' | 'hidden';
   sourcemapDebugIds?: boolean;
+  /**
+   * Whether to embed the code of the sources in the sourcemaps as `sourcesContent`.
+   *
+   * @default true
+   */
+  sourcemapIncludeSources?: boolean;
+  /**
+   * File name of a JSON asset describing each entry: its file, the chunks it imports statically and dynamically, and the assets emitted for its modules. Keyed by the entry module id relative to `cwd`.
+   */
+  manifest?: string;
+  /**
+   * The URL of the map in the `//# sourceMappingURL=` comment of each chunk, for `sourcemap: true`.
+   *
+   * A string is a base URL, such as `https://cdn.example.com/maps/`, followed by the path of the map in the output directory. A function gets that path and returns the URL.
+   */
+  sourcemapUrl?: string | SourcemapUrlOption;
   sourcemapIgnoreList?: boolean | SourcemapIgnoreListOption;
   sourcemapPathTransform?: SourcemapPathTransformOption;
   banner?: string | AddonFunction;
@@ -65,8 +84,57 @@ export interface OutputOptions {
   cssChunkFileNames?: string | ChunkFileNamesFunction;
   sanitizeFileName?: boolean | ((name: string) => string);
   minify?: boolean | 'dce-only' | MinifyOptions;
+  /**
+   * Print the code without the whitespace that isn't needed. Unlike `minify`, the identifiers and the syntax are kept as they are.
+   *
+   * @default false
+   */
+  compact?: boolean;
   name?: string;
   globals?: Record<string, string> | GlobalsFunction;
+  /**
+   * Maps external module ids to the specifiers written into the output, e.g. `{ react: 'https://esm.sh/react@18' }`.
+   *
+   * Applies to imports, re-exports, `require` calls and dynamic imports of external modules. Relative externals are matched by their id relative to `cwd`. Unmapped externals are rendered unchanged.
+   */
+  paths?: Record<string, string> | PathsFunction;
+  /**
+   * Controls the syntax of the code rolldown generates itself, i.e. runtime helpers and the wrappers of `cjs`, `iife` and `umd` chunks. User code is not affected.
+   *
+   * Accepts a preset name as a shorthand for `{ preset }`.
+   */
+  generatedCode?: 'es5' | 'es2015' | {
+    /**
+     * The syntax level the other options default to. `es5` disables arrow functions, `const` bindings and object shorthands.
+     *
+     * @default 'es2015'
+     */
+    preset?: 'es5' | 'es2015';
+    /**
+     * Use arrow functions in runtime helpers and module wrappers. Set to `false` to emit function expressions instead.
+     *
+     * @default true
+     */
+    arrowFunctions?: boolean;
+    /**
+     * Use `const` for the bindings created for `require` calls in `cjs` chunks. Set to `false` to emit `var` instead.
+     *
+     * @default true
+     */
+    constBindings?: boolean;
+    /**
+     * Use shorthand properties and methods in runtime helpers and module wrappers. Set to `false` to emit `key: value` properties instead.
+     *
+     * @default true
+     */
+    objectShorthand?: boolean;
+    /**
+     * Add `Symbol.toStringTag: 'Module'` to the exports of `cjs`, `iife` and `umd` chunks that are marked with `__esModule`.
+     *
+     * @default false
+     */
+    symbols?: boolean;
+  };
   externalLiveBindings?: boolean;
   inlineDynamicImports?: boolean;
   /**
@@ -221,13 +289,23 @@ export interface OutputOptions {
   legalComments?: 'none' | 'inline';
   plugins?: RolldownOutputPluginOption;
   polyfillRequire?: boolean;
-  hoistTransitiveImports?: false;
+  /**
+   * Whether entry chunks should import the chunks of their transitive dependencies as well, so they are loaded in parallel.
+   *
+   * If `false`, chunks only import the chunks of their direct dependencies.
+   *
+   * Defaults to `true`, which is how rolldown has always emitted entry chunks.
+   *
+   * @default true
+   */
+  hoistTransitiveImports?: boolean;
   preserveModules?: boolean;
   virtualDirname?: string;
   preserveModulesRoot?: string;
 }
 
 interface OverwriteOutputOptionsForCli {
+  sourcemapUrl?: string;
   banner?: string;
   footer?: string;
   intro?: string;
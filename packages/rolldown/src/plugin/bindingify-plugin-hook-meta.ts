@@ -6,6 +6,7 @@ export function bindingifyPluginHookMeta(
 ): BindingPluginHookMeta {
   return {
     order: bindingPluginOrder(options.order),
+    priority: options.priority,
   };
 }
 
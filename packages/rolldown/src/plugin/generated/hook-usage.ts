@@ -23,6 +23,7 @@ export enum HookUsageKind {
   footer = 1 << 18,
   intro = 1 << 19,
   outro = 1 << 20,
+  hmrAccept = 1 << 21,
 }
 
 export class HookUsage {
@@ -214,7 +214,15 @@ export type ChangeEvent = 'create' | 'update' | 'delete';
 
 export type PluginOrder = 'pre' | 'post' | null;
 
-export type ObjectHookMeta = { order?: PluginOrder };
+export type ObjectHookMeta = {
+  order?: PluginOrder;
+  /**
+   * Orders hooks with the same `order`: a lower priority runs earlier, and hooks with equal
+   * priorities run in plugin registration order.
+   * @default 0
+   */
+  priority?: number;
+};
 
 export type ObjectHook<T, O = {}> = T | ({ handler: T } & ObjectHookMeta & O);
 type SyncPluginHooks = DefinedHookNames[
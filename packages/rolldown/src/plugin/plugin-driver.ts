@@ -101,24 +101,30 @@ export function getSortedPlugins(
   hookName: 'options' | 'outputOptions' | 'onLog',
   plugins: readonly Plugin[],
 ): Plugin[] {
-  const pre: Plugin[] = [];
-  const normal: Plugin[] = [];
-  const post: Plugin[] = [];
+  const pre: [Plugin, number][] = [];
+  const normal: [Plugin, number][] = [];
+  const post: [Plugin, number][] = [];
   for (const plugin of plugins) {
     const hook = plugin[hookName];
     if (hook) {
       if (typeof hook === 'object') {
+        const priority = hook.priority ?? 0;
         if (hook.order === 'pre') {
-          pre.push(plugin);
+          pre.push([plugin, priority]);
           continue;
         }
         if (hook.order === 'post') {
-          post.push(plugin);
+          post.push([plugin, priority]);
           continue;
         }
+        normal.push([plugin, priority]);
+        continue;
       }
-      normal.push(plugin);
+      normal.push([plugin, 0]);
     }
   }
-  return [...pre, ...normal, ...post];
+  // `Array.prototype.sort` is stable, so equal priorities keep the registration order.
+  return [pre, normal, post].flatMap((group) =>
+    group.sort(([, a], [, b]) => a - b).map(([plugin]) => plugin),
+  );
 }
@@ -79,6 +79,11 @@ export class TransformPluginContextImpl extends PluginContextImpl {
   public getCombinedSourcemap(): SourceMap {
     return JSON.parse(this.inner.getCombinedSourcemap());
   }
+
+  // The module being transformed is rebuilt whenever a file it watches changes.
+  public addWatchFile(id: string): void {
+    this.inner.addWatchFile(id);
+  }
 }
 
 function _assert() {
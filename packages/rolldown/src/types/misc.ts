@@ -3,6 +3,8 @@ export type SourcemapPathTransformOption = (
   sourcemapPath: string,
 ) => string;
 
+export type SourcemapUrlOption = (mapFileName: string) => string;
+
 export type SourcemapIgnoreListOption = (
   relativeSourcePath: string,
   sourcemapPath: string,
@@ -28,7 +28,15 @@ export interface SourceMap {
 
 export interface RenderedModule {
   readonly code: string | null;
+  /** Bytes of the code of the module after the `load` and `transform` hooks. */
+  originalLength: number;
+  /** Bytes of the module in the chunk, after tree shaking and before minifying. */
   renderedLength: number;
+  /**
+   * Estimated bytes of the module in the minified chunk, when `minify` is enabled. The chunk is
+   * minified as a whole, so this is `renderedLength` scaled by how much the chunk shrank.
+   */
+  minifiedLength: number | null;
   renderedExports: string[];
 }
 
@@ -60,11 +68,23 @@ export interface OutputChunk {
   };
   imports: string[];
   dynamicImports: string[];
+  /**
+   * The names imported from each dependency in `imports`. Namespace imports are listed as `*`.
+   */
+  importedBindings: Record<string, string[]>;
+  /**
+   * The css assets holding the stylesheets imported by the modules of the chunk, to be loaded along with it.
+   */
+  importedCss: string[];
   facadeModuleId: string | null;
   isDynamicEntry: boolean;
   moduleIds: string[];
   map: SourceMap | null;
   sourcemapFileName: string | null;
+  /**
+   * The id written to the sourcemap and the `//# debugId=` comment of the chunk, with `output.sourcemapDebugIds`.
+   */
+  debugId: string | null;
   preliminaryFileName: string;
 }
 
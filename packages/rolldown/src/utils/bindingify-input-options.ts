@@ -88,6 +88,7 @@ export function bindingifyInputOptions(
       attachDebugInfo: bindingifyAttachDebugInfo(
         inputOptions.experimental?.attachDebugInfo,
       ),
+      deterministic: inputOptions.experimental?.deterministic,
     },
     profilerNames: inputOptions?.profilerNames,
     jsx,
@@ -112,6 +113,7 @@ export function bindingifyInputOptions(
       inputOptions.makeAbsoluteExternalsRelative,
     ),
     debug: inputOptions.debug,
+    debugPluginOrder: inputOptions.debugPluginOrder,
     invalidateJsSideCache: pluginContextData.clear.bind(pluginContextData),
     markModuleLoaded: pluginContextData.markModuleLoaded.bind(
       pluginContextData,
@@ -274,8 +276,16 @@ function bindingifyInput(
     return input.map((src) => ({ import: src }));
   }
 
-  return Object.entries(input).map(([name, import_path]) => {
-    return { name, import: import_path };
+  return Object.entries(input).map(([name, item]) => {
+    if (typeof item === 'string') {
+      return { name, import: item };
+    }
+    return {
+      name,
+      import: item.import,
+      fileName: item.fileName,
+      banner: item.banner,
+    };
   });
 }
 
@@ -15,8 +15,11 @@ export function bindingifyOutputOptions(
     hashCharacters,
     sourcemap,
     sourcemapDebugIds,
+    sourcemapIncludeSources,
+    sourcemapUrl,
     sourcemapIgnoreList,
     sourcemapPathTransform,
+    manifest,
     name,
     assetFileNames,
     entryFileNames,
@@ -29,6 +32,8 @@ export function bindingifyOutputOptions(
     outro,
     esModule,
     globals,
+    paths,
+    generatedCode,
     file,
     sanitizeFileName,
     preserveModules,
@@ -46,14 +51,21 @@ export function bindingifyOutputOptions(
     hashCharacters,
     sourcemap: bindingifySourcemap(sourcemap),
     sourcemapDebugIds,
+    sourcemapIncludeSources,
+    sourcemapUrl,
     sourcemapIgnoreList: bindingifySourcemapIgnoreList(sourcemapIgnoreList),
     sourcemapPathTransform,
+    manifest,
     banner: bindingifyAddon(banner),
     footer: bindingifyAddon(footer),
     intro: bindingifyAddon(intro),
     outro: bindingifyAddon(outro),
     extend: outputOptions.extend,
     globals,
+    paths,
+    generatedCode: typeof generatedCode === 'string'
+      ? { preset: generatedCode }
+      : generatedCode,
     esModule,
     name,
     assetFileNames: bindingifyAssetFilenames(assetFileNames),
@@ -64,10 +76,12 @@ export function bindingifyOutputOptions(
     // TODO(sapphi-red): support parallel plugins
     plugins: [],
     minify: outputOptions.minify,
+    compact: outputOptions.compact,
     externalLiveBindings: outputOptions.externalLiveBindings,
     inlineDynamicImports: outputOptions.inlineDynamicImports,
     advancedChunks: outputOptions.advancedChunks,
     polyfillRequire: outputOptions.polyfillRequire,
+    hoistTransitiveImports: outputOptions.hoistTransitiveImports,
     sanitizeFileName,
     preserveModules,
     virtualDirname,
@@ -485,8 +485,8 @@ function isComposablePlugin(plugin: RolldownPlugin): plugin is Plugin {
 
     if (plugin[hookName]) {
       const { meta } = normalizeHook(plugin[hookName]);
-      // if `order` is specified with `pre` or `post`, it's unsafe to compose this plugin
-      if (meta.order === 'pre' || meta.order === 'post') {
+      // if `order` is specified with `pre` or `post` or `priority` is set, it's unsafe to compose this plugin
+      if (meta.order === 'pre' || meta.order === 'post' || meta.priority) {
         return !OK_TO_COMPOSE;
       }
     }
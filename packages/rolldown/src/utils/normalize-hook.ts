@@ -31,12 +31,13 @@ export function normalizeHook<Hook extends ObjectHook<AnyFn | string>>(
   }
 
   if (typeof hook === 'object' && hook !== null) {
-    const { handler, order, ...options } = hook;
+    const { handler, order, priority, ...options } = hook;
     return {
       handler,
       options,
       meta: {
         order,
+        priority,
       },
     } as Return;
   }
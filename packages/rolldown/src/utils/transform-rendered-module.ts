@@ -8,8 +8,14 @@ export function transformToRenderedModule(
     get code() {
       return bindingRenderedModule.code;
     },
+    get originalLength() {
+      return bindingRenderedModule.originalLength;
+    },
     get renderedLength() {
-      return bindingRenderedModule.code?.length || 0;
+      return bindingRenderedModule.renderedLength;
+    },
+    get minifiedLength() {
+      return bindingRenderedModule.minifiedLength;
     },
     get renderedExports() {
       return bindingRenderedModule.renderedExports;
@@ -59,6 +59,12 @@ function transformToRollupOutputChunk(
     get dynamicImports() {
       return bindingChunk.dynamicImports;
     },
+    get importedBindings() {
+      return bindingChunk.importedBindings;
+    },
+    get importedCss() {
+      return bindingChunk.importedCss;
+    },
     exports: bindingChunk.exports,
     isEntry: bindingChunk.isEntry,
     facadeModuleId: bindingChunk.facadeModuleId || null,
@@ -72,6 +78,7 @@ function transformToRollupOutputChunk(
         : null;
     },
     sourcemapFileName: bindingChunk.sourcemapFileName || null,
+    debugId: bindingChunk.debugId || null,
     preliminaryFileName: bindingChunk.preliminaryFileName,
   } as OutputChunk;
   const cache: Record<string | symbol, any> = {};
@@ -10,6 +10,7 @@ import type {
 import type {
   SourcemapIgnoreListOption,
   SourcemapPathTransformOption,
+  SourcemapUrlOption,
 } from '../types/misc';
 import type { RenderedChunk } from '../types/rolldown-output';
 import type { ObjectSchema } from '../types/schema';
@@ -30,10 +31,16 @@ const RollupLogWithStringSchema = v.union([RollupLogSchema, v.string()]);
 
 /// --- InputSchema ---
 
+const InputItemOptionSchema = v.strictObject({
+  import: v.string(),
+  fileName: v.optional(v.string()),
+  banner: v.optional(v.string()),
+});
+
 const InputOptionSchema = v.union([
   v.string(),
   v.array(v.string()),
-  v.record(v.string(), v.string()),
+  v.record(v.string(), v.union([v.string(), InputItemOptionSchema])),
 ]);
 
 const ExternalSchema = v.union([
@@ -77,7 +84,7 @@ const JsxOptionsSchema = v.strictObject({
     v.description('Development specific information'),
   ),
   throwIfNamespace: v.pipe(
-    v.optional(v.string()),
+    v.optional(v.boolean()),
     v.description(
       'Toggles whether to throw an error when a tag name uses an XML namespace',
     ),
@@ -85,14 +92,14 @@ const JsxOptionsSchema = v.strictObject({
   importSource: v.pipe(
     v.optional(v.string()),
     v.description(
-      'Import the factory of element and fragment if mode is classic',
+      'Import the `jsx`/`jsxs` factories from `<importSource>/jsx-runtime` if runtime is automatic',
     ),
   ),
   pragma: v.pipe(
     v.optional(v.string()),
     v.description('Jsx element transformation'),
   ),
-  pragmaFlag: v.pipe(
+  pragmaFrag: v.pipe(
     v.optional(
       v.string(),
     ),
@@ -263,6 +270,42 @@ const ChecksOptionsSchema = v.strictObject({
       'Whether to emit warning when detecting configuration field conflict',
     ),
   ),
+  unsupportedImportAttributes: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      "Whether to emit warning when import attributes of an external module can't be kept in the output format",
+    ),
+  ),
+  shimmedExport: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when a missing export is shimmed by `shimMissingExports`',
+    ),
+  ),
+  pluginWarning: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warnings reported by plugins through `PluginContext::warn`',
+    ),
+  ),
+  hmrExportChange: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      "Whether to emit warning when a hot update changes the exports of a module accepting it, with `experimental.hmr.exportChange: 'warn'`",
+    ),
+  ),
+  duplicatePackage: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when a package is bundled in more than one version',
+    ),
+  ),
+  pluginOrder: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit the order plugin hooks run in, with `debugPluginOrder: true`',
+    ),
+  ),
 });
 
 const MinifyOptionsSchema = v.strictObject({
@@ -336,7 +379,21 @@ const HmrSchema = v.union([
   v.strictObject({
     port: v.optional(v.number()),
     host: v.optional(v.string()),
-    implement: v.optional(v.string()),
+    implement: v.optional(
+      v.union([
+        v.string(),
+        v.strictObject({
+          esm: v.optional(v.string()),
+          cjs: v.optional(v.string()),
+          iife: v.optional(v.string()),
+          umd: v.optional(v.string()),
+        }),
+      ]),
+    ),
+    exportChange: v.optional(
+      v.union([v.literal('full-reload'), v.literal('warn')]),
+    ),
+    maxPatchSize: v.optional(v.number()),
   }),
 ]);
 
@@ -393,6 +450,7 @@ const InputOptionsSchema = v.strictObject({
         v.literal('simple'),
         v.literal('full'),
       ])),
+      deterministic: v.optional(v.boolean()),
     }),
   ),
   define: v.pipe(
@@ -437,6 +495,10 @@ const InputOptionsSchema = v.strictObject({
       'Enable debug mode. Emit debug information to disk. This might slow down the build process significantly.',
     ),
   ),
+  debugPluginOrder: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Log the order plugins run in for each hook'),
+  ),
   preserveEntrySignatures: v.pipe(
     v.optional(v.union([
       v.literal('strict'),
@@ -557,6 +619,40 @@ const GlobalsFunctionSchema = v.pipe(
   v.returns(v.string()),
 );
 
+const PathsFunctionSchema = v.pipe(
+  v.function(),
+  v.args(v.tuple([v.string()])),
+  v.returns(v.string()),
+);
+
+const GeneratedCodePresetSchema = v.union([
+  v.literal('es5'),
+  v.literal('es2015'),
+]);
+
+const GeneratedCodeSchema = v.strictObject({
+  preset: v.pipe(
+    v.optional(GeneratedCodePresetSchema),
+    v.description('Syntax level of generated code'),
+  ),
+  arrowFunctions: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Use arrow functions in generated code'),
+  ),
+  constBindings: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Use `const` for bindings in generated code'),
+  ),
+  objectShorthand: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Use object shorthands in generated code'),
+  ),
+  symbols: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Mark namespaces with `Symbol.toStringTag`'),
+  ),
+});
+
 const AdvancedChunksSchema = v.strictObject({
   minSize: v.optional(v.number()),
   maxSize: v.optional(v.number()),
@@ -640,6 +736,17 @@ const OutputOptionsSchema = v.strictObject({
     v.optional(v.boolean()),
     v.description('Inject sourcemap debug IDs'),
   ),
+  sourcemapIncludeSources: v.pipe(
+    v.optional(v.boolean()),
+    v.description('embedding the source contents in sourcemaps'),
+  ),
+  manifest: v.pipe(
+    v.optional(v.string()),
+    v.description('File name of the build manifest asset'),
+  ),
+  sourcemapUrl: v.optional(
+    v.union([v.string(), v.custom<SourcemapUrlOption>(() => true)]),
+  ),
   sourcemapIgnoreList: v.optional(
     v.union([v.boolean(), v.custom<SourcemapIgnoreListOption>(() => true)]),
   ),
@@ -669,6 +776,10 @@ const OutputOptionsSchema = v.strictObject({
     ),
     v.description('Minify the bundled file'),
   ),
+  compact: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Print the bundled file without unneeded whitespace'),
+  ),
   name: v.pipe(
     v.optional(v.string()),
     v.description('Name for UMD / IIFE format outputs'),
@@ -681,6 +792,15 @@ const OutputOptionsSchema = v.strictObject({
       'Global variable of UMD / IIFE dependencies (syntax: `key=value`)',
     ),
   ),
+  paths: v.pipe(
+    v.optional(
+      v.union([v.record(v.string(), v.string()), PathsFunctionSchema]),
+    ),
+    v.description('Maps external module ids to paths'),
+  ),
+  generatedCode: v.optional(
+    v.union([GeneratedCodePresetSchema, GeneratedCodeSchema]),
+  ),
   externalLiveBindings: v.pipe(
     v.optional(v.boolean()),
     v.description('external live bindings'),
@@ -702,13 +822,9 @@ const OutputOptionsSchema = v.strictObject({
     v.optional(v.boolean()),
     v.description('Disable require polyfill injection'),
   ),
-  hoistTransitiveImports: v.optional(
-    v.custom<boolean, () => string>((input) => {
-      if (input) {
-        return false;
-      }
-      return true;
-    }, () => `The 'true' value is not supported`),
+  hoistTransitiveImports: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Hoist imports of transitive dependencies into entry chunks'),
   ),
   preserveModules: v.pipe(
     v.optional(v.boolean()),
@@ -756,6 +872,10 @@ const OutputCliOverrideSchema = v.strictObject({
     v.optional(v.boolean()),
     v.description('Sanitize file name'),
   ),
+  sourcemapUrl: v.pipe(
+    v.optional(v.string()),
+    v.description('Base URL of the sourcemaps in `sourceMappingURL` comments'),
+  ),
   banner: v.pipe(
     v.optional(v.string()),
     v.description(getAddonDescription('top', 'outside')),
@@ -818,6 +938,8 @@ const OutputCliOptionsSchema = v.omit(
     'sourcemapPathTransform',
     'plugins',
     'hoistTransitiveImports',
+    'paths',
+    'generatedCode',
   ],
 );
 
@@ -35,6 +35,8 @@ export default defineTest({
             '//#region main.js\nconsole.log();\n\n//#endregion',
           )
           expect(Object.values(chunk.modules)[0].renderedLength).toBe(46)
+          expect(Object.values(chunk.modules)[0].originalLength).toBe(32)
+          expect(Object.values(chunk.modules)[0].minifiedLength).toBeNull()
           expect(chunk.map).toBeDefined()
           expect(chunk.map!.toString()).toContain('"version":')
           // called bundle.generate()
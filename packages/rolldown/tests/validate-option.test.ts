@@ -43,16 +43,16 @@ test('validate output option', async () => {
 - For the "foo". Invalid key: Expected never but received "foo". `)
 })
 
-test('give a warning for hoistTransitiveImports: true', async () => {
+test('give a warning for invalid hoistTransitiveImports', async () => {
   const consoleSpy = vi.spyOn(console, 'warn')
-    const bundle = await rolldown({
-      input: './build-api/main.js',
-      cwd: import.meta.dirname,
-    })
-    await bundle.write({
-      // @ts-ignore  invalid value
-      hoistTransitiveImports: true
-    })
-    expect(consoleSpy).toHaveBeenCalledWith(`Warning validate output options.
-- For the "hoistTransitiveImports". The 'true' value is not supported. `)
+  const bundle = await rolldown({
+    input: './build-api/main.js',
+    cwd: import.meta.dirname,
+  })
+  await bundle.write({
+    // @ts-ignore  invalid value
+    hoistTransitiveImports: 'yes',
+  })
+  expect(consoleSpy).toHaveBeenCalledWith(`Warning validate output options.
+- For the "hoistTransitiveImports". Invalid type: Expected boolean but received "yes". `)
 })
@@ -406,6 +406,41 @@ test.sequential('watch include/exclude', async () => {
   await watcher.close()
 })
 
+test.sequential('watch excludes node_modules by default', async () => {
+  const { input, output, dir } = await createTestInputAndOutput(
+    'exclude-node-modules',
+    `import './node_modules/dep/index.js'\nimport './foo.js'`,
+  )
+  const dep = path.join(dir, 'node_modules/dep/index.js')
+  fs.mkdirSync(path.dirname(dep), { recursive: true })
+  fs.writeFileSync(dep, 'console.log("dep 1")')
+  const foo = path.join(dir, 'foo.js')
+  fs.writeFileSync(foo, 'console.log("foo 1")')
+  const watcher = watch({
+    input,
+    output: { file: output },
+  })
+  await waitBuildFinished(watcher)
+
+  const restartFn = vi.fn()
+  watcher.on('restart', restartFn)
+
+  // The dependency is in the module graph, but it is excluded, so no rebuild happens
+  fs.writeFileSync(dep, 'console.log("dep 2")')
+  await sleep(200)
+  expect(restartFn).not.toBeCalled()
+
+  fs.writeFileSync(foo, 'console.log("foo 2")')
+  await waitUtil(() => {
+    const code = fs.readFileSync(output, 'utf-8')
+    expect(code.includes('foo 2')).toBe(true)
+    expect(code.includes('dep 2')).toBe(true)
+    expect(restartFn).toBeCalledTimes(1)
+  })
+
+  await watcher.close()
+})
+
 test.sequential('error handling', async () => {
   // first build error, the watching could be work with recover error
   const { input, output } = await createTestInputAndOutput(
@@ -11,7 +11,7 @@ pub struct HookUsageGenerator {}
 
 define_generator!(HookUsageGenerator);
 
-const HOOK_KIND: [&str; 21] = [
+const HOOK_KIND: [&str; 22] = [
   "build_start",
   "resolve_id",
   "resolve_dynamic_import",
@@ -33,9 +33,10 @@ const HOOK_KIND: [&str; 21] = [
   "footer",
   "intro",
   "outro",
+  "hmr_accept",
 ];
 
-const DISABLE_JS_HOOK: [&str; 1] = ["transform_ast"];
+const DISABLE_JS_HOOK: [&str; 2] = ["transform_ast", "hmr_accept"];
 
 impl Generator for HookUsageGenerator {
   fn generate_many(&self, _ctx: &Context) -> anyhow::Result<Vec<crate::output::Output>> {